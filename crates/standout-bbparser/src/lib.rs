@@ -40,6 +40,19 @@
 //!
 //! For validation, use [`BBParser::validate`] to check for unknown tags before parsing.
 //!
+//! # Void Tags
+//!
+//! Tags registered with [`BBParser::void_tag`] take no content and no closing
+//! tag. They expand to a fixed string, e.g. `[br]` → `\n`:
+//!
+//! ```rust
+//! use standout_bbparser::{BBParser, TagTransform};
+//! use std::collections::HashMap;
+//!
+//! let parser = BBParser::new(HashMap::new(), TagTransform::Remove).void_tag("br", "\n");
+//! assert_eq!(parser.parse("one[br]two"), "one\ntwo");
+//! ```
+//!
 //! # Tag Name Syntax
 //!
 //! Tag names follow CSS identifier rules:
@@ -49,6 +62,13 @@
 //! - Case-sensitive (lowercase recommended)
 //!
 //! Pattern: `[a-z_][a-z0-9_-]*`
+//!
+//! # Escaping Brackets
+//!
+//! A backslash before a bracket produces a literal bracket that is never read
+//! as a tag delimiter, in every transform mode: `\[todo\]` → `[todo]`.
+//! Escaped brackets are not reported by [`BBParser::parse_with_diagnostics`].
+//! A backslash before any other character is kept as-is.
 
 use console::Style;
 use std::collections::HashMap;
@@ -99,6 +119,38 @@ pub enum UnknownTagKind {
     UnexpectedClose,
 }
 
+impl UnknownTagKind {
+    /// How serious this kind of problem is.
+    ///
+    /// Unknown tags are [`TagSeverity::Warning`]s: the markup is well formed and
+    /// the style may simply not be in this theme. Unbalanced and unexpected
+    /// closing tags are [`TagSeverity::Error`]s.
+    pub fn severity(self) -> TagSeverity {
+        match self {
+            UnknownTagKind::Open | UnknownTagKind::Close => TagSeverity::Warning,
+            UnknownTagKind::Unbalanced | UnknownTagKind::UnexpectedClose => TagSeverity::Error,
+        }
+    }
+}
+
+/// Severity of a tag diagnostic, ordered from least to most serious.
+#[derive(Debug, Clone, Copy, PartialEq, Eq, PartialOrd, Ord, Hash)]
+pub enum TagSeverity {
+    /// The tag is well formed but has no style.
+    Warning,
+    /// The markup is malformed.
+    Error,
+}
+
+impl std::fmt::Display for TagSeverity {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        match self {
+            TagSeverity::Warning => write!(f, "warning"),
+            TagSeverity::Error => write!(f, "error"),
+        }
+    }
+}
+
 /// An error representing an unknown tag in the input.
 #[derive(Debug, Clone, PartialEq, Eq)]
 pub struct UnknownTagError {
@@ -112,6 +164,13 @@ pub struct UnknownTagError {
     pub end: usize,
 }
 
+impl UnknownTagError {
+    /// Returns the severity of this problem; see [`UnknownTagKind::severity`].
+    pub fn severity(&self) -> TagSeverity {
+        self.kind.severity()
+    }
+}
+
 impl std::fmt::Display for UnknownTagError {
     fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
         let kind = match self.kind {
@@ -157,6 +216,15 @@ impl UnknownTagErrors {
     pub fn push(&mut self, error: UnknownTagError) {
         self.errors.push(error);
     }
+
+    /// Returns the most serious severity in the collection, or `None` if it
+    /// is empty.
+    ///
+    /// A lint command can fail only on [`TagSeverity::Error`] and print
+    /// warnings for unknown tags.
+    pub fn max_severity(&self) -> Option<TagSeverity> {
+        self.errors.iter().map(UnknownTagError::severity).max()
+    }
 }
 
 impl std::fmt::Display for UnknownTagErrors {
@@ -198,6 +266,7 @@ pub struct BBParser {
     styles: HashMap<String, Style>,
     transform: TagTransform,
     unknown_behavior: UnknownTagBehavior,
+    void_tags: HashMap<String, String>,
 }
 
 impl BBParser {
@@ -215,6 +284,7 @@ impl BBParser {
             styles,
             transform,
             unknown_behavior: UnknownTagBehavior::default(),
+            void_tags: HashMap::new(),
         }
     }
 
@@ -237,6 +307,36 @@ impl BBParser {
         self
     }
 
+    /// Registers a void tag: `[name]` with no closing tag that expands to
+    /// `expansion`.
+    ///
+    /// The expansion is emitted in both `Apply` and `Remove` modes, so a
+    /// `[br]` keeps its line break in plain text. In `Apply` mode it is styled
+    /// with the style of the same name, if there is one; `Keep` mode shows
+    /// the tag itself. Void tags are never reported as unknown or unbalanced.
+    ///
+    /// The name must follow the tag name rules. For a rule that spans the
+    /// terminal, build the expansion from the current width:
+    ///
+    /// ```rust
+    /// use standout_bbparser::{BBParser, TagTransform};
+    /// use console::Style;
+    /// use std::collections::HashMap;
+    ///
+    /// let width = 12; // e.g. from console::Term::stdout().size().1
+    /// let mut styles = HashMap::new();
+    /// styles.insert("hr".to_string(), Style::new().dim());
+    ///
+    /// let parser = BBParser::new(styles, TagTransform::Remove)
+    ///     .void_tag("br", "\n")
+    ///     .void_tag("hr", "─".repeat(width));
+    /// assert_eq!(parser.parse("Title[br][hr]"), "Title\n────────────");
+    /// ```
+    pub fn void_tag(mut self, name: impl Into<String>, expansion: impl Into<String>) -> Self {
+        self.void_tags.insert(name.into(), expansion.into());
+        self
+    }
+
     /// Parses and transforms input.
     ///
     /// Unknown tags are handled according to the configured [`UnknownTagBehavior`].
@@ -268,12 +368,17 @@ impl BBParser {
 
     /// Validates input for unknown tags without producing transformed output.
     ///
-    /// Returns `Ok(())` if all tags are known, `Err` with details otherwise.
+    /// Only tag balance and known-ness are checked; no styles are applied and
+    /// no output string is built, so it is cheap enough for linting templates
+    /// or editor integrations. Returns `Ok(())` if all tags are known and
+    /// balanced, `Err` with every problem otherwise. Each error carries its
+    /// byte offsets and a [`TagSeverity`]: unknown tags are warnings, unbalanced
+    /// and unexpected closing tags are errors.
     ///
     /// # Example
     ///
     /// ```rust
-    /// use standout_bbparser::{BBParser, TagTransform};
+    /// use standout_bbparser::{BBParser, TagSeverity, TagTransform};
     /// use std::collections::HashMap;
     /// use console::Style;
     ///
@@ -288,9 +393,14 @@ impl BBParser {
     /// // Unknown tag fails validation
     /// let result = parser.validate("[unknown]text[/unknown]");
     /// assert!(result.is_err());
+    ///
+    /// // Malformed markup is an error rather than a warning
+    /// let errors = parser.validate("[bold]text").unwrap_err();
+    /// assert_eq!(errors.max_severity(), Some(TagSeverity::Error));
+    /// assert_eq!((errors.errors[0].start, errors.errors[0].end), (0, 6));
     /// ```
     pub fn validate(&self, input: &str) -> Result<(), UnknownTagErrors> {
-        let (_, errors) = self.parse_internal(input);
+        let (_, errors) = self.parse_events(input);
         if errors.is_empty() {
             Ok(())
         } else {
@@ -300,6 +410,12 @@ impl BBParser {
 
     /// Internal parsing that returns both output and errors.
     fn parse_internal(&self, input: &str) -> (String, UnknownTagErrors) {
+        let (events, errors) = self.parse_events(input);
+        (self.render(events), errors)
+    }
+
+    /// Matches tags and collects the events to render, without rendering.
+    fn parse_events<'a>(&self, input: &'a str) -> (Vec<ParseEvent<'a>>, UnknownTagErrors) {
         let tokens = Tokenizer::new(input).collect::<Vec<_>>();
         let valid_opens = self.compute_valid_tags(&tokens);
         let mut events = Vec::new();
@@ -314,6 +430,9 @@ impl BBParser {
                 Token::Text { content, .. } => {
                     events.push(ParseEvent::Literal(std::borrow::Cow::Borrowed(content)));
                 }
+                Token::OpenTag { name, .. } if self.void_tags.contains_key(*name) => {
+                    self.emit_void_tag_event(&mut events, name);
+                }
                 Token::OpenTag { name, start, end } => {
                     if valid_opens.contains(&i) {
                         stack.push(name);
@@ -373,7 +492,7 @@ impl BBParser {
                         ))));
                     }
                 }
-                Token::InvalidTag { content, .. } => {
+                Token::InvalidTag { content, .. } | Token::Escaped { content, .. } => {
                     events.push(ParseEvent::Literal(std::borrow::Cow::Borrowed(content)));
                 }
             }
@@ -384,8 +503,7 @@ impl BBParser {
             self.emit_close_tag_event(&mut events, &mut errors, tag, 0, 0);
         }
 
-        let output = self.render(events);
-        (output, errors)
+        (events, errors)
     }
 
     fn emit_open_tag_event<'a>(
@@ -437,6 +555,27 @@ impl BBParser {
         }
     }
 
+    fn emit_void_tag_event<'a>(&self, events: &mut Vec<ParseEvent<'a>>, tag: &'a str) {
+        let expansion = std::borrow::Cow::Owned(self.void_tags[tag].clone());
+
+        match self.transform {
+            TagTransform::Keep => {
+                events.push(ParseEvent::Literal(std::borrow::Cow::Owned(format!(
+                    "[{}]",
+                    tag
+                ))));
+            }
+            TagTransform::Apply if self.styles.contains_key(tag) => {
+                events.push(ParseEvent::StyleStart(tag));
+                events.push(ParseEvent::Literal(expansion));
+                events.push(ParseEvent::StyleEnd(tag));
+            }
+            TagTransform::Apply | TagTransform::Remove => {
+                events.push(ParseEvent::Literal(expansion));
+            }
+        }
+    }
+
     fn emit_close_tag_event<'a>(
         &self,
         events: &mut Vec<ParseEvent<'a>>,
@@ -521,7 +660,7 @@ impl BBParser {
 
         for (i, token) in tokens.iter().enumerate() {
             match token {
-                Token::OpenTag { name, .. } => {
+                Token::OpenTag { name, .. } if !self.void_tags.contains_key(*name) => {
                     open_indices_by_tag.entry(name).or_default().push(i);
                 }
                 Token::CloseTag { name, .. } => {
@@ -595,6 +734,12 @@ enum Token<'a> {
         start: usize,
         end: usize,
     },
+    /// Escaped bracket: `\[` or `\]`. `content` is the bare bracket.
+    Escaped {
+        content: &'a str,
+        start: usize,
+        end: usize,
+    },
 }
 
 /// Tokenizer for BBCode-style tags.
@@ -631,6 +776,14 @@ impl<'a> Tokenizer<'a> {
 
         true
     }
+
+    /// Finds the next `[` or escaped bracket (`\[`, `\]`) in `s`.
+    fn find_special(s: &str) -> Option<usize> {
+        let bytes = s.as_bytes();
+        bytes.iter().enumerate().position(|(i, &b)| {
+            b == b'[' || (b == b'\\' && matches!(bytes.get(i + 1), Some(b'[' | b']')))
+        })
+    }
 }
 
 impl<'a> Iterator for Tokenizer<'a> {
@@ -644,8 +797,8 @@ impl<'a> Iterator for Tokenizer<'a> {
         let remaining = &self.input[self.pos..];
         let start_pos = self.pos;
 
-        // Look for the next '['
-        if let Some(bracket_pos) = remaining.find('[') {
+        // Look for the next '[' or escaped bracket
+        if let Some(bracket_pos) = Self::find_special(remaining) {
             if bracket_pos > 0 {
                 // There's text before the bracket
                 let text = &remaining[..bracket_pos];
@@ -657,10 +810,30 @@ impl<'a> Iterator for Tokenizer<'a> {
                 });
             }
 
+            if remaining.starts_with('\\') {
+                self.pos += 2;
+                return Some(Token::Escaped {
+                    content: &remaining[1..2],
+                    start: start_pos,
+                    end: self.pos,
+                });
+            }
+
             // We're at a '['
             // Try to parse a tag
             if let Some(close_bracket) = remaining.find(']') {
                 let tag_content = &remaining[1..close_bracket];
+
+                // `[a\]` or `[a \[b]`: the bracket can't start a tag, and the
+                // escapes after it still need to be unescaped.
+                if tag_content.contains('\\') {
+                    self.pos += 1;
+                    return Some(Token::Text {
+                        content: &remaining[..1],
+                        start: start_pos,
+                        end: self.pos,
+                    });
+                }
                 let full_tag = &remaining[..=close_bracket];
                 let end_pos = start_pos + close_bracket + 1;
 
@@ -908,6 +1081,58 @@ mod tests {
         }
     }
 
+    // ==================== Void Tag Tests ====================
+
+    mod void_tags {
+        use super::*;
+
+        fn parser(transform: TagTransform) -> BBParser {
+            BBParser::new(test_styles(), transform)
+                .void_tag("br", "\n")
+                .void_tag("hr", "----")
+        }
+
+        #[test]
+        fn remove_mode_expands() {
+            let parser = parser(TagTransform::Remove);
+            assert_eq!(parser.parse("a[br]b[hr]"), "a\nb----");
+        }
+
+        #[test]
+        fn keep_mode_shows_tag() {
+            let parser = parser(TagTransform::Keep);
+            assert_eq!(parser.parse("a[br]b"), "a[br]b");
+        }
+
+        #[test]
+        fn apply_mode_expands_without_style() {
+            let parser = parser(TagTransform::Apply);
+            assert_eq!(parser.parse("a[br]b"), "a\nb");
+        }
+
+        #[test]
+        fn apply_mode_uses_same_named_style() {
+            let mut styles = HashMap::new();
+            styles.insert("hr".to_string(), Style::new().dim().force_styling(true));
+            let parser = BBParser::new(styles, TagTransform::Apply).void_tag("hr", "----");
+            assert_eq!(parser.parse("[hr]"), "\x1b[2m----\x1b[0m");
+        }
+
+        #[test]
+        fn no_diagnostics_for_void_tags() {
+            let parser = parser(TagTransform::Apply);
+            let (_, errors) = parser.parse_with_diagnostics("[br][bold]x[hr]y[/bold][br]");
+            assert!(errors.is_empty());
+        }
+
+        #[test]
+        fn unregistered_tag_still_unbalanced() {
+            let parser = BBParser::new(test_styles(), TagTransform::Remove);
+            let (_, errors) = parser.parse_with_diagnostics("a[br]b");
+            assert_eq!(errors.errors[0].kind, UnknownTagKind::Unbalanced);
+        }
+    }
+
     // ==================== Validation Tests ====================
 
     mod validation {
@@ -1029,6 +1254,61 @@ mod tests {
             let parser = BBParser::new(test_styles(), TagTransform::Apply);
             assert!(parser.validate("").is_ok());
         }
+
+        #[test]
+        fn validate_clean_input_passes() {
+            let parser = BBParser::new(test_styles(), TagTransform::Apply).void_tag("br", "\n");
+            assert!(parser
+                .validate(r"[bold]a[br][red]\[b\][/red][/bold] array[0]")
+                .is_ok());
+        }
+
+        #[test]
+        fn validate_unknown_tags_are_warnings() {
+            let parser = BBParser::new(test_styles(), TagTransform::Apply);
+            let errors = parser.validate("[foo]a[/foo]").unwrap_err();
+            assert!(errors
+                .errors
+                .iter()
+                .all(|e| e.severity() == TagSeverity::Warning));
+            assert_eq!(errors.max_severity(), Some(TagSeverity::Warning));
+        }
+
+        #[test]
+        fn validate_unbalanced_tags_are_errors() {
+            let parser = BBParser::new(test_styles(), TagTransform::Apply);
+            let errors = parser.validate("x [bold]a [/red]").unwrap_err();
+            assert_eq!(
+                errors.errors,
+                vec![
+                    UnknownTagError {
+                        tag: "bold".to_string(),
+                        kind: UnknownTagKind::Unbalanced,
+                        start: 2,
+                        end: 8,
+                    },
+                    UnknownTagError {
+                        tag: "red".to_string(),
+                        kind: UnknownTagKind::UnexpectedClose,
+                        start: 10,
+                        end: 16,
+                    },
+                ]
+            );
+            assert_eq!(errors.max_severity(), Some(TagSeverity::Error));
+        }
+
+        #[test]
+        fn validate_ignores_transform_mode() {
+            for transform in [
+                TagTransform::Apply,
+                TagTransform::Remove,
+                TagTransform::Keep,
+            ] {
+                let parser = BBParser::new(test_styles(), transform);
+                assert_eq!(parser.validate("[foo]a[/foo]").unwrap_err().len(), 2);
+            }
+        }
     }
 
     // ==================== Parse With Diagnostics Tests ====================
@@ -1225,6 +1505,14 @@ mod tests {
             assert_eq!(parser.parse("hello [bold world"), "hello [bold world");
         }
 
+        #[test]
+        fn backslash_before_other_chars_kept() {
+            let parser = BBParser::new(test_styles(), TagTransform::Remove);
+            assert_eq!(parser.parse(r"C:\temp [bold]x[/bold]"), r"C:\temp x");
+            assert_eq!(parser.parse(r"[foo\bar]"), r"[foo\bar]");
+            assert_eq!(parser.parse(r"[a \[b]"), "[a [b]");
+        }
+
         #[test]
         fn multiline_content() {
             let parser = BBParser::new(test_styles(), TagTransform::Remove);
@@ -1353,6 +1641,36 @@ mod tests {
             );
         }
 
+        #[test]
+        fn tokenize_escaped_brackets() {
+            let tokens: Vec<_> = Tokenizer::new(r"a\[b\]").collect();
+            assert_eq!(
+                tokens,
+                vec![
+                    Token::Text {
+                        content: "a",
+                        start: 0,
+                        end: 1
+                    },
+                    Token::Escaped {
+                        content: "[",
+                        start: 1,
+                        end: 3
+                    },
+                    Token::Text {
+                        content: "b",
+                        start: 3,
+                        end: 4
+                    },
+                    Token::Escaped {
+                        content: "]",
+                        start: 4,
+                        end: 6
+                    },
+                ]
+            );
+        }
+
         #[test]
         fn tokenize_mixed() {
             let tokens: Vec<_> = Tokenizer::new("a[b]c[/b]d").collect();
@@ -105,3 +105,58 @@ fn test_unexpected_close_raises_error() {
         "Expected errors for unexpected close tag 'text[/bold]'"
     );
 }
+
+#[test]
+fn test_escaped_brackets_keep() {
+    let parser = BBParser::new(test_styles(), TagTransform::Keep);
+    assert_eq!(
+        parser.parse(r"[bold]\[todo\] ship it[/bold]"),
+        "[bold][todo] ship it[/bold]"
+    );
+    assert_eq!(parser.parse(r"\[bold\]x\[/bold\]"), "[bold]x[/bold]");
+}
+
+#[test]
+fn test_escaped_brackets_remove() {
+    let parser = BBParser::new(test_styles(), TagTransform::Remove);
+    assert_eq!(
+        parser.parse(r"[bold]\[todo\] ship it[/bold]"),
+        "[todo] ship it"
+    );
+    // Escaped brackets never form a tag, even around a known style name
+    assert_eq!(parser.parse(r"\[bold\]x\[/bold\]"), "[bold]x[/bold]");
+}
+
+#[test]
+fn test_escaped_brackets_apply() {
+    let parser = BBParser::new(test_styles(), TagTransform::Apply);
+    let output = parser.parse(r"[red]\[not a tag\][/red] \[bold\]");
+    assert!(output.contains("\x1b[31m"));
+    assert_eq!(console::strip_ansi_codes(&output), "[not a tag] [bold]");
+}
+
+#[test]
+fn test_escaped_brackets_not_flagged() {
+    let parser = BBParser::new(test_styles(), TagTransform::Apply);
+    let (output, errors) = parser.parse_with_diagnostics(r"\[todo\] fix \[/done\] \]");
+    assert!(errors.is_empty(), "unexpected errors: {}", errors);
+    assert_eq!(output, "[todo] fix [/done] ]");
+}
+
+#[test]
+fn test_void_tags_with_styled_content() {
+    let input = "[bold]Title[/bold][br][hr][br][red]a[br]b[/red]";
+    let void_tags = |parser: BBParser| parser.void_tag("br", "\n").void_tag("hr", "===");
+
+    let parser = void_tags(BBParser::new(test_styles(), TagTransform::Remove));
+    assert_eq!(parser.parse(input), "Title\n===\na\nb");
+
+    let parser = void_tags(BBParser::new(test_styles(), TagTransform::Keep));
+    assert_eq!(parser.parse(input), input);
+
+    let parser = void_tags(BBParser::new(test_styles(), TagTransform::Apply));
+    let (output, errors) = parser.parse_with_diagnostics(input);
+    assert!(errors.is_empty(), "unexpected errors: {}", errors);
+    assert!(output.contains("\x1b[31m"));
+    assert_eq!(console::strip_ansi_codes(&output), "Title\n===\na\nb");
+}
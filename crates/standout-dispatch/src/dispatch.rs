@@ -3,12 +3,14 @@
 //! Core utilities for extracting command paths from clap ArgMatches
 //! and managing the dispatch pipeline.
 
-use clap::ArgMatches;
+use clap::{ArgMatches, Command};
 
-/// Extracts the command path from ArgMatches by following the subcommand chain.
+/// Resolves the command path and the deepest subcommand matches in one pass.
 ///
-/// For example, `myapp db migrate` produces `["db", "migrate"]`.
-pub fn extract_command_path(matches: &ArgMatches) -> Vec<String> {
+/// Both values come from the same walk down the subcommand chain, so the
+/// returned matches always belong to the command named by the path. A
+/// `help` subcommand ends the walk, as it's handled separately.
+pub fn resolve_invocation(matches: &ArgMatches) -> (Vec<String>, &ArgMatches) {
     let mut path = Vec::new();
     let mut current = matches;
 
@@ -21,7 +23,16 @@ pub fn extract_command_path(matches: &ArgMatches) -> Vec<String> {
         current = sub;
     }
 
-    path
+    (path, current)
+}
+
+/// Extracts the command path from ArgMatches by following the subcommand chain.
+///
+/// For example, `myapp db migrate` produces `["db", "migrate"]`. Subcommands
+/// invoked through an alias are reported by their canonical name, so
+/// `myapp db mig` (with `mig` an alias of `migrate`) gives the same path.
+pub fn extract_command_path(matches: &ArgMatches) -> Vec<String> {
+    resolve_invocation(matches).0
 }
 
 /// Gets the deepest subcommand matches.
@@ -29,16 +40,7 @@ pub fn extract_command_path(matches: &ArgMatches) -> Vec<String> {
 /// Traverses the subcommand chain and returns the ArgMatches
 /// for the most deeply nested command.
 pub fn get_deepest_matches(matches: &ArgMatches) -> &ArgMatches {
-    let mut current = matches;
-
-    while let Some((name, sub)) = current.subcommand() {
-        if name == "help" {
-            break;
-        }
-        current = sub;
-    }
-
-    current
+    resolve_invocation(matches).1
 }
 
 /// Returns true if the matches contain a subcommand (excluding "help").
@@ -86,6 +88,92 @@ pub fn string_to_path(s: &str) -> Vec<String> {
     }
 }
 
+/// Lists the dot-separated path of every subcommand in the tree.
+///
+/// Paths use canonical names and skip the built-in `help` subcommand. For
+/// `myapp db migrate`, the result contains `"db"` and `"db.migrate"`.
+pub fn command_paths(cmd: &Command) -> Vec<String> {
+    let mut paths = Vec::new();
+    collect_paths(cmd, "", &mut paths);
+    paths
+}
+
+fn collect_paths(cmd: &Command, prefix: &str, paths: &mut Vec<String>) {
+    for sub in cmd.get_subcommands() {
+        if sub.get_name() == "help" {
+            continue;
+        }
+        let path = if prefix.is_empty() {
+            sub.get_name().to_string()
+        } else {
+            format!("{}.{}", prefix, sub.get_name())
+        };
+        collect_paths(sub, &path, paths);
+        paths.push(path);
+    }
+}
+
+/// Returns the registered paths that don't match any subcommand of `cmd`.
+///
+/// Each entry names the path and, when one is close enough to be a likely
+/// typo, the nearest real path: `"confg.get" (did you mean "config.get"?)`.
+/// Entries are sorted by path.
+pub fn find_unreachable_paths<'a, I>(registered: I, cmd: &Command) -> Vec<String>
+where
+    I: IntoIterator<Item = &'a str>,
+{
+    let known = command_paths(cmd);
+    let mut unreachable: Vec<&str> = registered
+        .into_iter()
+        .filter(|path| !known.iter().any(|k| k == path))
+        .collect();
+    unreachable.sort_unstable();
+
+    unreachable
+        .into_iter()
+        .map(|path| match closest_path(path, &known) {
+            Some(hint) => format!("\"{}\" (did you mean \"{}\"?)", path, hint),
+            None => format!("\"{}\"", path),
+        })
+        .collect()
+}
+
+/// Finds the known path closest to `path` by edit distance.
+///
+/// Only paths within a third of the input's length (at least one edit) are
+/// considered; ties go to the shortest path, then alphabetical order.
+fn closest_path<'a>(path: &str, known: &'a [String]) -> Option<&'a str> {
+    let threshold = (path.chars().count() / 3).max(1);
+    known
+        .iter()
+        .map(|candidate| (edit_distance(path, candidate), candidate.as_str()))
+        .filter(|(distance, _)| *distance <= threshold)
+        .min_by(|a, b| {
+            a.0.cmp(&b.0)
+                .then(a.1.len().cmp(&b.1.len()))
+                .then(a.1.cmp(b.1))
+        })
+        .map(|(_, candidate)| candidate)
+}
+
+/// Levenshtein distance between two strings, counting characters.
+fn edit_distance(a: &str, b: &str) -> usize {
+    let b: Vec<char> = b.chars().collect();
+    let mut prev: Vec<usize> = (0..=b.len()).collect();
+    let mut curr = vec![0; b.len() + 1];
+
+    for (i, ca) in a.chars().enumerate() {
+        curr[0] = i + 1;
+        for (j, cb) in b.iter().enumerate() {
+            let substitution = prev[j] + usize::from(ca != *cb);
+            curr[j + 1] = substitution.min(prev[j + 1] + 1).min(curr[j] + 1);
+        }
+        std::mem::swap(&mut prev, &mut curr);
+    }
+
+    prev[b.len()]
+}
+
 #[cfg(test)]
 mod tests {
     use super::*;
@@ -112,6 +200,87 @@ mod tests {
         assert_eq!(path, vec!["list"]);
     }
 
+    #[test]
+    fn test_extract_command_path_uses_canonical_names_for_aliases() {
+        let cmd = Command::new("app").subcommand(
+            Command::new("config")
+                .visible_alias("cfg")
+                .subcommand(Command::new("list").alias("ls")),
+        );
+
+        let matches = cmd.try_get_matches_from(["app", "cfg", "ls"]).unwrap();
+        let path = extract_command_path(&matches);
+
+        assert_eq!(path, vec!["config", "list"]);
+    }
+
+    #[test]
+    fn test_resolve_invocation_path_matches_agree() {
+        let cmd = Command::new("app").subcommand(
+            Command::new("config").subcommand(
+                Command::new("get")
+                    .subcommand(Command::new("key").arg(clap::Arg::new("name").long("name"))),
+            ),
+        );
+
+        let matches = cmd
+            .try_get_matches_from(["app", "config", "get", "key", "--name", "color"])
+            .unwrap();
+        let (path, deepest) = resolve_invocation(&matches);
+
+        assert_eq!(path, vec!["config", "get", "key"]);
+        assert_eq!(
+            deepest.get_one::<String>("name").map(String::as_str),
+            Some("color")
+        );
+        assert!(deepest.subcommand().is_none());
+        assert_eq!(path, extract_command_path(&matches));
+        assert!(std::ptr::eq(deepest, get_deepest_matches(&matches)));
+    }
+
+    fn config_app() -> Command {
+        Command::new("app")
+            .subcommand(Command::new("list"))
+            .subcommand(
+                Command::new("config")
+                    .subcommand(Command::new("get"))
+                    .subcommand(Command::new("set")),
+            )
+    }
+
+    #[test]
+    fn test_command_paths() {
+        let mut paths = command_paths(&config_app());
+        paths.sort();
+        assert_eq!(paths, vec!["config", "config.get", "config.set", "list"]);
+    }
+
+    #[test]
+    fn test_find_unreachable_paths_all_valid() {
+        let cmd = config_app();
+        assert!(find_unreachable_paths(["list", "config.get"], &cmd).is_empty());
+    }
+
+    #[test]
+    fn test_find_unreachable_paths_suggests_closest() {
+        let cmd = config_app();
+        let unreachable = find_unreachable_paths(["confg.get", "list", "lst"], &cmd);
+        assert_eq!(
+            unreachable,
+            vec![
+                "\"confg.get\" (did you mean \"config.get\"?)",
+                "\"lst\" (did you mean \"list\"?)",
+            ]
+        );
+    }
+
+    #[test]
+    fn test_find_unreachable_paths_without_hint() {
+        let cmd = config_app();
+        let unreachable = find_unreachable_paths(["deploy.production"], &cmd);
+        assert_eq!(unreachable, vec!["\"deploy.production\""]);
+    }
+
     #[test]
     fn test_extract_command_path_empty() {
         let cmd = Command::new("app");
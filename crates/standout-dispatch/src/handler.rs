@@ -65,6 +65,7 @@
 //! - [`Handler`]: Trait for thread-safe command handlers (`Send + Sync`, `&self`)
 //! - [`LocalHandler`]: Trait for local command handlers (no `Send + Sync`, `&mut self`)
 
+use clap::parser::ValueSource;
 use clap::ArgMatches;
 use serde::Serialize;
 use std::any::{Any, TypeId};
@@ -72,6 +73,8 @@ use std::collections::HashMap;
 use std::fmt;
 use std::sync::Arc;
 
+use crate::progress::ProgressSink;
+
 /// Type-safe container for injecting custom state into handlers.
 ///
 /// Extensions allow pre-dispatch hooks to inject state that handlers can retrieve.
@@ -290,7 +293,24 @@ impl Clone for Extensions {
 ///     Ok(())
 /// }
 /// ```
-#[derive(Debug)]
+///
+/// # Construction
+///
+/// Dispatchers build the context for you. When constructing one by hand (for
+/// example in handler tests), use [`new`](Self::new) or [`Default`], and spell
+/// out only the fields you need with `..Default::default()`, so the code keeps
+/// compiling when fields are added:
+///
+/// ```rust
+/// use standout_dispatch::CommandContext;
+///
+/// let ctx = CommandContext {
+///     command_path: vec!["config".into(), "get".into()],
+///     dry_run: true,
+///     ..Default::default()
+/// };
+/// assert!(ctx.dry_run);
+/// ```
 pub struct CommandContext {
     /// The command path being executed (e.g., ["config", "get"])
     pub command_path: Vec<String>,
@@ -308,6 +328,27 @@ pub struct CommandContext {
     /// Pre-dispatch hooks can insert values that handlers retrieve.
     /// Each dispatch gets a fresh Extensions instance.
     pub extensions: Extensions,
+
+    /// Receiver for [`progress`](Self::progress) updates.
+    ///
+    /// Frameworks install a terminal sink when stderr is a TTY. When `None`,
+    /// progress reports are discarded.
+    pub progress_sink: Option<Arc<dyn ProgressSink>>,
+
+    /// The matches for the whole command line, starting at the root command.
+    ///
+    /// Handlers receive the matches of the deepest subcommand, so arguments
+    /// declared on a parent (such as a root `--verbose`) are only reachable
+    /// through here. Prefer [`global_flag`](Self::global_flag), which walks
+    /// the chain for you. `None` when the context wasn't built by a dispatcher.
+    pub root_matches: Option<ArgMatches>,
+
+    /// Whether the user asked for a dry run (`--dry-run`).
+    ///
+    /// Frameworks set this when dry runs are enabled and the flag was given.
+    /// Handlers should skip side effects but still return a preview of what
+    /// would happen, so the output renders as usual.
+    pub dry_run: bool,
 }
 
 impl CommandContext {
@@ -319,16 +360,106 @@ impl CommandContext {
             command_path,
             app_state,
             extensions: Extensions::new(),
+            progress_sink: None,
+            root_matches: None,
+            dry_run: false,
+        }
+    }
+
+    /// Looks up an argument anywhere along the matched command chain.
+    ///
+    /// Searches from the deepest subcommand up to the root, so a handler for
+    /// `app --verbose config get` can read `--verbose` even though it was
+    /// declared on `app`. The lookup order is:
+    ///
+    /// 1. The deepest command where the value was given explicitly (on the
+    ///    command line or through an environment variable)
+    /// 2. Otherwise, the deepest command that supplies a default value
+    /// 3. Otherwise, `None`
+    ///
+    /// `T` must match the argument's value type: `bool` for
+    /// `ArgAction::SetTrue`, `u8` for `ArgAction::Count`, and so on. Commands
+    /// that don't declare `name`, or store it as another type, are skipped.
+    /// Returns `None` when [`root_matches`](Self::root_matches) is unset.
+    ///
+    /// ```rust,ignore
+    /// fn get(matches: &ArgMatches, ctx: &CommandContext) -> HandlerResult<Value> {
+    ///     let verbosity = ctx.global_flag::<u8>("verbose").unwrap_or(0);
+    ///     // ...
+    /// }
+    /// ```
+    pub fn global_flag<T>(&self, name: &str) -> Option<T>
+    where
+        T: Any + Clone + Send + Sync + 'static,
+    {
+        let mut chain = Vec::new();
+        let mut current = self.root_matches.as_ref();
+        while let Some(matches) = current {
+            chain.push(matches);
+            current = matches.subcommand().map(|(_, sub)| sub);
+        }
+
+        let mut fallback = None;
+        for matches in chain.into_iter().rev() {
+            let Ok(Some(value)) = matches.try_get_one::<T>(name) else {
+                continue;
+            };
+            match matches.value_source(name) {
+                Some(ValueSource::DefaultValue) => {
+                    fallback.get_or_insert_with(|| value.clone());
+                }
+                _ => return Some(value.clone()),
+            }
+        }
+        fallback
+    }
+
+    /// Reports progress of a long-running handler.
+    ///
+    /// `current` counts completed units of work out of `total` (use `0` for an
+    /// unknown total). This is a no-op unless a [`ProgressSink`] is installed,
+    /// so handlers can call it unconditionally.
+    ///
+    /// ```rust,ignore
+    /// fn import(matches: &ArgMatches, ctx: &CommandContext) -> HandlerResult<Summary> {
+    ///     let files = collect_files(matches)?;
+    ///     for (i, file) in files.iter().enumerate() {
+    ///         ctx.progress(i as u64, files.len() as u64, "importing");
+    ///         import_file(file)?;
+    ///     }
+    ///     ctx.progress(files.len() as u64, files.len() as u64, "importing");
+    ///     Ok(Output::Render(summary))
+    /// }
+    /// ```
+    pub fn progress(&self, current: u64, total: u64, message: &str) {
+        if let Some(sink) = &self.progress_sink {
+            sink.progress(current, total, message);
         }
     }
 }
 
+impl fmt::Debug for CommandContext {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        f.debug_struct("CommandContext")
+            .field("command_path", &self.command_path)
+            .field("app_state", &self.app_state)
+            .field("extensions", &self.extensions)
+            .field("progress_sink", &self.progress_sink.is_some())
+            .field("root_matches", &self.root_matches.is_some())
+            .field("dry_run", &self.dry_run)
+            .finish()
+    }
+}
+
 impl Default for CommandContext {
     fn default() -> Self {
         Self {
             command_path: Vec::new(),
             app_state: Arc::new(Extensions::new()),
             extensions: Extensions::new(),
+            progress_sink: None,
+            root_matches: None,
+            dry_run: false,
         }
     }
 }
@@ -342,16 +473,52 @@ pub enum Output<T: Serialize> {
     Render(T),
     /// Silent exit (no output produced)
     Silent,
+    /// Text that is already in its final form, printed without templating.
+    ///
+    /// Use this for output that doesn't come from your own data, such as the
+    /// stdout of a proxied tool. It is emitted unchanged in every output mode,
+    /// JSON and YAML included: wrapping it would double-encode text that is
+    /// often JSON already. Return [`Output::Render`] when the output should
+    /// follow `--output`. Post-dispatch hooks don't run, since there is no
+    /// data to transform; output hooks do.
+    Raw(String),
     /// Binary output for file exports
+    ///
+    /// [`Output::binary`] builds one with the defaults.
     Binary {
         /// The binary data
         data: Vec<u8>,
         /// Suggested filename for the output
         filename: String,
+        /// Always write the bytes to stdout, for piping into another tool.
+        ///
+        /// When false, the app's binary output policy picks stdout or the file.
+        to_stdout: bool,
+        /// MIME type of the data, e.g. `application/pdf`, reported when the
+        /// file is written.
+        content_type: Option<String>,
     },
 }
 
 impl<T: Serialize> Output<T> {
+    /// Creates binary output delivered according to the app's binary output
+    /// policy, with no content type.
+    ///
+    /// ```rust
+    /// use standout_dispatch::Output;
+    ///
+    /// let output: Output<()> = Output::binary(vec![0x50, 0x4b], "export.zip");
+    /// assert!(output.is_binary());
+    /// ```
+    pub fn binary(data: impl Into<Vec<u8>>, filename: impl Into<String>) -> Self {
+        Output::Binary {
+            data: data.into(),
+            filename: filename.into(),
+            to_stdout: false,
+            content_type: None,
+        }
+    }
+
     /// Returns true if this is a render result.
     pub fn is_render(&self) -> bool {
         matches!(self, Output::Render(_))
@@ -362,6 +529,11 @@ impl<T: Serialize> Output<T> {
         matches!(self, Output::Silent)
     }
 
+    /// Returns true if this is a raw text result.
+    pub fn is_raw(&self) -> bool {
+        matches!(self, Output::Raw(_))
+    }
+
     /// Returns true if this is a binary result.
     pub fn is_binary(&self) -> bool {
         matches!(self, Output::Binary { .. })
@@ -435,8 +607,17 @@ impl<T: Serialize> IntoHandlerResult<T> for HandlerResult<T> {
 pub enum RunResult {
     /// A handler processed the command; contains the rendered output
     Handled(String),
-    /// A handler produced binary output (bytes, suggested filename)
-    Binary(Vec<u8>, String),
+    /// A handler produced binary output; see [`Output::Binary`] for the fields
+    Binary {
+        /// The binary data
+        data: Vec<u8>,
+        /// Suggested filename for the output
+        filename: String,
+        /// The handler asked for the bytes to go to stdout
+        to_stdout: bool,
+        /// MIME type of the data, if the handler gave one
+        content_type: Option<String>,
+    },
     /// Silent output (handler completed but produced no output)
     Silent,
     /// No handler matched; contains the ArgMatches for manual handling
@@ -451,7 +632,7 @@ impl RunResult {
 
     /// Returns true if the result is binary output.
     pub fn is_binary(&self) -> bool {
-        matches!(self, RunResult::Binary(_, _))
+        matches!(self, RunResult::Binary { .. })
     }
 
     /// Returns true if the result is silent.
@@ -468,9 +649,12 @@ impl RunResult {
     }
 
     /// Returns the binary data and filename if binary, or None otherwise.
+    ///
+    /// Prefer this over matching on [`RunResult::Binary`] when only the bytes
+    /// and filename are needed.
     pub fn binary(&self) -> Option<(&[u8], &str)> {
         match self {
-            RunResult::Binary(bytes, filename) => Some((bytes, filename)),
+            RunResult::Binary { data, filename, .. } => Some((data, filename)),
             _ => None,
         }
     }
@@ -755,6 +939,9 @@ mod tests {
             command_path: vec!["config".into(), "get".into()],
             app_state: Arc::new(Extensions::new()),
             extensions: Extensions::new(),
+            progress_sink: None,
+            root_matches: None,
+            dry_run: false,
         };
         assert_eq!(ctx.command_path, vec!["config", "get"]);
     }
@@ -767,6 +954,112 @@ mod tests {
         assert!(ctx.app_state.is_empty());
     }
 
+    fn verbose_app() -> clap::Command {
+        use clap::{Arg, ArgAction, Command};
+
+        let verbose = || {
+            Arg::new("verbose")
+                .long("verbose")
+                .action(ArgAction::SetTrue)
+        };
+        Command::new("app")
+            .arg(verbose())
+            .arg(Arg::new("profile").long("profile").default_value("dev"))
+            .subcommand(
+                Command::new("config").subcommand(
+                    Command::new("get")
+                        .arg(verbose())
+                        .arg(Arg::new("profile").long("profile").default_value("test")),
+                ),
+            )
+    }
+
+    fn context_for(args: &[&str]) -> CommandContext {
+        CommandContext {
+            root_matches: Some(verbose_app().try_get_matches_from(args).unwrap()),
+            ..Default::default()
+        }
+    }
+
+    #[test]
+    fn test_global_flag_reads_root_argument() {
+        let ctx = context_for(&["app", "--verbose", "config", "get"]);
+        assert_eq!(ctx.global_flag::<bool>("verbose"), Some(true));
+    }
+
+    #[test]
+    fn test_global_flag_prefers_explicit_value_over_defaults() {
+        let ctx = context_for(&["app", "--profile", "prod", "config", "get"]);
+        assert_eq!(
+            ctx.global_flag::<String>("profile").as_deref(),
+            Some("prod")
+        );
+
+        let ctx = context_for(&[
+            "app",
+            "--profile",
+            "prod",
+            "config",
+            "get",
+            "--profile",
+            "ci",
+        ]);
+        assert_eq!(ctx.global_flag::<String>("profile").as_deref(), Some("ci"));
+    }
+
+    #[test]
+    fn test_global_flag_falls_back_to_deepest_default() {
+        let ctx = context_for(&["app", "config", "get"]);
+        assert_eq!(
+            ctx.global_flag::<String>("profile").as_deref(),
+            Some("test")
+        );
+        assert_eq!(ctx.global_flag::<bool>("verbose"), Some(false));
+    }
+
+    #[test]
+    fn test_global_flag_unknown_or_mistyped() {
+        let ctx = context_for(&["app", "--verbose", "config", "get"]);
+        assert_eq!(ctx.global_flag::<bool>("missing"), None);
+        assert_eq!(ctx.global_flag::<String>("verbose"), None);
+        assert_eq!(
+            CommandContext::default().global_flag::<bool>("verbose"),
+            None
+        );
+    }
+
+    #[test]
+    fn test_command_context_progress_without_sink_is_noop() {
+        let ctx = CommandContext::default();
+        ctx.progress(1, 10, "working");
+    }
+
+    #[test]
+    fn test_command_context_progress_reaches_sink() {
+        use std::sync::Mutex;
+
+        let updates = Arc::new(Mutex::new(Vec::new()));
+        let captured = updates.clone();
+
+        let ctx = CommandContext {
+            progress_sink: Some(Arc::new(move |current: u64, total: u64, message: &str| {
+                captured
+                    .lock()
+                    .unwrap()
+                    .push((current, total, message.to_string()));
+            })),
+            ..Default::default()
+        };
+
+        ctx.progress(1, 3, "syncing");
+        ctx.progress(3, 3, "done");
+
+        assert_eq!(
+            *updates.lock().unwrap(),
+            vec![(1, 3, "syncing".to_string()), (3, 3, "done".to_string())]
+        );
+    }
+
     #[test]
     fn test_command_context_with_app_state() {
         struct Database {
@@ -789,6 +1082,9 @@ mod tests {
             command_path: vec!["list".into()],
             app_state: app_state.clone(),
             extensions: Extensions::new(),
+            progress_sink: None,
+            root_matches: None,
+            dry_run: false,
         };
 
         // Retrieve app state
@@ -813,6 +1109,9 @@ mod tests {
             command_path: vec![],
             app_state: Arc::new(app_state),
             extensions: Extensions::new(),
+            progress_sink: None,
+            root_matches: None,
+            dry_run: false,
         };
 
         // Success case
@@ -1010,6 +1309,15 @@ mod tests {
         assert!(!output.is_binary());
     }
 
+    #[test]
+    fn test_output_raw() {
+        let output: Output<String> = Output::Raw("text".into());
+        assert!(output.is_raw());
+        assert!(!output.is_render());
+        assert!(!output.is_silent());
+        assert!(!output.is_binary());
+    }
+
     #[test]
     fn test_output_silent() {
         let output: Output<String> = Output::Silent;
@@ -1023,12 +1331,33 @@ mod tests {
         let output: Output<String> = Output::Binary {
             data: vec![0x25, 0x50, 0x44, 0x46],
             filename: "report.pdf".into(),
+            to_stdout: true,
+            content_type: Some("application/pdf".into()),
         };
         assert!(!output.is_render());
         assert!(!output.is_silent());
         assert!(output.is_binary());
     }
 
+    #[test]
+    fn test_output_binary_constructor_defaults() {
+        let output: Output<String> = Output::binary(vec![1, 2], "data.bin");
+        match output {
+            Output::Binary {
+                data,
+                filename,
+                to_stdout,
+                content_type,
+            } => {
+                assert_eq!(data, vec![1, 2]);
+                assert_eq!(filename, "data.bin");
+                assert!(!to_stdout);
+                assert_eq!(content_type, None);
+            }
+            _ => panic!("Expected Output::Binary"),
+        }
+    }
+
     #[test]
     fn test_run_result_handled() {
         let result = RunResult::Handled("output".into());
@@ -1050,7 +1379,12 @@ mod tests {
     #[test]
     fn test_run_result_binary() {
         let bytes = vec![0x25, 0x50, 0x44, 0x46];
-        let result = RunResult::Binary(bytes.clone(), "report.pdf".into());
+        let result = RunResult::Binary {
+            data: bytes.clone(),
+            filename: "report.pdf".into(),
+            to_stdout: false,
+            content_type: None,
+        };
         assert!(!result.is_handled());
         assert!(result.is_binary());
         assert!(!result.is_silent());
@@ -1162,15 +1496,12 @@ mod tests {
     fn test_into_handler_result_passthrough_binary() {
         use super::IntoHandlerResult;
 
-        let handler_result: HandlerResult<String> = Ok(Output::Binary {
-            data: vec![1, 2, 3],
-            filename: "test.bin".to_string(),
-        });
+        let handler_result: HandlerResult<String> = Ok(Output::binary(vec![1, 2, 3], "test.bin"));
         let result = handler_result.into_handler_result();
 
         assert!(result.is_ok());
         match result.unwrap() {
-            Output::Binary { data, filename } => {
+            Output::Binary { data, filename, .. } => {
                 assert_eq!(data, vec![1, 2, 3]);
                 assert_eq!(filename, "test.bin");
             }
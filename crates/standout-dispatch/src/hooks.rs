@@ -14,6 +14,7 @@
 //!   → logic handler
 //!   → POST-DISPATCH HOOK ← (data transformation, enrichment)
 //!   → render handler
+//!   → PRE-OUTPUT HOOK ← (suppression, redirection, teeing)
 //!   → POST-OUTPUT HOOK ← (output transformation, logging)
 //! ```
 //!
@@ -26,7 +27,13 @@
 //!   handler data as `serde_json::Value`. Can inspect, modify, or replace the data.
 //!   Use for: adding metadata, data transformation, caching.
 //!
-//! - Post-output: Runs after output is generated. Can transform output or abort.
+//! - Pre-output: Runs after output is generated, before any post-output hook.
+//!   Can replace the output or suppress it by returning `None`, which skips the
+//!   remaining pre-output hooks and all post-output hooks.
+//!   Use for: teeing output to a log file, per-command pager decisions.
+//!
+//! - Post-output: Runs after output is generated and pre-output hooks have
+//!   passed it on. Can transform output or abort.
 //!   Use for: logging, clipboard copy, output filtering.
 
 use std::fmt;
@@ -43,13 +50,39 @@ use clap::ArgMatches;
 pub enum RenderedOutput {
     /// Text output (rendered template or error message)
     Text(String),
-    /// Binary output with suggested filename
-    Binary(Vec<u8>, String),
+    /// Binary output; see [`Output::Binary`](crate::Output::Binary) for the fields
+    Binary {
+        /// The binary data
+        data: Vec<u8>,
+        /// Suggested filename for the output
+        filename: String,
+        /// The handler asked for the bytes to go to stdout
+        to_stdout: bool,
+        /// MIME type of the data, if the handler gave one
+        content_type: Option<String>,
+    },
     /// No output (silent command)
     Silent,
 }
 
 impl RenderedOutput {
+    /// Creates binary output with the defaults of [`Output::binary`](crate::Output::binary).
+    ///
+    /// ```rust
+    /// use standout_dispatch::RenderedOutput;
+    ///
+    /// let output = RenderedOutput::binary(vec![0x50, 0x4b], "export.zip");
+    /// assert_eq!(output.as_binary(), Some((&[0x50, 0x4b][..], "export.zip")));
+    /// ```
+    pub fn binary(data: impl Into<Vec<u8>>, filename: impl Into<String>) -> Self {
+        RenderedOutput::Binary {
+            data: data.into(),
+            filename: filename.into(),
+            to_stdout: false,
+            content_type: None,
+        }
+    }
+
     /// Returns true if this is text output.
     pub fn is_text(&self) -> bool {
         matches!(self, RenderedOutput::Text(_))
@@ -57,7 +90,7 @@ impl RenderedOutput {
 
     /// Returns true if this is binary output.
     pub fn is_binary(&self) -> bool {
-        matches!(self, RenderedOutput::Binary(_, _))
+        matches!(self, RenderedOutput::Binary { .. })
     }
 
     /// Returns true if this is silent (no output).
@@ -74,9 +107,12 @@ impl RenderedOutput {
     }
 
     /// Returns the binary content and filename if this is binary output.
+    ///
+    /// Prefer this over matching on [`RenderedOutput::Binary`] when only the
+    /// bytes and filename are needed.
     pub fn as_binary(&self) -> Option<(&[u8], &str)> {
         match self {
-            RenderedOutput::Binary(bytes, filename) => Some((bytes, filename)),
+            RenderedOutput::Binary { data, filename, .. } => Some((data, filename)),
             _ => None,
         }
     }
@@ -89,6 +125,8 @@ pub enum HookPhase {
     PreDispatch,
     /// Error occurred during post-dispatch phase
     PostDispatch,
+    /// Error occurred during pre-output phase
+    PreOutput,
     /// Error occurred during post-output phase
     PostOutput,
 }
@@ -98,6 +136,7 @@ impl fmt::Display for HookPhase {
         match self {
             HookPhase::PreDispatch => write!(f, "pre-dispatch"),
             HookPhase::PostDispatch => write!(f, "post-dispatch"),
+            HookPhase::PreOutput => write!(f, "pre-output"),
             HookPhase::PostOutput => write!(f, "post-output"),
         }
     }
@@ -135,6 +174,15 @@ impl HookError {
         }
     }
 
+    /// Creates a new hook error for the pre-output phase.
+    pub fn pre_output(message: impl Into<String>) -> Self {
+        Self {
+            message: message.into(),
+            phase: HookPhase::PreOutput,
+            source: None,
+        }
+    }
+
     /// Creates a new hook error for the post-output phase.
     pub fn post_output(message: impl Into<String>) -> Self {
         Self {
@@ -168,6 +216,19 @@ pub type PostDispatchFn = Arc<
         + Sync,
 >;
 
+/// Type alias for pre-output hook functions.
+///
+/// Returning `Ok(None)` suppresses the output.
+pub type PreOutputFn = Arc<
+    dyn Fn(
+            &ArgMatches,
+            &CommandContext,
+            RenderedOutput,
+        ) -> Result<Option<RenderedOutput>, HookError>
+        + Send
+        + Sync,
+>;
+
 /// Type alias for post-output hook functions.
 pub type PostOutputFn = Arc<
     dyn Fn(&ArgMatches, &CommandContext, RenderedOutput) -> Result<RenderedOutput, HookError>
@@ -182,6 +243,7 @@ pub type PostOutputFn = Arc<
 pub struct Hooks {
     pre_dispatch: Vec<PreDispatchFn>,
     post_dispatch: Vec<PostDispatchFn>,
+    pre_output: Vec<PreOutputFn>,
     post_output: Vec<PostOutputFn>,
 }
 
@@ -193,7 +255,10 @@ impl Hooks {
 
     /// Returns true if no hooks are registered.
     pub fn is_empty(&self) -> bool {
-        self.pre_dispatch.is_empty() && self.post_dispatch.is_empty() && self.post_output.is_empty()
+        self.pre_dispatch.is_empty()
+            && self.post_dispatch.is_empty()
+            && self.pre_output.is_empty()
+            && self.post_output.is_empty()
     }
 
     /// Adds a pre-dispatch hook.
@@ -240,6 +305,41 @@ impl Hooks {
         self
     }
 
+    /// Adds a pre-output hook.
+    ///
+    /// Pre-output hooks run before any post-output hook. Return `Ok(Some(_))`
+    /// with the original or a replacement output to pass it on, or `Ok(None)`
+    /// to suppress it: the command then produces no output, and the remaining
+    /// pre-output hooks and all post-output hooks are skipped.
+    ///
+    /// # Example
+    ///
+    /// ```rust
+    /// use standout_dispatch::{Hooks, RenderedOutput};
+    ///
+    /// let hooks = Hooks::new()
+    ///     .pre_output(|matches, _ctx, output| {
+    ///         if matches.get_flag("quiet") {
+    ///             return Ok(None);
+    ///         }
+    ///         Ok(Some(output))
+    ///     });
+    /// ```
+    pub fn pre_output<F>(mut self, f: F) -> Self
+    where
+        F: Fn(
+                &ArgMatches,
+                &CommandContext,
+                RenderedOutput,
+            ) -> Result<Option<RenderedOutput>, HookError>
+            + Send
+            + Sync
+            + 'static,
+    {
+        self.pre_output.push(Arc::new(f));
+        self
+    }
+
     /// Adds a post-output hook.
     pub fn post_output<F>(mut self, f: F) -> Self
     where
@@ -280,6 +380,41 @@ impl Hooks {
         Ok(current)
     }
 
+    /// Runs all pre-output hooks, chaining transformations.
+    ///
+    /// Returns `None` as soon as a hook suppresses the output.
+    pub fn run_pre_output(
+        &self,
+        matches: &ArgMatches,
+        ctx: &CommandContext,
+        output: RenderedOutput,
+    ) -> Result<Option<RenderedOutput>, HookError> {
+        let mut current = output;
+        for hook in &self.pre_output {
+            match hook(matches, ctx, current)? {
+                Some(next) => current = next,
+                None => return Ok(None),
+            }
+        }
+        Ok(Some(current))
+    }
+
+    /// Runs the pre-output hooks, then the post-output hooks.
+    ///
+    /// Suppressed output becomes [`RenderedOutput::Silent`] without reaching
+    /// the post-output hooks.
+    pub fn run_output(
+        &self,
+        matches: &ArgMatches,
+        ctx: &CommandContext,
+        output: RenderedOutput,
+    ) -> Result<RenderedOutput, HookError> {
+        match self.run_pre_output(matches, ctx, output)? {
+            Some(output) => self.run_post_output(matches, ctx, output),
+            None => Ok(RenderedOutput::Silent),
+        }
+    }
+
     /// Runs all post-output hooks, chaining transformations.
     pub fn run_post_output(
         &self,
@@ -300,6 +435,7 @@ impl fmt::Debug for Hooks {
         f.debug_struct("Hooks")
             .field("pre_dispatch_count", &self.pre_dispatch.len())
             .field("post_dispatch_count", &self.post_dispatch.len())
+            .field("pre_output_count", &self.pre_output.len())
             .field("post_output_count", &self.post_output.len())
             .finish()
     }
@@ -328,7 +464,7 @@ mod tests {
         assert!(!text.is_silent());
         assert_eq!(text.as_text(), Some("hello"));
 
-        let binary = RenderedOutput::Binary(vec![1, 2, 3], "file.bin".into());
+        let binary = RenderedOutput::binary(vec![1, 2, 3], "file.bin");
         assert!(!binary.is_text());
         assert!(binary.is_binary());
         assert_eq!(binary.as_binary(), Some((&[1u8, 2, 3][..], "file.bin")));
@@ -471,4 +607,61 @@ mod tests {
         assert!(result.is_ok());
         assert_eq!(result.unwrap().as_text(), Some("HELLO"));
     }
+
+    #[test]
+    fn test_pre_output_runs_before_post_output() {
+        let hooks = Hooks::new()
+            .post_output(|_, _, output| match output {
+                RenderedOutput::Text(text) => Ok(RenderedOutput::Text(format!("{}!", text))),
+                other => Ok(other),
+            })
+            .pre_output(|_, _, output| match output {
+                RenderedOutput::Text(text) => Ok(Some(RenderedOutput::Text(text.to_uppercase()))),
+                other => Ok(Some(other)),
+            });
+        assert!(!Hooks::new().pre_output(|_, _, o| Ok(Some(o))).is_empty());
+
+        let ctx = test_context();
+        let matches = test_matches();
+        let result = hooks
+            .run_output(&matches, &ctx, RenderedOutput::Text("hello".into()))
+            .unwrap();
+
+        assert_eq!(result.as_text(), Some("HELLO!"));
+    }
+
+    #[test]
+    fn test_pre_output_suppression_skips_later_hooks() {
+        let hooks = Hooks::new()
+            .pre_output(|_, _, _| Ok(None))
+            .pre_output(|_, _, _| panic!("suppressed output reached a pre-output hook"))
+            .post_output(|_, _, _| panic!("suppressed output reached a post-output hook"));
+
+        let ctx = test_context();
+        let matches = test_matches();
+
+        let pre = hooks
+            .run_pre_output(&matches, &ctx, RenderedOutput::Text("hello".into()))
+            .unwrap();
+        assert!(pre.is_none());
+
+        let result = hooks
+            .run_output(&matches, &ctx, RenderedOutput::Text("hello".into()))
+            .unwrap();
+        assert!(result.is_silent());
+    }
+
+    #[test]
+    fn test_pre_output_error_phase() {
+        let hooks = Hooks::new().pre_output(|_, _, _| Err(HookError::pre_output("no pager")));
+
+        let ctx = test_context();
+        let matches = test_matches();
+        let err = hooks
+            .run_output(&matches, &ctx, RenderedOutput::Silent)
+            .unwrap_err();
+
+        assert_eq!(err.phase, HookPhase::PreOutput);
+        assert_eq!(err.to_string(), "hook error (pre-output): no pager");
+    }
 }
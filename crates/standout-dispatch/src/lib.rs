@@ -123,12 +123,13 @@
 mod dispatch;
 mod handler;
 mod hooks;
+mod progress;
 mod render;
 
 // Re-export command routing utilities
 pub use dispatch::{
-    extract_command_path, get_deepest_matches, has_subcommand, insert_default_command,
-    path_to_string, string_to_path,
+    command_paths, extract_command_path, find_unreachable_paths, get_deepest_matches,
+    has_subcommand, insert_default_command, path_to_string, resolve_invocation, string_to_path,
 };
 
 // Re-export handler types
@@ -139,8 +140,12 @@ pub use handler::{
 
 // Re-export hook types
 pub use hooks::{
-    HookError, HookPhase, Hooks, PostDispatchFn, PostOutputFn, PreDispatchFn, RenderedOutput,
+    HookError, HookPhase, Hooks, PostDispatchFn, PostOutputFn, PreDispatchFn, PreOutputFn,
+    RenderedOutput,
 };
 
+// Re-export progress reporting
+pub use progress::ProgressSink;
+
 // Re-export render abstraction
 pub use render::{from_fn, from_fn_mut, LocalRenderFn, RenderError, RenderFn};
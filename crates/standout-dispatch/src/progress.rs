@@ -0,0 +1,50 @@
+//! Progress reporting for long-running handlers.
+//!
+//! Handlers stay synchronous: they call [`CommandContext::progress`] periodically
+//! and the installed [`ProgressSink`] decides what to do with each update. When no
+//! sink is installed, reporting is a no-op, so handlers can report unconditionally.
+//!
+//! Frameworks install a sink that draws a progress line on the terminal. Tests
+//! install their own sink to capture updates:
+//!
+//! ```rust
+//! use std::sync::{Arc, Mutex};
+//! use standout_dispatch::CommandContext;
+//!
+//! let updates = Arc::new(Mutex::new(Vec::new()));
+//! let captured = updates.clone();
+//!
+//! let ctx = CommandContext {
+//!     progress_sink: Some(Arc::new(move |current: u64, total: u64, message: &str| {
+//!         captured.lock().unwrap().push((current, total, message.to_string()));
+//!     })),
+//!     ..Default::default()
+//! };
+//!
+//! ctx.progress(1, 2, "importing");
+//! ctx.progress(2, 2, "importing");
+//!
+//! assert_eq!(updates.lock().unwrap().len(), 2);
+//! ```
+//!
+//! [`CommandContext::progress`]: crate::CommandContext::progress
+
+/// Receives progress updates from handlers.
+///
+/// `current` counts completed units of work out of `total`. A `total` of zero
+/// means the amount of work is unknown.
+///
+/// Closures with the matching signature implement this trait.
+pub trait ProgressSink: Send + Sync {
+    /// Reports that `current` of `total` units are done.
+    fn progress(&self, current: u64, total: u64, message: &str);
+}
+
+impl<F> ProgressSink for F
+where
+    F: Fn(u64, u64, &str) + Send + Sync,
+{
+    fn progress(&self, current: u64, total: u64, message: &str) {
+        self(current, total, message)
+    }
+}
@@ -27,7 +27,9 @@
 use proc_macro2::TokenStream;
 use quote::quote;
 use std::path::{Path, PathBuf};
-use syn::LitStr;
+use syn::parse::{Parse, ParseStream};
+use syn::punctuated::Punctuated;
+use syn::{Ident, LitBool, LitStr, Token};
 
 /// Template file extensions (must match standout::render::registry::TEMPLATE_EXTENSIONS).
 pub const TEMPLATE_EXTENSIONS: &[&str] = &[".jinja", ".jinja2", ".j2", ".txt"];
@@ -35,25 +37,169 @@ pub const TEMPLATE_EXTENSIONS: &[&str] = &[".jinja", ".jinja2", ".j2", ".txt"];
 /// Stylesheet file extensions (must match standout::style::STYLESHEET_EXTENSIONS).
 pub const STYLESHEET_EXTENSIONS: &[&str] = &[".yaml", ".yml"];
 
+/// Arguments of `embed_templates!`: a directory, optional name filters and
+/// whitespace trimming.
+///
+/// ```text
+/// embed_templates!("./templates")
+/// embed_templates!("./templates", include = ["report/**"], exclude = ["**/_*"])
+/// embed_templates!("./templates", trim_trailing_newline = true)
+/// ```
+pub struct EmbedTemplatesArgs {
+    path: LitStr,
+    filter: NameFilter,
+    trim_trailing_newline: bool,
+}
+
+impl Parse for EmbedTemplatesArgs {
+    fn parse(input: ParseStream) -> syn::Result<Self> {
+        let path: LitStr = input.parse()?;
+        let mut include: Option<Vec<String>> = None;
+        let mut exclude: Option<Vec<String>> = None;
+        let mut trim_trailing_newline: Option<bool> = None;
+
+        while !input.is_empty() {
+            input.parse::<Token![,]>()?;
+            if input.is_empty() {
+                break;
+            }
+
+            let key: Ident = input.parse()?;
+            input.parse::<Token![=]>()?;
+
+            if key == "trim_trailing_newline" {
+                if trim_trailing_newline.is_some() {
+                    return Err(syn::Error::new(
+                        key.span(),
+                        format!("duplicate `{}` argument", key),
+                    ));
+                }
+                trim_trailing_newline = Some(input.parse::<LitBool>()?.value);
+                continue;
+            }
+
+            let content;
+            syn::bracketed!(content in input);
+            let globs: Vec<String> = Punctuated::<LitStr, Token![,]>::parse_terminated(&content)?
+                .iter()
+                .map(LitStr::value)
+                .collect();
+
+            let slot = match key.to_string().as_str() {
+                "include" => &mut include,
+                "exclude" => &mut exclude,
+                other => {
+                    return Err(syn::Error::new(
+                        key.span(),
+                        format!(
+                            "unknown argument `{}`, expected `include`, `exclude` or `trim_trailing_newline`",
+                            other
+                        ),
+                    ))
+                }
+            };
+            if slot.is_some() {
+                return Err(syn::Error::new(
+                    key.span(),
+                    format!("duplicate `{}` argument", key),
+                ));
+            }
+            *slot = Some(globs);
+        }
+
+        Ok(Self {
+            path,
+            filter: NameFilter {
+                include: include.unwrap_or_default(),
+                exclude: exclude.unwrap_or_default(),
+            },
+            trim_trailing_newline: trim_trailing_newline.unwrap_or(false),
+        })
+    }
+}
+
+/// Include/exclude globs selecting which files get embedded.
+///
+/// Globs match the relative name without extension (`report/summary` for
+/// `report/summary.jinja`). `*` and `?` match within one path segment, `**`
+/// matches any number of segments. A name is kept when it matches some
+/// include glob (or there are none) and no exclude glob: exclude wins.
+#[derive(Debug, Default)]
+pub struct NameFilter {
+    include: Vec<String>,
+    exclude: Vec<String>,
+}
+
+impl NameFilter {
+    /// Returns true if the filter keeps every file.
+    fn is_empty(&self) -> bool {
+        self.include.is_empty() && self.exclude.is_empty()
+    }
+
+    /// Returns true if the extensionless `name` should be embedded.
+    fn allows(&self, name: &str) -> bool {
+        if self.exclude.iter().any(|glob| glob_match(glob, name)) {
+            return false;
+        }
+        self.include.is_empty() || self.include.iter().any(|glob| glob_match(glob, name))
+    }
+}
+
+/// Matches a `/`-separated name against a glob.
+fn glob_match(pattern: &str, name: &str) -> bool {
+    let pattern: Vec<&str> = pattern.split('/').collect();
+    let segments: Vec<&str> = name.split('/').collect();
+    match_segments(&pattern, &segments)
+}
+
+fn match_segments(pattern: &[&str], segments: &[&str]) -> bool {
+    match pattern.split_first() {
+        None => segments.is_empty(),
+        Some((&"**", rest)) => (0..=segments.len()).any(|i| match_segments(rest, &segments[i..])),
+        Some((glob, rest)) => segments.split_first().is_some_and(|(segment, tail)| {
+            let glob: Vec<char> = glob.chars().collect();
+            let segment: Vec<char> = segment.chars().collect();
+            match_segment(&glob, &segment) && match_segments(rest, tail)
+        }),
+    }
+}
+
+/// Matches one path segment against a glob with `*` and `?` wildcards.
+fn match_segment(glob: &[char], text: &[char]) -> bool {
+    match glob.split_first() {
+        None => text.is_empty(),
+        Some(('*', rest)) => (0..=text.len()).any(|i| match_segment(rest, &text[i..])),
+        Some(('?', rest)) => !text.is_empty() && match_segment(rest, &text[1..]),
+        Some((c, rest)) => text.first() == Some(c) && match_segment(rest, &text[1..]),
+    }
+}
+
 /// Generates code to create an EmbeddedTemplates source.
 ///
 /// This function:
 /// 1. Walks the directory at compile time
-/// 2. Collects all files matching template extensions
+/// 2. Collects all files matching template extensions and the name filter
 /// 3. Generates an `EmbeddedSource<TemplateResource>` with entries and source path
 ///
 /// The returned `EmbeddedSource` can be passed to `RenderSetup` or converted
-/// to a `TemplateRegistry` via `into()`.
-pub fn embed_templates_impl(input: LitStr) -> TokenStream {
+/// to a `TemplateRegistry` via `into()`. Filtered sources are marked so that
+/// hot-reload only re-reads the files that were embedded, and trimming is
+/// recorded on the source so it applies to hot-reloaded files as well.
+pub fn embed_templates_impl(args: EmbedTemplatesArgs) -> TokenStream {
+    let input = args.path;
     let source_path = input.value();
     let dir_path = resolve_path(&source_path);
 
-    let files = match collect_files(&dir_path, TEMPLATE_EXTENSIONS) {
+    let files = match collect_files(&dir_path, TEMPLATE_EXTENSIONS, &args.filter) {
         Ok(files) => files,
         Err(e) => {
             return syn::Error::new(input.span(), e).to_compile_error();
         }
     };
+    let filtered = (!args.filter.is_empty()).then(|| quote! { .filtered() });
+    let trimmed = args
+        .trim_trailing_newline
+        .then(|| quote! { .trim_trailing_newline() });
 
     // Store the absolute path for runtime hot-reload to work correctly
     let absolute_path = dir_path.to_string_lossy().to_string();
@@ -75,6 +221,8 @@ pub fn embed_templates_impl(input: LitStr) -> TokenStream {
                 ENTRIES,
                 #absolute_path,
             )
+            #filtered
+            #trimmed
         }
     }
 }
@@ -88,11 +236,17 @@ pub fn embed_templates_impl(input: LitStr) -> TokenStream {
 ///
 /// The returned `EmbeddedSource` can be passed to `RenderSetup` or converted
 /// to a `StylesheetRegistry` via `into()`.
+///
+/// Each stylesheet is checked for YAML syntax errors, which are reported as
+/// compile errors naming the offending file. Style definitions themselves are
+/// validated when the registry is built at runtime.
 pub fn embed_styles_impl(input: LitStr) -> TokenStream {
     let source_path = input.value();
     let dir_path = resolve_path(&source_path);
 
-    let files = match collect_files(&dir_path, STYLESHEET_EXTENSIONS) {
+    let files = match collect_files(&dir_path, STYLESHEET_EXTENSIONS, &NameFilter::default())
+        .and_then(|files| check_yaml_syntax(&dir_path, &files).map(|()| files))
+    {
         Ok(files) => files,
         Err(e) => {
             return syn::Error::new(input.span(), e).to_compile_error();
@@ -138,8 +292,15 @@ fn resolve_path(path: &str) -> PathBuf {
 /// Returns a vector of (name_with_ext, content) pairs where name_with_ext
 /// is the relative path from root INCLUDING the extension (e.g., "themes/dark.yaml").
 ///
+/// Files whose extensionless name the filter rejects are skipped before their
+/// content is read, so the registry never sees them.
+///
 /// NO extension stripping or priority logic is done here - that's the registry's job.
-fn collect_files(dir: &Path, extensions: &[&str]) -> Result<Vec<(String, String)>, String> {
+fn collect_files(
+    dir: &Path,
+    extensions: &[&str],
+    filter: &NameFilter,
+) -> Result<Vec<(String, String)>, String> {
     if !dir.exists() {
         return Err(format!("Directory not found: {}", dir.display()));
     }
@@ -148,7 +309,7 @@ fn collect_files(dir: &Path, extensions: &[&str]) -> Result<Vec<(String, String)
     }
 
     let mut files = Vec::new();
-    collect_files_recursive(dir, dir, extensions, &mut files)?;
+    collect_files_recursive(dir, dir, extensions, filter, &mut files)?;
 
     // Sort for deterministic output (helps with reproducible builds)
     files.sort_by(|a, b| a.0.cmp(&b.0));
@@ -161,6 +322,7 @@ fn collect_files_recursive(
     current: &Path,
     root: &Path,
     extensions: &[&str],
+    filter: &NameFilter,
     files: &mut Vec<(String, String)>,
 ) -> Result<(), String> {
     let entries = std::fs::read_dir(current)
@@ -171,12 +333,12 @@ fn collect_files_recursive(
         let path = entry.path();
 
         if path.is_dir() {
-            collect_files_recursive(&path, root, extensions, files)?;
+            collect_files_recursive(&path, root, extensions, filter, files)?;
         } else if path.is_file() {
             let path_str = path.to_string_lossy();
 
             // Check if file has a recognized extension
-            if extensions.iter().any(|ext| path_str.ends_with(ext)) {
+            if let Some(ext) = extensions.iter().find(|ext| path_str.ends_with(*ext)) {
                 // Compute relative path from root (with extension)
                 let relative = path.strip_prefix(root).map_err(|_| {
                     format!("Failed to compute relative path for {}", path.display())
@@ -186,6 +348,11 @@ fn collect_files_recursive(
                     .to_string_lossy()
                     .replace(std::path::MAIN_SEPARATOR, "/");
 
+                let name = &name_with_ext[..name_with_ext.len() - ext.len()];
+                if !filter.allows(name) {
+                    continue;
+                }
+
                 let content = std::fs::read_to_string(&path)
                     .map_err(|e| format!("Failed to read {}: {}", path.display(), e))?;
 
@@ -197,6 +364,22 @@ fn collect_files_recursive(
     Ok(())
 }
 
+/// Checks that every collected stylesheet is well-formed YAML.
+///
+/// Returns an error naming the first file that fails to parse.
+fn check_yaml_syntax(dir: &Path, files: &[(String, String)]) -> Result<(), String> {
+    for (name, content) in files {
+        if let Err(e) = serde_yaml::from_str::<serde_yaml::Value>(content) {
+            return Err(format!(
+                "Failed to parse stylesheet {}: {}",
+                dir.join(name).display(),
+                e
+            ));
+        }
+    }
+    Ok(())
+}
+
 #[cfg(test)]
 mod tests {
     use super::*;
@@ -216,7 +399,12 @@ mod tests {
         let temp_dir = TempDir::new().unwrap();
         create_file(temp_dir.path(), "config.yaml", "key: value");
 
-        let files = collect_files(temp_dir.path(), STYLESHEET_EXTENSIONS).unwrap();
+        let files = collect_files(
+            temp_dir.path(),
+            STYLESHEET_EXTENSIONS,
+            &NameFilter::default(),
+        )
+        .unwrap();
 
         assert_eq!(files.len(), 1);
         assert_eq!(files[0].0, "config.yaml"); // Extension preserved
@@ -229,7 +417,12 @@ mod tests {
         create_file(temp_dir.path(), "themes/dark.yaml", "dark content");
         create_file(temp_dir.path(), "themes/light.yaml", "light content");
 
-        let files = collect_files(temp_dir.path(), STYLESHEET_EXTENSIONS).unwrap();
+        let files = collect_files(
+            temp_dir.path(),
+            STYLESHEET_EXTENSIONS,
+            &NameFilter::default(),
+        )
+        .unwrap();
 
         assert_eq!(files.len(), 2);
         let names: Vec<&str> = files.iter().map(|(n, _)| n.as_str()).collect();
@@ -243,7 +436,12 @@ mod tests {
         create_file(temp_dir.path(), "good.yaml", "yaml content");
         create_file(temp_dir.path(), "bad.txt", "text content");
 
-        let files = collect_files(temp_dir.path(), STYLESHEET_EXTENSIONS).unwrap();
+        let files = collect_files(
+            temp_dir.path(),
+            STYLESHEET_EXTENSIONS,
+            &NameFilter::default(),
+        )
+        .unwrap();
 
         assert_eq!(files.len(), 1);
         assert_eq!(files[0].0, "good.yaml");
@@ -255,7 +453,12 @@ mod tests {
         create_file(temp_dir.path(), "a.yaml", "a");
         create_file(temp_dir.path(), "b.yml", "b");
 
-        let files = collect_files(temp_dir.path(), STYLESHEET_EXTENSIONS).unwrap();
+        let files = collect_files(
+            temp_dir.path(),
+            STYLESHEET_EXTENSIONS,
+            &NameFilter::default(),
+        )
+        .unwrap();
 
         assert_eq!(files.len(), 2);
         let names: Vec<&str> = files.iter().map(|(n, _)| n.as_str()).collect();
@@ -269,7 +472,12 @@ mod tests {
         create_file(temp_dir.path(), "config.yaml", "yaml version");
         create_file(temp_dir.path(), "config.yml", "yml version");
 
-        let files = collect_files(temp_dir.path(), STYLESHEET_EXTENSIONS).unwrap();
+        let files = collect_files(
+            temp_dir.path(),
+            STYLESHEET_EXTENSIONS,
+            &NameFilter::default(),
+        )
+        .unwrap();
 
         // Both should be collected - registry handles priority
         assert_eq!(files.len(), 2);
@@ -277,11 +485,35 @@ mod tests {
 
     #[test]
     fn test_collect_files_directory_not_found() {
-        let result = collect_files(Path::new("/nonexistent/path"), STYLESHEET_EXTENSIONS);
+        let result = collect_files(
+            Path::new("/nonexistent/path"),
+            STYLESHEET_EXTENSIONS,
+            &NameFilter::default(),
+        );
         assert!(result.is_err());
         assert!(result.unwrap_err().contains("not found"));
     }
 
+    #[test]
+    fn test_check_yaml_syntax_accepts_valid_stylesheets() {
+        let files = vec![
+            ("a.yaml".to_string(), "title:\n  fg: red\n".to_string()),
+            ("empty.yml".to_string(), String::new()),
+        ];
+        assert!(check_yaml_syntax(Path::new("styles"), &files).is_ok());
+    }
+
+    #[test]
+    fn test_check_yaml_syntax_names_broken_file() {
+        let files = vec![
+            ("good.yaml".to_string(), "title: bold".to_string()),
+            ("themes/bad.yaml".to_string(), "title: [bold".to_string()),
+        ];
+        let err = check_yaml_syntax(Path::new("styles"), &files).unwrap_err();
+        assert!(err.contains("themes/bad.yaml"), "{}", err);
+        assert!(!err.contains("good.yaml"), "{}", err);
+    }
+
     #[test]
     fn test_collect_files_sorted_output() {
         let temp_dir = TempDir::new().unwrap();
@@ -289,9 +521,107 @@ mod tests {
         create_file(temp_dir.path(), "alpha.yaml", "a");
         create_file(temp_dir.path(), "middle.yaml", "m");
 
-        let files = collect_files(temp_dir.path(), STYLESHEET_EXTENSIONS).unwrap();
+        let files = collect_files(
+            temp_dir.path(),
+            STYLESHEET_EXTENSIONS,
+            &NameFilter::default(),
+        )
+        .unwrap();
 
         let names: Vec<&str> = files.iter().map(|(n, _)| n.as_str()).collect();
         assert_eq!(names, vec!["alpha.yaml", "middle.yaml", "zebra.yaml"]);
     }
+
+    fn name_filter(include: &[&str], exclude: &[&str]) -> NameFilter {
+        NameFilter {
+            include: include.iter().map(|s| s.to_string()).collect(),
+            exclude: exclude.iter().map(|s| s.to_string()).collect(),
+        }
+    }
+
+    #[test]
+    fn test_glob_match() {
+        assert!(glob_match("report/**", "report/summary"));
+        assert!(glob_match("report/**", "report/deep/detail"));
+        assert!(!glob_match("report/**", "list"));
+        assert!(glob_match("**/_*", "_base"));
+        assert!(glob_match("**/_*", "report/_partial"));
+        assert!(!glob_match("**/_*", "report/summary"));
+        assert!(glob_match("*", "list"));
+        assert!(!glob_match("*", "report/summary"));
+        assert!(glob_match("report/sum?ary", "report/summary"));
+        assert!(glob_match("*/summary", "report/summary"));
+    }
+
+    #[test]
+    fn test_name_filter_exclude_wins() {
+        let filter = name_filter(&["report/**"], &["**/_*"]);
+        assert!(filter.allows("report/summary"));
+        assert!(!filter.allows("report/_partial"));
+        assert!(!filter.allows("list"));
+
+        assert!(NameFilter::default().allows("anything/at/all"));
+        assert!(name_filter(&[], &["**/_*"]).allows("list"));
+    }
+
+    #[test]
+    fn test_collect_files_applies_filter_to_extensionless_name() {
+        let temp_dir = TempDir::new().unwrap();
+        create_file(temp_dir.path(), "list.jinja", "list");
+        create_file(temp_dir.path(), "report/summary.jinja", "summary");
+        create_file(temp_dir.path(), "report/summary.txt", "summary text");
+        create_file(temp_dir.path(), "report/_partial.jinja", "partial");
+
+        let files = collect_files(
+            temp_dir.path(),
+            TEMPLATE_EXTENSIONS,
+            &name_filter(&["report/**"], &["**/_*"]),
+        )
+        .unwrap();
+
+        let names: Vec<&str> = files.iter().map(|(n, _)| n.as_str()).collect();
+        assert_eq!(names, vec!["report/summary.jinja", "report/summary.txt"]);
+    }
+
+    #[test]
+    fn test_parse_args() {
+        let args: EmbedTemplatesArgs =
+            syn::parse_str(r#""templates", include = ["report/**"], exclude = ["**/_*", "x"],"#)
+                .unwrap();
+        assert_eq!(args.path.value(), "templates");
+        assert_eq!(args.filter.include, vec!["report/**"]);
+        assert_eq!(args.filter.exclude, vec!["**/_*", "x"]);
+
+        let args: EmbedTemplatesArgs = syn::parse_str(r#""templates""#).unwrap();
+        assert!(args.filter.is_empty());
+        assert!(!args.trim_trailing_newline);
+
+        let args: EmbedTemplatesArgs =
+            syn::parse_str(r#""templates", trim_trailing_newline = true, include = ["a"]"#)
+                .unwrap();
+        assert!(args.trim_trailing_newline);
+        assert_eq!(args.filter.include, vec!["a"]);
+    }
+
+    #[test]
+    fn test_parse_args_errors() {
+        let err = syn::parse_str::<EmbedTemplatesArgs>(r#""t", only = ["a"]"#)
+            .err()
+            .unwrap();
+        assert!(err.to_string().contains("unknown argument `only`"));
+
+        let err = syn::parse_str::<EmbedTemplatesArgs>(r#""t", include = ["a"], include = ["b"]"#)
+            .err()
+            .unwrap();
+        assert!(err.to_string().contains("duplicate `include`"));
+
+        let err = syn::parse_str::<EmbedTemplatesArgs>(
+            r#""t", trim_trailing_newline = true, trim_trailing_newline = false"#,
+        )
+        .err()
+        .unwrap();
+        assert!(err
+            .to_string()
+            .contains("duplicate `trim_trailing_newline`"));
+    }
 }
@@ -69,11 +69,48 @@ use syn::{parse_macro_input, DeriveInput, LitStr};
 /// (e.g., `config.jinja` and `config.txt`), the higher-priority extension wins
 /// for extensionless lookups.
 ///
+/// # Selecting a Subset
+///
+/// Optional `include` and `exclude` glob lists restrict which files are
+/// embedded, e.g. when one templates directory serves several binaries:
+///
+/// ```rust,ignore
+/// let templates = embed_templates!(
+///     "./templates",
+///     include = ["report/**"],
+///     exclude = ["**/_*"],
+/// );
+/// ```
+///
+/// Globs match the relative name without extension (`report/summary` for
+/// `report/summary.jinja`): `*` and `?` match within one path segment, `**`
+/// matches any number of segments. Without `include` every file is a
+/// candidate. Exclude wins over include when both match. Filtering happens
+/// before extension priority is applied, so an excluded `.jinja` file does
+/// not hide a `.txt` file with the same base name unless both are excluded.
+///
+/// # Trimming Trailing Newlines
+///
+/// Template files usually end with a newline, which shows up as a stray blank
+/// line in CLI output. `trim_trailing_newline = true` strips trailing line
+/// breaks from every template, embedded or hot-reloaded:
+///
+/// ```rust,ignore
+/// let templates = embed_templates!("./templates", trim_trailing_newline = true);
+/// ```
+///
+/// Content is kept as-is by default. MiniJinja's own whitespace control
+/// (`{%- ... -%}`) still applies on top. For registries built by hand, see
+/// `TemplateRegistry::trim_trailing_newline`.
+///
 /// # Hot Reload Behavior
 ///
 /// - Release builds: Uses embedded content (zero file I/O)
 /// - Debug builds: Reads from disk if source path exists (hot-reload)
 ///
+/// With `include`/`exclude`, hot-reload re-reads only the files that were
+/// embedded; new files need a rebuild to be picked up.
+///
 /// For working examples, see `standout/tests/embed_macros.rs`.
 ///
 /// # Compile-Time Errors
@@ -81,15 +118,17 @@ use syn::{parse_macro_input, DeriveInput, LitStr};
 /// The macro will fail to compile if:
 /// - The directory doesn't exist
 /// - The directory is not readable
-/// - Any file content is not valid UTF-8
+/// - Any embedded file content is not valid UTF-8
+/// - An argument other than `include`, `exclude` or `trim_trailing_newline`
+///   is given, or one is repeated
 ///
 /// [`EmbeddedTemplates`]: standout::EmbeddedTemplates
 /// [`RenderSetup`]: standout::RenderSetup
 /// [`TemplateRegistry`]: standout::TemplateRegistry
 #[proc_macro]
 pub fn embed_templates(input: TokenStream) -> TokenStream {
-    let path_lit = parse_macro_input!(input as LitStr);
-    embed::embed_templates_impl(path_lit).into()
+    let args = parse_macro_input!(input as embed::EmbedTemplatesArgs);
+    embed::embed_templates_impl(args).into()
 }
 
 /// Embeds all stylesheet files from a directory at compile time.
@@ -120,6 +159,7 @@ pub fn embed_templates(input: TokenStream) -> TokenStream {
 /// - The directory doesn't exist
 /// - The directory is not readable
 /// - Any file content is not valid UTF-8
+/// - Any stylesheet is not valid YAML (the error names the offending file)
 ///
 /// [`EmbeddedStyles`]: standout::EmbeddedStyles
 /// [`RenderSetup`]: standout::RenderSetup
@@ -185,7 +225,7 @@ pub fn dispatch_derive(input: TokenStream) -> TokenStream {
 ///
 /// | Attribute | Type | Description |
 /// |-----------|------|-------------|
-/// | `width` | `usize` or `"fill"` or `"Nfr"` | Column width strategy |
+/// | `width` | `usize` or `"fill"`, `"fill_max"` or `"Nfr"` | Column width strategy |
 /// | `min` | `usize` | Minimum width (for bounded) |
 /// | `max` | `usize` | Maximum width (for bounded) |
 /// | `align` | `"left"`, `"right"`, `"center"` | Text alignment |
@@ -299,6 +339,7 @@ pub fn tabular_row_derive(input: TokenStream) -> TokenStream {
 /// | `Bool` | Boolean field (supports Eq, Ne, Is) |
 /// | `skip` | Exclude this field from queries |
 /// | `rename = "..."` | Use a custom name for queries |
+/// | `flatten` | Expose a nested `Seekable` struct's fields as `field.sub_field` |
 ///
 /// # Generated Code
 ///
@@ -306,6 +347,8 @@ pub fn tabular_row_derive(input: TokenStream) -> TokenStream {
 ///
 /// 1. Field name constants (e.g., `Task::NAME`, `Task::PRIORITY`)
 /// 2. Implementation of `Seekable::seeker_field_value()`
+/// 3. Implementation of `SeekerSchema`
+/// 4. With `#[seek(query)]` on the struct, a typed query builder (`TaskQuery`)
 ///
 /// # Example
 ///
@@ -392,6 +435,76 @@ pub fn tabular_row_derive(input: TokenStream) -> TokenStream {
 ///     created_at: MyDateTime,
 /// }
 /// ```
+///
+/// # Optional Fields
+///
+/// `Option<T>` fields take the seek type of `T`. A `None` value never matches
+/// comparison operators, and can be queried with `isnull` / `isnotnull`:
+///
+/// ```ignore
+/// #[derive(Seekable)]
+/// struct Task {
+///     #[seek(String)]
+///     assignee: Option<String>,
+/// }
+///
+/// // `assignee-isnull` matches unassigned tasks
+/// let query = Query::new().and_is_null(Task::ASSIGNEE).build();
+/// ```
+///
+/// # Nested Fields
+///
+/// Mark a field whose type also derives `Seekable` with `#[seek(flatten)]` to
+/// query its fields through dotted paths. The prefix is the field name, or the
+/// `rename` value:
+///
+/// ```ignore
+/// #[derive(Seekable)]
+/// struct Author {
+///     #[seek(String)]
+///     name: String,
+/// }
+///
+/// #[derive(Seekable)]
+/// struct Post {
+///     #[seek(flatten)]
+///     author: Author,
+/// }
+///
+/// // `author.name-contains=ada` parses to field `author.name`, operator `Contains`
+/// let query = parse_query::<Post>(pairs)?;
+/// ```
+///
+/// Flattened fields don't get field name constants or typed query builder
+/// methods; use the dotted names directly.
+///
+/// # Typed Query Builder
+///
+/// Add `#[seek(query)]` to the struct to generate a `<Struct>Query` builder with
+/// one method per field and valid operator. AND clauses use `field_op`, OR and
+/// NOT clauses use `or_field_op` and `not_field_op`:
+///
+/// ```ignore
+/// #[derive(Seekable)]
+/// #[seek(query)]
+/// struct Task {
+///     #[seek(String)]
+///     name: String,
+///     #[seek(Number)]
+///     priority: u8,
+/// }
+///
+/// let query = TaskQuery::new()
+///     .priority_gte(3)
+///     .not_name_contains("draft")
+///     .build();
+///
+/// // Does not compile: `contains` is not a numeric operator
+/// // TaskQuery::new().priority_contains("3");
+/// ```
+///
+/// `Option` fields also get argument-less `field_isnull` and `field_isnotnull`
+/// methods.
 #[proc_macro_derive(Seekable, attributes(seek))]
 pub fn seekable_derive(input: TokenStream) -> TokenStream {
     let input = parse_macro_input!(input as DeriveInput);
@@ -73,6 +73,8 @@ pub struct SeekAttr {
     pub skip: bool,
     /// Custom field name for queries (default: field name).
     pub rename: Option<String>,
+    /// Expose the nested struct's seekable fields as `field.sub_field`.
+    pub flatten: bool,
     /// The span for error reporting.
     pub span: Span,
 }
@@ -83,6 +85,7 @@ impl Default for SeekAttr {
             seek_type: None,
             skip: false,
             rename: None,
+            flatten: false,
             span: Span::call_site(),
         }
     }
@@ -100,13 +103,16 @@ impl Parse for SeekAttr {
                 Meta::Path(p) => {
                     if p.is_ident("skip") {
                         attr.skip = true;
+                    } else if p.is_ident("flatten") {
+                        attr.flatten = true;
+                        attr.span = p.span();
                     } else if let Some(ident) = p.get_ident() {
                         attr.seek_type = Some(SeekType::from_ident(ident)?);
                         attr.span = ident.span();
                     } else {
                         return Err(Error::new(
                             p.span(),
-                            "expected seek type: String, Number, Timestamp, Enum, Bool, or skip",
+                            "expected seek type: String, Number, Timestamp, Enum, Bool, skip, or flatten",
                         ));
                     }
                 }
@@ -118,7 +124,7 @@ impl Parse for SeekAttr {
                             lit: Lit::Str(s), ..
                         }) = &nv.value
                         {
-                            attr.rename = Some(s.value());
+                            attr.rename = Some(validate_rename(s)?);
                         } else {
                             return Err(Error::new(
                                 nv.value.span(),
@@ -147,16 +153,64 @@ impl Parse for SeekAttr {
                 _ => {
                     return Err(Error::new(
                         meta.span(),
-                        "unknown seek attribute. Expected: String, Number, Timestamp, Enum, Bool, skip, rename = \"...\", or ty = \"...\"",
+                        "unknown seek attribute. Expected: String, Number, Timestamp, Enum, Bool, skip, flatten, rename = \"...\", or ty = \"...\"",
                     ));
                 }
             }
         }
 
+        if attr.flatten && attr.seek_type.is_some() {
+            return Err(Error::new(
+                attr.span,
+                "flatten cannot be combined with a seek type; the nested struct defines its own field types",
+            ));
+        }
+
+        Ok(attr)
+    }
+}
+
+/// Struct-level attributes from `#[seek(...)]`.
+#[derive(Debug, Clone, Default)]
+pub struct SeekStructAttr {
+    /// Generate a typed query builder: `#[seek(query)]`
+    pub query: bool,
+}
+
+impl Parse for SeekStructAttr {
+    fn parse(input: ParseStream) -> Result<Self> {
+        let mut attr = SeekStructAttr::default();
+
+        let content: Punctuated<Ident, Token![,]> = Punctuated::parse_terminated(input)?;
+
+        for ident in content {
+            if ident == "query" {
+                attr.query = true;
+            } else {
+                return Err(Error::new(
+                    ident.span(),
+                    format!(
+                        "unknown struct-level seek attribute: '{}'. Expected: query",
+                        ident
+                    ),
+                ));
+            }
+        }
+
         Ok(attr)
     }
 }
 
+/// Extract `#[seek(...)]` attributes from a struct's attributes.
+pub fn parse_seek_struct_attrs(attrs: &[Attribute]) -> Result<SeekStructAttr> {
+    for attr in attrs {
+        if attr.path().is_ident("seek") {
+            return attr.parse_args::<SeekStructAttr>();
+        }
+    }
+    Ok(SeekStructAttr::default())
+}
+
 /// Extract `#[seek(...)]` attributes from a field's attributes.
 pub fn parse_seek_attrs(attrs: &[Attribute]) -> Result<SeekAttr> {
     for attr in attrs {
@@ -167,6 +221,28 @@ pub fn parse_seek_attrs(attrs: &[Attribute]) -> Result<SeekAttr> {
     Ok(SeekAttr::default())
 }
 
+/// Checks that a `rename` value can name the generated field constant and
+/// query builder methods: letters, digits, `_` and `-`, not starting with a
+/// digit.
+fn validate_rename(lit: &syn::LitStr) -> Result<String> {
+    let name = lit.value();
+    let as_ident = name.replace('-', "_");
+    let valid = !name.is_empty()
+        && !name.starts_with(|c: char| c.is_ascii_digit())
+        && syn::parse_str::<Ident>(&format!("_{}", as_ident)).is_ok();
+    if valid {
+        Ok(name)
+    } else {
+        Err(Error::new(
+            lit.span(),
+            format!(
+                "invalid rename \"{}\": use letters, digits, '_' or '-', not starting with a digit",
+                name
+            ),
+        ))
+    }
+}
+
 #[cfg(test)]
 mod tests {
     use super::*;
@@ -249,6 +325,21 @@ mod tests {
         assert_eq!(attr.rename, Some("status".to_string()));
     }
 
+    #[test]
+    fn test_seek_rename_with_dash() {
+        let attr = parse_seek(r#"Timestamp, rename = "due-at""#).unwrap();
+        assert_eq!(attr.rename, Some("due-at".to_string()));
+    }
+
+    #[test]
+    fn test_seek_rename_must_be_identifier_like() {
+        for name in ["", "due date", "2fast", "a.b", "x!"] {
+            let result = parse_seek(&format!(r#"String, rename = "{}""#, name));
+            let err = result.expect_err(name).to_string();
+            assert!(err.contains("invalid rename"), "{}: {}", name, err);
+        }
+    }
+
     #[test]
     fn test_seek_invalid_type() {
         let result = parse_seek("invalid");
@@ -259,6 +350,36 @@ mod tests {
             .contains("unknown seek type"));
     }
 
+    #[test]
+    fn test_seek_flatten() {
+        let attr = parse_seek(r#"flatten, rename = "by""#).unwrap();
+        assert!(attr.flatten);
+        assert_eq!(attr.seek_type, None);
+        assert_eq!(attr.rename, Some("by".to_string()));
+    }
+
+    #[test]
+    fn test_seek_flatten_with_type_rejected() {
+        let result = parse_seek("flatten, String");
+        assert!(result.is_err());
+    }
+
+    #[test]
+    fn test_seek_struct_query() {
+        let attr = syn::parse_str::<SeekStructAttr>("query").unwrap();
+        assert!(attr.query);
+    }
+
+    #[test]
+    fn test_seek_struct_unknown() {
+        let result = syn::parse_str::<SeekStructAttr>("builder");
+        assert!(result.is_err());
+        assert!(result
+            .unwrap_err()
+            .to_string()
+            .contains("unknown struct-level seek attribute"));
+    }
+
     #[test]
     fn test_seek_enumeration_alias() {
         let attr = parse_seek("enumeration").unwrap();
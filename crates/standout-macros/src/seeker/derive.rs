@@ -7,7 +7,7 @@ use proc_macro2::TokenStream;
 use quote::{format_ident, quote};
 use syn::{spanned::Spanned, Data, DeriveInput, Error, Fields, Result};
 
-use super::attrs::{parse_seek_attrs, SeekType};
+use super::attrs::{parse_seek_attrs, parse_seek_struct_attrs, SeekType};
 
 /// Information about a seekable field.
 struct FieldInfo {
@@ -17,11 +17,26 @@ struct FieldInfo {
     seek_type: SeekType,
     /// The actual field identifier.
     field_ident: syn::Ident,
+    /// The field's Rust type, unwrapped from `Option` for optional fields.
+    field_ty: syn::Type,
+    /// Whether the field is an `Option<T>`.
+    optional: bool,
+}
+
+/// Information about a `#[seek(flatten)]` field.
+struct NestedInfo {
+    /// The query name prefix (field name or rename), without the trailing dot.
+    prefix: String,
+    /// The actual field identifier.
+    field_ident: syn::Ident,
+    /// The nested struct type.
+    field_ty: syn::Type,
 }
 
 /// Main implementation of the Seekable derive macro.
 pub fn seekable_derive_impl(input: DeriveInput) -> Result<TokenStream> {
     let struct_name = &input.ident;
+    let struct_attrs = parse_seek_struct_attrs(&input.attrs)?;
 
     // Ensure we have a struct with named fields
     let fields = match &input.data {
@@ -44,6 +59,7 @@ pub fn seekable_derive_impl(input: DeriveInput) -> Result<TokenStream> {
 
     // Collect field information
     let mut field_infos: Vec<FieldInfo> = Vec::new();
+    let mut nested_infos: Vec<NestedInfo> = Vec::new();
 
     for field in fields.iter() {
         let field_name = field
@@ -59,6 +75,16 @@ pub fn seekable_derive_impl(input: DeriveInput) -> Result<TokenStream> {
             continue;
         }
 
+        // Nested seekable struct: expose its fields under a dotted prefix
+        if seek_attrs.flatten {
+            nested_infos.push(NestedInfo {
+                prefix: seek_attrs.rename.unwrap_or_else(|| field_name.to_string()),
+                field_ident: field_name.clone(),
+                field_ty: field.ty.clone(),
+            });
+            continue;
+        }
+
         // If no seek type is specified, skip this field
         let seek_type = match seek_attrs.seek_type {
             Some(t) => t,
@@ -68,10 +94,17 @@ pub fn seekable_derive_impl(input: DeriveInput) -> Result<TokenStream> {
         // Determine the query field name
         let query_name = seek_attrs.rename.unwrap_or_else(|| field_name.to_string());
 
+        let (field_ty, optional) = match option_inner_type(&field.ty) {
+            Some(inner) => (inner.clone(), true),
+            None => (field.ty.clone(), false),
+        };
+
         field_infos.push(FieldInfo {
             query_name,
             seek_type,
             field_ident: field_name.clone(),
+            field_ty,
+            optional,
         });
     }
 
@@ -94,30 +127,44 @@ pub fn seekable_derive_impl(input: DeriveInput) -> Result<TokenStream> {
         .map(|info| {
             let query_name = &info.query_name;
             let field_ident = &info.field_ident;
+            // `v` is a reference to the field value (or to the `Some` payload)
             let value_expr = match info.seek_type {
                 SeekType::String => {
-                    quote! { ::standout_seeker::Value::String(&self.#field_ident) }
+                    quote! { ::standout_seeker::Value::String(v) }
                 }
                 SeekType::Number => {
-                    quote! { ::standout_seeker::Value::Number(::standout_seeker::Number::from(self.#field_ident)) }
+                    quote! { ::standout_seeker::Value::Number(::standout_seeker::Number::from(*v)) }
                 }
                 SeekType::Timestamp => {
                     quote! {
                         ::standout_seeker::Value::Timestamp(
-                            ::standout_seeker::SeekerTimestamp::seeker_timestamp(&self.#field_ident)
+                            ::standout_seeker::SeekerTimestamp::seeker_timestamp(v)
                         )
                     }
                 }
                 SeekType::Enum => {
                     quote! {
                         ::standout_seeker::Value::Enum(
-                            ::standout_seeker::SeekerEnum::seeker_discriminant(&self.#field_ident)
+                            ::standout_seeker::SeekerEnum::seeker_discriminant(v)
                         )
                     }
                 }
                 SeekType::Bool => {
-                    quote! { ::standout_seeker::Value::Bool(self.#field_ident) }
+                    quote! { ::standout_seeker::Value::Bool(*v) }
+                }
+            };
+            let value_expr = if info.optional {
+                quote! {
+                    match &self.#field_ident {
+                        ::core::option::Option::Some(v) => #value_expr,
+                        ::core::option::Option::None => ::standout_seeker::Value::None,
+                    }
                 }
+            } else {
+                quote! {{
+                    let v = &self.#field_ident;
+                    #value_expr
+                }}
             };
             quote! {
                 #query_name => #value_expr,
@@ -149,6 +196,69 @@ pub fn seekable_derive_impl(input: DeriveInput) -> Result<TokenStream> {
         .map(|info| info.query_name.as_str())
         .collect();
 
+    // Nested fields delegate to the nested struct after stripping "prefix."
+    let nested_value_dispatch: Vec<TokenStream> = nested_infos
+        .iter()
+        .map(|info| {
+            let dotted = format!("{}.", info.prefix);
+            let field_ident = &info.field_ident;
+            quote! {
+                if let ::core::option::Option::Some(rest) = field.strip_prefix(#dotted) {
+                    return ::standout_seeker::Seekable::seeker_field_value(&self.#field_ident, rest);
+                }
+            }
+        })
+        .collect();
+
+    let nested_type_dispatch: Vec<TokenStream> = nested_infos
+        .iter()
+        .map(|info| {
+            let dotted = format!("{}.", info.prefix);
+            let field_ty = &info.field_ty;
+            quote! {
+                if let ::core::option::Option::Some(rest) = field.strip_prefix(#dotted) {
+                    return <#field_ty as ::standout_seeker::SeekerSchema>::field_type(rest);
+                }
+            }
+        })
+        .collect();
+
+    // Flat structs return a static array; nested names are joined once at runtime
+    let field_names_body = if nested_infos.is_empty() {
+        quote! { &[#(#field_name_literals),*] }
+    } else {
+        let nested_names: Vec<TokenStream> = nested_infos
+            .iter()
+            .map(|info| {
+                let prefix = &info.prefix;
+                let field_ty = &info.field_ty;
+                quote! {
+                    for nested in <#field_ty as ::standout_seeker::SeekerSchema>::field_names() {
+                        let name = ::std::format!("{}.{}", #prefix, nested);
+                        names.push(&*::std::boxed::Box::leak(name.into_boxed_str()));
+                    }
+                }
+            })
+            .collect();
+        quote! {
+            static NAMES: ::std::sync::OnceLock<::std::vec::Vec<&'static str>> =
+                ::std::sync::OnceLock::new();
+            NAMES.get_or_init(|| {
+                let mut names: ::std::vec::Vec<&'static str> =
+                    ::std::vec![#(#field_name_literals),*];
+                #(#nested_names)*
+                names
+            })
+        }
+    };
+
+    // Generate the typed query builder if requested
+    let query_builder = if struct_attrs.query {
+        generate_query_builder(&input, &field_infos)
+    } else {
+        TokenStream::new()
+    };
+
     // Generate the impl blocks
     let expanded = quote! {
         impl #struct_name {
@@ -157,6 +267,7 @@ pub fn seekable_derive_impl(input: DeriveInput) -> Result<TokenStream> {
 
         impl ::standout_seeker::Seekable for #struct_name {
             fn seeker_field_value(&self, field: &str) -> ::standout_seeker::Value<'_> {
+                #(#nested_value_dispatch)*
                 match field {
                     #(#field_matches)*
                     _ => ::standout_seeker::Value::None,
@@ -166,6 +277,7 @@ pub fn seekable_derive_impl(input: DeriveInput) -> Result<TokenStream> {
 
         impl ::standout_seeker::SeekerSchema for #struct_name {
             fn field_type(field: &str) -> ::core::option::Option<::standout_seeker::SeekType> {
+                #(#nested_type_dispatch)*
                 match field {
                     #(#schema_field_type_matches)*
                     _ => ::core::option::Option::None,
@@ -173,14 +285,291 @@ pub fn seekable_derive_impl(input: DeriveInput) -> Result<TokenStream> {
             }
 
             fn field_names() -> &'static [&'static str] {
-                &[#(#field_name_literals),*]
+                #field_names_body
             }
         }
+
+        #query_builder
     };
 
     Ok(expanded)
 }
 
+/// The argument a generated query builder method takes.
+enum ArgKind {
+    /// `value: &str`
+    Str,
+    /// `pattern: &str`, returning `Result<Self>`
+    Regex,
+    /// `value: impl Into<Number>`
+    Number,
+    /// `ts: Timestamp`
+    Timestamp,
+    /// `value: FieldType` (via `SeekerEnum`)
+    Enum,
+    /// `values: impl IntoIterator<Item = FieldType>` (via `SeekerEnum`)
+    EnumSet,
+    /// `value: bool`
+    Bool,
+    /// No argument (`IsNull` / `IsNotNull` on `Option` fields)
+    Presence,
+}
+
+/// Returns the operators valid for a seek type, as (method suffix, `Op` variant, argument).
+///
+/// This mirrors the `is_*_op` checks on `standout_seeker::Op`.
+fn builder_ops(seek_type: SeekType) -> Vec<(&'static str, &'static str, ArgKind)> {
+    match seek_type {
+        SeekType::String => vec![
+            ("eq", "Eq", ArgKind::Str),
+            ("ne", "Ne", ArgKind::Str),
+            ("startswith", "StartsWith", ArgKind::Str),
+            ("endswith", "EndsWith", ArgKind::Str),
+            ("contains", "Contains", ArgKind::Str),
+            ("notcontains", "NotContains", ArgKind::Str),
+            ("regex", "Regex", ArgKind::Regex),
+        ],
+        SeekType::Number => vec![
+            ("eq", "Eq", ArgKind::Number),
+            ("ne", "Ne", ArgKind::Number),
+            ("gt", "Gt", ArgKind::Number),
+            ("gte", "Gte", ArgKind::Number),
+            ("lt", "Lt", ArgKind::Number),
+            ("lte", "Lte", ArgKind::Number),
+        ],
+        SeekType::Timestamp => vec![
+            ("eq", "Eq", ArgKind::Timestamp),
+            ("ne", "Ne", ArgKind::Timestamp),
+            ("gt", "Gt", ArgKind::Timestamp),
+            ("gte", "Gte", ArgKind::Timestamp),
+            ("lt", "Lt", ArgKind::Timestamp),
+            ("lte", "Lte", ArgKind::Timestamp),
+            ("before", "Before", ArgKind::Timestamp),
+            ("after", "After", ArgKind::Timestamp),
+        ],
+        SeekType::Enum => vec![
+            ("eq", "Eq", ArgKind::Enum),
+            ("ne", "Ne", ArgKind::Enum),
+            ("in", "In", ArgKind::EnumSet),
+            ("notin", "NotIn", ArgKind::EnumSet),
+        ],
+        SeekType::Bool => vec![
+            ("eq", "Eq", ArgKind::Bool),
+            ("ne", "Ne", ArgKind::Bool),
+            ("is", "Is", ArgKind::Bool),
+        ],
+    }
+}
+
+/// Generates the `<Struct>Query` typed builder for `#[seek(query)]`.
+///
+/// Each field gets one method per valid operator and clause group, e.g.
+/// `priority_gte`, `or_priority_gte` and `not_priority_gte`. Operators that are
+/// invalid for a field's type have no method, so misuse fails to compile.
+fn generate_query_builder(input: &DeriveInput, field_infos: &[FieldInfo]) -> TokenStream {
+    let struct_name = &input.ident;
+    let vis = &input.vis;
+    let builder_name = format_ident!("{}Query", struct_name);
+    let builder_doc = format!(
+        "Typed query builder for [`{}`], generated by `#[seek(query)]`.",
+        struct_name
+    );
+
+    // (method prefix, Query group method, clause description)
+    let groups = [
+        ("", "and", "an AND"),
+        ("or_", "or", "an OR"),
+        ("not_", "not", "a NOT"),
+    ];
+
+    let mut methods: Vec<TokenStream> = Vec::new();
+    for info in field_infos {
+        let query_name = &info.query_name;
+        let field_ty = &info.field_ty;
+        let snake = to_screaming_snake_case(query_name).to_lowercase();
+
+        let mut ops = builder_ops(info.seek_type);
+        if info.optional {
+            ops.push(("isnull", "IsNull", ArgKind::Presence));
+            ops.push(("isnotnull", "IsNotNull", ArgKind::Presence));
+        }
+
+        for (suffix, op_variant, arg) in ops {
+            let op_ident = format_ident!("{}", op_variant);
+
+            for (prefix, group, group_label) in groups {
+                let method = format_ident!("{}{}_{}", prefix, snake, suffix);
+                let group_ident = format_ident!("{}", group);
+                let doc = match arg {
+                    ArgKind::Presence => {
+                        format!("Adds {} clause: `{}` {}.", group_label, query_name, suffix)
+                    }
+                    _ => format!(
+                        "Adds {} clause: `{}` {} the given value.",
+                        group_label, query_name, suffix
+                    ),
+                };
+
+                let method_tokens = match arg {
+                    ArgKind::Regex => {
+                        let regex_method = format_ident!("{}_regex", group);
+                        quote! {
+                            #[doc = #doc]
+                            ///
+                            /// Returns an error if the pattern is invalid.
+                            pub fn #method(self, pattern: &str) -> ::standout_seeker::Result<Self> {
+                                self.query
+                                    .#regex_method(#query_name, pattern)
+                                    .map(|query| Self { query })
+                            }
+                        }
+                    }
+                    _ => {
+                        let (param, value) = match arg {
+                            ArgKind::Str => (
+                                quote! { value: &str },
+                                quote! { ::standout_seeker::ClauseValue::String(value.to_string()) },
+                            ),
+                            ArgKind::Number => (
+                                quote! { value: impl ::core::convert::Into<::standout_seeker::Number> },
+                                quote! { ::standout_seeker::ClauseValue::Number(value.into()) },
+                            ),
+                            ArgKind::Timestamp => (
+                                quote! { ts: ::standout_seeker::Timestamp },
+                                quote! { ::standout_seeker::ClauseValue::Timestamp(ts) },
+                            ),
+                            ArgKind::Enum => (
+                                quote! { value: #field_ty },
+                                quote! {
+                                    ::standout_seeker::ClauseValue::Enum(
+                                        ::standout_seeker::SeekerEnum::seeker_discriminant(&value)
+                                    )
+                                },
+                            ),
+                            ArgKind::EnumSet => (
+                                quote! { values: impl ::core::iter::IntoIterator<Item = #field_ty> },
+                                quote! {
+                                    ::standout_seeker::ClauseValue::EnumSet(
+                                        values
+                                            .into_iter()
+                                            .map(|v| ::standout_seeker::SeekerEnum::seeker_discriminant(&v))
+                                            .collect()
+                                    )
+                                },
+                            ),
+                            ArgKind::Bool => (
+                                quote! { value: bool },
+                                quote! { ::standout_seeker::ClauseValue::Bool(value) },
+                            ),
+                            ArgKind::Presence => (
+                                quote! {},
+                                quote! { ::standout_seeker::ClauseValue::Bool(true) },
+                            ),
+                            ArgKind::Regex => unreachable!(),
+                        };
+                        quote! {
+                            #[doc = #doc]
+                            pub fn #method(self, #param) -> Self {
+                                Self {
+                                    query: self.query.#group_ident(
+                                        #query_name,
+                                        ::standout_seeker::Op::#op_ident,
+                                        #value,
+                                    ),
+                                }
+                            }
+                        }
+                    }
+                };
+                methods.push(method_tokens);
+            }
+        }
+    }
+
+    quote! {
+        #[doc = #builder_doc]
+        ///
+        /// Only operators valid for each field's type have methods, so invalid
+        /// combinations are rejected at compile time.
+        #[derive(Debug, Clone, Default)]
+        #vis struct #builder_name {
+            query: ::standout_seeker::Query,
+        }
+
+        impl #builder_name {
+            /// Creates an empty query builder.
+            pub fn new() -> Self {
+                Self::default()
+            }
+
+            #(#methods)*
+
+            /// Adds an ordering by the given field.
+            pub fn order_by(self, field: &str, dir: ::standout_seeker::Dir) -> Self {
+                Self {
+                    query: self.query.order_by(field, dir),
+                }
+            }
+
+            /// Adds an ascending ordering by the given field.
+            pub fn order_asc(self, field: &str) -> Self {
+                self.order_by(field, ::standout_seeker::Dir::Asc)
+            }
+
+            /// Adds a descending ordering by the given field.
+            pub fn order_desc(self, field: &str) -> Self {
+                self.order_by(field, ::standout_seeker::Dir::Desc)
+            }
+
+            /// Limits the number of results.
+            pub fn limit(self, n: usize) -> Self {
+                Self {
+                    query: self.query.limit(n),
+                }
+            }
+
+            /// Skips the first `n` results.
+            pub fn offset(self, n: usize) -> Self {
+                Self {
+                    query: self.query.offset(n),
+                }
+            }
+
+            /// Finalizes the builder into a [`Query`](::standout_seeker::Query).
+            pub fn build(self) -> ::standout_seeker::Query {
+                self.query.build()
+            }
+        }
+
+        impl ::core::convert::From<#builder_name> for ::standout_seeker::Query {
+            fn from(builder: #builder_name) -> Self {
+                builder.build()
+            }
+        }
+    }
+}
+
+/// Returns `T` if `ty` is `Option<T>` (also matching `std::option::Option<T>`).
+fn option_inner_type(ty: &syn::Type) -> Option<&syn::Type> {
+    let syn::Type::Path(type_path) = ty else {
+        return None;
+    };
+    if type_path.qself.is_some() {
+        return None;
+    }
+    let segment = type_path.path.segments.last()?;
+    if segment.ident != "Option" {
+        return None;
+    }
+    let syn::PathArguments::AngleBracketed(args) = &segment.arguments else {
+        return None;
+    };
+    match args.args.first()? {
+        syn::GenericArgument::Type(inner) if args.args.len() == 1 => Some(inner),
+        _ => None,
+    }
+}
+
 /// Convert a string to SCREAMING_SNAKE_CASE.
 fn to_screaming_snake_case(s: &str) -> String {
     let mut result = String::with_capacity(s.len() + 4);
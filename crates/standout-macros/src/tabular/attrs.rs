@@ -22,6 +22,8 @@ pub struct ColAttr {
     pub width_fixed: Option<usize>,
     /// Fill width: `width = "fill"`
     pub width_fill: bool,
+    /// Content-capped fill width: `width = "fill_max"`
+    pub width_fill_max: bool,
     /// Fraction width: `width = "2fr"`
     pub width_fraction: Option<usize>,
     /// Minimum width: `min = 10`
@@ -189,6 +191,7 @@ impl Parse for TabularAttr {
 /// Parse width value which can be:
 /// - Integer: `width = 8` → Fixed(8)
 /// - String "fill": `width = "fill"` → Fill
+/// - String "fill_max": `width = "fill_max"` → FillMax
 /// - String "Nfr": `width = "2fr"` → Fraction(2)
 fn parse_width_value(expr: &Expr, attr: &mut ColAttr) -> Result<()> {
     match expr {
@@ -201,6 +204,8 @@ fn parse_width_value(expr: &Expr, attr: &mut ColAttr) -> Result<()> {
                 let s = lit_str.value();
                 if s == "fill" {
                     attr.width_fill = true;
+                } else if s == "fill_max" {
+                    attr.width_fill_max = true;
                 } else if s.ends_with("fr") {
                     let num_str = s.trim_end_matches("fr");
                     let n: usize = num_str.parse().map_err(|_| {
@@ -213,14 +218,17 @@ fn parse_width_value(expr: &Expr, attr: &mut ColAttr) -> Result<()> {
                 } else {
                     return Err(Error::new(
                         lit_str.span(),
-                        format!("invalid width string: '{}'. Expected 'fill' or '<n>fr'", s),
+                        format!(
+                            "invalid width string: '{}'. Expected 'fill', 'fill_max' or '<n>fr'",
+                            s
+                        ),
                     ));
                 }
             }
             _ => {
                 return Err(Error::new(
                     expr_lit.span(),
-                    "width must be an integer or string ('fill' or '<n>fr')",
+                    "width must be an integer or string ('fill', 'fill_max' or '<n>fr')",
                 ));
             }
         },
@@ -276,11 +284,13 @@ pub fn parse_tabular_attrs(attrs: &[Attribute]) -> Result<TabularAttr> {
 
 /// Generate token stream for Width enum variant.
 pub fn generate_width_tokens(attr: &ColAttr) -> TokenStream {
-    // Priority: width_fixed > width_fill > width_fraction > bounded(min, max) > default
+    // Priority: width_fixed > width_fill > width_fill_max > width_fraction > bounded(min, max) > default
     if let Some(w) = attr.width_fixed {
         quote! { ::standout::tabular::Width::Fixed(#w) }
     } else if attr.width_fill {
         quote! { ::standout::tabular::Width::Fill }
+    } else if attr.width_fill_max {
+        quote! { ::standout::tabular::Width::FillMax }
     } else if let Some(n) = attr.width_fraction {
         quote! { ::standout::tabular::Width::Fraction(#n) }
     } else if attr.min.is_some() || attr.max.is_some() {
@@ -398,6 +408,13 @@ mod tests {
         assert_eq!(attr.width_fixed, None);
     }
 
+    #[test]
+    fn test_col_width_fill_max() {
+        let attr = parse_col(r#"width = "fill_max""#).unwrap();
+        assert!(attr.width_fill_max);
+        assert!(!attr.width_fill);
+    }
+
     #[test]
     fn test_col_width_fraction() {
         let attr = parse_col(r#"width = "2fr""#).unwrap();
@@ -550,6 +567,16 @@ mod tests {
         assert!(tokens.to_string().contains("Fill"));
     }
 
+    #[test]
+    fn test_generate_width_fill_max() {
+        let attr = ColAttr {
+            width_fill_max: true,
+            ..Default::default()
+        };
+        let tokens = generate_width_tokens(&attr);
+        assert!(tokens.to_string().contains("FillMax"));
+    }
+
     #[test]
     fn test_generate_width_fraction() {
         let attr = ColAttr {
@@ -107,6 +107,7 @@ pub fn tabular_derive_impl(input: DeriveInput) -> Result<TokenStream> {
                 style_from_value: #style_from_value,
                 key: #key_tokens,
                 header: #header_tokens,
+                priority: 0,
             }
         });
     }
@@ -120,16 +121,14 @@ pub fn tabular_derive_impl(input: DeriveInput) -> Result<TokenStream> {
     let expanded = quote! {
         impl ::standout::tabular::Tabular for #struct_name {
             fn tabular_spec() -> ::standout::tabular::TabularSpec {
-                ::standout::tabular::TabularSpec {
-                    columns: vec![
+                ::standout::tabular::TabularSpec::builder()
+                    .columns(vec![
                         #(#column_tokens),*
-                    ],
-                    decorations: ::standout::tabular::Decorations {
-                        column_sep: #separator.to_string(),
-                        row_prefix: #prefix.to_string(),
-                        row_suffix: #suffix.to_string(),
-                    },
-                }
+                    ])
+                    .separator(#separator)
+                    .prefix(#prefix)
+                    .suffix(#suffix)
+                    .build()
             }
         }
     };
@@ -58,6 +58,9 @@ use std::collections::HashMap;
 use std::fmt::Debug;
 use std::sync::Arc;
 
+/// Template variable holding [`RenderContext::terminal_width`].
+pub(crate) const TERMINAL_WIDTH_VAR: &str = "terminal_width";
+
 /// Information available at render time for dynamic context providers.
 ///
 /// This struct is passed to [`ContextProvider::provide`] to allow context objects
@@ -260,11 +263,21 @@ impl ContextRegistry {
     /// Resolves all context providers into values for the given render context.
     ///
     /// Returns a map of names to values that can be merged into the template context.
+    /// When the render context has a width, it is included as `terminal_width`
+    /// unless a provider of that name is registered; the `table` and `tabular`
+    /// template functions use it as their default width.
     pub fn resolve(&self, ctx: &RenderContext) -> HashMap<String, Value> {
-        self.providers
+        let mut values: HashMap<String, Value> = self
+            .providers
             .iter()
             .map(|(name, provider)| (name.clone(), provider.provide(ctx)))
-            .collect()
+            .collect();
+        if let Some(width) = ctx.terminal_width {
+            values
+                .entry(TERMINAL_WIDTH_VAR.to_string())
+                .or_insert_with(|| Value::from(width));
+        }
+        values
     }
 
     /// Gets the names of all registered context entries.
@@ -380,6 +393,21 @@ mod tests {
         assert_eq!(resolved.get("terminal_width"), Some(&Value::from(120)));
     }
 
+    #[test]
+    fn context_registry_resolve_includes_width() {
+        let (theme, data) = test_context();
+        let registry = ContextRegistry::new();
+
+        let ctx = RenderContext::new(OutputMode::Text, Some(42), &theme, &data);
+        assert_eq!(
+            registry.resolve(&ctx).get("terminal_width"),
+            Some(&Value::from(42))
+        );
+
+        let ctx = RenderContext::new(OutputMode::Text, None, &theme, &data);
+        assert!(registry.resolve(&ctx).is_empty());
+    }
+
     #[test]
     fn context_registry_names() {
         let mut registry = ContextRegistry::new();
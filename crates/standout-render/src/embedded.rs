@@ -30,7 +30,7 @@ use std::marker::PhantomData;
 use std::path::Path;
 
 use crate::file_loader::{build_embedded_registry, walk_dir};
-use crate::style::{StylesheetRegistry, STYLESHEET_EXTENSIONS};
+use crate::style::{StylesheetError, StylesheetRegistry, STYLESHEET_EXTENSIONS};
 use crate::template::{walk_template_dir, TemplateRegistry};
 use crate::theme::Theme;
 
@@ -60,6 +60,13 @@ pub struct EmbeddedSource<R> {
     /// In debug mode, if this path exists, files are read from disk instead.
     pub source_path: &'static str,
 
+    /// Whether the entries are a filtered subset of the source directory.
+    /// Hot-reload then only re-reads files that were embedded.
+    filtered: bool,
+
+    /// Whether templates built from this source strip trailing line breaks.
+    trim_trailing_newline: bool,
+
     /// Marker for the resource type.
     _marker: PhantomData<R>,
 }
@@ -76,10 +83,46 @@ impl<R> EmbeddedSource<R> {
         Self {
             entries,
             source_path,
+            filtered: false,
+            trim_trailing_newline: false,
             _marker: PhantomData,
         }
     }
 
+    /// Marks the entries as a filtered subset of the source directory.
+    ///
+    /// This is called by `embed_templates!` when `include`/`exclude` are given.
+    #[doc(hidden)]
+    pub const fn filtered(mut self) -> Self {
+        self.filtered = true;
+        self
+    }
+
+    /// Strips trailing line breaks from templates built from this source.
+    ///
+    /// This is called by `embed_templates!` when `trim_trailing_newline = true`
+    /// is given. See [`TemplateRegistry::trim_trailing_newline`].
+    #[doc(hidden)]
+    pub const fn trim_trailing_newline(mut self) -> Self {
+        self.trim_trailing_newline = true;
+        self
+    }
+
+    /// Returns true if templates built from this source strip trailing line breaks.
+    pub fn trims_trailing_newline(&self) -> bool {
+        self.trim_trailing_newline
+    }
+
+    /// Returns true if the entries are a filtered subset of the source directory.
+    pub fn is_filtered(&self) -> bool {
+        self.filtered
+    }
+
+    /// Returns true if an entry with this name (including extension) was embedded.
+    fn has_entry(&self, name_with_ext: &str) -> bool {
+        self.entries.iter().any(|(name, _)| *name == name_with_ext)
+    }
+
     /// Returns the embedded entries.
     pub fn entries(&self) -> &'static [(&'static str, &'static str)] {
         self.entries
@@ -110,36 +153,51 @@ impl From<EmbeddedTemplates> for TemplateRegistry {
     /// Converts embedded templates into a TemplateRegistry.
     ///
     /// In debug mode, if the source path exists, templates are loaded from disk
-    /// (enabling hot-reload). Otherwise, embedded content is used.
+    /// (enabling hot-reload). Otherwise, embedded content is used. Trailing
+    /// line breaks are trimmed the same way in both cases.
     fn from(source: EmbeddedTemplates) -> Self {
-        if source.should_hot_reload() {
-            // Debug mode with existing source path: load from filesystem
-            // Use walk_template_dir + add_from_files for immediate loading
-            // (add_template_dir uses lazy loading which doesn't work well here)
-            let files = match walk_template_dir(source.source_path) {
-                Ok(files) => files,
-                Err(e) => {
-                    eprintln!(
-                        "Warning: Failed to walk templates directory '{}', using embedded: {}",
-                        source.source_path, e
-                    );
-                    return TemplateRegistry::from_embedded_entries(source.entries);
-                }
-            };
+        let mut registry = templates_from_source(&source);
+        registry.trim_trailing_newline(source.trim_trailing_newline);
+        registry
+    }
+}
 
-            let mut registry = TemplateRegistry::new();
-            if let Err(e) = registry.add_from_files(files) {
+/// Builds the template registry for an embedded source, from disk when hot
+/// reloading and from the embedded entries otherwise.
+fn templates_from_source(source: &EmbeddedTemplates) -> TemplateRegistry {
+    if source.should_hot_reload() {
+        // Debug mode with existing source path: load from filesystem
+        // Use walk_template_dir + add_from_files for immediate loading
+        // (add_template_dir uses lazy loading which doesn't work well here)
+        let files = match walk_template_dir(source.source_path) {
+            // A filtered source only hot-reloads the files it embedded
+            Ok(files) if source.filtered => files
+                .into_iter()
+                .filter(|file| source.has_entry(&file.name_with_ext))
+                .collect(),
+            Ok(files) => files,
+            Err(e) => {
                 eprintln!(
-                    "Warning: Failed to register templates from '{}', using embedded: {}",
+                    "Warning: Failed to walk templates directory '{}', using embedded: {}",
                     source.source_path, e
                 );
                 return TemplateRegistry::from_embedded_entries(source.entries);
             }
-            registry
-        } else {
-            // Release mode or missing source: use embedded content
-            TemplateRegistry::from_embedded_entries(source.entries)
+        };
+
+        let mut registry = TemplateRegistry::new();
+        if let Err(e) = registry.add_from_files(files) {
+            eprintln!(
+                "Warning: Failed to register templates from '{}', using embedded: {}",
+                source.source_path, e
+            );
+            return TemplateRegistry::from_embedded_entries(source.entries);
         }
+        registry.mark_embedded();
+        registry
+    } else {
+        // Release mode or missing source: use embedded content
+        TemplateRegistry::from_embedded_entries(source.entries)
     }
 }
 
@@ -152,6 +210,8 @@ impl From<EmbeddedStyles> for StylesheetRegistry {
     /// # Panics
     ///
     /// Panics if embedded YAML content fails to parse (should be caught in dev).
+    /// YAML syntax errors are already rejected by `embed_styles!` at compile time;
+    /// the panic message names the stylesheet with the invalid definition.
     fn from(source: EmbeddedStyles) -> Self {
         if source.should_hot_reload() {
             // Debug mode with existing source path: load from filesystem
@@ -165,7 +225,7 @@ impl From<EmbeddedStyles> for StylesheetRegistry {
                         source.source_path, e
                     );
                     return StylesheetRegistry::from_embedded_entries(source.entries)
-                        .expect("embedded stylesheets should parse");
+                        .unwrap_or_else(|e| embedded_styles_panic(source.source_path, e));
                 }
             };
 
@@ -202,7 +262,7 @@ impl From<EmbeddedStyles> for StylesheetRegistry {
                             source.source_path, e
                         );
                         return StylesheetRegistry::from_embedded_entries(source.entries)
-                            .expect("embedded stylesheets should parse");
+                            .unwrap_or_else(|e| embedded_styles_panic(source.source_path, e));
                     }
                 };
 
@@ -212,11 +272,19 @@ impl From<EmbeddedStyles> for StylesheetRegistry {
         } else {
             // Release mode or missing source: use embedded content
             StylesheetRegistry::from_embedded_entries(source.entries)
-                .expect("embedded stylesheets should parse")
+                .unwrap_or_else(|e| embedded_styles_panic(source.source_path, e))
         }
     }
 }
 
+/// Panics with the embedded styles directory and the stylesheet that failed.
+fn embedded_styles_panic(source_path: &str, err: StylesheetError) -> ! {
+    panic!(
+        "embedded stylesheets from '{}' should parse: {}",
+        source_path, err
+    )
+}
+
 #[cfg(test)]
 mod tests {
     use super::*;
@@ -57,6 +57,36 @@ impl std::error::Error for RenderError {
     }
 }
 
+/// A non-fatal problem found while rendering.
+///
+/// Returned alongside the output by the `render_with_warnings` functions, so
+/// callers can surface template mistakes (in debug builds, or with
+/// `--output=term-debug`) without failing the render.
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub enum RenderWarning {
+    /// A style tag names a style the theme doesn't define.
+    UndefinedStyle {
+        /// The undefined style name.
+        style: String,
+        /// The template being rendered, when it has a name.
+        template: Option<String>,
+    },
+}
+
+impl fmt::Display for RenderWarning {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        match self {
+            RenderWarning::UndefinedStyle { style, template } => {
+                write!(f, "used undefined style '{}'", style)?;
+                if let Some(template) = template {
+                    write!(f, " in template '{}'", template)?;
+                }
+                Ok(())
+            }
+        }
+    }
+}
+
 impl From<std::io::Error> for RenderError {
     fn from(err: std::io::Error) -> Self {
         RenderError::IoError(err)
@@ -130,6 +160,24 @@ mod tests {
         assert!(err.to_string().contains("foo"));
     }
 
+    #[test]
+    fn test_warning_display() {
+        let warning = RenderWarning::UndefinedStyle {
+            style: "titel".to_string(),
+            template: None,
+        };
+        assert_eq!(warning.to_string(), "used undefined style 'titel'");
+
+        let warning = RenderWarning::UndefinedStyle {
+            style: "titel".to_string(),
+            template: Some("list".to_string()),
+        };
+        assert_eq!(
+            warning.to_string(),
+            "used undefined style 'titel' in template 'list'"
+        );
+    }
+
     #[test]
     fn test_from_io_error() {
         let io_err = std::io::Error::new(std::io::ErrorKind::NotFound, "file not found");
@@ -279,6 +279,20 @@ pub fn build_embedded_registry<T, E, F>(
 where
     T: Clone,
     F: Fn(&str) -> Result<T, E>,
+{
+    build_named_embedded_registry(entries, extensions, |_, content| transform(content))
+}
+
+/// Like [`build_embedded_registry`], but the transform also receives the
+/// entry's name with extension, so errors can point at the offending file.
+pub fn build_named_embedded_registry<T, E, F>(
+    entries: &[(&str, &str)],
+    extensions: &[&str],
+    transform: F,
+) -> Result<HashMap<String, T>, E>
+where
+    T: Clone,
+    F: Fn(&str, &str) -> Result<T, E>,
 {
     let mut registry = HashMap::new();
 
@@ -289,7 +303,7 @@ where
     let mut seen_base_names = std::collections::HashSet::new();
 
     for (name_with_ext, content) in sorted {
-        let value = transform(content)?;
+        let value = transform(name_with_ext, content)?;
         let base_name = strip_extension(name_with_ext, extensions);
 
         // Register under full name with extension
@@ -344,6 +358,7 @@ pub enum LoadedEntry<T> {
 ///     transform: |content| parse_style_definitions(content),
 /// }
 /// ```
+#[derive(Clone)]
 pub struct FileRegistryConfig<T> {
     /// Valid file extensions in priority order (first = highest priority).
     ///
@@ -472,6 +487,7 @@ impl std::error::Error for LoadError {}
 ///
 /// let definitions = registry.get("darcula")?;
 /// ```
+#[derive(Clone)]
 pub struct FileRegistry<T> {
     /// Configuration for this registry.
     config: FileRegistryConfig<T>,
@@ -650,7 +666,19 @@ impl<T: Clone> FileRegistry<T> {
     /// - [`LoadError::Transform`] if the transform function fails
     pub fn get(&mut self, name: &str) -> Result<T, LoadError> {
         self.ensure_initialized()?;
+        self.read(name)
+    }
 
+    /// Gets a resource by name without triggering initialization.
+    ///
+    /// Behaves like [`get`](Self::get), but only sees entries discovered by
+    /// an earlier [`refresh`](Self::refresh), so it can be called through a
+    /// shared reference.
+    ///
+    /// # Errors
+    ///
+    /// Same as [`get`](Self::get).
+    pub fn read(&self, name: &str) -> Result<T, LoadError> {
         match self.entries.get(name) {
             Some(LoadedEntry::Embedded(content)) => Ok(content.clone()),
             Some(LoadedEntry::File(path)) => {
@@ -714,8 +742,17 @@ impl<T: Clone> FileRegistry<T> {
     }
 }
 
+/// Default nesting limit for [`walk_dir`].
+pub const DEFAULT_MAX_DEPTH: usize = 32;
+
 /// Walks a directory recursively and collects files with recognized extensions.
 ///
+/// Symlinked directories are followed, so a directory reachable under two
+/// names (e.g. `latest -> releases/v2`) is registered under both. A symlink
+/// back to one of its own ancestors (e.g. `latest -> .`) would loop forever;
+/// it is skipped with a warning on stderr. Nesting is limited to [`DEFAULT_MAX_DEPTH`]; use
+/// [`walk_dir_with_depth`] to change the limit.
+///
 /// # Arguments
 ///
 /// - `root`: The directory to walk
@@ -725,45 +762,98 @@ impl<T: Clone> FileRegistry<T> {
 ///
 /// A vector of [`LoadedFile`] entries, one for each discovered file.
 pub fn walk_dir(root: &Path, extensions: &[&str]) -> Result<Vec<LoadedFile>, LoadError> {
+    walk_dir_with_depth(root, extensions, DEFAULT_MAX_DEPTH)
+}
+
+/// Like [`walk_dir`], with an explicit nesting limit.
+///
+/// Files directly in `root` are at depth 0.
+///
+/// # Errors
+///
+/// Returns [`LoadError::Io`] naming the offending directory if the tree is
+/// nested deeper than `max_depth`.
+pub fn walk_dir_with_depth(
+    root: &Path,
+    extensions: &[&str],
+    max_depth: usize,
+) -> Result<Vec<LoadedFile>, LoadError> {
     let root_canonical = root.canonicalize().map_err(|e| LoadError::Io {
         path: root.to_path_buf(),
         message: e.to_string(),
     })?;
 
-    let mut files = Vec::new();
-    walk_dir_recursive(&root_canonical, &root_canonical, extensions, &mut files)?;
-    Ok(files)
+    let mut walk = DirWalk {
+        root: &root_canonical,
+        extensions,
+        max_depth,
+        ancestors: vec![root_canonical.clone()],
+        files: Vec::new(),
+    };
+    walk.visit(&root_canonical, 0)?;
+    Ok(walk.files)
 }
 
-/// Recursive helper for directory walking.
-fn walk_dir_recursive(
-    current: &Path,
-    root: &Path,
-    extensions: &[&str],
-    files: &mut Vec<LoadedFile>,
-) -> Result<(), LoadError> {
-    let entries = std::fs::read_dir(current).map_err(|e| LoadError::Io {
-        path: current.to_path_buf(),
-        message: e.to_string(),
-    })?;
+/// State for a single [`walk_dir_with_depth`] call.
+struct DirWalk<'a> {
+    root: &'a Path,
+    extensions: &'a [&'a str],
+    max_depth: usize,
+    /// Canonical paths of the directories being walked, root first
+    ancestors: Vec<PathBuf>,
+    files: Vec<LoadedFile>,
+}
 
-    for entry in entries {
-        let entry = entry.map_err(|e| LoadError::Io {
+impl DirWalk<'_> {
+    fn visit(&mut self, current: &Path, depth: usize) -> Result<(), LoadError> {
+        let entries = std::fs::read_dir(current).map_err(|e| LoadError::Io {
             path: current.to_path_buf(),
             message: e.to_string(),
         })?;
-        let path = entry.path();
 
-        if path.is_dir() {
-            walk_dir_recursive(&path, root, extensions, files)?;
-        } else if path.is_file() {
-            if let Some(loaded_file) = try_parse_file(&path, root, extensions) {
-                files.push(loaded_file);
+        // Entries that can't be read or stat'ed (dangling symlinks, permission
+        // errors) are skipped so one bad file doesn't abort the whole walk.
+        for entry in entries.flatten() {
+            let path = entry.path();
+            let Ok(metadata) = std::fs::metadata(&path) else {
+                continue;
+            };
+
+            if metadata.is_dir() {
+                // A directory that is its own ancestor is a symlink cycle
+                let Ok(canonical) = path.canonicalize() else {
+                    continue;
+                };
+                if self.ancestors.contains(&canonical) {
+                    eprintln!(
+                        "Warning: skipping '{}', a symlink cycle back to '{}'",
+                        path.display(),
+                        canonical.display()
+                    );
+                    continue;
+                }
+                if depth >= self.max_depth {
+                    return Err(LoadError::Io {
+                        path,
+                        message: format!(
+                            "directory nesting exceeds the maximum depth of {}",
+                            self.max_depth
+                        ),
+                    });
+                }
+                self.ancestors.push(canonical);
+                let result = self.visit(&path, depth + 1);
+                self.ancestors.pop();
+                result?;
+            } else if metadata.is_file() {
+                if let Some(loaded_file) = try_parse_file(&path, self.root, self.extensions) {
+                    self.files.push(loaded_file);
+                }
             }
         }
-    }
 
-    Ok(())
+        Ok(())
+    }
 }
 
 /// Attempts to parse a file path as a loadable file.
@@ -1124,6 +1214,75 @@ mod tests {
         assert!(names.contains(&"sub/deep/very"));
     }
 
+    #[cfg(unix)]
+    #[test]
+    fn test_walk_dir_symlink_loop_terminates() {
+        let temp_dir = TempDir::new().unwrap();
+        create_file(temp_dir.path(), "root.tmpl", "content");
+        create_file(temp_dir.path(), "sub/nested.tmpl", "content");
+        std::os::unix::fs::symlink(temp_dir.path(), temp_dir.path().join("latest")).unwrap();
+        std::os::unix::fs::symlink(temp_dir.path(), temp_dir.path().join("sub/up")).unwrap();
+
+        let files = walk_dir(temp_dir.path(), &[".tmpl"]).unwrap();
+
+        let mut names: Vec<&str> = files.iter().map(|f| f.name.as_str()).collect();
+        names.sort();
+        assert_eq!(names, vec!["root", "sub/nested"]);
+    }
+
+    #[cfg(unix)]
+    #[test]
+    fn test_walk_dir_follows_non_cyclic_symlink_under_both_names() {
+        let temp_dir = TempDir::new().unwrap();
+        create_file(temp_dir.path(), "releases/v2/notes.tmpl", "content");
+        std::os::unix::fs::symlink(
+            temp_dir.path().join("releases/v2"),
+            temp_dir.path().join("latest"),
+        )
+        .unwrap();
+
+        let files = walk_dir(temp_dir.path(), &[".tmpl"]).unwrap();
+
+        let mut names: Vec<&str> = files.iter().map(|f| f.name.as_str()).collect();
+        names.sort();
+        assert_eq!(names, vec!["latest/notes", "releases/v2/notes"]);
+    }
+
+    #[test]
+    fn test_walk_dir_with_depth_limit() {
+        let temp_dir = TempDir::new().unwrap();
+        create_file(temp_dir.path(), "a/b/c/deep.tmpl", "content");
+
+        let files = walk_dir_with_depth(temp_dir.path(), &[".tmpl"], 3).unwrap();
+        assert_eq!(files.len(), 1);
+
+        let err = walk_dir_with_depth(temp_dir.path(), &[".tmpl"], 2).unwrap_err();
+        match err {
+            LoadError::Io { path, message } => {
+                assert!(path.ends_with("a/b/c"));
+                assert!(message.contains("maximum depth of 2"));
+            }
+            other => panic!("expected Io error, got {:?}", other),
+        }
+    }
+
+    #[cfg(unix)]
+    #[test]
+    fn test_walk_dir_skips_unreadable_entries() {
+        let temp_dir = TempDir::new().unwrap();
+        create_file(temp_dir.path(), "good.tmpl", "content");
+        std::os::unix::fs::symlink(
+            temp_dir.path().join("missing.tmpl"),
+            temp_dir.path().join("dangling.tmpl"),
+        )
+        .unwrap();
+
+        let files = walk_dir(temp_dir.path(), &[".tmpl"]).unwrap();
+
+        assert_eq!(files.len(), 1);
+        assert_eq!(files[0].name, "good");
+    }
+
     // =========================================================================
     // Error display tests
     // =========================================================================
@@ -0,0 +1,178 @@
+//! Syntax highlighting for JSON output.
+//!
+//! [`highlight_json`] pretty-prints a JSON value the same way as
+//! `serde_json::to_string_pretty`, wrapping each token in a theme style.
+//! Themes control the colors through these style names:
+//!
+//! | Style name    | Applies to                 | Default      |
+//! |---------------|----------------------------|--------------|
+//! | `json.key`    | Object keys (with quotes)  | bold blue    |
+//! | `json.string` | String values              | green        |
+//! | `json.number` | Numbers                    | cyan         |
+//! | `json.bool`   | `true` / `false`           | yellow       |
+//! | `json.null`   | `null`                     | dim          |
+//!
+//! Punctuation (`{`, `[`, `:`, `,`) is never styled.
+
+use console::Style;
+use serde_json::Value;
+
+use crate::style::Styles;
+
+/// Style name for object keys.
+pub const JSON_KEY_STYLE: &str = "json.key";
+/// Style name for string values.
+pub const JSON_STRING_STYLE: &str = "json.string";
+/// Style name for numbers.
+pub const JSON_NUMBER_STYLE: &str = "json.number";
+/// Style name for `true` and `false`.
+pub const JSON_BOOL_STYLE: &str = "json.bool";
+/// Style name for `null`.
+pub const JSON_NULL_STYLE: &str = "json.null";
+
+const INDENT: &str = "  ";
+
+/// Pretty-prints `value` with ANSI syntax highlighting.
+///
+/// Token styles come from `styles` (see the [module docs](self) for the
+/// names), falling back to built-in defaults for any that aren't defined.
+/// Styling is always applied; callers decide whether color is appropriate.
+///
+/// # Example
+///
+/// ```rust
+/// use standout_render::{highlight_json, Styles};
+///
+/// let value = serde_json::json!({"name": "standout"});
+/// let output = highlight_json(&value, &Styles::new());
+/// assert!(output.contains("\x1b["));
+/// assert_eq!(console::strip_ansi_codes(&output), "{\n  \"name\": \"standout\"\n}");
+/// ```
+pub fn highlight_json(value: &Value, styles: &Styles) -> String {
+    let palette = JsonPalette::new(styles);
+    let mut out = String::new();
+    palette.write_value(value, 0, &mut out);
+    out
+}
+
+/// Resolved styles for each token kind.
+struct JsonPalette {
+    key: Style,
+    string: Style,
+    number: Style,
+    bool: Style,
+    null: Style,
+}
+
+impl JsonPalette {
+    fn new(styles: &Styles) -> Self {
+        let pick = |name: &str, default: Style| {
+            styles.resolve(name).unwrap_or(default).force_styling(true)
+        };
+        Self {
+            key: pick(JSON_KEY_STYLE, Style::new().blue().bold()),
+            string: pick(JSON_STRING_STYLE, Style::new().green()),
+            number: pick(JSON_NUMBER_STYLE, Style::new().cyan()),
+            bool: pick(JSON_BOOL_STYLE, Style::new().yellow()),
+            null: pick(JSON_NULL_STYLE, Style::new().dim()),
+        }
+    }
+
+    fn write_value(&self, value: &Value, depth: usize, out: &mut String) {
+        match value {
+            Value::Null => out.push_str(&self.null.apply_to("null").to_string()),
+            Value::Bool(b) => out.push_str(&self.bool.apply_to(b).to_string()),
+            Value::Number(n) => out.push_str(&self.number.apply_to(n).to_string()),
+            Value::String(s) => out.push_str(&self.string.apply_to(quote(s)).to_string()),
+            Value::Array(items) if items.is_empty() => out.push_str("[]"),
+            Value::Array(items) => {
+                out.push('[');
+                for (i, item) in items.iter().enumerate() {
+                    if i > 0 {
+                        out.push(',');
+                    }
+                    newline(depth + 1, out);
+                    self.write_value(item, depth + 1, out);
+                }
+                newline(depth, out);
+                out.push(']');
+            }
+            Value::Object(map) if map.is_empty() => out.push_str("{}"),
+            Value::Object(map) => {
+                out.push('{');
+                for (i, (key, item)) in map.iter().enumerate() {
+                    if i > 0 {
+                        out.push(',');
+                    }
+                    newline(depth + 1, out);
+                    out.push_str(&self.key.apply_to(quote(key)).to_string());
+                    out.push_str(": ");
+                    self.write_value(item, depth + 1, out);
+                }
+                newline(depth, out);
+                out.push('}');
+            }
+        }
+    }
+}
+
+fn newline(depth: usize, out: &mut String) {
+    out.push('\n');
+    for _ in 0..depth {
+        out.push_str(INDENT);
+    }
+}
+
+/// Quotes and escapes a string as a JSON string literal.
+fn quote(s: &str) -> String {
+    Value::String(s.to_string()).to_string()
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use serde_json::json;
+
+    #[test]
+    fn test_layout_matches_pretty_printer() {
+        let value = json!({
+            "name": "app",
+            "tags": ["a", "b\"c"],
+            "count": 3,
+            "ratio": 0.5,
+            "enabled": true,
+            "parent": null,
+            "empty_list": [],
+            "empty_map": {},
+            "nested": {"list": [{"x": 1}]}
+        });
+
+        let highlighted = highlight_json(&value, &Styles::new());
+        assert_eq!(
+            console::strip_ansi_codes(&highlighted),
+            serde_json::to_string_pretty(&value).unwrap()
+        );
+    }
+
+    #[test]
+    fn test_uses_theme_styles() {
+        let styles = Styles::new().add(JSON_KEY_STYLE, Style::new().red());
+        let output = highlight_json(&json!({"k": 1}), &styles);
+
+        let red_key = Style::new()
+            .red()
+            .force_styling(true)
+            .apply_to("\"k\"")
+            .to_string();
+        assert!(output.contains(&red_key), "{:?}", output);
+    }
+
+    #[test]
+    fn test_scalars_are_styled() {
+        for value in [json!("s"), json!(1), json!(false), json!(null)] {
+            let output = highlight_json(&value, &Styles::new());
+            assert!(output.contains("\x1b["), "{:?}", output);
+            assert_eq!(console::strip_ansi_codes(&output), value.to_string());
+        }
+    }
+}
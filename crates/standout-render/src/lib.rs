@@ -150,6 +150,7 @@ pub mod context;
 mod embedded;
 mod error;
 pub mod file_loader;
+mod json_highlight;
 pub mod output;
 pub mod prelude;
 pub mod style;
@@ -159,21 +160,31 @@ pub mod theme;
 mod util;
 
 // Error type
-pub use error::RenderError;
+pub use error::{RenderError, RenderWarning};
 
 // Style module exports (including former stylesheet exports)
 pub use style::{
-    parse_css, parse_stylesheet, ColorDef, StyleAttributes, StyleDefinition, StyleValidationError,
-    StyleValue, Styles, StylesheetError, StylesheetRegistry, ThemeVariants,
-    DEFAULT_MISSING_STYLE_INDICATOR, STYLESHEET_EXTENSIONS,
+    detect_color_depth, parse_css, parse_stylesheet, set_color_depth, ColorDef, ColorDepth,
+    MissingStylePolicy, StyleAttributes, StyleDefinition, StyleValidationError, StyleValue, Styles,
+    StylesheetError, StylesheetRegistry, ThemeVariants, DEFAULT_MISSING_STYLE_INDICATOR,
+    STYLESHEET_EXTENSIONS,
 };
 
 // Theme module exports
-pub use theme::{detect_color_mode, set_theme_detector, ColorMode, Theme};
+pub use theme::{
+    detect_color_mode, parse_colorfgbg, resolve_color_mode, set_theme_detector, ColorMode,
+    ColorModeSource, Palette, Theme,
+};
 
 // Output module exports
 pub use output::{write_binary_output, write_output, OutputDestination, OutputMode};
 
+// JSON highlighting exports
+pub use json_highlight::{
+    highlight_json, JSON_BOOL_STYLE, JSON_KEY_STYLE, JSON_NULL_STYLE, JSON_NUMBER_STYLE,
+    JSON_STRING_STYLE,
+};
+
 // Render module exports
 pub use template::{
     render,
@@ -185,14 +196,18 @@ pub use template::{
     render_with_mode,
     render_with_output,
     render_with_vars,
+    render_with_warnings,
     validate_template,
     // Template registry
     walk_template_dir,
+    walk_template_dir_with_depth,
     // Template engine abstraction
+    FilterFn,
     MiniJinjaEngine,
     RegistryError,
     Renderer,
     ResolvedTemplate,
+    SharedTemplateRegistry,
     TemplateEngine,
     TemplateFile,
     TemplateRegistry,
@@ -200,15 +215,23 @@ pub use template::{
 };
 
 // Re-export BBParser types for template validation
-pub use standout_bbparser::{UnknownTagError, UnknownTagErrors, UnknownTagKind};
+pub use standout_bbparser::{
+    BBParser, TagSeverity, TagTransform, UnknownTagBehavior, UnknownTagError, UnknownTagErrors,
+    UnknownTagKind,
+};
 
 // Utility exports
-pub use util::{flatten_json_for_csv, rgb_to_ansi256, rgb_to_truecolor, truncate_to_width};
+pub use util::{
+    flatten_json_for_csv, format_duration, pad_to_width, rgb_to_ansi16, rgb_to_ansi256,
+    rgb_to_truecolor, to_json_lines, to_toml, truncate_to_width, truncate_to_width_with,
+    TruncateOptions,
+};
 
 // File loader exports
 pub use file_loader::{
-    build_embedded_registry, extension_priority, strip_extension, walk_dir, FileRegistry,
-    FileRegistryConfig, LoadError, LoadedEntry, LoadedFile,
+    build_embedded_registry, build_named_embedded_registry, extension_priority, strip_extension,
+    walk_dir, walk_dir_with_depth, FileRegistry, FileRegistryConfig, LoadError, LoadedEntry,
+    LoadedFile, DEFAULT_MAX_DEPTH,
 };
 
 // Embedded source types (for macros)
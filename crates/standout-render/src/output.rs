@@ -167,8 +167,17 @@ pub enum OutputMode {
     TermDebug,
     /// Structured output: serialize data as JSON (skips template rendering)
     Json,
+    /// Structured output: serialize data as single-line JSON (skips template rendering)
+    JsonCompact,
+    /// Structured output: serialize data as JSON Lines, one compact value per
+    /// array element (skips template rendering)
+    JsonLines,
     /// Structured output: serialize data as YAML (skips template rendering)
     Yaml,
+    /// Structured output: serialize data as TOML (skips template rendering).
+    ///
+    /// Requires the `toml` feature; the data must serialize to a table.
+    Toml,
     /// Structured output: serialize data as XML (skips template rendering)
     Xml,
     /// Structured output: serialize flattened data as CSV (skips template rendering)
@@ -182,32 +191,58 @@ impl OutputMode {
     /// - `Term` always returns `true`
     /// - `Text` always returns `false`
     /// - `TermDebug` returns `false` (handled specially by apply methods)
-    /// - `Json` returns `false` (structured output, no ANSI codes)
+    /// - `Json`, `JsonCompact` and `JsonLines` return `false` (structured output, no ANSI codes)
     pub fn should_use_color(&self) -> bool {
         match self {
             OutputMode::Auto => Term::stdout().features().colors_supported(),
             OutputMode::Term => true,
             OutputMode::Text => false,
-            OutputMode::TermDebug => false, // Handled specially
-            OutputMode::Json => false,      // Structured output
-            OutputMode::Yaml => false,      // Structured output
-            OutputMode::Xml => false,       // Structured output
-            OutputMode::Csv => false,       // Structured output
+            OutputMode::TermDebug => false,   // Handled specially
+            OutputMode::Json => false,        // Structured output
+            OutputMode::JsonCompact => false, // Structured output
+            OutputMode::JsonLines => false,   // Structured output
+            OutputMode::Yaml => false,        // Structured output
+            OutputMode::Toml => false,        // Structured output
+            OutputMode::Xml => false,         // Structured output
+            OutputMode::Csv => false,         // Structured output
         }
     }
 
+    /// Returns true if output in this mode should be JSON syntax-highlighted.
+    ///
+    /// Only `Json` is highlighted, and only when stdout supports color (the
+    /// same detection `Auto` uses), so piped JSON stays plain. `JsonCompact`
+    /// and `JsonLines` are meant for machines and are never highlighted.
+    pub fn should_highlight_json(&self) -> bool {
+        matches!(self, OutputMode::Json) && OutputMode::Auto.should_use_color()
+    }
+
     /// Returns true if this is debug mode (bracket tags instead of ANSI).
     pub fn is_debug(&self) -> bool {
         matches!(self, OutputMode::TermDebug)
     }
 
+    /// Returns true if this mode produces JSON (`Json`, `JsonCompact` or `JsonLines`).
+    pub fn is_json(&self) -> bool {
+        matches!(
+            self,
+            OutputMode::Json | OutputMode::JsonCompact | OutputMode::JsonLines
+        )
+    }
+
     /// Returns true if this is a structured output mode (JSON, etc.).
     ///
     /// Structured modes serialize data directly instead of rendering templates.
     pub fn is_structured(&self) -> bool {
         matches!(
             self,
-            OutputMode::Json | OutputMode::Yaml | OutputMode::Xml | OutputMode::Csv
+            OutputMode::Json
+                | OutputMode::JsonCompact
+                | OutputMode::JsonLines
+                | OutputMode::Yaml
+                | OutputMode::Toml
+                | OutputMode::Xml
+                | OutputMode::Csv
         )
     }
 }
@@ -250,9 +285,43 @@ mod tests {
         assert!(!OutputMode::Json.should_use_color());
     }
 
+    #[test]
+    fn test_only_json_mode_is_highlighted() {
+        for mode in [
+            OutputMode::Auto,
+            OutputMode::Term,
+            OutputMode::Text,
+            OutputMode::TermDebug,
+            OutputMode::JsonCompact,
+            OutputMode::JsonLines,
+            OutputMode::Yaml,
+            OutputMode::Csv,
+        ] {
+            assert!(!mode.should_highlight_json(), "{:?}", mode);
+        }
+        assert_eq!(
+            OutputMode::Json.should_highlight_json(),
+            OutputMode::Auto.should_use_color()
+        );
+    }
+
     #[test]
     fn test_output_mode_json_is_structured() {
         assert!(OutputMode::Json.is_structured());
+        assert!(OutputMode::JsonCompact.is_structured());
+        assert!(!OutputMode::JsonCompact.should_use_color());
+        assert!(OutputMode::JsonLines.is_structured());
+        assert!(!OutputMode::JsonLines.should_use_color());
+    }
+
+    #[test]
+    fn test_output_mode_is_json() {
+        assert!(OutputMode::Json.is_json());
+        assert!(OutputMode::JsonCompact.is_json());
+        assert!(OutputMode::JsonLines.is_json());
+        assert!(!OutputMode::Yaml.is_json());
+        assert!(!OutputMode::Text.is_json());
+        assert!(!OutputMode::Auto.is_json());
     }
 
     #[test]
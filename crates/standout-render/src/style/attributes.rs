@@ -23,6 +23,7 @@
 use console::Style;
 
 use super::color::ColorDef;
+use super::depth::{detect_color_depth, ColorDepth};
 use super::error::StylesheetError;
 
 /// Parsed style attributes from YAML.
@@ -186,14 +187,21 @@ impl StyleAttributes {
     }
 
     /// Converts these attributes to a `console::Style`.
+    ///
+    /// Colors are degraded to the detected terminal [`ColorDepth`].
     pub fn to_style(&self) -> Style {
+        self.to_style_for(detect_color_depth())
+    }
+
+    /// Converts these attributes to a `console::Style` for the given color depth.
+    pub fn to_style_for(&self, depth: ColorDepth) -> Style {
         let mut style = Style::new();
 
         if let Some(ref fg) = self.fg {
-            style = style.fg(fg.to_console_color());
+            style = style.fg(fg.to_console_color_for(depth));
         }
         if let Some(ref bg) = self.bg {
-            style = style.bg(bg.to_console_color());
+            style = style.bg(bg.to_console_color_for(depth));
         }
         if self.bold == Some(true) {
             style = style.bold();
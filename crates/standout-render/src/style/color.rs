@@ -8,6 +8,9 @@
 //! - RGB hex: `"#ff6b35"` or `"#fff"` (3 or 6 digit)
 //! - RGB tuple: `[255, 107, 53]`
 //!
+//! RGB and 256-color values are degraded to the terminal's [`ColorDepth`]
+//! when converted to a `console::Color`.
+//!
 //! # Example
 //!
 //! ```rust
@@ -26,6 +29,9 @@
 
 use console::Color;
 
+use super::depth::{detect_color_depth, ColorDepth};
+use crate::util::{ansi256_to_rgb, rgb_to_truecolor};
+
 /// Parsed color definition from stylesheet.
 #[derive(Debug, Clone, PartialEq, Eq)]
 pub enum ColorDef {
@@ -184,15 +190,57 @@ impl ColorDef {
     }
 
     /// Converts this color definition to a `console::Color`.
+    ///
+    /// Colors are degraded to what the terminal supports, as reported by
+    /// [`detect_color_depth`]. See [`to_console_color_for`](Self::to_console_color_for).
     pub fn to_console_color(&self) -> Color {
-        match self {
-            ColorDef::Named(c) => *c,
-            ColorDef::Color256(n) => Color::Color256(*n),
-            ColorDef::Rgb(r, g, b) => Color::Color256(crate::rgb_to_ansi256((*r, *g, *b))),
+        self.to_console_color_for(detect_color_depth())
+    }
+
+    /// Converts this color definition to a `console::Color` for the given depth.
+    ///
+    /// - [`ColorDepth::TrueColor`] and [`ColorDepth::Ansi256`]: RGB colors map to
+    ///   the nearest 256-color index (`console` has no 24-bit color variant, so
+    ///   true color currently renders through the 256-color palette).
+    /// - [`ColorDepth::Ansi16`]: RGB and 256-color values map to the nearest of
+    ///   the 16 basic colors.
+    ///
+    /// Named colors are supported at every depth and pass through unchanged.
+    pub fn to_console_color_for(&self, depth: ColorDepth) -> Color {
+        match (self, depth) {
+            (ColorDef::Named(c), _) => *c,
+            (ColorDef::Color256(n), ColorDepth::Ansi16) if *n >= 16 => {
+                ansi16_color(crate::rgb_to_ansi16(ansi256_to_rgb(*n)))
+            }
+            (ColorDef::Color256(n), ColorDepth::Ansi16) => ansi16_color(*n),
+            (ColorDef::Color256(n), _) => Color::Color256(*n),
+            (ColorDef::Rgb(r, g, b), ColorDepth::Ansi16) => {
+                ansi16_color(crate::rgb_to_ansi16((*r, *g, *b)))
+            }
+            (ColorDef::Rgb(r, g, b), _) => {
+                Color::Color256(crate::rgb_to_ansi256(rgb_to_truecolor((*r, *g, *b))))
+            }
         }
     }
 }
 
+/// Maps a basic ANSI color index (0-15) to a `console::Color`.
+///
+/// Bright colors use `Color256(8..=15)`, matching how `bright_*` names parse.
+fn ansi16_color(index: u8) -> Color {
+    match index {
+        0 => Color::Black,
+        1 => Color::Red,
+        2 => Color::Green,
+        3 => Color::Yellow,
+        4 => Color::Blue,
+        5 => Color::Magenta,
+        6 => Color::Cyan,
+        7 => Color::White,
+        n => Color::Color256(n),
+    }
+}
+
 #[cfg(test)]
 mod tests {
     use super::*;
@@ -426,17 +474,66 @@ mod tests {
     #[test]
     fn test_to_console_color_256() {
         let c = ColorDef::Color256(208);
-        assert_eq!(c.to_console_color(), Color::Color256(208));
+        assert_eq!(
+            c.to_console_color_for(ColorDepth::Ansi256),
+            Color::Color256(208)
+        );
     }
 
     #[test]
     fn test_to_console_color_rgb() {
         let c = ColorDef::Rgb(255, 107, 53);
         // RGB gets converted to 256 color via rgb_to_ansi256
-        if let Color::Color256(_) = c.to_console_color() {
+        if let Color::Color256(_) = c.to_console_color_for(ColorDepth::Ansi256) {
             // OK - it converted
         } else {
             panic!("Expected Color256");
         }
     }
+
+    #[test]
+    fn test_to_console_color_rgb_truecolor() {
+        let c = ColorDef::Rgb(255, 0, 0);
+        assert_eq!(
+            c.to_console_color_for(ColorDepth::TrueColor),
+            Color::Color256(196)
+        );
+    }
+
+    #[test]
+    fn test_to_console_color_rgb_ansi16() {
+        assert_eq!(
+            ColorDef::Rgb(255, 0, 0).to_console_color_for(ColorDepth::Ansi16),
+            Color::Color256(9)
+        );
+        assert_eq!(
+            ColorDef::Rgb(0, 190, 0).to_console_color_for(ColorDepth::Ansi16),
+            Color::Green
+        );
+    }
+
+    #[test]
+    fn test_to_console_color_256_ansi16() {
+        // Palette entries beyond the basic 16 degrade to the nearest basic color
+        assert_eq!(
+            ColorDef::Color256(196).to_console_color_for(ColorDepth::Ansi16),
+            Color::Color256(9)
+        );
+        // Basic entries are kept as-is
+        assert_eq!(
+            ColorDef::Color256(1).to_console_color_for(ColorDepth::Ansi16),
+            Color::Red
+        );
+        assert_eq!(
+            ColorDef::Color256(12).to_console_color_for(ColorDepth::Ansi16),
+            Color::Color256(12)
+        );
+    }
+
+    #[test]
+    fn test_to_console_color_named_any_depth() {
+        let c = ColorDef::Named(Color::Cyan);
+        assert_eq!(c.to_console_color_for(ColorDepth::Ansi16), Color::Cyan);
+        assert_eq!(c.to_console_color_for(ColorDepth::TrueColor), Color::Cyan);
+    }
 }
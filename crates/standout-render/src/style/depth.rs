@@ -0,0 +1,188 @@
+//! Terminal color depth detection.
+//!
+//! Stylesheets can describe colors as RGB values, but not every terminal can
+//! display them. [`ColorDepth`] captures how many colors the terminal supports,
+//! and [`ColorDef::to_console_color`](super::ColorDef::to_console_color) uses it
+//! to degrade RGB and 256-color values to the nearest color the terminal can show.
+//!
+//! # Detection
+//!
+//! The depth is detected from the environment:
+//!
+//! 1. `COLORTERM=truecolor` or `COLORTERM=24bit` → [`ColorDepth::TrueColor`]
+//! 2. `TERM` containing `truecolor`, `24bit` or ending in `-direct` → [`ColorDepth::TrueColor`]
+//! 3. `TERM` containing `256` → [`ColorDepth::Ansi256`]
+//! 4. Any other `TERM` (`xterm`, `screen`, `linux`, `dumb`, ...) → [`ColorDepth::Ansi16`]
+//! 5. No `TERM` at all → [`ColorDepth::Ansi256`]
+//!
+//! Use [`set_color_depth`] to force a depth regardless of the environment:
+//!
+//! ```rust
+//! use standout_render::{set_color_depth, detect_color_depth, ColorDepth};
+//!
+//! set_color_depth(Some(ColorDepth::Ansi16));
+//! assert_eq!(detect_color_depth(), ColorDepth::Ansi16);
+//!
+//! // Back to environment detection
+//! set_color_depth(None);
+//! ```
+
+use once_cell::sync::Lazy;
+use std::sync::Mutex;
+
+/// The number of colors a terminal can display.
+#[derive(Debug, Clone, Copy, PartialEq, Eq, PartialOrd, Ord, Hash)]
+pub enum ColorDepth {
+    /// The 16 basic ANSI colors (8 normal + 8 bright).
+    Ansi16,
+    /// The xterm 256-color palette.
+    Ansi256,
+    /// 24-bit RGB colors.
+    TrueColor,
+}
+
+impl ColorDepth {
+    /// Detects the color depth from the process environment.
+    ///
+    /// This ignores any override set with [`set_color_depth`]; use
+    /// [`detect_color_depth`] for the effective depth.
+    pub fn from_env() -> Self {
+        Self::from_env_lookup(|key| std::env::var(key).ok())
+    }
+
+    /// Detects the color depth using the given environment lookup.
+    ///
+    /// This is the testable core of [`from_env`](Self::from_env).
+    ///
+    /// # Example
+    ///
+    /// ```rust
+    /// use standout_render::ColorDepth;
+    ///
+    /// let depth = ColorDepth::from_env_lookup(|key| match key {
+    ///     "TERM" => Some("xterm-256color".to_string()),
+    ///     _ => None,
+    /// });
+    /// assert_eq!(depth, ColorDepth::Ansi256);
+    /// ```
+    pub fn from_env_lookup<F>(lookup: F) -> Self
+    where
+        F: Fn(&str) -> Option<String>,
+    {
+        if let Some(colorterm) = lookup("COLORTERM") {
+            let colorterm = colorterm.to_lowercase();
+            if colorterm == "truecolor" || colorterm == "24bit" {
+                return ColorDepth::TrueColor;
+            }
+        }
+
+        match lookup("TERM") {
+            Some(term) => {
+                let term = term.to_lowercase();
+                if term.contains("truecolor") || term.contains("24bit") || term.ends_with("-direct")
+                {
+                    ColorDepth::TrueColor
+                } else if term.contains("256") {
+                    ColorDepth::Ansi256
+                } else {
+                    ColorDepth::Ansi16
+                }
+            }
+            None => ColorDepth::Ansi256,
+        }
+    }
+}
+
+static COLOR_DEPTH_OVERRIDE: Lazy<Mutex<Option<ColorDepth>>> = Lazy::new(|| Mutex::new(None));
+
+/// Forces a color depth, or restores environment detection with `None`.
+///
+/// This is useful for testing, or for apps that know better than the
+/// environment (e.g. a `--colors=256` flag).
+pub fn set_color_depth(depth: Option<ColorDepth>) {
+    let mut guard = COLOR_DEPTH_OVERRIDE.lock().unwrap();
+    *guard = depth;
+}
+
+/// Returns the effective color depth.
+///
+/// Uses the override from [`set_color_depth`] if one is set, otherwise
+/// detects the depth from the environment.
+pub fn detect_color_depth() -> ColorDepth {
+    let forced = *COLOR_DEPTH_OVERRIDE.lock().unwrap();
+    forced.unwrap_or_else(ColorDepth::from_env)
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use serial_test::serial;
+    use std::collections::HashMap;
+
+    fn lookup(vars: &[(&str, &str)]) -> impl Fn(&str) -> Option<String> {
+        let map: HashMap<String, String> = vars
+            .iter()
+            .map(|(k, v)| (k.to_string(), v.to_string()))
+            .collect();
+        move |key| map.get(key).cloned()
+    }
+
+    #[test]
+    fn test_colorterm_truecolor() {
+        let env = lookup(&[("COLORTERM", "truecolor"), ("TERM", "xterm")]);
+        assert_eq!(ColorDepth::from_env_lookup(env), ColorDepth::TrueColor);
+
+        let env = lookup(&[("COLORTERM", "24bit")]);
+        assert_eq!(ColorDepth::from_env_lookup(env), ColorDepth::TrueColor);
+    }
+
+    #[test]
+    fn test_term_direct_is_truecolor() {
+        let env = lookup(&[("TERM", "xterm-direct")]);
+        assert_eq!(ColorDepth::from_env_lookup(env), ColorDepth::TrueColor);
+    }
+
+    #[test]
+    fn test_term_256color() {
+        let env = lookup(&[("TERM", "screen-256color")]);
+        assert_eq!(ColorDepth::from_env_lookup(env), ColorDepth::Ansi256);
+    }
+
+    #[test]
+    fn test_basic_term_is_ansi16() {
+        for term in ["xterm", "linux", "vt100", "dumb"] {
+            let env = lookup(&[("TERM", term)]);
+            assert_eq!(
+                ColorDepth::from_env_lookup(env),
+                ColorDepth::Ansi16,
+                "TERM={}",
+                term
+            );
+        }
+    }
+
+    #[test]
+    fn test_unknown_colorterm_falls_back_to_term() {
+        let env = lookup(&[("COLORTERM", "yes"), ("TERM", "xterm-256color")]);
+        assert_eq!(ColorDepth::from_env_lookup(env), ColorDepth::Ansi256);
+    }
+
+    #[test]
+    fn test_no_term_defaults_to_ansi256() {
+        let env = lookup(&[]);
+        assert_eq!(ColorDepth::from_env_lookup(env), ColorDepth::Ansi256);
+    }
+
+    #[test]
+    #[serial]
+    fn test_override_takes_precedence() {
+        set_color_depth(Some(ColorDepth::Ansi16));
+        assert_eq!(detect_color_depth(), ColorDepth::Ansi16);
+
+        set_color_depth(Some(ColorDepth::TrueColor));
+        assert_eq!(detect_color_depth(), ColorDepth::TrueColor);
+
+        set_color_depth(None);
+        assert_eq!(detect_color_depth(), ColorDepth::from_env());
+    }
+}
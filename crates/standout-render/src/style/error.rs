@@ -11,6 +11,8 @@ pub enum StyleValidationError {
     UnresolvedAlias { from: String, to: String },
     /// A cycle was detected in alias resolution
     CycleDetected { path: Vec<String> },
+    /// A style name is not registered
+    UnknownStyle { name: String },
 }
 
 impl std::fmt::Display for StyleValidationError {
@@ -22,6 +24,9 @@ impl std::fmt::Display for StyleValidationError {
             StyleValidationError::CycleDetected { path } => {
                 write!(f, "cycle detected in style aliases: {}", path.join(" -> "))
             }
+            StyleValidationError::UnknownStyle { name } => {
+                write!(f, "unknown style '{}'", name)
+            }
         }
     }
 }
@@ -92,6 +97,25 @@ pub enum StylesheetError {
     },
 }
 
+impl StylesheetError {
+    /// Attaches a source path to errors that carry one and don't have it yet.
+    pub fn with_path(mut self, source: impl Into<PathBuf>) -> Self {
+        match &mut self {
+            StylesheetError::Parse { path, .. }
+            | StylesheetError::InvalidColor { path, .. }
+            | StylesheetError::UnknownAttribute { path, .. }
+            | StylesheetError::InvalidShorthand { path, .. }
+            | StylesheetError::InvalidDefinition { path, .. } => {
+                if path.is_none() {
+                    *path = Some(source.into());
+                }
+            }
+            StylesheetError::AliasError { .. } | StylesheetError::Load { .. } => {}
+        }
+        self
+    }
+}
+
 impl std::fmt::Display for StylesheetError {
     fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
         match self {
@@ -188,6 +212,35 @@ mod tests {
         assert!(msg.contains("missing"));
     }
 
+    #[test]
+    fn test_unknown_style_error_display() {
+        let err = StyleValidationError::UnknownStyle {
+            name: "titel".to_string(),
+        };
+        assert_eq!(err.to_string(), "unknown style 'titel'");
+    }
+
+    #[test]
+    fn test_with_path_fills_missing_path() {
+        let err = StylesheetError::Parse {
+            path: None,
+            message: "bad".to_string(),
+        }
+        .with_path("themes/dark.yaml");
+        assert_eq!(
+            err.to_string(),
+            "Failed to parse stylesheet themes/dark.yaml: bad"
+        );
+
+        let err = StylesheetError::InvalidColor {
+            style: "title".to_string(),
+            value: "nope".to_string(),
+            path: Some(PathBuf::from("orig.yaml")),
+        }
+        .with_path("other.yaml");
+        assert!(err.to_string().contains("orig.yaml"));
+    }
+
     #[test]
     fn test_cycle_detected_error_display() {
         let err = StyleValidationError::CycleDetected {
@@ -61,7 +61,9 @@ use std::collections::HashMap;
 use std::path::Path;
 
 use super::super::theme::Theme;
-use crate::file_loader::{build_embedded_registry, FileRegistry, FileRegistryConfig, LoadError};
+use crate::file_loader::{
+    build_named_embedded_registry, FileRegistry, FileRegistryConfig, LoadError,
+};
 
 use super::error::StylesheetError;
 
@@ -103,6 +105,11 @@ fn stylesheet_config() -> FileRegistryConfig<Theme> {
 ///
 /// In development mode (debug builds), file-based themes are re-read and
 /// re-parsed on each access, enabling rapid iteration without restarts.
+/// Directories are scanned when they are added; call
+/// [`refresh`](Self::refresh) to pick up files created later.
+///
+/// Lookups only need `&self`, so a populated registry can be shared behind
+/// an `Arc` without a lock.
 ///
 /// # Example
 ///
@@ -201,9 +208,18 @@ impl StylesheetRegistry {
     /// - `"darcula"` → `./themes/darcula.yaml`
     /// - `"monokai"` → `./themes/monokai.yaml`
     ///
+    /// The directory is scanned immediately, so its themes are visible to
+    /// [`get`](Self::get), [`contains`](Self::contains) and
+    /// [`names`](Self::names) as soon as this returns.
+    ///
     /// # Errors
     ///
-    /// Returns an error if the directory doesn't exist.
+    /// Returns an error if the directory doesn't exist or cannot be read.
+    ///
+    /// # Panics
+    ///
+    /// Panics if a theme name collides with one from a previously added
+    /// directory.
     ///
     /// # Example
     ///
@@ -212,9 +228,12 @@ impl StylesheetRegistry {
     /// let theme = registry.get("darcula")?;
     /// ```
     pub fn add_dir<P: AsRef<Path>>(&mut self, path: P) -> Result<(), StylesheetError> {
-        self.inner.add_dir(path).map_err(|e| StylesheetError::Load {
-            message: e.to_string(),
-        })
+        self.inner
+            .add_dir(path)
+            .and_then(|()| self.inner.refresh())
+            .map_err(|e| StylesheetError::Load {
+                message: e.to_string(),
+            })
     }
 
     /// Adds pre-embedded themes (for release builds).
@@ -279,7 +298,7 @@ impl StylesheetRegistry {
     ///     ("themes/dark.yaml", "panel:\n  fg: white"),
     /// ];
     ///
-    /// let mut registry = StylesheetRegistry::from_embedded_entries(entries).unwrap();
+    /// let registry = StylesheetRegistry::from_embedded_entries(entries).unwrap();
     ///
     /// // Access by base name or full name
     /// assert!(registry.get("default").is_ok());
@@ -289,10 +308,10 @@ impl StylesheetRegistry {
     pub fn from_embedded_entries(entries: &[(&str, &str)]) -> Result<Self, StylesheetError> {
         let mut registry = Self::new();
 
-        // Use shared helper with YAML parsing transform
+        // Use shared helper with YAML parsing transform; errors name the offending file
         registry.inline =
-            build_embedded_registry(entries, STYLESHEET_EXTENSIONS, |yaml_content| {
-                Theme::from_yaml(yaml_content)
+            build_named_embedded_registry(entries, STYLESHEET_EXTENSIONS, |name, yaml_content| {
+                Theme::from_yaml(yaml_content).map_err(|e| e.with_path(name))
             })?;
 
         Ok(registry)
@@ -316,14 +335,14 @@ impl StylesheetRegistry {
     /// ```rust,ignore
     /// let theme = registry.get("darcula")?;
     /// ```
-    pub fn get(&mut self, name: &str) -> Result<Theme, StylesheetError> {
+    pub fn get(&self, name: &str) -> Result<Theme, StylesheetError> {
         // Check inline first
         if let Some(theme) = self.inline.get(name) {
             return Ok(theme.clone());
         }
 
         // Try file-based
-        let theme = self.inner.get(name).map_err(|e| StylesheetError::Load {
+        let theme = self.inner.read(name).map_err(|e| StylesheetError::Load {
             message: e.to_string(),
         })?;
 
@@ -445,6 +464,24 @@ mod tests {
         assert!(styles.has("muted"));
     }
 
+    #[test]
+    fn test_registry_shared_lookup_after_add_dir() {
+        let temp_dir = TempDir::new().unwrap();
+        fs::write(temp_dir.path().join("nord.yaml"), "header:\n  fg: cyan\n").unwrap();
+
+        let mut registry = StylesheetRegistry::new();
+        registry.add_dir(temp_dir.path()).unwrap();
+
+        // The directory is scanned eagerly, so shared lookups see it.
+        assert!(registry.contains("nord"));
+        let shared = std::sync::Arc::new(registry);
+        let handle = {
+            let shared = std::sync::Arc::clone(&shared);
+            std::thread::spawn(move || shared.get("nord").map(|t| t.name().map(String::from)))
+        };
+        assert_eq!(handle.join().unwrap().unwrap().as_deref(), Some("nord"));
+    }
+
     #[test]
     fn test_registry_add_dir() {
         let temp_dir = TempDir::new().unwrap();
@@ -566,7 +603,7 @@ mod tests {
 
     #[test]
     fn test_registry_not_found() {
-        let mut registry = StylesheetRegistry::new();
+        let registry = StylesheetRegistry::new();
         let result = registry.get("nonexistent");
         assert!(result.is_err());
     }
@@ -697,7 +734,7 @@ mod tests {
             ("config.yml", "from_yml:\n    fg: red"),
             ("config.yaml", "from_yaml:\n    fg: cyan"),
         ];
-        let mut registry = StylesheetRegistry::from_embedded_entries(entries).unwrap();
+        let registry = StylesheetRegistry::from_embedded_entries(entries).unwrap();
 
         // Base name should resolve to higher priority (.yaml)
         let theme = registry.get("config").unwrap();
@@ -717,7 +754,7 @@ mod tests {
             ("config.yaml", "from_yaml:\n    fg: cyan"),
             ("config.yml", "from_yml:\n    fg: red"),
         ];
-        let mut registry = StylesheetRegistry::from_embedded_entries(entries).unwrap();
+        let registry = StylesheetRegistry::from_embedded_entries(entries).unwrap();
 
         // Base name should still resolve to higher priority (.yaml)
         let theme = registry.get("config").unwrap();
@@ -754,4 +791,18 @@ mod tests {
 
         assert!(result.is_err());
     }
+
+    #[test]
+    fn test_from_embedded_entries_error_names_file() {
+        let entries: &[(&str, &str)] = &[
+            ("good.yaml", "header:\n    fg: cyan"),
+            ("themes/bad.yaml", "header:\n    fg: not-a-color"),
+        ];
+        let err = match StylesheetRegistry::from_embedded_entries(entries) {
+            Ok(_) => panic!("expected an error"),
+            Err(err) => err,
+        };
+
+        assert!(err.to_string().contains("themes/bad.yaml"), "{}", err);
+    }
 }
@@ -51,6 +51,9 @@
 //! fg: [255, 107, 53]    # RGB tuple
 //! ```
 //!
+//! RGB and 256-color values degrade to the nearest color the terminal can
+//! display, based on the detected [`ColorDepth`] (see [`set_color_depth`]).
+//!
 //! ## Example
 //!
 //! ```rust
@@ -83,12 +86,13 @@ mod attributes;
 mod color;
 mod css_parser;
 mod definition;
+mod depth;
 mod file_registry;
 mod parser;
 
 // Core exports
 pub use error::{StyleValidationError, StylesheetError};
-pub use registry::{Styles, DEFAULT_MISSING_STYLE_INDICATOR};
+pub use registry::{MissingStylePolicy, Styles, DEFAULT_MISSING_STYLE_INDICATOR};
 pub use value::StyleValue;
 
 // Stylesheet parsing exports
@@ -96,5 +100,6 @@ pub use attributes::StyleAttributes;
 pub use color::ColorDef;
 pub use css_parser::parse_css;
 pub use definition::StyleDefinition;
+pub use depth::{detect_color_depth, set_color_depth, ColorDepth};
 pub use file_registry::{StylesheetRegistry, STYLESHEET_EXTENSIONS};
 pub use parser::{parse_stylesheet, ThemeVariants};
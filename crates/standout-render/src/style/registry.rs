@@ -1,7 +1,7 @@
 //! Style registry for managing named styles.
 
 use console::Style;
-use std::collections::HashMap;
+use std::collections::{HashMap, HashSet};
 
 use super::error::StyleValidationError;
 use super::value::StyleValue;
@@ -9,6 +9,32 @@ use super::value::StyleValue;
 /// Default prefix shown when a style name is not found.
 pub const DEFAULT_MISSING_STYLE_INDICATOR: &str = "(!?)";
 
+/// What [`Styles`] does when asked to apply a style name it can't resolve.
+///
+/// The default is a [`Marker`](Self::Marker) with
+/// [`DEFAULT_MISSING_STYLE_INDICATOR`].
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub enum MissingStylePolicy {
+    /// Return the text unchanged.
+    Passthrough,
+    /// Prepend the marker to the text, e.g. `(!?) text`.
+    Marker(String),
+    /// Fail from [`Styles::try_apply_with_mode`] with
+    /// [`StyleValidationError::UnknownStyle`].
+    ///
+    /// The infallible `apply*` methods fall back to the default marker.
+    /// Template rendering doesn't consult this policy: style tags like
+    /// `[titel]` are resolved by the tag parser, whose unknown tags are
+    /// caught by `validate_template` or reported by `render_with_warnings`.
+    Error,
+}
+
+impl Default for MissingStylePolicy {
+    fn default() -> Self {
+        MissingStylePolicy::Marker(DEFAULT_MISSING_STYLE_INDICATOR.to_string())
+    }
+}
+
 /// A collection of named styles.
 ///
 /// Styles are registered by name and applied via the `style` filter in templates.
@@ -16,7 +42,8 @@ pub const DEFAULT_MISSING_STYLE_INDICATOR: &str = "(!?)";
 /// enabling layered styling (semantic -> presentation -> visual).
 ///
 /// When a style name is not found, a configurable indicator is prepended to the text
-/// to help catch typos in templates (defaults to `(!?)`).
+/// to help catch typos in templates (defaults to `(!?)`). See [`MissingStylePolicy`]
+/// for the alternatives.
 ///
 /// # Example
 ///
@@ -42,19 +69,10 @@ pub const DEFAULT_MISSING_STYLE_INDICATOR: &str = "(!?)";
 /// let unknown = styles.apply("typo", "Hello");
 /// assert!(unknown.starts_with("(!?)"));
 /// ```
-#[derive(Debug, Clone)]
+#[derive(Debug, Clone, Default)]
 pub struct Styles {
     styles: HashMap<String, StyleValue>,
-    missing_indicator: String,
-}
-
-impl Default for Styles {
-    fn default() -> Self {
-        Self {
-            styles: HashMap::new(),
-            missing_indicator: DEFAULT_MISSING_STYLE_INDICATOR.to_string(),
-        }
-    }
+    missing_policy: MissingStylePolicy,
 }
 
 impl Styles {
@@ -81,10 +99,40 @@ impl Styles {
     /// assert_eq!(output, "[MISSING] Hello");
     /// ```
     pub fn missing_indicator(mut self, indicator: &str) -> Self {
-        self.missing_indicator = indicator.to_string();
+        self.missing_policy = if indicator.is_empty() {
+            MissingStylePolicy::Passthrough
+        } else {
+            MissingStylePolicy::Marker(indicator.to_string())
+        };
         self
     }
 
+    /// Sets what happens when a style name is not found.
+    ///
+    /// # Example
+    ///
+    /// ```rust
+    /// use standout_render::{MissingStylePolicy, StyleValidationError, Styles};
+    ///
+    /// let styles = Styles::new()
+    ///     .missing_style_policy(MissingStylePolicy::Error)
+    ///     .add("title", console::Style::new().bold());
+    ///
+    /// assert_eq!(
+    ///     styles.try_apply_with_mode("titel", "Hello", false),
+    ///     Err(StyleValidationError::UnknownStyle { name: "titel".into() })
+    /// );
+    /// ```
+    pub fn missing_style_policy(mut self, policy: MissingStylePolicy) -> Self {
+        self.missing_policy = policy;
+        self
+    }
+
+    /// Returns the current missing style policy.
+    pub fn get_missing_style_policy(&self) -> &MissingStylePolicy {
+        &self.missing_policy
+    }
+
     /// Adds a named style. Returns self for chaining.
     ///
     /// The value can be either a concrete `Style` or a `&str`/`String` alias
@@ -107,30 +155,88 @@ impl Styles {
         self
     }
 
-    /// Resolves a style name to a concrete `Style`, following alias chains.
+    /// Resolves a style name to its concrete `Style`, following alias chains.
+    ///
+    /// Returns `None` if the style doesn't exist, if an alias in the chain is
+    /// dangling, or if the chain contains a cycle. For detailed error
+    /// information, use [`validate`](Self::validate) instead.
+    ///
+    /// # Example
+    ///
+    /// ```rust
+    /// use standout_render::Styles;
+    /// use console::Style;
+    ///
+    /// let styles = Styles::new()
+    ///     .add("muted", Style::new().dim())
+    ///     .add("disabled", "muted")
+    ///     .add("timestamp", "disabled");
+    ///
+    /// assert_eq!(styles.resolve("timestamp"), Some(Style::new().dim()));
+    /// assert_eq!(styles.resolve("missing"), None);
+    /// ```
+    pub fn resolve(&self, name: &str) -> Option<Style> {
+        self.resolve_ref(name).cloned()
+    }
+
+    /// Borrowing variant of [`resolve`](Self::resolve).
+    pub(crate) fn resolve_ref(&self, name: &str) -> Option<&Style> {
+        let mut stack = Vec::new();
+        self.walk_alias_chain(name, &HashSet::new(), &mut stack)
+            .ok()
+            .flatten()
+    }
+
+    /// Checks if a style name can be resolved (exists and has no cycles).
+    fn can_resolve(&self, name: &str) -> bool {
+        self.resolve_ref(name).is_some()
+    }
+
+    /// Follows the alias chain starting at `start`, pushing each visited name
+    /// onto `stack`.
     ///
-    /// Returns `None` if the style doesn't exist or if a cycle is detected.
-    /// For detailed error information, use `validate()` instead.
-    pub(crate) fn resolve(&self, name: &str) -> Option<&Style> {
-        let mut current = name;
-        let mut visited = std::collections::HashSet::new();
+    /// Returns the concrete style at the end of the chain, or `None` if the walk
+    /// reached a name in `done` (already known to resolve). The walk is iterative,
+    /// so chain depth is not limited by the call stack.
+    fn walk_alias_chain<'a>(
+        &'a self,
+        start: &str,
+        done: &HashSet<&str>,
+        stack: &mut Vec<&'a str>,
+    ) -> Result<Option<&'a Style>, StyleValidationError> {
+        // Position of each name in `stack` (the recursion stack of the DFS)
+        let mut on_stack: HashMap<&str, usize> = HashMap::new();
+        let mut current: &str = start;
 
         loop {
-            if !visited.insert(current) {
-                return None; // Cycle detected
+            if let Some(&pos) = on_stack.get(current) {
+                // Re-entered the stack: report only the cycle itself,
+                // not the acyclic prefix that led into it.
+                let mut path: Vec<String> = stack[pos..].iter().map(|s| s.to_string()).collect();
+                path.push(current.to_string());
+                return Err(StyleValidationError::CycleDetected { path });
             }
-            match self.styles.get(current)? {
-                StyleValue::Concrete(style) => return Some(style),
+            if done.contains(current) {
+                return Ok(None);
+            }
+
+            let (key, value) = self.styles.get_key_value(current).ok_or_else(|| {
+                StyleValidationError::UnresolvedAlias {
+                    from: stack.last().copied().unwrap_or(start).to_string(),
+                    to: current.to_string(),
+                }
+            })?;
+
+            on_stack.insert(key, stack.len());
+            stack.push(key);
+
+            match value {
+                StyleValue::Concrete(style) => return Ok(Some(style)),
                 StyleValue::Alias(next) => current = next,
             }
         }
     }
 
-    /// Checks if a style name can be resolved (exists and has no cycles).
-    fn can_resolve(&self, name: &str) -> bool {
-        self.resolve(name).is_some()
-    }
-
     /// Validates that all style aliases resolve correctly.
     ///
     /// Returns `Ok(())` if all aliases point to existing styles with no cycles.
@@ -166,41 +272,25 @@ impl Styles {
     /// ));
     /// ```
     pub fn validate(&self) -> Result<(), StyleValidationError> {
-        for (name, value) in &self.styles {
-            if let StyleValue::Alias(target) = value {
-                self.validate_alias_chain(name, target)?;
+        // Alias chains form a graph where each node has at most one outgoing
+        // edge, so a depth-first search is a walk along the chain. Names from
+        // completed walks are remembered, so every style is visited once.
+        let mut done: HashSet<&str> = HashSet::new();
+        let mut stack: Vec<&str> = Vec::new();
+
+        let mut names: Vec<&str> = self.styles.keys().map(String::as_str).collect();
+        names.sort_unstable();
+
+        for start in names {
+            if done.contains(start) {
+                continue;
             }
+            stack.clear();
+            self.walk_alias_chain(start, &done, &mut stack)?;
+            done.extend(stack.iter().copied());
         }
-        Ok(())
-    }
 
-    /// Validates a single alias chain starting from `name` -> `target`.
-    fn validate_alias_chain(&self, name: &str, target: &str) -> Result<(), StyleValidationError> {
-        let mut current = target;
-        let mut path = vec![name.to_string()];
-
-        loop {
-            // Check if target exists
-            let value =
-                self.styles
-                    .get(current)
-                    .ok_or_else(|| StyleValidationError::UnresolvedAlias {
-                        from: path.last().unwrap().clone(),
-                        to: current.to_string(),
-                    })?;
-
-            path.push(current.to_string());
-
-            // Check for cycle (if we've seen this name before in our path)
-            if path[..path.len() - 1].contains(&current.to_string()) {
-                return Err(StyleValidationError::CycleDetected { path });
-            }
-
-            match value {
-                StyleValue::Concrete(_) => return Ok(()),
-                StyleValue::Alias(next) => current = next,
-            }
-        }
+        Ok(())
     }
 
     /// Applies a named style to text.
@@ -208,10 +298,9 @@ impl Styles {
     /// Resolves aliases to find the concrete style, then applies it.
     /// If the style doesn't exist or can't be resolved, prepends the missing indicator.
     pub fn apply(&self, name: &str, text: &str) -> String {
-        match self.resolve(name) {
+        match self.resolve_ref(name) {
             Some(style) => style.apply_to(text).to_string(),
-            None if self.missing_indicator.is_empty() => text.to_string(),
-            None => format!("{} {}", self.missing_indicator, text),
+            None => self.missing(text),
         }
     }
 
@@ -220,10 +309,10 @@ impl Styles {
     /// If the style exists and resolves, returns the text unchanged.
     /// If not found or unresolvable, prepends the missing indicator (unless it's empty).
     pub fn apply_plain(&self, name: &str, text: &str) -> String {
-        if self.can_resolve(name) || self.missing_indicator.is_empty() {
+        if self.can_resolve(name) {
             text.to_string()
         } else {
-            format!("{} {}", self.missing_indicator, text)
+            self.missing(text)
         }
     }
 
@@ -243,10 +332,54 @@ impl Styles {
         }
     }
 
-    /// Applies a style in debug mode, rendering as bracket tags.
+    /// Fallible variant of [`apply_with_mode`](Self::apply_with_mode).
     ///
-    /// Returns `[name]text[/name]` for styles that resolve correctly,
-    /// or applies the missing indicator for unknown/unresolvable styles.
+    /// With [`MissingStylePolicy::Error`], a name that doesn't resolve returns
+    /// an error: [`StyleValidationError::UnknownStyle`] for unregistered names,
+    /// or the alias error for dangling aliases and cycles. Other policies never
+    /// fail and behave exactly like `apply_with_mode`.
+    pub fn try_apply_with_mode(
+        &self,
+        name: &str,
+        text: &str,
+        use_color: bool,
+    ) -> Result<String, StyleValidationError> {
+        if self.missing_policy == MissingStylePolicy::Error {
+            let mut stack = Vec::new();
+            match self.walk_alias_chain(name, &HashSet::new(), &mut stack) {
+                Ok(_) => {}
+                Err(_) if stack.is_empty() => {
+                    return Err(StyleValidationError::UnknownStyle {
+                        name: name.to_string(),
+                    })
+                }
+                Err(err) => return Err(err),
+            }
+        }
+        Ok(self.apply_with_mode(name, text, use_color))
+    }
+
+    /// Text for a style that doesn't resolve, according to the policy.
+    fn missing(&self, text: &str) -> String {
+        let marker = match &self.missing_policy {
+            MissingStylePolicy::Passthrough => return text.to_string(),
+            MissingStylePolicy::Marker(marker) => marker.as_str(),
+            MissingStylePolicy::Error => DEFAULT_MISSING_STYLE_INDICATOR,
+        };
+        if marker.is_empty() {
+            text.to_string()
+        } else {
+            format!("{} {}", marker, text)
+        }
+    }
+
+    /// Applies a style in debug mode, annotating the text instead of styling it.
+    ///
+    /// Returns `⟦path (attributes):text⟧`, where `path` is the requested name
+    /// followed by every alias it resolves through (`timestamp→disabled→muted`)
+    /// and `attributes` summarizes the concrete style at the end of the chain,
+    /// e.g. `fg=red bg=236 bold` (`plain` if it sets nothing). Unknown or
+    /// unresolvable styles get the missing style treatment.
     ///
     /// # Example
     ///
@@ -255,25 +388,29 @@ impl Styles {
     /// use console::Style;
     ///
     /// let styles = Styles::new()
-    ///     .add("bold", Style::new().bold())
-    ///     .add("emphasis", "bold");  // Alias
+    ///     .add("title", Style::new().bold().red())
+    ///     .add("muted", Style::new().dim())
+    ///     .add("disabled", "muted")
+    ///     .add("timestamp", "disabled");
     ///
-    /// // Direct style renders as bracket tags
-    /// assert_eq!(styles.apply_debug("bold", "hello"), "[bold]hello[/bold]");
+    /// assert_eq!(styles.apply_debug("title", "Report"), "⟦title (fg=red bold):Report⟧");
     ///
-    /// // Alias also renders with its own name (not the target)
-    /// assert_eq!(styles.apply_debug("emphasis", "hello"), "[emphasis]hello[/emphasis]");
+    /// // Aliases show the full resolution path
+    /// assert_eq!(
+    ///     styles.apply_debug("timestamp", "12:00"),
+    ///     "⟦timestamp→disabled→muted (dim):12:00⟧"
+    /// );
     ///
     /// // Unknown style shows indicator
     /// assert_eq!(styles.apply_debug("unknown", "hello"), "(!?) hello");
     /// ```
     pub fn apply_debug(&self, name: &str, text: &str) -> String {
-        if self.can_resolve(name) {
-            format!("[{}]{}[/{}]", name, text, name)
-        } else if self.missing_indicator.is_empty() {
-            text.to_string()
-        } else {
-            format!("{} {}", self.missing_indicator, text)
+        let mut path = Vec::new();
+        match self.walk_alias_chain(name, &HashSet::new(), &mut path) {
+            Ok(Some(style)) => {
+                format!("⟦{} ({}):{}⟧", path.join("→"), style_summary(style), text)
+            }
+            _ => self.missing(text),
         }
     }
 
@@ -316,7 +453,7 @@ impl Styles {
     pub fn to_resolved_map(&self) -> HashMap<String, Style> {
         let mut result = HashMap::new();
         for name in self.styles.keys() {
-            if let Some(style) = self.resolve(name) {
+            if let Some(style) = self.resolve_ref(name) {
                 result.insert(name.clone(), style.clone());
             }
         }
@@ -324,6 +461,53 @@ impl Styles {
     }
 }
 
+/// Summarizes the attributes a concrete style sets, e.g. `fg=red bold`.
+///
+/// `console::Style` doesn't expose its attributes, so this renders a probe with
+/// styling forced on and reads them back from the SGR sequences.
+fn style_summary(style: &Style) -> String {
+    const COLORS: [&str; 8] = [
+        "black", "red", "green", "yellow", "blue", "magenta", "cyan", "white",
+    ];
+    let probe = style.clone().force_styling(true).apply_to("x").to_string();
+    let codes = probe.split('x').next().unwrap_or("");
+
+    let mut parts: Vec<String> = Vec::new();
+    for sequence in codes.split("\x1b[").filter(|s| !s.is_empty()) {
+        let params: Vec<&str> = sequence.trim_end_matches('m').split(';').collect();
+        let part = match params.as_slice() {
+            ["38", "5", n] => format!("fg={}", n),
+            ["48", "5", n] => format!("bg={}", n),
+            [code] => match code.parse::<u8>() {
+                Ok(1) => "bold".to_string(),
+                Ok(2) => "dim".to_string(),
+                Ok(3) => "italic".to_string(),
+                Ok(4) => "underlined".to_string(),
+                Ok(5) | Ok(6) => "blink".to_string(),
+                Ok(7) => "reverse".to_string(),
+                Ok(8) => "hidden".to_string(),
+                Ok(9) => "strikethrough".to_string(),
+                Ok(n @ 30..=37) => format!("fg={}", COLORS[(n - 30) as usize]),
+                Ok(n @ 40..=47) => format!("bg={}", COLORS[(n - 40) as usize]),
+                Ok(n @ 90..=97) => format!("fg=bright_{}", COLORS[(n - 90) as usize]),
+                Ok(n @ 100..=107) => format!("bg=bright_{}", COLORS[(n - 100) as usize]),
+                _ => continue,
+            },
+            _ => continue,
+        };
+        // Keep the probe order (colors, then attributes), without repeats
+        if !parts.contains(&part) {
+            parts.push(part);
+        }
+    }
+
+    if parts.is_empty() {
+        "plain".to_string()
+    } else {
+        parts.join(" ")
+    }
+}
+
 #[cfg(test)]
 mod tests {
     use super::*;
@@ -436,7 +620,7 @@ mod tests {
     fn test_styles_apply_debug_known_style() {
         let styles = Styles::new().add("bold", Style::new().bold());
         let result = styles.apply_debug("bold", "hello");
-        assert_eq!(result, "[bold]hello[/bold]");
+        assert_eq!(result, "⟦bold (bold):hello⟧");
     }
 
     #[test]
@@ -502,6 +686,16 @@ mod tests {
         assert!(styles.resolve("level4").is_some());
     }
 
+    #[test]
+    fn test_resolve_returns_final_concrete_style() {
+        let styles = Styles::new()
+            .add("visual", Style::new().cyan().bold())
+            .add("presentation", "visual")
+            .add("semantic", "presentation");
+
+        assert_eq!(styles.resolve("semantic"), Some(Style::new().cyan().bold()));
+    }
+
     #[test]
     fn test_resolve_dangling_alias_returns_none() {
         let styles = Styles::new().add("orphan", "nonexistent");
@@ -647,6 +841,38 @@ mod tests {
         }
     }
 
+    #[test]
+    fn test_validate_very_deep_alias_chain() {
+        let mut styles = Styles::new().add("level0", Style::new().bold());
+        for i in 1..=10_000 {
+            styles = styles.add(&format!("level{}", i), format!("level{}", i - 1));
+        }
+
+        assert!(styles.validate().is_ok());
+    }
+
+    #[test]
+    fn test_validate_cycle_path_excludes_acyclic_prefix() {
+        // A long chain leading into a three-node cycle
+        let mut styles = Styles::new()
+            .add("cycle_a", "cycle_b")
+            .add("cycle_b", "cycle_c")
+            .add("cycle_c", "cycle_a")
+            .add("chain0", "cycle_a");
+        for i in 1..=10_000 {
+            styles = styles.add(&format!("chain{}", i), format!("chain{}", i - 1));
+        }
+
+        match styles.validate().unwrap_err() {
+            StyleValidationError::CycleDetected { path } => {
+                assert_eq!(path.len(), 4, "path: {:?}", path);
+                assert_eq!(path.first(), path.last());
+                assert!(path.iter().all(|name| name.starts_with("cycle_")));
+            }
+            other => panic!("Expected CycleDetected error, got {:?}", other),
+        }
+    }
+
     #[test]
     fn test_validate_mixed_valid_and_invalid() {
         let styles = Styles::new()
@@ -714,7 +940,38 @@ mod tests {
             .add("alias", "base");
 
         let result = styles.apply_debug("alias", "text");
-        assert_eq!(result, "[alias]text[/alias]");
+        assert_eq!(result, "⟦alias→base (bold):text⟧");
+    }
+
+    #[test]
+    fn test_apply_debug_shows_full_alias_path_and_attributes() {
+        let styles = Styles::new()
+            .add(
+                "muted",
+                Style::new()
+                    .dim()
+                    .italic()
+                    .fg(console::Color::Color256(245)),
+            )
+            .add("disabled", "muted")
+            .add("timestamp", "disabled");
+
+        let result = styles.apply_debug("timestamp", "12:00");
+        assert!(result.starts_with("⟦timestamp→disabled→muted ("));
+        assert!(result.contains("dim"));
+        assert!(result.contains("italic"));
+        assert!(result.contains("fg=245"));
+        assert!(result.ends_with("):12:00⟧"));
+    }
+
+    #[test]
+    fn test_style_summary() {
+        assert_eq!(style_summary(&Style::new()), "plain");
+        assert_eq!(
+            style_summary(&Style::new().bold().underlined().red().on_blue()),
+            "fg=red bg=blue bold underlined"
+        );
+        assert_eq!(style_summary(&Style::new().on_color256(236)), "bg=236");
     }
 
     #[test]
@@ -723,4 +980,85 @@ mod tests {
         let result = styles.apply_debug("orphan", "text");
         assert_eq!(result, "(!?) text");
     }
+
+    #[test]
+    fn test_missing_policy_default_is_marker() {
+        let styles = Styles::new();
+        assert_eq!(
+            styles.get_missing_style_policy(),
+            &MissingStylePolicy::Marker(DEFAULT_MISSING_STYLE_INDICATOR.to_string())
+        );
+        assert_eq!(
+            styles.try_apply_with_mode("titel", "hello", false),
+            Ok("(!?) hello".to_string())
+        );
+    }
+
+    #[test]
+    fn test_missing_policy_passthrough() {
+        let styles = Styles::new().missing_style_policy(MissingStylePolicy::Passthrough);
+        assert_eq!(styles.apply_with_mode("titel", "hello", true), "hello");
+        assert_eq!(styles.apply_with_mode("titel", "hello", false), "hello");
+        assert_eq!(styles.apply_debug("titel", "hello"), "hello");
+        assert_eq!(
+            styles.try_apply_with_mode("titel", "hello", false),
+            Ok("hello".to_string())
+        );
+    }
+
+    #[test]
+    fn test_missing_policy_marker() {
+        let styles =
+            Styles::new().missing_style_policy(MissingStylePolicy::Marker("<?>".to_string()));
+        assert_eq!(styles.apply_with_mode("titel", "hello", true), "<?> hello");
+        assert_eq!(styles.apply_with_mode("titel", "hello", false), "<?> hello");
+    }
+
+    #[test]
+    fn test_missing_policy_error() {
+        let styles = Styles::new()
+            .missing_style_policy(MissingStylePolicy::Error)
+            .add("title", Style::new().bold())
+            .add("dangling", "nowhere")
+            .add("a", "b")
+            .add("b", "a");
+
+        assert_eq!(
+            styles.try_apply_with_mode("title", "hello", false),
+            Ok("hello".to_string())
+        );
+        assert_eq!(
+            styles.try_apply_with_mode("titel", "hello", true),
+            Err(StyleValidationError::UnknownStyle {
+                name: "titel".to_string()
+            })
+        );
+        assert!(matches!(
+            styles.try_apply_with_mode("dangling", "hello", false),
+            Err(StyleValidationError::UnresolvedAlias { .. })
+        ));
+        assert!(matches!(
+            styles.try_apply_with_mode("a", "hello", false),
+            Err(StyleValidationError::CycleDetected { .. })
+        ));
+        // Infallible methods keep the text visible with the default marker
+        assert_eq!(
+            styles.apply_with_mode("titel", "hello", false),
+            "(!?) hello"
+        );
+    }
+
+    #[test]
+    fn test_missing_indicator_sets_policy() {
+        let styles = Styles::new().missing_indicator("");
+        assert_eq!(
+            styles.get_missing_style_policy(),
+            &MissingStylePolicy::Passthrough
+        );
+        let styles = Styles::new().missing_indicator("[MISSING]");
+        assert_eq!(
+            styles.get_missing_style_policy(),
+            &MissingStylePolicy::Marker("[MISSING]".to_string())
+        );
+    }
 }
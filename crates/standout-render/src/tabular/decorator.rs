@@ -190,9 +190,9 @@ impl Table {
         let formatter = TabularFormatter::new(&spec, total_width);
         Table {
             formatter,
-            headers: None,
+            headers: spec.header,
             border: BorderStyle::None,
-            header_style: None,
+            header_style: spec.header_style,
             row_separator: false,
         }
     }
@@ -202,9 +202,9 @@ impl Table {
         let formatter = TabularFormatter::new(spec, total_width);
         Table {
             formatter,
-            headers: None,
+            headers: spec.header.clone(),
             border: BorderStyle::None,
-            header_style: None,
+            header_style: spec.header_style.clone(),
             row_separator: false,
         }
     }
@@ -483,10 +483,12 @@ impl Table {
         if !header.is_empty() {
             output.push(header);
 
-            // Separator after header
+            // Separator after header, or the spec's header divider without a frame
             let sep = self.separator_row();
             if !sep.is_empty() {
                 output.push(sep);
+            } else if let Some(divider) = self.formatter.header_divider_row() {
+                output.push(divider);
             }
         }
 
@@ -722,6 +724,41 @@ mod tests {
         assert!(header.contains("[/header]"));
     }
 
+    #[test]
+    fn table_from_spec_uses_spec_header() {
+        let spec = TabularSpec::builder()
+            .column(Col::bounded(2, 20))
+            .column(Col::fixed(4))
+            .separator("  ")
+            .header(["Description", "Done"])
+            .header_style("title")
+            .build();
+        // Without a header the bounded column would shrink to its minimum of 2
+        let table = Table::from_spec(&spec, 10);
+
+        let header = table.header_row();
+        assert!(header.starts_with("[title]Description"), "{}", header);
+        assert!(header.ends_with("[/title]"), "{}", header);
+        assert_eq!(table.formatter.widths()[0], 11);
+    }
+
+    #[test]
+    fn table_render_markdown_preset() {
+        let spec = TabularSpec::builder()
+            .column(Col::fixed(4))
+            .column(Col::fixed(6))
+            .border(crate::tabular::BorderPreset::Markdown)
+            .header(["Name", "Status"])
+            .build();
+        let table = Table::from_spec(&spec, 80);
+
+        let output = table.render(&[vec!["Bob", "active"]]);
+        assert_eq!(
+            output,
+            "| Name | Status |\n|------|--------|\n| Bob  | active |"
+        );
+    }
+
     #[test]
     fn table_no_header() {
         let table = Table::new(simple_spec(), 80);
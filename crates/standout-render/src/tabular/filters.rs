@@ -10,12 +10,19 @@
 //! - `pad_right(width)` - Left-align with padding
 //! - `truncate_at(width, pos, ellipsis)` - Truncate at position
 //! - `display_width` - Get display width of a string
+//! - `wrap(width, indent)` - Wrap text onto lines of at most `width` columns
 //! - `style_as(style)` - Wrap value in style tags
 //!
 //! ## Global Functions
 //!
 //! - `tabular(columns, separator=?, width=?)` - Create a TabularFormatter
-//! - `table(columns, border=?, header=?, header_style=?)` - Create a Table
+//! - `table(columns, border=?, header=?, header_style=?, width=?)` - Create a Table
+//!
+//! - `row(cells, spec_name, width=?)` - Format one row with a spec registered
+//!   through [`register_table_spec`]
+//!
+//! Without `width=`, these functions use the `terminal_width` template variable,
+//! which is set from the render context's width, and fall back to 80 columns.
 //!
 //! ### Column Definition Format
 //!
@@ -41,7 +48,9 @@
 //! {% endfor %}
 //! ```
 
-use minijinja::{Environment, Value};
+use minijinja::{Environment, State, Value};
+
+use crate::context::TERMINAL_WIDTH_VAR;
 
 use super::decorator::{BorderStyle, Table};
 use super::formatter::TabularFormatter;
@@ -49,6 +58,7 @@ use super::traits::Tabular;
 use super::types::{Align, Column, Overflow, TabularSpec, TruncateAt, Width};
 use super::util::{
     display_width, pad_center, pad_left, pad_right, truncate_end, truncate_middle, truncate_start,
+    wrap_indent,
 };
 
 /// Register all tabular-related filters on a MiniJinja environment.
@@ -59,6 +69,7 @@ use super::util::{
 /// - `pad_left(width)` - Right-align with padding
 /// - `pad_right(width)` - Left-align with padding
 /// - `truncate_at(width, pos, ellipsis)` - Truncate at position
+/// - `wrap(width, indent)` - Wrap text at word boundaries
 ///
 /// # Example
 ///
@@ -158,6 +169,31 @@ pub fn register_tabular_filters(env: &mut Environment<'static>) {
         display_width(&value.to_string())
     });
 
+    // wrap filter: {{ value | wrap(40) }} or {{ value | wrap(40, 2) }}
+    // Wraps each input line separately, so existing line breaks and leading
+    // indentation are kept.
+    env.add_filter(
+        "wrap",
+        |value: Value, width: usize, indent: Option<usize>| -> Result<String, minijinja::Error> {
+            let indent = indent.unwrap_or(0);
+            if width == 0 || indent >= width {
+                return Err(minijinja::Error::new(
+                    minijinja::ErrorKind::InvalidOperation,
+                    format!(
+                        "wrap: indent must be smaller than width, got width {} and indent {}",
+                        width, indent
+                    ),
+                ));
+            }
+            Ok(value
+                .to_string()
+                .lines()
+                .flat_map(|line| wrap_line(line, width, indent))
+                .collect::<Vec<_>>()
+                .join("\n"))
+        },
+    );
+
     // style_as filter: {{ value | style_as("error") }} => [error]value[/error]
     env.add_filter("style_as", |value: Value, style: String| -> String {
         let text = value.to_string();
@@ -172,17 +208,45 @@ pub fn register_tabular_filters(env: &mut Environment<'static>) {
     register_table_functions(env);
 }
 
+/// Wraps one input line for the `wrap` filter, repeating its leading
+/// whitespace on every output line when there is room for it.
+fn wrap_line(line: &str, width: usize, indent: usize) -> Vec<String> {
+    let text = line.trim_start();
+    let lead = &line[..line.len() - text.len()];
+    let lead_width = display_width(lead);
+    let (lead, width) = if width.saturating_sub(lead_width) > indent {
+        (lead, width - lead_width)
+    } else {
+        ("", width)
+    };
+
+    let wrapped = wrap_indent(text, width, indent);
+    if wrapped.is_empty() {
+        return vec![String::new()];
+    }
+    wrapped
+        .into_iter()
+        .map(|part| format!("{}{}", lead, part))
+        .collect()
+}
+
 /// Register global functions for creating table formatters.
 fn register_table_functions(env: &mut Environment<'static>) {
     // tabular(columns, separator=?, width=?) -> TabularFormatter
     env.add_function(
         "tabular",
-        |columns: Value, kwargs: minijinja::value::Kwargs| -> Result<Value, minijinja::Error> {
+        |state: &State,
+         columns: Value,
+         kwargs: minijinja::value::Kwargs|
+         -> Result<Value, minijinja::Error> {
             let cols = parse_columns(&columns)?;
             let separator = kwargs
                 .get::<Option<String>>("separator")?
                 .unwrap_or_default();
-            let width = kwargs.get::<Option<usize>>("width")?.unwrap_or(80);
+            let width = match kwargs.get::<Option<usize>>("width")? {
+                Some(width) => width,
+                None => default_width(state),
+            };
             kwargs.assert_all_used()?;
 
             let mut builder = TabularSpec::builder();
@@ -202,7 +266,10 @@ fn register_table_functions(env: &mut Environment<'static>) {
     // table(columns, border=?, header=?, header_style=?, width=?) -> Table
     env.add_function(
         "table",
-        |columns: Value, kwargs: minijinja::value::Kwargs| -> Result<Value, minijinja::Error> {
+        |state: &State,
+         columns: Value,
+         kwargs: minijinja::value::Kwargs|
+         -> Result<Value, minijinja::Error> {
             let cols = parse_columns(&columns)?;
             let separator = kwargs
                 .get::<Option<String>>("separator")?
@@ -213,7 +280,10 @@ fn register_table_functions(env: &mut Environment<'static>) {
             let row_separator = kwargs
                 .get::<Option<bool>>("row_separator")?
                 .unwrap_or(false);
-            let width = kwargs.get::<Option<usize>>("width")?.unwrap_or(80);
+            let width = match kwargs.get::<Option<usize>>("width")? {
+                Some(width) => width,
+                None => default_width(state),
+            };
             kwargs.assert_all_used()?;
 
             let mut builder = TabularSpec::builder();
@@ -224,10 +294,7 @@ fn register_table_functions(env: &mut Environment<'static>) {
                 builder = builder.separator(&separator);
             }
 
-            let spec = builder.build();
-            let mut table = Table::new(spec, width).border(parse_border_style(&border));
-
-            // Set header if provided
+            // Set header if provided (part of the spec so it counts toward widths)
             if let Some(h) = header {
                 let headers: Vec<String> = h
                     .try_iter()
@@ -239,9 +306,12 @@ fn register_table_functions(env: &mut Environment<'static>) {
                     })?
                     .map(|v| v.to_string())
                     .collect();
-                table = table.header(headers);
+                builder = builder.header(headers);
             }
 
+            let spec = builder.build();
+            let mut table = Table::new(spec, width).border(parse_border_style(&border));
+
             // Set header style if provided
             if let Some(style) = header_style {
                 table = table.header_style(style);
@@ -255,6 +325,100 @@ fn register_table_functions(env: &mut Environment<'static>) {
             Ok(Value::from_object(table))
         },
     );
+
+    // row(cells, spec_name, width=?) -> formatted row using a registered spec
+    env.add_function(
+        "row",
+        |state: &State,
+         cells: Value,
+         spec_name: String,
+         kwargs: minijinja::value::Kwargs|
+         -> Result<String, minijinja::Error> {
+            let width = match kwargs.get::<Option<usize>>("width")? {
+                Some(width) => width,
+                None => default_width(state),
+            };
+            kwargs.assert_all_used()?;
+
+            let spec_value = state
+                .lookup(&format!("{TABLE_SPEC_PREFIX}{spec_name}"))
+                .ok_or_else(|| {
+                    minijinja::Error::new(
+                        minijinja::ErrorKind::InvalidOperation,
+                        format!("unknown table spec '{}'", spec_name),
+                    )
+                })?;
+            let spec = spec_value
+                .downcast_object_ref::<RegisteredSpec>()
+                .map(|registered| &registered.0)
+                .ok_or_else(|| {
+                    minijinja::Error::new(
+                        minijinja::ErrorKind::InvalidOperation,
+                        format!("'{}' is not a table spec", spec_name),
+                    )
+                })?;
+
+            let cells: Vec<String> = cells
+                .try_iter()
+                .map_err(|_| {
+                    minijinja::Error::new(
+                        minijinja::ErrorKind::InvalidOperation,
+                        "cells must be an array",
+                    )
+                })?
+                .map(|v| v.to_string())
+                .collect();
+
+            let resolved = spec.resolve_widths_from_data(width, std::slice::from_ref(&cells));
+            Ok(TabularFormatter::from_resolved(spec, resolved).format_row(&cells))
+        },
+    );
+}
+
+/// Prefix of the globals holding specs registered with [`register_table_spec`].
+const TABLE_SPEC_PREFIX: &str = "__standout_table_spec:";
+
+/// A [`TabularSpec`] stored as a template global.
+#[derive(Debug)]
+struct RegisteredSpec(TabularSpec);
+
+impl minijinja::value::Object for RegisteredSpec {}
+
+/// Register a named [`TabularSpec`] for the `row(cells, spec_name)` function.
+///
+/// Registering a spec under an existing name replaces it.
+///
+/// # Example
+///
+/// ```rust,ignore
+/// use minijinja::Environment;
+/// use standout_render::tabular::filters::{register_table_spec, register_tabular_filters};
+/// use standout_render::tabular::{Column, TabularSpec, Width};
+///
+/// let mut env = Environment::new();
+/// register_tabular_filters(&mut env);
+/// let spec = TabularSpec::builder()
+///     .column(Column::new(Width::Bounded { min: Some(4), max: Some(20) }))
+///     .column(Column::new(Width::Fixed(8)))
+///     .separator("  ")
+///     .build();
+/// register_table_spec(&mut env, "tasks", spec);
+/// // {{ row([task.name, task.status], "tasks") }}
+/// ```
+pub fn register_table_spec(env: &mut Environment<'static>, name: &str, spec: TabularSpec) {
+    env.add_global(
+        format!("{TABLE_SPEC_PREFIX}{name}"),
+        Value::from_object(RegisteredSpec(spec)),
+    );
+}
+
+/// The table width when none is given: the render width, or 80 columns.
+fn default_width(state: &State) -> usize {
+    state
+        .lookup(TERMINAL_WIDTH_VAR)
+        .and_then(|width| usize::try_from(width).ok())
+        .filter(|&width| width > 0)
+        .unwrap_or(80)
 }
 
 /// Parse column definitions from a template array value.
@@ -406,11 +570,14 @@ fn parse_width(value: &Value) -> Result<Width, minijinja::Error> {
         return Ok(Width::Fixed(n as usize));
     }
 
-    // String "fill" or "Nfr" (fractional) -> Fill or Fraction
+    // String "fill", "fill_max" or "Nfr" (fractional) -> Fill, FillMax or Fraction
     if let Some(s) = value.as_str() {
         if s == "fill" {
             return Ok(Width::Fill);
         }
+        if s == "fill_max" {
+            return Ok(Width::FillMax);
+        }
 
         // Check for fractional syntax: "2fr", "1fr", etc.
         if let Some(num_part) = s.strip_suffix("fr") {
@@ -422,7 +589,7 @@ fn parse_width(value: &Value) -> Result<Width, minijinja::Error> {
         return Err(minijinja::Error::new(
             minijinja::ErrorKind::InvalidOperation,
             format!(
-                "unknown width string: '{}' (use number, 'fill', 'Nfr', or object)",
+                "unknown width string: '{}' (use number, 'fill', 'fill_max', 'Nfr', or object)",
                 s
             ),
         ));
@@ -867,6 +1034,75 @@ mod tests {
         assert!(lines[1].starts_with("bar       "));
     }
 
+    #[test]
+    fn filter_wrap() {
+        let mut env = setup_env();
+        env.add_template("test", "{{ value | wrap(11) }}").unwrap();
+        let render = |value: &str| {
+            env.get_template("test")
+                .unwrap()
+                .render(context!(value => value))
+                .unwrap()
+        };
+
+        assert_eq!(render("hello world foo bar"), "hello world\nfoo bar");
+        assert_eq!(render("short"), "short");
+        // Existing line breaks and blank lines are kept
+        assert_eq!(render("hello world foo\n\nbar"), "hello world\nfoo\n\nbar");
+    }
+
+    #[test]
+    fn filter_wrap_with_indent() {
+        let mut env = setup_env();
+        env.add_template("test", "{{ value | wrap(12, 2) }}")
+            .unwrap();
+        let result = env
+            .get_template("test")
+            .unwrap()
+            .render(context!(value => "hello world foo bar"))
+            .unwrap();
+        assert_eq!(result, "hello world\n  foo bar");
+    }
+
+    #[test]
+    fn filter_wrap_keeps_every_word() {
+        let mut env = setup_env();
+        env.add_template("test", "{{ value | wrap(width, indent) }}")
+            .unwrap();
+        let render = |value: &str, width: usize, indent: usize| {
+            env.get_template("test")
+                .unwrap()
+                .render(context!(value => value, width => width, indent => indent))
+        };
+
+        assert_eq!(
+            render("alpha beta gamma delta", 6, 1).unwrap(),
+            "alpha\n beta\n gamma\n delta"
+        );
+        assert_eq!(render("alpha", 2, 1).unwrap(), "a…\n l\n p\n h\n a");
+        // Leading indentation of each input line is kept on its wrapped lines
+        assert_eq!(
+            render("  hello world foo", 9, 0).unwrap(),
+            "  hello\n  world\n  foo"
+        );
+    }
+
+    #[test]
+    fn filter_wrap_rejects_unusable_width() {
+        let mut env = setup_env();
+        env.add_template("test", "{{ value | wrap(width, indent) }}")
+            .unwrap();
+        let render = |width: usize, indent: usize| {
+            env.get_template("test").unwrap().render(
+                context!(value => "alpha beta gamma delta", width => width, indent => indent),
+            )
+        };
+
+        assert!(render(4, 4).is_err());
+        assert!(render(6, 6).is_err());
+        assert!(render(0, 0).is_err());
+    }
+
     #[test]
     fn filter_style_as() {
         let mut env = setup_env();
@@ -974,6 +1210,72 @@ mod tests {
         assert_eq!(display_width(&result), 20);
     }
 
+    #[test]
+    fn function_tabular_default_width_from_context() {
+        let mut env = setup_env();
+        env.add_template(
+            "test",
+            r#"{% set fmt = tabular([{"width": 5}, {"width": "fill"}], separator="  ") %}{{ fmt.row(["A", "B"]) }}"#,
+        )
+        .unwrap();
+        let template = env.get_template("test").unwrap();
+
+        let result = template.render(context!(terminal_width => 30)).unwrap();
+        assert_eq!(display_width(&result), 30);
+
+        // Without a render width, tables fall back to 80 columns
+        let result = template.render(context!()).unwrap();
+        assert_eq!(display_width(&result), 80);
+    }
+
+    #[test]
+    fn function_row_with_registered_spec() {
+        let mut env = setup_env();
+        let spec = TabularSpec::builder()
+            .column(Column::new(Width::Fixed(8)))
+            .column(Column::new(Width::Fixed(6)).align(Align::Right))
+            .separator(" | ")
+            .build();
+        register_table_spec(&mut env, "tasks", spec);
+        env.add_template("test", r#"{{ row([name, status], "tasks") }}"#)
+            .unwrap();
+        let result = env
+            .get_template("test")
+            .unwrap()
+            .render(context!(name => "deploy", status => "done"))
+            .unwrap();
+        assert_eq!(result, "deploy   |   done");
+    }
+
+    #[test]
+    fn function_row_unknown_spec() {
+        let mut env = setup_env();
+        env.add_template("test", r#"{{ row(["a"], "missing") }}"#)
+            .unwrap();
+        let err = env
+            .get_template("test")
+            .unwrap()
+            .render(context!())
+            .unwrap_err();
+        assert!(err.to_string().contains("unknown table spec 'missing'"));
+    }
+
+    #[test]
+    fn function_table_explicit_width_beats_context() {
+        let mut env = setup_env();
+        env.add_template(
+            "test",
+            r#"{% set t = table([{"width": 4}, {"width": "fill"}], width=12) %}{{ t.row(["A", "B"]) }}"#,
+        )
+        .unwrap();
+        let result = env
+            .get_template("test")
+            .unwrap()
+            .render(context!(terminal_width => 50))
+            .unwrap();
+        assert_eq!(display_width(&result), 12);
+    }
+
     #[test]
     fn function_tabular_right_align() {
         let mut env = setup_env();
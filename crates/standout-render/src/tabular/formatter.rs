@@ -82,12 +82,17 @@ pub struct TabularFormatter {
     columns: Vec<Column>,
     /// Resolved widths for each column.
     widths: Vec<usize>,
+    /// Position of each column's value in the rows passed in. Differs from
+    /// the column index once columns were dropped.
+    indices: Vec<usize>,
     /// Column separator string.
     separator: String,
     /// Row prefix string.
     prefix: String,
     /// Row suffix string.
     suffix: String,
+    /// Whether a divider row goes under the header.
+    header_divider: bool,
     /// Total target width for anchor calculations.
     total_width: usize,
 }
@@ -107,10 +112,13 @@ impl TabularFormatter {
     /// Create a formatter with pre-resolved widths.
     ///
     /// Use this when you've already calculated widths (e.g., from data).
+    /// Columns listed in [`ResolvedWidths::dropped`] are left out of every
+    /// row; rows are still passed in with a value for each spec column.
     pub fn from_resolved(spec: &FlatDataSpec, resolved: ResolvedWidths) -> Self {
         // Calculate total width from resolved widths + overhead
         let content_width: usize = resolved.widths.iter().sum();
-        let overhead = spec.decorations.overhead(resolved.widths.len());
+        let visible = resolved.widths.len() - resolved.dropped.len();
+        let overhead = spec.decorations.overhead(visible);
         let total_width = content_width + overhead;
         Self::from_resolved_with_width(spec, resolved, total_width)
     }
@@ -121,12 +129,20 @@ impl TabularFormatter {
         resolved: ResolvedWidths,
         total_width: usize,
     ) -> Self {
+        let indices: Vec<usize> = (0..spec.columns.len())
+            .filter(|i| !resolved.is_dropped(*i))
+            .collect();
         TabularFormatter {
-            columns: spec.columns.clone(),
-            widths: resolved.widths,
+            columns: indices.iter().map(|&i| spec.columns[i].clone()).collect(),
+            widths: indices
+                .iter()
+                .map(|&i| resolved.widths.get(i).copied().unwrap_or(0))
+                .collect(),
+            indices,
             separator: spec.decorations.column_sep.clone(),
             prefix: spec.decorations.row_prefix.clone(),
             suffix: spec.decorations.row_suffix.clone(),
+            header_divider: spec.decorations.header_divider,
             total_width,
         }
     }
@@ -137,11 +153,13 @@ impl TabularFormatter {
     pub fn with_widths(columns: Vec<Column>, widths: Vec<usize>) -> Self {
         let total_width = widths.iter().sum();
         TabularFormatter {
+            indices: (0..columns.len()).collect(),
             columns,
             widths,
             separator: String::new(),
             prefix: String::new(),
             suffix: String::new(),
+            header_divider: false,
             total_width,
         }
     }
@@ -239,7 +257,7 @@ impl TabularFormatter {
             }
 
             let width = self.widths.get(i).copied().unwrap_or(0);
-            let value = values.get(i).map(|s| s.as_ref()).unwrap_or(&col.null_repr);
+            let value = self.value_at(values, i).unwrap_or(&col.null_repr);
 
             let formatted = format_cell(value, width, col);
             result.push_str(&formatted);
@@ -249,6 +267,12 @@ impl TabularFormatter {
         result
     }
 
+    /// The value for formatted column `i`, skipping values of dropped columns.
+    fn value_at<'a, S: AsRef<str>>(&self, values: &'a [S], i: usize) -> Option<&'a str> {
+        let index = self.indices.get(i).copied().unwrap_or(i);
+        values.get(index).map(|s| s.as_ref())
+    }
+
     /// Calculate the anchor gap size and transition point.
     ///
     /// Returns (gap_size, transition_index) where:
@@ -294,6 +318,24 @@ impl TabularFormatter {
         rows.iter().map(|row| self.format_row(row)).collect()
     }
 
+    /// The divider row drawn under the header, if the decorations ask for one.
+    ///
+    /// Cells become dashes and the spaces in the separator and edges do too,
+    /// so `| a | b |` gets `|---|---|`, as Markdown tables expect.
+    pub fn header_divider_row(&self) -> Option<String> {
+        if !self.header_divider {
+            return None;
+        }
+        let rule = |s: &str| s.replace(' ', "-");
+        let cells: Vec<String> = self.widths.iter().map(|&w| "-".repeat(w)).collect();
+        Some(format!(
+            "{}{}{}",
+            rule(&self.prefix),
+            cells.join(&rule(&self.separator)),
+            rule(&self.suffix)
+        ))
+    }
+
     /// Format a row that may produce multiple output lines (due to wrapping).
     ///
     /// If any cell wraps to multiple lines, the output contains multiple lines
@@ -322,7 +364,7 @@ impl TabularFormatter {
             .enumerate()
             .map(|(i, col)| {
                 let width = self.widths.get(i).copied().unwrap_or(0);
-                let value = values.get(i).map(|s| s.as_ref()).unwrap_or(&col.null_repr);
+                let value = self.value_at(values, i).unwrap_or(&col.null_repr);
                 format_cell_lines(value, width, col)
             })
             .collect();
@@ -393,17 +435,25 @@ impl TabularFormatter {
     ///
     /// This is useful for `Table::header_from_columns()`.
     pub fn extract_headers(&self) -> Vec<String> {
-        self.columns
-            .iter()
-            .map(|col| {
-                col.header
-                    .as_deref()
-                    .or(col.key.as_deref())
-                    .or(col.name.as_deref())
-                    .unwrap_or("")
-                    .to_string()
-            })
-            .collect()
+        self.by_position(|col| {
+            col.header
+                .as_deref()
+                .or(col.key.as_deref())
+                .or(col.name.as_deref())
+                .unwrap_or("")
+                .to_string()
+        })
+    }
+
+    /// Build one value per spec column, leaving dropped columns empty so the
+    /// result lines up with the rows `format_row` expects.
+    fn by_position(&self, f: impl Fn(&Column) -> String) -> Vec<String> {
+        let len = self.indices.last().map_or(0, |&i| i + 1);
+        let mut values = vec![String::new(); len];
+        for (col, &index) in self.columns.iter().zip(&self.indices) {
+            values[index] = f(col);
+        }
+        values
     }
 
     /// Format a row by extracting values from a serializable struct.
@@ -507,21 +557,18 @@ impl TabularFormatter {
         // Convert to JSON for field access
         let json = match serde_json::to_value(value) {
             Ok(v) => v,
-            Err(_) => return vec![String::new(); self.columns.len()],
+            Err(_) => return self.by_position(|_| String::new()),
         };
 
-        self.columns
-            .iter()
-            .map(|col| {
-                // Use key first, fall back to name
-                let key = col.key.as_ref().or(col.name.as_ref());
+        self.by_position(|col| {
+            // Use key first, fall back to name
+            let key = col.key.as_ref().or(col.name.as_ref());
 
-                match key {
-                    Some(k) => extract_field(&json, k),
-                    None => col.null_repr.clone(),
-                }
-            })
-            .collect()
+            match key {
+                Some(k) => extract_field(&json, k),
+                None => col.null_repr.clone(),
+            }
+        })
     }
 }
 
@@ -958,6 +1005,22 @@ mod tests {
         assert_eq!(formatter.widths(), &[5, 16, 5]);
     }
 
+    #[test]
+    fn format_row_skips_dropped_columns() {
+        let spec = FlatDataSpec::builder()
+            .column(Column::new(Width::Fixed(5)))
+            .column(Column::new(Width::Fixed(5)).priority(1))
+            .column(Column::new(Width::Fixed(5)))
+            .separator(" ")
+            .build();
+
+        // 15 + 2 separators does not fit in 12: the middle column is dropped
+        let formatter = TabularFormatter::new(&spec, 12);
+        assert_eq!(formatter.num_columns(), 2);
+        assert_eq!(formatter.widths(), &[5, 5]);
+        assert_eq!(formatter.format_row(&["a", "b", "c"]), "a     c    ");
+    }
+
     #[test]
     fn formatter_accessors() {
         let spec = FlatDataSpec::builder()
@@ -1500,6 +1563,33 @@ mod tests {
         assert!(row.contains("42"));
     }
 
+    #[test]
+    fn row_from_skips_dropped_columns() {
+        #[derive(Serialize)]
+        struct Record {
+            name: String,
+            value: i32,
+            note: String,
+        }
+
+        let spec = FlatDataSpec::builder()
+            .column(Column::new(Width::Fixed(6)).key("name"))
+            .column(Column::new(Width::Fixed(6)).key("note").priority(1))
+            .column(Column::new(Width::Fixed(3)).key("value"))
+            .separator(" ")
+            .build();
+        let formatter = TabularFormatter::new(&spec, 12);
+
+        let record = Record {
+            name: "Test".to_string(),
+            value: 42,
+            note: "hidden".to_string(),
+        };
+
+        assert_eq!(formatter.row_from(&record), "Test   42 ");
+        assert_eq!(formatter.extract_headers(), vec!["name", "", "value"]);
+    }
+
     #[test]
     fn row_from_uses_name_as_fallback() {
         #[derive(Serialize)]
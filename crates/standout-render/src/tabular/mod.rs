@@ -58,6 +58,7 @@
 //! - [`Width::Fixed(n)`] - Exactly n display columns
 //! - [`Width::Bounded { min, max }`] - Auto-size within bounds based on content
 //! - [`Width::Fill`] - Expand to fill remaining space
+//! - [`Width::FillMax`] - Like `Fill`, but no wider than the widest cell
 //!
 //! ## Truncation Modes
 //!
@@ -85,6 +86,7 @@
 //! - [`Width::Fixed(n)`] - Exactly n display columns
 //! - [`Width::Bounded { min, max }`] - Auto-calculate from content within bounds
 //! - [`Width::Fill`] - Expand to fill remaining space (one per table)
+//! - [`Width::FillMax`] - Fill remaining space up to the widest cell
 //!
 //! # Truncation Modes
 //!
@@ -120,12 +122,12 @@ pub use traits::{Tabular, TabularFieldDisplay, TabularFieldOption, TabularRow};
 // Note: Tabular and TabularRow derive macros are re-exported from the main `standout` crate
 // when the "macros" feature is enabled.
 pub use types::{
-    Align, Anchor, Col, Column, ColumnBuilder, Decorations, FlatDataSpec, FlatDataSpecBuilder,
-    Overflow, TabularSpec, TabularSpecBuilder, TruncateAt, Width,
+    Align, Anchor, BorderPreset, Col, Column, ColumnBuilder, Decorations, FlatDataSpec,
+    FlatDataSpecBuilder, Overflow, TabularSpec, TabularSpecBuilder, TruncateAt, Width,
 };
 
 // Re-export utility functions
 pub use util::{
-    display_width, pad_center, pad_left, pad_right, truncate_end, truncate_middle, truncate_start,
-    wrap, wrap_indent,
+    display_width, pad_center, pad_left, pad_right, strip_ansi, truncate_end, truncate_middle,
+    truncate_start, wrap, wrap_indent,
 };
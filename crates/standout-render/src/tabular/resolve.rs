@@ -2,18 +2,46 @@
 //!
 //! This module handles calculating the actual display width for each column
 //! based on the column specifications and available space.
+//!
+//! # Dropping columns
+//!
+//! When the Fixed and Bounded columns plus decorations need more than the
+//! available width, columns with a non-zero [`Column::priority`] are dropped,
+//! lowest priority first (the rightmost one on ties), until the rest fit.
+//! Columns with priority `0` are never dropped, so a table can still overflow.
+//! Fill and Fraction columns only take space that is left over, so they never
+//! cause a drop; they can still be dropped if they have a priority.
+//!
+//! [`Column::priority`]: super::Column::priority
+
+use std::cmp::Reverse;
 
 use super::types::{FlatDataSpec, Width};
 use super::util::display_width;
 
 /// Resolved widths for all columns in a table.
-#[derive(Clone, Debug, PartialEq, Eq)]
+///
+/// Build one by hand with [`ResolvedWidths::new`] rather than a struct
+/// literal, so the code keeps compiling when fields are added.
+#[derive(Clone, Debug, Default, PartialEq, Eq)]
 pub struct ResolvedWidths {
     /// Width for each column in display columns.
+    ///
+    /// Dropped columns keep their slot with a width of 0.
     pub widths: Vec<usize>,
+    /// Indices of columns dropped to fit the available width, in column order.
+    pub dropped: Vec<usize>,
 }
 
 impl ResolvedWidths {
+    /// Create resolved widths with no dropped columns.
+    pub fn new(widths: Vec<usize>) -> Self {
+        ResolvedWidths {
+            widths,
+            dropped: vec![],
+        }
+    }
+
     /// Get the width of a specific column.
     pub fn get(&self, index: usize) -> Option<usize> {
         self.widths.get(index).copied()
@@ -33,6 +61,11 @@ impl ResolvedWidths {
     pub fn is_empty(&self) -> bool {
         self.widths.is_empty()
     }
+
+    /// Check if the column at `index` was dropped.
+    pub fn is_dropped(&self, index: usize) -> bool {
+        self.dropped.contains(&index)
+    }
 }
 
 impl FlatDataSpec {
@@ -52,8 +85,9 @@ impl FlatDataSpec {
     /// Resolve column widths by examining data to determine optimal widths.
     ///
     /// For Bounded columns, scans the data to find the actual maximum width
-    /// needed, then clamps to the specified bounds. Fill columns receive
-    /// remaining space after all other columns are resolved.
+    /// needed, then clamps to the specified bounds. The header row, if set,
+    /// counts as an extra row. Fill columns receive remaining space after all
+    /// other columns are resolved; FillMax columns stop at their widest cell.
     ///
     /// # Arguments
     ///
@@ -83,7 +117,9 @@ impl FlatDataSpec {
         data: &[Vec<S>],
     ) -> ResolvedWidths {
         // Calculate max width for each column from data
-        let mut max_data_widths: Vec<usize> = vec![0; self.columns.len()];
+        let mut max_data_widths: Vec<usize> = (0..self.columns.len())
+            .map(|i| self.header_width(i))
+            .collect();
 
         for row in data {
             for (i, cell) in row.iter().enumerate() {
@@ -104,22 +140,21 @@ impl FlatDataSpec {
         data_widths: Option<&[usize]>,
     ) -> ResolvedWidths {
         if self.columns.is_empty() {
-            return ResolvedWidths { widths: vec![] };
+            return ResolvedWidths {
+                widths: vec![],
+                dropped: vec![],
+            };
         }
 
-        let overhead = self.decorations.overhead(self.columns.len());
-        let available = total_width.saturating_sub(overhead);
-
         let mut widths: Vec<usize> = Vec::with_capacity(self.columns.len());
-        let mut flex_indices: Vec<(usize, usize)> = Vec::new(); // (index, weight) for Fill/Fraction
-        let mut used_width: usize = 0;
+        // (index, weight, cap) for Fill/FillMax/Fraction
+        let mut flex_indices: Vec<(usize, usize, Option<usize>)> = Vec::new();
 
         // First pass: resolve Fixed and Bounded columns, collect flex columns
         for (i, col) in self.columns.iter().enumerate() {
             match &col.width {
                 Width::Fixed(w) => {
                     widths.push(*w);
-                    used_width += w;
                 }
                 Width::Bounded { min, max } => {
                     let min_w = min.unwrap_or(0);
@@ -127,31 +162,71 @@ impl FlatDataSpec {
 
                     // If we have data widths, use them; otherwise use minimum
                     let data_w = data_widths.and_then(|dw| dw.get(i).copied()).unwrap_or(0);
-                    let width = data_w.max(min_w).min(max_w);
+                    // The header label raises the effective minimum, even past max
+                    let width = data_w.max(min_w).min(max_w).max(self.header_width(i));
 
                     widths.push(width);
-                    used_width += width;
                 }
                 Width::Fill => {
                     widths.push(0); // Placeholder, will be filled later
-                    flex_indices.push((i, 1)); // Fill has weight 1
+                    flex_indices.push((i, 1, None)); // Fill has weight 1
+                }
+                Width::FillMax => {
+                    widths.push(0); // Placeholder, will be filled later
+                                    // Capped at the widest cell, uncapped without data
+                    let cap = data_widths.and_then(|dw| dw.get(i).copied());
+                    flex_indices.push((i, 1, cap));
                 }
                 Width::Fraction(n) => {
                     widths.push(0); // Placeholder, will be filled later
-                    flex_indices.push((i, *n)); // Fraction has weight n
+                    flex_indices.push((i, *n, None)); // Fraction has weight n
                 }
             }
         }
 
+        // Drop low-priority columns until the rest fit
+        let dropped = self.columns_to_drop(&widths, total_width);
+        for &i in &dropped {
+            widths[i] = 0;
+        }
+        flex_indices.retain(|(i, _, _)| !dropped.contains(i));
+
+        let overhead = self
+            .decorations
+            .overhead(self.columns.len() - dropped.len());
+        let available = total_width.saturating_sub(overhead);
+        let used_width: usize = widths.iter().sum();
+
         // Second pass: allocate remaining space to Fill/Fraction columns proportionally
         let remaining = available.saturating_sub(used_width);
 
         if !flex_indices.is_empty() {
-            let total_weight: usize = flex_indices.iter().map(|(_, w)| w).sum();
+            let mut remaining = remaining;
+
+            // Settle FillMax columns whose fair share covers their content,
+            // then share what they leave among the rest. Repeat until stable.
+            loop {
+                let total_weight: usize = flex_indices.iter().map(|(_, w, _)| w).sum();
+                let (capped, open): (Vec<_>, Vec<_>) =
+                    flex_indices.iter().partition(|(_, weight, cap)| {
+                        cap.is_some_and(|cap| cap * total_weight <= remaining * weight)
+                    });
+                if capped.is_empty() {
+                    break;
+                }
+                for (idx, _, cap) in capped {
+                    let cap = cap.unwrap_or(0);
+                    widths[idx] = cap;
+                    remaining -= cap;
+                }
+                flex_indices = open;
+            }
+
+            let total_weight: usize = flex_indices.iter().map(|(_, w, _)| w).sum();
             if total_weight > 0 {
                 let mut remaining_space = remaining;
 
-                for (i, (idx, weight)) in flex_indices.iter().enumerate() {
+                for (i, (idx, weight, _)) in flex_indices.iter().enumerate() {
                     // Last flex column gets all remaining space to avoid rounding errors
                     let width = if i == flex_indices.len() - 1 {
                         remaining_space
@@ -166,11 +241,9 @@ impl FlatDataSpec {
         } else if remaining > 0 {
             // If no Fill columns, distribute remaining space to the rightmost Bounded column
             // This ensures the table tries to fill the available width if possible
-            if let Some(idx) = self
-                .columns
-                .iter()
-                .rposition(|c| matches!(c.width, Width::Bounded { .. }))
-            {
+            if let Some(idx) = self.columns.iter().enumerate().rposition(|(i, c)| {
+                matches!(c.width, Width::Bounded { .. }) && !dropped.contains(&i)
+            }) {
                 // We expand the column beyond its current calculated width
                 // Note: We deliberately ignore 'max' here because this is an
                 // explicit layout expansion step, similar to how Fill works.
@@ -180,7 +253,46 @@ impl FlatDataSpec {
             }
         }
 
-        ResolvedWidths { widths }
+        ResolvedWidths { widths, dropped }
+    }
+
+    /// Indices of the columns to drop so the others fit in `total_width`.
+    ///
+    /// `widths` holds the first-pass widths (0 for Fill, FillMax and Fraction columns).
+    fn columns_to_drop(&self, widths: &[usize], total_width: usize) -> Vec<usize> {
+        let mut dropped = Vec::new();
+        let mut required: usize = widths.iter().sum();
+
+        loop {
+            let visible = self.columns.len() - dropped.len();
+            if required + self.decorations.overhead(visible) <= total_width {
+                break;
+            }
+            let next = self
+                .columns
+                .iter()
+                .enumerate()
+                .filter(|(i, col)| col.priority > 0 && !dropped.contains(i))
+                .min_by_key(|(i, col)| (col.priority, Reverse(*i)));
+            match next {
+                Some((i, _)) => {
+                    required -= widths[i];
+                    dropped.push(i);
+                }
+                None => break,
+            }
+        }
+
+        dropped.sort_unstable();
+        dropped
+    }
+
+    /// Display width of the header label for column `index`, or 0 without a header.
+    fn header_width(&self, index: usize) -> usize {
+        self.header
+            .as_ref()
+            .and_then(|labels| labels.get(index))
+            .map_or(0, |label| display_width(label))
     }
 }
 
@@ -383,9 +495,7 @@ mod tests {
 
     #[test]
     fn resolved_widths_accessors() {
-        let resolved = ResolvedWidths {
-            widths: vec![10, 20, 30],
-        };
+        let resolved = ResolvedWidths::new(vec![10, 20, 30]);
 
         assert_eq!(resolved.get(0), Some(10));
         assert_eq!(resolved.get(1), Some(20));
@@ -396,6 +506,144 @@ mod tests {
         assert!(!resolved.is_empty());
     }
 
+    #[test]
+    fn resolve_fill_max_without_data_acts_like_fill() {
+        let spec = FlatDataSpec::builder()
+            .column(Column::new(Width::Fixed(10)))
+            .column(Column::new(Width::FillMax))
+            .build();
+
+        let resolved = spec.resolve_widths(40);
+        assert_eq!(resolved.widths, vec![10, 30]);
+    }
+
+    #[test]
+    fn resolve_fill_max_stops_at_content_width() {
+        let spec = FlatDataSpec::builder()
+            .column(Column::new(Width::Fixed(10)))
+            .column(Column::new(Width::FillMax))
+            .build();
+
+        let data = vec![vec!["id", "short"], vec!["id", "tiny"]];
+        let resolved = spec.resolve_widths_from_data(40, &data);
+
+        // The rest of the space is left unused
+        assert_eq!(resolved.widths, vec![10, 5]);
+        assert_eq!(resolved.total(), 15);
+    }
+
+    #[test]
+    fn resolve_fill_max_cedes_space_to_fill() {
+        let spec = FlatDataSpec::builder()
+            .column(Column::new(Width::FillMax))
+            .column(Column::new(Width::Fill))
+            .build();
+
+        // Fair share is 20 each; FillMax needs 6 and the Fill takes the rest
+        let data = vec![vec!["status", "a long description"]];
+        let resolved = spec.resolve_widths_from_data(40, &data);
+        assert_eq!(resolved.widths, vec![6, 34]);
+    }
+
+    #[test]
+    fn resolve_fill_max_shares_when_content_is_wider() {
+        let spec = FlatDataSpec::builder()
+            .column(Column::new(Width::FillMax))
+            .column(Column::new(Width::FillMax))
+            .column(Column::new(Width::Fill))
+            .build();
+
+        // First FillMax fits in its share, the second does not and gets a
+        // share of what is left alongside the Fill column
+        let long = "x".repeat(50);
+        let data = vec![vec!["abc", long.as_str(), "y"]];
+        let resolved = spec.resolve_widths_from_data(43, &data);
+        assert_eq!(resolved.widths, vec![3, 20, 20]);
+    }
+
+    #[test]
+    fn resolve_fill_max_counts_header() {
+        let spec = FlatDataSpec::builder()
+            .column(Column::new(Width::FillMax))
+            .header(["Description"])
+            .build();
+
+        let data = vec![vec!["short"]];
+        let resolved = spec.resolve_widths_from_data(40, &data);
+        assert_eq!(resolved.widths, vec![11]);
+    }
+
+    #[test]
+    fn resolve_drops_lowest_priority_first() {
+        let spec = FlatDataSpec::builder()
+            .column(Column::new(Width::Fixed(10)))
+            .column(Column::new(Width::Fixed(10)).priority(2))
+            .column(Column::new(Width::Fixed(10)).priority(1))
+            .separator(" ")
+            .build();
+
+        // Everything fits: 30 + 2 separators
+        let resolved = spec.resolve_widths(32);
+        assert!(resolved.dropped.is_empty());
+
+        // One short: drop priority 1
+        let resolved = spec.resolve_widths(31);
+        assert_eq!(resolved.dropped, vec![2]);
+        assert_eq!(resolved.widths, vec![10, 10, 0]);
+
+        // Still too narrow for two columns: drop priority 2 as well
+        let resolved = spec.resolve_widths(20);
+        assert_eq!(resolved.dropped, vec![1, 2]);
+        assert_eq!(resolved.widths, vec![10, 0, 0]);
+        assert!(resolved.is_dropped(1));
+        assert!(!resolved.is_dropped(0));
+    }
+
+    #[test]
+    fn resolve_drops_rightmost_on_priority_tie() {
+        let spec = FlatDataSpec::builder()
+            .column(Column::new(Width::Fixed(10)).priority(1))
+            .column(Column::new(Width::Fixed(10)).priority(1))
+            .build();
+
+        let resolved = spec.resolve_widths(15);
+        assert_eq!(resolved.dropped, vec![1]);
+    }
+
+    #[test]
+    fn resolve_never_drops_priority_zero() {
+        let spec = FlatDataSpec::builder()
+            .column(Column::new(Width::Fixed(10)))
+            .column(Column::new(Width::Fixed(10)).priority(1))
+            .build();
+
+        // Overflows once only the priority 0 column is left
+        let resolved = spec.resolve_widths(5);
+        assert_eq!(resolved.dropped, vec![1]);
+        assert_eq!(resolved.widths, vec![10, 0]);
+    }
+
+    #[test]
+    fn resolve_fill_takes_space_of_dropped_columns() {
+        let spec = FlatDataSpec::builder()
+            .column(Column::new(Width::Fixed(10)))
+            .column(Column::new(Width::Fill))
+            .column(Column::new(Width::Fixed(20)).priority(1))
+            .separator(" ")
+            .build();
+
+        // 10 + 20 + 2 separators leaves 8 for Fill
+        let resolved = spec.resolve_widths(40);
+        assert!(resolved.dropped.is_empty());
+        assert_eq!(resolved.widths, vec![10, 8, 20]);
+
+        // 10 + 20 + 2 separators does not fit, so the Fill column gets the rest
+        let resolved = spec.resolve_widths(25);
+        assert_eq!(resolved.dropped, vec![2]);
+        assert_eq!(resolved.widths, vec![10, 14, 0]);
+        assert_eq!(resolved.total(), 24);
+    }
+
     #[test]
     fn resolve_fraction_columns() {
         let spec = FlatDataSpec::builder()
@@ -466,6 +714,54 @@ mod tests {
         assert_eq!(resolved.widths, vec![20, 20, 60]);
         assert_eq!(resolved.total(), 100);
     }
+
+    #[test]
+    fn resolve_long_header_widens_bounded_column() {
+        let spec = FlatDataSpec::builder()
+            .column(Column::new(Width::Bounded {
+                min: Some(2),
+                max: Some(20),
+            }))
+            .column(Column::new(Width::Fill))
+            .header(["Identifier", "Description"])
+            .build();
+
+        // Data is at most 3 wide, but the header needs 10
+        let data = vec![vec!["a", "x"], vec!["abc", "y"]];
+        let resolved = spec.resolve_widths_from_data(50, &data);
+        assert_eq!(resolved.widths, vec![10, 40]);
+    }
+
+    #[test]
+    fn resolve_header_overrides_bounded_max() {
+        let spec = FlatDataSpec::builder()
+            .column(Column::new(Width::Bounded {
+                min: None,
+                max: Some(4),
+            }))
+            .column(Column::new(Width::Fixed(5)))
+            .header(["Priority", "Done"])
+            .build();
+
+        let data = vec![vec!["hi", "yes"]];
+        let resolved = spec.resolve_widths_from_data(13, &data);
+        assert_eq!(resolved.widths, vec![8, 5]);
+    }
+
+    #[test]
+    fn resolve_header_raises_minimum_without_data() {
+        let spec = FlatDataSpec::builder()
+            .column(Column::new(Width::Bounded {
+                min: Some(3),
+                max: None,
+            }))
+            .column(Column::new(Width::Fixed(5)))
+            .header(["Owner"])
+            .build();
+
+        let resolved = spec.resolve_widths(10);
+        assert_eq!(resolved.widths, vec![5, 5]);
+    }
 }
 
 #[cfg(test)]
@@ -120,6 +120,10 @@ pub enum Width {
     /// Expand to fill all remaining space.
     /// Multiple Fill columns share remaining space equally.
     Fill,
+    /// Like `Fill`, but never wider than the widest cell in the data.
+    /// Space it does not need goes to the other Fill columns, or is left unused.
+    /// Without data it behaves exactly like `Fill`.
+    FillMax,
     /// Proportional: takes n parts of the remaining space.
     /// `Fraction(2)` gets twice the space of `Fraction(1)` or `Fill`.
     Fraction(usize),
@@ -144,6 +148,7 @@ impl From<Width> for WidthRaw {
             Width::Fixed(w) => WidthRaw::Fixed(w),
             Width::Bounded { min, max } => WidthRaw::Bounded { min, max },
             Width::Fill => WidthRaw::StringVariant("fill".to_string()),
+            Width::FillMax => WidthRaw::StringVariant("fill_max".to_string()),
             Width::Fraction(n) => WidthRaw::StringVariant(format!("{}fr", n)),
         }
     }
@@ -157,6 +162,7 @@ impl TryFrom<WidthRaw> for Width {
             WidthRaw::Fixed(w) => Ok(Width::Fixed(w)),
             WidthRaw::Bounded { min, max } => Ok(Width::Bounded { min, max }),
             WidthRaw::StringVariant(s) if s == "fill" => Ok(Width::Fill),
+            WidthRaw::StringVariant(s) if s == "fill_max" => Ok(Width::FillMax),
             WidthRaw::StringVariant(s) if s.ends_with("fr") => {
                 let num_str = s.trim_end_matches("fr");
                 num_str
@@ -165,7 +171,7 @@ impl TryFrom<WidthRaw> for Width {
                     .map_err(|_| format!("Invalid fraction: '{}'. Expected format like '2fr'.", s))
             }
             WidthRaw::StringVariant(s) => Err(format!(
-                "Invalid width string: '{}'. Expected 'fill' or '<n>fr'.",
+                "Invalid width string: '{}'. Expected 'fill', 'fill_max' or '<n>fr'.",
                 s
             )),
         }
@@ -216,6 +222,11 @@ impl Width {
         Width::Fill
     }
 
+    /// Create a fill column that grows no wider than its content.
+    pub fn fill_max() -> Self {
+        Width::FillMax
+    }
+
     /// Create a fractional width column.
     /// `Fraction(2)` gets twice the space of `Fraction(1)` or `Fill`.
     pub fn fraction(n: usize) -> Self {
@@ -246,6 +257,13 @@ pub struct Column {
     pub key: Option<String>,
     /// Optional header title (for table headers and CSV export).
     pub header: Option<String>,
+    /// Drop priority when the table is too wide.
+    ///
+    /// `0` (the default) keeps the column. Otherwise, when the columns don't
+    /// fit, the lowest-priority column is dropped first. See
+    /// [`ResolvedWidths::dropped`](super::ResolvedWidths::dropped).
+    #[serde(default)]
+    pub priority: u8,
 }
 
 impl Default for Column {
@@ -261,6 +279,7 @@ impl Default for Column {
             style_from_value: false,
             key: None,
             header: None,
+            priority: 0,
         }
     }
 }
@@ -396,6 +415,14 @@ impl Column {
         self.header = Some(header.into());
         self
     }
+
+    /// Make the column droppable when the table is too wide.
+    ///
+    /// Columns with lower priorities are dropped first; `0` means never drop.
+    pub fn priority(mut self, priority: u8) -> Self {
+        self.priority = priority;
+        self
+    }
 }
 
 /// Builder for constructing `Column` instances.
@@ -411,6 +438,7 @@ pub struct ColumnBuilder {
     style_from_value: bool,
     key: Option<String>,
     header: Option<String>,
+    priority: u8,
 }
 
 impl ColumnBuilder {
@@ -438,6 +466,12 @@ impl ColumnBuilder {
         self
     }
 
+    /// Set the column to fill remaining space, up to its widest cell.
+    pub fn fill_max(mut self) -> Self {
+        self.width = Some(Width::FillMax);
+        self
+    }
+
     /// Set bounded width with min and max.
     pub fn bounded(mut self, min: usize, max: usize) -> Self {
         self.width = Some(Width::bounded(min, max));
@@ -544,6 +578,12 @@ impl ColumnBuilder {
         self
     }
 
+    /// Set the drop priority (`0` never drops).
+    pub fn priority(mut self, priority: u8) -> Self {
+        self.priority = priority;
+        self
+    }
+
     /// Build the `Column` instance.
     pub fn build(self) -> Column {
         let default = Column::default();
@@ -558,6 +598,7 @@ impl ColumnBuilder {
             style_from_value: self.style_from_value,
             key: self.key,
             header: self.header,
+            priority: self.priority,
         }
     }
 }
@@ -613,7 +654,37 @@ impl Col {
     }
 }
 
+/// Named decoration presets for table rows.
+///
+/// Each preset sets the column separator and row edges, so callers don't have
+/// to assemble box-drawing characters by hand. For framing a whole table with
+/// top and bottom lines, see [`BorderStyle`](super::BorderStyle).
+#[derive(Clone, Copy, Debug, Default, PartialEq, Eq, Serialize, Deserialize)]
+#[serde(rename_all = "lowercase")]
+pub enum BorderPreset {
+    /// Two-space separator, no edges.
+    #[default]
+    None,
+    /// `| ` edges and ` | ` separators.
+    Ascii,
+    /// `│ ` edges and ` │ ` separators (light box-drawing).
+    Unicode,
+    /// Like `Ascii`, plus a `|---|` divider row under the header.
+    Markdown,
+}
+
 /// Decorations for table rows (separators, prefixes, suffixes).
+///
+/// Build decorations with [`Decorations::with_separator`] or
+/// [`Decorations::preset`] and the setter methods rather than a struct
+/// literal, so the code keeps compiling when fields are added:
+///
+/// ```rust
+/// use standout_render::tabular::Decorations;
+///
+/// let dec = Decorations::with_separator(" | ").prefix("| ").suffix(" |");
+/// assert_eq!(dec.overhead(2), 7);
+/// ```
 #[derive(Clone, Debug, Default, Serialize, Deserialize)]
 pub struct Decorations {
     /// Separator between columns (e.g., "  " or " │ ").
@@ -622,6 +693,9 @@ pub struct Decorations {
     pub row_prefix: String,
     /// Suffix at the end of each row.
     pub row_suffix: String,
+    /// Whether a divider row is drawn under the header (Markdown tables).
+    #[serde(default)]
+    pub header_divider: bool,
 }
 
 impl Decorations {
@@ -631,6 +705,30 @@ impl Decorations {
             column_sep: sep.into(),
             row_prefix: String::new(),
             row_suffix: String::new(),
+            header_divider: false,
+        }
+    }
+
+    /// Create decorations from a named preset.
+    ///
+    /// ```rust
+    /// use standout_render::tabular::{BorderPreset, Decorations};
+    ///
+    /// let dec = Decorations::preset(BorderPreset::Unicode);
+    /// assert_eq!(dec.column_sep, " │ ");
+    /// assert_eq!(dec.overhead(3), 10);
+    /// ```
+    pub fn preset(preset: BorderPreset) -> Self {
+        let (prefix, sep, suffix) = match preset {
+            BorderPreset::None => ("", "  ", ""),
+            BorderPreset::Ascii | BorderPreset::Markdown => ("| ", " | ", " |"),
+            BorderPreset::Unicode => ("│ ", " │ ", " │"),
+        };
+        Decorations {
+            column_sep: sep.to_string(),
+            row_prefix: prefix.to_string(),
+            row_suffix: suffix.to_string(),
+            header_divider: preset == BorderPreset::Markdown,
         }
     }
 
@@ -664,12 +762,25 @@ impl Decorations {
 }
 
 /// Complete specification for a flat data layout (Table or CSV).
+///
+/// Build specs with [`FlatDataSpec::builder`] (or [`FlatDataSpec::new`])
+/// rather than a struct literal, so the code keeps compiling when fields are
+/// added.
 #[derive(Clone, Debug, Serialize, Deserialize)]
 pub struct FlatDataSpec {
     /// Column specifications.
     pub columns: Vec<Column>,
     /// Row decorations (separators, prefix, suffix).
     pub decorations: Decorations,
+    /// Optional header row, one label per column.
+    ///
+    /// Header labels count toward column widths: a Bounded column is never
+    /// narrower than its label.
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub header: Option<Vec<String>>,
+    /// Style name applied to the header row when rendered.
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub header_style: Option<String>,
 }
 
 impl FlatDataSpec {
@@ -678,6 +789,8 @@ impl FlatDataSpec {
         FlatDataSpec {
             columns,
             decorations: Decorations::default(),
+            header: None,
+            header_style: None,
         }
     }
 
@@ -691,9 +804,11 @@ impl FlatDataSpec {
         self.columns.len()
     }
 
-    /// Check if any column uses Fill width.
+    /// Check if any column uses Fill or FillMax width.
     pub fn has_fill_column(&self) -> bool {
-        self.columns.iter().any(|c| matches!(c.width, Width::Fill))
+        self.columns
+            .iter()
+            .any(|c| matches!(c.width, Width::Fill | Width::FillMax))
     }
 
     /// Extract a header row from the spec.
@@ -757,6 +872,8 @@ fn extract_value(data: &Value, path: &str) -> Option<String> {
 pub struct FlatDataSpecBuilder {
     columns: Vec<Column>,
     decorations: Decorations,
+    header: Option<Vec<String>>,
+    header_style: Option<String>,
 }
 
 impl FlatDataSpecBuilder {
@@ -796,11 +913,45 @@ impl FlatDataSpecBuilder {
         self
     }
 
+    /// Set all decorations from a named preset.
+    ///
+    /// ```rust
+    /// use standout_render::tabular::{BorderPreset, Col, TabularFormatter, TabularSpec};
+    ///
+    /// let spec = TabularSpec::builder()
+    ///     .column(Col::fixed(3))
+    ///     .column(Col::fixed(4))
+    ///     .border(BorderPreset::Markdown)
+    ///     .build();
+    ///
+    /// let formatter = TabularFormatter::new(&spec, 80);
+    /// assert_eq!(formatter.format_row(&["id", "name"]), "| id  | name |");
+    /// assert_eq!(formatter.header_divider_row().unwrap(), "|-----|------|");
+    /// ```
+    pub fn border(mut self, preset: BorderPreset) -> Self {
+        self.decorations = Decorations::preset(preset);
+        self
+    }
+
+    /// Set the header row labels.
+    pub fn header<S: Into<String>, I: IntoIterator<Item = S>>(mut self, labels: I) -> Self {
+        self.header = Some(labels.into_iter().map(Into::into).collect());
+        self
+    }
+
+    /// Set the style name applied to the header row.
+    pub fn header_style(mut self, style: impl Into<String>) -> Self {
+        self.header_style = Some(style.into());
+        self
+    }
+
     /// Build the `FlatDataSpec` instance.
     pub fn build(self) -> FlatDataSpec {
         FlatDataSpec {
             columns: self.columns,
             decorations: self.decorations,
+            header: self.header,
+            header_style: self.header_style,
         }
     }
 }
@@ -908,6 +1059,16 @@ mod tests {
         assert_eq!(parsed, width);
     }
 
+    #[test]
+    fn width_serde_fill_max() {
+        let width = Width::FillMax;
+        let json = serde_json::to_string(&width).unwrap();
+        assert_eq!(json, "\"fill_max\"");
+
+        let parsed: Width = serde_json::from_str(&json).unwrap();
+        assert_eq!(parsed, width);
+    }
+
     #[test]
     fn width_serde_fraction() {
         let width = Width::Fraction(2);
@@ -1135,6 +1296,31 @@ mod tests {
         assert_eq!(dec.column_sep, "  ");
     }
 
+    #[test]
+    fn decorations_preset_overhead() {
+        // 3 columns: prefix + suffix + 2 separators, measured in display columns
+        assert_eq!(Decorations::preset(BorderPreset::None).overhead(3), 4);
+        assert_eq!(Decorations::preset(BorderPreset::Ascii).overhead(3), 10);
+        assert_eq!(Decorations::preset(BorderPreset::Markdown).overhead(3), 10);
+        // Box-drawing characters are 3 bytes but 1 column wide
+        assert_eq!(Decorations::preset(BorderPreset::Unicode).overhead(3), 10);
+    }
+
+    #[test]
+    fn decorations_preset_header_divider() {
+        assert!(Decorations::preset(BorderPreset::Markdown).header_divider);
+        assert!(!Decorations::preset(BorderPreset::Ascii).header_divider);
+        assert!(!Decorations::preset(BorderPreset::Unicode).header_divider);
+    }
+
+    #[test]
+    fn border_preset_serde() {
+        let json = serde_json::to_string(&BorderPreset::Markdown).unwrap();
+        assert_eq!(json, "\"markdown\"");
+        let parsed: BorderPreset = serde_json::from_str("\"unicode\"").unwrap();
+        assert_eq!(parsed, BorderPreset::Unicode);
+    }
+
     #[test]
     fn decorations_overhead() {
         let dec = Decorations::default()
@@ -3,13 +3,14 @@
 //! All functions in this module correctly handle ANSI escape codes: they are
 //! preserved in output but don't count toward display width calculations.
 
-use console::{measure_text_width, pad_str, Alignment};
+use console::{pad_str, Alignment};
+use std::borrow::Cow;
+use unicode_width::UnicodeWidthStr;
 
 /// Returns the display width of a string, ignoring ANSI escape codes.
 ///
-/// This is a convenience wrapper around `console::measure_text_width` that
-/// correctly handles:
-/// - ANSI escape sequences (colors, styles)
+/// Measures the visible text left by [`strip_ansi`], so it correctly handles:
+/// - ANSI escape sequences (colors, styles) and OSC 8 hyperlinks
 /// - Unicode characters including CJK wide characters
 /// - Zero-width characters and combining marks
 ///
@@ -23,7 +24,65 @@ use console::{measure_text_width, pad_str, Alignment};
 /// assert_eq!(display_width("日本"), 4);  // CJK characters are 2 columns each
 /// ```
 pub fn display_width(s: &str) -> usize {
-    measure_text_width(s)
+    strip_ansi(s).width()
+}
+
+/// Removes terminal escape sequences, leaving only the visible text.
+///
+/// Strips CSI sequences (including SGR styling such as `\x1b[1;31m`), OSC
+/// sequences terminated by BEL or `ESC \` (such as OSC 8 hyperlinks), and
+/// other two-character escapes. Returns the input unchanged, without
+/// allocating, when it contains no escape character.
+///
+/// # Example
+///
+/// ```rust
+/// use standout_render::tabular::strip_ansi;
+///
+/// assert_eq!(strip_ansi("\x1b[1mbold\x1b[0m"), "bold");
+/// let link = "\x1b]8;;https://example.com\x1b\\docs\x1b]8;;\x1b\\";
+/// assert_eq!(strip_ansi(link), "docs");
+/// ```
+pub fn strip_ansi(s: &str) -> Cow<'_, str> {
+    if !s.contains('\x1b') {
+        return Cow::Borrowed(s);
+    }
+
+    let mut out = String::with_capacity(s.len());
+    let mut chars = s.chars().peekable();
+    while let Some(c) = chars.next() {
+        if c != '\x1b' {
+            out.push(c);
+            continue;
+        }
+        match chars.next() {
+            // CSI: parameter and intermediate bytes up to a final byte in '@'..='~'
+            Some('[') => {
+                for c in chars.by_ref() {
+                    if ('@'..='~').contains(&c) {
+                        break;
+                    }
+                }
+            }
+            // OSC: runs until BEL or the string terminator ESC \
+            Some(']') => {
+                while let Some(c) = chars.next() {
+                    if c == '\x07' {
+                        break;
+                    }
+                    if c == '\x1b' {
+                        if chars.peek() == Some(&'\\') {
+                            chars.next();
+                        }
+                        break;
+                    }
+                }
+            }
+            // Two-character escape (or a trailing ESC): drop it
+            _ => {}
+        }
+    }
+    Cow::Owned(out)
 }
 
 /// Truncates a string from the end to fit within a maximum display width.
@@ -48,12 +107,12 @@ pub fn display_width(s: &str) -> usize {
 /// assert_eq!(truncate_end("Short", 10, "…"), "Short");
 /// ```
 pub fn truncate_end(s: &str, max_width: usize, ellipsis: &str) -> String {
-    let width = measure_text_width(s);
+    let width = display_width(s);
     if width <= max_width {
         return s.to_string();
     }
 
-    let ellipsis_width = measure_text_width(ellipsis);
+    let ellipsis_width = display_width(ellipsis);
     if max_width < ellipsis_width {
         // Not enough room even for ellipsis - truncate ellipsis itself
         return truncate_to_display_width(ellipsis, max_width);
@@ -86,12 +145,12 @@ pub fn truncate_end(s: &str, max_width: usize, ellipsis: &str) -> String {
 /// assert_eq!(truncate_start("/path/to/file.rs", 12, "…"), "…/to/file.rs");
 /// ```
 pub fn truncate_start(s: &str, max_width: usize, ellipsis: &str) -> String {
-    let width = measure_text_width(s);
+    let width = display_width(s);
     if width <= max_width {
         return s.to_string();
     }
 
-    let ellipsis_width = measure_text_width(ellipsis);
+    let ellipsis_width = display_width(ellipsis);
     if max_width < ellipsis_width {
         // Not enough room even for ellipsis - truncate ellipsis itself
         return truncate_to_display_width(ellipsis, max_width);
@@ -123,12 +182,12 @@ pub fn truncate_start(s: &str, max_width: usize, ellipsis: &str) -> String {
 /// assert_eq!(truncate_middle("abcdefghij", 7, "..."), "ab...ij");
 /// ```
 pub fn truncate_middle(s: &str, max_width: usize, ellipsis: &str) -> String {
-    let width = measure_text_width(s);
+    let width = display_width(s);
     if width <= max_width {
         return s.to_string();
     }
 
-    let ellipsis_width = measure_text_width(ellipsis);
+    let ellipsis_width = display_width(ellipsis);
     if max_width < ellipsis_width {
         // Not enough room even for ellipsis - truncate ellipsis itself
         return truncate_to_display_width(ellipsis, max_width);
@@ -263,7 +322,7 @@ pub fn wrap_indent(s: &str, width: usize, indent: usize) -> Vec<String> {
     }
 
     // If the whole string fits, return it directly
-    if measure_text_width(s) <= width {
+    if display_width(s) <= width {
         return vec![s.to_string()];
     }
 
@@ -274,25 +333,27 @@ pub fn wrap_indent(s: &str, width: usize, indent: usize) -> Vec<String> {
 
     // Split on whitespace, preserving the structure
     for word in s.split_whitespace() {
-        let word_width = measure_text_width(word);
-        let effective_width = if is_first_line {
-            width
-        } else {
-            width.saturating_sub(indent)
+        let word_width = display_width(word);
+        let line_width = |is_first_line: bool| {
+            if is_first_line {
+                width
+            } else {
+                width.saturating_sub(indent)
+            }
         };
 
+        // Finish the current line if the word doesn't fit after it
+        if !current_line.is_empty() && current_width + 1 + word_width > line_width(is_first_line) {
+            lines.push(std::mem::take(&mut current_line));
+            current_width = 0;
+            is_first_line = false;
+        }
+        let effective_width = line_width(is_first_line);
+
         // Handle words longer than available width
         if word_width > effective_width {
-            // Finish current line if it has content
-            if !current_line.is_empty() {
-                lines.push(current_line);
-                current_line = String::new();
-                current_width = 0;
-                is_first_line = false;
-            }
-
             // Force-break the long word
-            let broken = break_long_word(word, effective_width, indent, is_first_line);
+            let broken = break_long_word(word, width, indent, is_first_line);
             let broken_len = broken.len();
             for (i, part) in broken.into_iter().enumerate() {
                 if i == 0 && is_first_line {
@@ -304,39 +365,24 @@ pub fn wrap_indent(s: &str, width: usize, indent: usize) -> Vec<String> {
                 } else {
                     // Last part - becomes the start of the next line
                     current_line = part;
-                    current_width = measure_text_width(&current_line);
+                    current_width = display_width(&current_line);
                 }
             }
             continue;
         }
 
-        // Check if word fits on current line
-        let needed_width = if current_line.is_empty() {
-            word_width
-        } else {
-            current_width + 1 + word_width // +1 for space
-        };
-
-        if needed_width <= effective_width {
-            // Word fits - add to current line
-            if !current_line.is_empty() {
-                current_line.push(' ');
-                current_width += 1;
+        if current_line.is_empty() {
+            // Start a new line, indented unless it's the first
+            if !is_first_line {
+                current_line.push_str(&" ".repeat(indent));
+                current_width = indent;
             }
-            current_line.push_str(word);
-            current_width += word_width;
         } else {
-            // Word doesn't fit - start new line
-            if !current_line.is_empty() {
-                lines.push(current_line);
-            }
-            is_first_line = false;
-
-            // Start new line with indent
-            let indent_str: String = " ".repeat(indent);
-            current_line = format!("{}{}", indent_str, word);
-            current_width = indent + word_width;
+            current_line.push(' ');
+            current_width += 1;
         }
+        current_line.push_str(word);
+        current_width += word_width;
     }
 
     // Don't forget the last line
@@ -370,7 +416,7 @@ fn break_long_word(word: &str, width: usize, indent: usize, is_first: bool) -> V
             break;
         }
 
-        let remaining_width = measure_text_width(remaining);
+        let remaining_width = display_width(remaining);
         if remaining_width <= effective_width {
             // Rest fits
             let prefix = if first_part {
@@ -384,24 +430,23 @@ fn break_long_word(word: &str, width: usize, indent: usize, is_first: bool) -> V
 
         // Need to break - leave room for ellipsis to indicate continuation
         let break_width = effective_width.saturating_sub(1); // -1 for "…"
-        if break_width == 0 {
-            // Not enough room even for one char + ellipsis
-            let prefix = if first_part {
-                String::new()
-            } else {
-                " ".repeat(indent)
-            };
-            parts.push(format!("{}…", prefix));
-            break;
-        }
 
         let prefix = if first_part {
             String::new()
         } else {
             " ".repeat(indent)
         };
-        let truncated = truncate_to_display_width(remaining, break_width);
-        parts.push(format!("{}{}…", prefix, truncated));
+        let mut truncated = truncate_to_display_width(remaining, break_width);
+        if truncated.is_empty() {
+            // No room for a character and the marker (width 1, or a wide
+            // character at width 2); give the next character the line so no
+            // text is dropped and the loop advances.
+            let first = remaining.chars().next().unwrap_or_default();
+            truncated.push(first);
+            parts.push(format!("{}{}", prefix, truncated));
+        } else {
+            parts.push(format!("{}{}…", prefix, truncated));
+        }
 
         // Find where we actually cut in the original string
         let truncated_len = truncated.chars().count();
@@ -426,7 +471,7 @@ fn truncate_to_display_width(s: &str, max_width: usize) -> String {
     }
 
     // Fast path: if string fits, return as-is
-    if measure_text_width(s) <= max_width {
+    if display_width(s) <= max_width {
         return s.to_string();
     }
 
@@ -472,7 +517,7 @@ fn find_suffix_with_width(s: &str, max_width: usize) -> String {
         return String::new();
     }
 
-    let total_width = measure_text_width(s);
+    let total_width = display_width(s);
     if total_width <= max_width {
         return s.to_string();
     }
@@ -516,6 +561,38 @@ fn find_suffix_with_width(s: &str, max_width: usize) -> String {
 mod tests {
     use super::*;
 
+    // --- strip_ansi tests ---
+
+    #[test]
+    fn strip_ansi_plain_text_is_borrowed() {
+        assert!(matches!(strip_ansi("plain"), Cow::Borrowed("plain")));
+    }
+
+    #[test]
+    fn strip_ansi_nested_sgr() {
+        let s = "\x1b[1m\x1b[38;5;196mred \x1b[4munder\x1b[24m\x1b[0m bold\x1b[0m";
+        assert_eq!(strip_ansi(s), "red under bold");
+        assert_eq!(display_width(s), 14);
+    }
+
+    #[test]
+    fn strip_ansi_osc8_hyperlink() {
+        // ST-terminated and BEL-terminated forms
+        let st = "\x1b]8;;https://example.com/path\x1b\\link\x1b]8;;\x1b\\";
+        let bel = "\x1b]8;;https://example.com\x07link\x1b]8;;\x07";
+        assert_eq!(strip_ansi(st), "link");
+        assert_eq!(strip_ansi(bel), "link");
+        assert_eq!(display_width(st), 4);
+        assert_eq!(display_width(bel), 4);
+    }
+
+    #[test]
+    fn strip_ansi_styled_hyperlink_with_wide_text() {
+        let s = "\x1b[32m\x1b]8;;file:///tmp\x1b\\日本\x1b]8;;\x1b\\\x1b[0m";
+        assert_eq!(strip_ansi(s), "日本");
+        assert_eq!(display_width(s), 4);
+    }
+
     // --- display_width tests ---
 
     #[test]
@@ -779,6 +856,18 @@ mod tests {
         }
     }
 
+    #[test]
+    fn wrap_wide_chars_at_width_two() {
+        assert_eq!(wrap("日本語", 2), vec!["日", "本", "語"]);
+        assert_eq!(wrap("ab日本", 2), vec!["a…", "b…", "日", "本"]);
+    }
+
+    #[test]
+    fn wrap_width_one_keeps_every_char() {
+        assert_eq!(wrap("abc", 1), vec!["a", "b", "c"]);
+        assert_eq!(wrap_indent("ab cd", 2, 1), vec!["ab", " c", " d"]);
+    }
+
     #[test]
     fn wrap_preserves_word_boundaries() {
         let result = wrap("hello world test", 10);
@@ -6,9 +6,41 @@
 
 use minijinja::{Environment, Value};
 
-use std::collections::HashMap;
+use std::collections::{HashMap, VecDeque};
+use std::sync::{Arc, Mutex};
 
+use super::filters::register_filters;
+use super::registry::{RegistryError, TemplateRegistry};
 use crate::error::RenderError;
+use crate::tabular::TabularSpec;
+
+/// A user-defined template filter.
+///
+/// Filters receive the piped value and any arguments as JSON values, so they
+/// work with any [`TemplateEngine`] that supports filters. Returning `Err`
+/// fails the render with the given message.
+///
+/// ```rust
+/// use standout_render::template::{FilterFn, MiniJinjaEngine, TemplateEngine};
+/// use std::sync::Arc;
+///
+/// let currency: FilterFn = Arc::new(|value, _args| {
+///     let cents = value.as_i64().ok_or("expected an integer")?;
+///     Ok(format!("${}.{:02}", cents / 100, cents % 100).into())
+/// });
+///
+/// let mut engine = MiniJinjaEngine::new();
+/// engine.add_filter("currency", currency).unwrap();
+/// let output = engine
+///     .render_template("{{ price | currency }}", &serde_json::json!({ "price": 1250 }))
+///     .unwrap();
+/// assert_eq!(output, "$12.50");
+/// ```
+pub type FilterFn = Arc<
+    dyn Fn(&serde_json::Value, &[serde_json::Value]) -> Result<serde_json::Value, String>
+        + Send
+        + Sync,
+>;
 
 /// A template engine that can render templates with data.
 ///
@@ -42,6 +74,22 @@ pub trait TemplateEngine: Send + Sync {
     /// The template must have been added via [`add_template`](Self::add_template).
     fn render_named(&self, name: &str, data: &serde_json::Value) -> Result<String, RenderError>;
 
+    /// Renders a previously registered template into `out`.
+    ///
+    /// Engines that can stream write output as it is produced, without
+    /// building the whole result in memory. The default implementation
+    /// renders with [`render_named`](Self::render_named) and writes the result.
+    fn render_named_to(
+        &self,
+        name: &str,
+        data: &serde_json::Value,
+        out: &mut dyn std::io::Write,
+    ) -> Result<(), RenderError> {
+        let output = self.render_named(name, data)?;
+        out.write_all(output.as_bytes())?;
+        Ok(())
+    }
+
     /// Checks if a template with the given name exists.
     fn has_template(&self, name: &str) -> bool;
 
@@ -56,6 +104,46 @@ pub trait TemplateEngine: Send + Sync {
         context: HashMap<String, serde_json::Value>,
     ) -> Result<String, RenderError>;
 
+    /// Registers a custom filter under `name`.
+    ///
+    /// Custom filters sit alongside the built-in ones (`nl`, `style`, and the
+    /// tabular filters). A filter with the same name as a built-in replaces it,
+    /// so pick names that don't collide unless that's the intent.
+    ///
+    /// The default implementation returns an error for engines without filter
+    /// support.
+    fn add_filter(&mut self, name: &str, filter: FilterFn) -> Result<(), RenderError> {
+        let _ = filter;
+        Err(RenderError::OperationError(format!(
+            "cannot register filter '{}': template engine does not support filters",
+            name
+        )))
+    }
+
+    /// Registers a named table spec for the `row(cells, spec_name)` function.
+    ///
+    /// The default implementation returns an error for engines without
+    /// tabular functions.
+    fn add_table_spec(&mut self, name: &str, spec: TabularSpec) -> Result<(), RenderError> {
+        let _ = spec;
+        Err(RenderError::OperationError(format!(
+            "cannot register table spec '{}': template engine does not support table functions",
+            name
+        )))
+    }
+
+    /// Resolves templates the engine doesn't know by name through `registry`.
+    ///
+    /// Engines that support includes use this to find partials referenced by
+    /// `{% include %}` and `{% import %}`, following the registry's lookup rules
+    /// (extensionless names, inline templates before files). Templates added
+    /// with [`add_template`](Self::add_template) still take precedence.
+    ///
+    /// The default implementation ignores the registry.
+    fn set_template_registry(&mut self, registry: Arc<TemplateRegistry>) {
+        let _ = registry;
+    }
+
     /// Whether this engine supports template includes (`{% include %}`).
     fn supports_includes(&self) -> bool;
 
@@ -95,16 +183,127 @@ pub trait TemplateEngine: Send + Sync {
 /// ).unwrap();
 /// assert_eq!(output, "Hello, World!");
 /// ```
+///
+/// # Template Cache
+///
+/// Inline templates passed to [`render_template`](TemplateEngine::render_template)
+/// and [`render_with_context`](TemplateEngine::render_with_context) are compiled
+/// once and kept in a least-recently-used cache keyed by their source, so hot
+/// paths rendering the same string skip recompilation. The cache holds
+/// [`DEFAULT_TEMPLATE_CACHE_CAPACITY`] templates by default; use
+/// [`with_cache_capacity`](Self::with_cache_capacity) to change it (`0` disables
+/// caching).
 pub struct MiniJinjaEngine {
     env: Environment<'static>,
+    cache: Mutex<TemplateCache>,
+}
+
+/// Default number of inline templates kept compiled by [`MiniJinjaEngine`].
+pub const DEFAULT_TEMPLATE_CACHE_CAPACITY: usize = 64;
+
+/// LRU cache of compiled inline templates.
+///
+/// Compiled templates live in a clone of the engine's environment, registered
+/// under generated names, so they see the same filters, functions and named
+/// templates. The clone is dropped whenever the engine's environment changes.
+///
+/// The clone is shared with in-flight renders, so rendering happens outside
+/// the cache lock; a compile while it is shared copies the environment.
+struct TemplateCache {
+    env: Option<Arc<Environment<'static>>>,
+    /// (source, generated name), least recently used first.
+    entries: VecDeque<(String, String)>,
+    capacity: usize,
+    next_id: u64,
+    compiles: usize,
+}
+
+impl TemplateCache {
+    fn new(capacity: usize) -> Self {
+        Self {
+            env: None,
+            entries: VecDeque::new(),
+            capacity,
+            next_id: 0,
+            compiles: 0,
+        }
+    }
+
+    fn clear(&mut self) {
+        self.env = None;
+        self.entries.clear();
+    }
+
+    /// Looks up `source`, compiling it if it isn't already cached, and returns
+    /// the environment holding it with its generated name.
+    fn lookup(
+        &mut self,
+        base: &Environment<'static>,
+        source: &str,
+    ) -> Result<(Arc<Environment<'static>>, String), RenderError> {
+        let env = self.env.get_or_insert_with(|| Arc::new(base.clone()));
+
+        let name = match self.entries.iter().position(|(src, _)| src == source) {
+            Some(index) => {
+                let entry = self.entries.remove(index).expect("index is in bounds");
+                let name = entry.1.clone();
+                self.entries.push_back(entry);
+                name
+            }
+            None => {
+                let name = format!("<string:{}>", self.next_id);
+                self.next_id += 1;
+                let env = Arc::make_mut(env);
+                env.add_template_owned(name.clone(), source.to_string())?;
+                self.compiles += 1;
+                self.entries.push_back((source.to_string(), name.clone()));
+                if self.entries.len() > self.capacity {
+                    if let Some((_, evicted)) = self.entries.pop_front() {
+                        env.remove_template(&evicted);
+                    }
+                }
+                name
+            }
+        };
+
+        Ok((Arc::clone(self.env.as_ref().expect("set above")), name))
+    }
 }
 
 impl MiniJinjaEngine {
     /// Creates a new MiniJinja engine with default filters registered.
     pub fn new() -> Self {
+        Self::with_cache_capacity(DEFAULT_TEMPLATE_CACHE_CAPACITY)
+    }
+
+    /// Creates a new engine that keeps up to `capacity` inline templates compiled.
+    ///
+    /// A capacity of `0` disables the cache, compiling inline templates on
+    /// every render.
+    pub fn with_cache_capacity(capacity: usize) -> Self {
         let mut env = Environment::new();
         register_filters(&mut env);
-        Self { env }
+        Self {
+            env,
+            cache: Mutex::new(TemplateCache::new(capacity)),
+        }
+    }
+
+    /// Renders an inline template through the cache.
+    fn render_cached<S: serde::Serialize>(
+        &self,
+        template: &str,
+        ctx: S,
+    ) -> Result<String, RenderError> {
+        let (env, name) = {
+            let mut cache = self.cache.lock().unwrap_or_else(|e| e.into_inner());
+            if cache.capacity == 0 {
+                drop(cache);
+                return Ok(self.env.render_str(template, ctx)?);
+            }
+            cache.lookup(&self.env, template)?
+        };
+        Ok(env.get_template(&name)?.render(ctx)?)
     }
 
     /// Returns a reference to the underlying MiniJinja environment.
@@ -120,8 +319,17 @@ impl MiniJinjaEngine {
     /// This allows advanced users to register custom filters, functions,
     /// or configure the environment directly.
     pub fn environment_mut(&mut self) -> &mut Environment<'static> {
+        self.invalidate_cache();
         &mut self.env
     }
+
+    /// Drops cached templates so they're recompiled against the current environment.
+    fn invalidate_cache(&mut self) {
+        self.cache
+            .get_mut()
+            .unwrap_or_else(|e| e.into_inner())
+            .clear();
+    }
 }
 
 impl Default for MiniJinjaEngine {
@@ -137,12 +345,13 @@ impl TemplateEngine for MiniJinjaEngine {
         data: &serde_json::Value,
     ) -> Result<String, RenderError> {
         let value = Value::from_serialize(data);
-        Ok(self.env.render_str(template, value)?)
+        self.render_cached(template, value)
     }
 
     fn add_template(&mut self, name: &str, source: &str) -> Result<(), RenderError> {
         self.env
             .add_template_owned(name.to_string(), source.to_string())?;
+        self.invalidate_cache();
         Ok(())
     }
 
@@ -152,6 +361,18 @@ impl TemplateEngine for MiniJinjaEngine {
         Ok(tmpl.render(value)?)
     }
 
+    fn render_named_to(
+        &self,
+        name: &str,
+        data: &serde_json::Value,
+        out: &mut dyn std::io::Write,
+    ) -> Result<(), RenderError> {
+        let tmpl = self.env.get_template(name)?;
+        let value = Value::from_serialize(data);
+        tmpl.render_captured_to(value, out)?;
+        Ok(())
+    }
+
     fn has_template(&self, name: &str) -> bool {
         self.env.get_template(name).is_ok()
     }
@@ -174,7 +395,53 @@ impl TemplateEngine for MiniJinjaEngine {
             }
         }
 
-        Ok(self.env.render_str(template, &combined)?)
+        self.render_cached(template, &combined)
+    }
+
+    fn set_template_registry(&mut self, registry: Arc<TemplateRegistry>) {
+        self.env
+            .set_loader(move |name| match registry.get_content(name) {
+                Ok(content) => Ok(Some(content)),
+                Err(RegistryError::NotFound { .. }) => Ok(None),
+                Err(e) => Err(minijinja::Error::new(
+                    minijinja::ErrorKind::TemplateNotFound,
+                    e.to_string(),
+                )),
+            });
+        self.invalidate_cache();
+    }
+
+    fn add_filter(&mut self, name: &str, filter: FilterFn) -> Result<(), RenderError> {
+        use minijinja::value::Rest;
+        use minijinja::{Error, ErrorKind};
+
+        let filter_name = name.to_string();
+        self.env.add_filter(
+            name.to_string(),
+            move |value: Value, args: Rest<Value>| -> Result<Value, Error> {
+                let to_json = |v: &Value| {
+                    serde_json::to_value(v)
+                        .map_err(|e| Error::new(ErrorKind::InvalidOperation, e.to_string()))
+                };
+                let value = to_json(&value)?;
+                let args = args.iter().map(to_json).collect::<Result<Vec<_>, _>>()?;
+                let result = filter(&value, &args).map_err(|msg| {
+                    Error::new(
+                        ErrorKind::InvalidOperation,
+                        format!("filter '{}': {}", filter_name, msg),
+                    )
+                })?;
+                Ok(Value::from_serialize(&result))
+            },
+        );
+        self.invalidate_cache();
+        Ok(())
+    }
+
+    fn add_table_spec(&mut self, name: &str, spec: TabularSpec) -> Result<(), RenderError> {
+        crate::tabular::filters::register_table_spec(&mut self.env, name, spec);
+        self.invalidate_cache();
+        Ok(())
     }
 
     fn supports_includes(&self) -> bool {
@@ -190,32 +457,6 @@ impl TemplateEngine for MiniJinjaEngine {
     }
 }
 
-/// Registers standout's custom filters with a MiniJinja environment.
-///
-/// This is called automatically by [`MiniJinjaEngine::new`]. If you're using
-/// the environment directly, call this to get standout's filters.
-pub fn register_filters(env: &mut Environment<'static>) {
-    use minijinja::{Error, ErrorKind};
-
-    // Newline filter
-    env.add_filter("nl", |value: Value| -> String { format!("{}\n", value) });
-
-    // Deprecated style filter with helpful error message
-    env.add_filter(
-        "style",
-        |_value: Value, _name: String| -> Result<String, Error> {
-            Err(Error::new(
-                ErrorKind::InvalidOperation,
-                "The `style()` filter was removed in Standout 1.0. \
-                 Use tag syntax instead: [stylename]{{ value }}[/stylename]",
-            ))
-        },
-    );
-
-    // Register tabular filters
-    crate::tabular::filters::register_tabular_filters(env);
-}
-
 #[cfg(test)]
 mod tests {
     use super::*;
@@ -279,6 +520,19 @@ mod tests {
         assert_eq!(output, "Hello, World!");
     }
 
+    #[test]
+    fn test_minijinja_engine_render_named_to() {
+        let mut engine = MiniJinjaEngine::new();
+        engine
+            .add_template("greeting", "Hello, {{ name }}!")
+            .unwrap();
+
+        let data = serde_json::json!({ "name": "World" });
+        let mut out: Vec<u8> = Vec::new();
+        engine.render_named_to("greeting", &data, &mut out).unwrap();
+        assert_eq!(out, b"Hello, World!");
+    }
+
     #[test]
     fn test_minijinja_engine_template_error() {
         let engine = MiniJinjaEngine::new();
@@ -311,6 +565,154 @@ mod tests {
         assert_eq!(output, "Test v1.0.0");
     }
 
+    fn compile_count(engine: &MiniJinjaEngine) -> usize {
+        engine.cache.lock().unwrap().compiles
+    }
+
+    #[test]
+    fn test_render_template_reuses_compiled_template() {
+        let engine = MiniJinjaEngine::new();
+        let template = "{% for i in items %}{{ i }}{% endfor %}";
+
+        for n in 0..100 {
+            let data = serde_json::json!({ "items": [n, n + 1] });
+            let output = engine.render_template(template, &data).unwrap();
+            assert_eq!(output, format!("{}{}", n, n + 1));
+        }
+        assert_eq!(compile_count(&engine), 1);
+
+        let context = HashMap::new();
+        engine
+            .render_with_context(template, &serde_json::json!({ "items": [] }), context)
+            .unwrap();
+        assert_eq!(compile_count(&engine), 1);
+    }
+
+    #[test]
+    fn test_render_template_cache_evicts_least_recently_used() {
+        let engine = MiniJinjaEngine::with_cache_capacity(2);
+        let data = serde_json::Value::Null;
+
+        engine.render_template("a", &data).unwrap();
+        engine.render_template("b", &data).unwrap();
+        engine.render_template("a", &data).unwrap(); // "b" is now least recent
+        engine.render_template("c", &data).unwrap(); // evicts "b"
+        assert_eq!(compile_count(&engine), 3);
+
+        engine.render_template("a", &data).unwrap();
+        assert_eq!(compile_count(&engine), 3);
+        assert_eq!(engine.render_template("b", &data).unwrap(), "b");
+        assert_eq!(compile_count(&engine), 4);
+    }
+
+    #[test]
+    fn test_render_template_cache_disabled() {
+        let engine = MiniJinjaEngine::with_cache_capacity(0);
+        engine
+            .render_template("x", &serde_json::Value::Null)
+            .unwrap();
+        engine
+            .render_template("x", &serde_json::Value::Null)
+            .unwrap();
+        assert_eq!(compile_count(&engine), 0);
+    }
+
+    #[test]
+    fn test_render_template_cache_sees_environment_changes() {
+        let mut engine = MiniJinjaEngine::new();
+        let template = r#"{% include "greeting" %}"#;
+        engine.add_template("greeting", "hi").unwrap();
+        assert_eq!(
+            engine
+                .render_template(template, &serde_json::Value::Null)
+                .unwrap(),
+            "hi"
+        );
+
+        engine.add_template("greeting", "hello").unwrap();
+        assert_eq!(
+            engine
+                .render_template(template, &serde_json::Value::Null)
+                .unwrap(),
+            "hello"
+        );
+
+        engine
+            .environment_mut()
+            .add_filter("shout", |v: String| v.to_uppercase());
+        assert_eq!(
+            engine
+                .render_template("{{ 'x' | shout }}", &serde_json::Value::Null)
+                .unwrap(),
+            "X"
+        );
+    }
+
+    #[test]
+    fn test_include_resolves_through_registry() {
+        let mut registry = TemplateRegistry::from_embedded_entries(&[
+            ("partials/header.jinja", "# {{ title }} "),
+            ("macros.j2", "{% macro bullet(x) %}- {{ x }}{% endmacro %}"),
+            ("partials/footer.jinja", "(embedded footer)"),
+        ]);
+        registry.add_inline("partials/footer", "(inline footer)");
+
+        let mut engine = MiniJinjaEngine::new();
+        engine.set_template_registry(Arc::new(registry));
+
+        let output = engine
+            .render_template(
+                r#"{% include "partials/header" %}{% import "macros" as m %}{{ m.bullet(item) }} {% include "partials/footer" %}"#,
+                &serde_json::json!({ "title": "Report", "item": "one" }),
+            )
+            .unwrap();
+        assert_eq!(output, "# Report - one (inline footer)");
+    }
+
+    #[test]
+    fn test_include_missing_partial_still_errors() {
+        let mut engine = MiniJinjaEngine::new();
+        engine.set_template_registry(Arc::new(TemplateRegistry::new()));
+
+        let result = engine.render_template(r#"{% include "nope" %}"#, &serde_json::Value::Null);
+        assert!(result.is_err());
+    }
+
+    #[test]
+    fn test_add_filter_with_arguments() {
+        let mut engine = MiniJinjaEngine::new();
+        engine
+            .add_filter(
+                "repeat",
+                Arc::new(|value, args| {
+                    let times = args.first().and_then(|a| a.as_u64()).unwrap_or(1) as usize;
+                    Ok(value.as_str().unwrap_or_default().repeat(times).into())
+                }),
+            )
+            .unwrap();
+
+        let output = engine
+            .render_template(
+                "{{ word | repeat(3) | nl }}{{ 'x' | pad_left(3) }}",
+                &serde_json::json!({ "word": "ab" }),
+            )
+            .unwrap();
+        assert_eq!(output, "ababab\n  x");
+    }
+
+    #[test]
+    fn test_add_filter_error_fails_render() {
+        let mut engine = MiniJinjaEngine::new();
+        engine
+            .add_filter("fail", Arc::new(|_, _| Err("nope".to_string())))
+            .unwrap();
+
+        let err = engine
+            .render_template("{{ 1 | fail }}", &serde_json::Value::Null)
+            .unwrap_err();
+        assert!(err.to_string().contains("filter 'fail': nope"), "{}", err);
+    }
+
     #[test]
     fn test_minijinja_engine_supports_features() {
         let engine = MiniJinjaEngine::new();
@@ -1,12 +1,20 @@
 //! MiniJinja filter registration.
 
+use minijinja::value::{Kwargs, Rest};
 use minijinja::{Environment, Error, ErrorKind, Value};
 
+use crate::util::format_duration;
+
 /// Registers all built-in filters on a minijinja environment.
 ///
+/// This is the single filter set used by every MiniJinja-based entry point:
+/// [`MiniJinjaEngine::new`](super::MiniJinjaEngine::new) calls it, and so
+/// should code that builds its own environment.
+///
 /// Styling is now handled by BBParser tags (e.g., `[title]text[/title]`) in a
 /// second pass after MiniJinja rendering. This function registers utility filters
-/// like `nl` and table formatting filters.
+/// like `nl`, `default`, `coalesce`, `duration` and `number`, and table
+/// formatting filters.
 ///
 /// # Arguments
 ///
@@ -17,6 +25,96 @@ pub fn register_filters(env: &mut Environment<'static>) {
     //        {{ "" | nl }} outputs just \n (a blank line)
     env.add_filter("nl", |value: Value| -> String { format!("{}\n", value) });
 
+    // Fallback for missing values. Unlike MiniJinja's built-in `default`, this
+    // also replaces none and empty strings. Like the built-in, a true second
+    // argument replaces every falsy value, and `d` is an alias.
+    // Usage: {{ task.owner | default("unassigned") }}
+    //        {{ task.count | d("none", true) }}
+    env.add_filter("default", default_filter);
+    env.add_filter("d", default_filter);
+
+    // First non-blank value, as a function or a filter.
+    // Usage: {{ coalesce(task.nickname, task.name, "unnamed") }}
+    //        {{ task.nickname | coalesce(task.name, "unnamed") }}
+    env.add_function("coalesce", |values: Rest<Value>| -> Value {
+        first_non_blank(values.0)
+    });
+    env.add_filter("coalesce", |value: Value, rest: Rest<Value>| -> Value {
+        first_non_blank(std::iter::once(value).chain(rest.0))
+    });
+
+    // Human-readable durations from millisecond (or second) counts.
+    // Usage: {{ elapsed_ms | duration }}                 => 1m 33s
+    //        {{ elapsed_s | duration(unit="s", precision=1) }} => 2h
+    env.add_filter(
+        "duration",
+        |value: Value, kwargs: Kwargs| -> Result<String, Error> {
+            let unit = kwargs.get::<Option<String>>("unit")?;
+            let precision = kwargs.get::<Option<usize>>("precision")?.unwrap_or(2);
+            kwargs.assert_all_used()?;
+
+            if value.is_undefined() || value.is_none() {
+                return Ok(String::new());
+            }
+            let scale = match unit.as_deref() {
+                None | Some("ms") => 1.0,
+                Some("s") => 1_000.0,
+                Some(other) => {
+                    return Err(Error::new(
+                        ErrorKind::InvalidOperation,
+                        format!("Invalid duration unit: '{}'. Use 'ms' or 's'", other),
+                    ))
+                }
+            };
+            let amount = f64::try_from(value.clone()).map_err(|_| {
+                Error::new(
+                    ErrorKind::InvalidOperation,
+                    format!("duration expects a number, got '{}'", value),
+                )
+            })?;
+            Ok(format_duration((amount * scale).round() as i64, precision))
+        },
+    );
+
+    // Digit grouping and fixed decimals for integers and floats.
+    // Usage: {{ total | number }}                       => 1,234,567
+    //        {{ price | number(2) }}                    => 1,234.50
+    //        {{ price | number(2, sep=".", decimal_sep=",") }} => 1.234,50
+    env.add_filter(
+        "number",
+        |value: Value, decimals: Option<usize>, kwargs: Kwargs| -> Result<String, Error> {
+            let sep = kwargs.get::<Option<String>>("sep")?;
+            let decimal_sep = kwargs.get::<Option<String>>("decimal_sep")?;
+            kwargs.assert_all_used()?;
+            let sep = sep.as_deref().unwrap_or(",");
+            let decimal_sep = decimal_sep.as_deref().unwrap_or(".");
+
+            if value.is_undefined() || value.is_none() {
+                return Ok(String::new());
+            }
+            let plain = if let Ok(int) = i128::try_from(value.clone()) {
+                match decimals {
+                    Some(places) if places > 0 => format!("{}.{}", int, "0".repeat(places)),
+                    _ => int.to_string(),
+                }
+            } else if let Ok(float) = f64::try_from(value.clone()) {
+                if !float.is_finite() {
+                    return Ok(float.to_string());
+                }
+                match decimals {
+                    Some(places) => format!("{:.*}", places, float),
+                    None => float.to_string(),
+                }
+            } else {
+                return Err(Error::new(
+                    ErrorKind::InvalidOperation,
+                    format!("number expects a number, got '{}'", value),
+                ));
+            };
+            Ok(group_number(&plain, sep, decimal_sep))
+        },
+    );
+
     // Deprecated style filter - provide helpful migration message
     // The old style() filter was replaced with BBCode-style tags in Standout 1.0
     env.add_filter(
@@ -35,9 +133,85 @@ pub fn register_filters(env: &mut Environment<'static>) {
     crate::tabular::filters::register_tabular_filters(env);
 }
 
+/// The `default` filter: `fallback` (or "") when `value` is blank, or any
+/// falsy value if `boolean` is true.
+fn default_filter(value: Value, fallback: Option<Value>, boolean: Option<bool>) -> Value {
+    if is_blank(&value) || (boolean == Some(true) && !value.is_true()) {
+        fallback.unwrap_or_else(|| Value::from(""))
+    } else {
+        value
+    }
+}
+
+/// Returns true for the values `default` and `coalesce` skip: undefined,
+/// none, and the empty string.
+fn is_blank(value: &Value) -> bool {
+    value.is_undefined() || value.is_none() || value.as_str() == Some("")
+}
+
+/// Inserts `sep` between groups of three integer digits of a plain decimal
+/// number like `-1234.5`, and swaps in `decimal_sep` for the point.
+fn group_number(plain: &str, sep: &str, decimal_sep: &str) -> String {
+    let (sign, unsigned) = match plain.strip_prefix('-') {
+        Some(rest) => ("-", rest),
+        None => ("", plain),
+    };
+    let (int_part, fraction) = match unsigned.split_once('.') {
+        Some((int_part, fraction)) => (int_part, Some(fraction)),
+        None => (unsigned, None),
+    };
+
+    let mut out = String::from(sign);
+    for (i, digit) in int_part.chars().enumerate() {
+        if i > 0 && (int_part.len() - i) % 3 == 0 {
+            out.push_str(sep);
+        }
+        out.push(digit);
+    }
+    if let Some(fraction) = fraction {
+        out.push_str(decimal_sep);
+        out.push_str(fraction);
+    }
+    out
+}
+
+/// Returns the first non-blank value, or undefined (which renders as nothing).
+fn first_non_blank(values: impl IntoIterator<Item = Value>) -> Value {
+    values
+        .into_iter()
+        .find(|value| !is_blank(value))
+        .unwrap_or(Value::UNDEFINED)
+}
+
 #[cfg(test)]
 mod tests {
     use super::*;
+    use crate::template::{MiniJinjaEngine, TemplateEngine};
+
+    #[test]
+    fn test_engine_and_bare_environment_render_identically() {
+        let template = "{{ title | nl }}{{ name | col(8) }}|{{ status | style_as('ok') }}|{{ 3 | pad_left(4) }}";
+        let data = serde_json::json!({ "title": "Tasks", "name": "write docs", "status": "done" });
+
+        let engine_output = MiniJinjaEngine::new()
+            .render_template(template, &data)
+            .unwrap();
+
+        let mut env = Environment::new();
+        register_filters(&mut env);
+        let env_output = env.render_str(template, &data).unwrap();
+
+        assert_eq!(engine_output, env_output);
+        assert_eq!(engine_output, "Tasks\nwrite d…|[ok]done[/ok]|   3");
+
+        let engine_err = MiniJinjaEngine::new()
+            .render_template("{{ x | style('a') }}", &data)
+            .unwrap_err();
+        let env_err = env.render_str("{{ x | style('a') }}", &data).unwrap_err();
+        assert!(engine_err
+            .to_string()
+            .contains(&env_err.detail().unwrap().to_string()));
+    }
 
     #[test]
     fn test_deprecated_style_filter_gives_helpful_error() {
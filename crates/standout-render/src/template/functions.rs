@@ -72,12 +72,12 @@
 //! [`Renderer::set_output_mode`]: super::renderer::Renderer::set_output_mode
 
 use serde::Serialize;
-use standout_bbparser::{BBParser, TagTransform, UnknownTagBehavior};
-use std::collections::HashMap;
+use standout_bbparser::{BBParser, TagTransform, UnknownTagBehavior, UnknownTagKind};
+use std::collections::{HashMap, HashSet};
 
 use super::engine::{MiniJinjaEngine, TemplateEngine};
 use crate::context::{ContextRegistry, RenderContext};
-use crate::error::RenderError;
+use crate::error::{RenderError, RenderWarning};
 use crate::output::OutputMode;
 use crate::style::Styles;
 use crate::tabular::FlatDataSpec;
@@ -97,9 +97,13 @@ fn output_mode_to_transform(mode: OutputMode) -> TagTransform {
         OutputMode::Text => TagTransform::Remove,
         OutputMode::TermDebug => TagTransform::Keep,
         // Structured modes shouldn't reach here (filtered out before)
-        OutputMode::Json | OutputMode::Yaml | OutputMode::Xml | OutputMode::Csv => {
-            TagTransform::Remove
-        }
+        OutputMode::Json
+        | OutputMode::JsonCompact
+        | OutputMode::JsonLines
+        | OutputMode::Yaml
+        | OutputMode::Toml
+        | OutputMode::Xml
+        | OutputMode::Csv => TagTransform::Remove,
     }
 }
 
@@ -114,6 +118,35 @@ pub(crate) fn apply_style_tags(output: &str, styles: &Styles, mode: OutputMode)
     parser.parse(output)
 }
 
+/// [`apply_style_tags`], also reporting tags for styles that aren't defined.
+///
+/// Each undefined style is reported once, in order of first use.
+pub(crate) fn apply_style_tags_with_warnings(
+    output: &str,
+    styles: &Styles,
+    mode: OutputMode,
+    template: Option<&str>,
+) -> (String, Vec<RenderWarning>) {
+    let transform = output_mode_to_transform(mode);
+    let resolved_styles = styles.to_resolved_map();
+    let parser =
+        BBParser::new(resolved_styles, transform).unknown_behavior(UnknownTagBehavior::Passthrough);
+    let (output, errors) = parser.parse_with_diagnostics(output);
+
+    let mut seen = HashSet::new();
+    let warnings = errors
+        .errors
+        .into_iter()
+        .filter(|e| matches!(e.kind, UnknownTagKind::Open | UnknownTagKind::Close))
+        .filter(|e| seen.insert(e.tag.clone()))
+        .map(|e| RenderWarning::UndefinedStyle {
+            style: e.tag,
+            template: template.map(str::to_string),
+        })
+        .collect();
+    (output, warnings)
+}
+
 /// Validates a template for unknown style tags.
 ///
 /// This function renders the template (performing variable substitution) and then
@@ -164,17 +197,13 @@ pub fn validate_template<T: Serialize>(
     data: &T,
     theme: &Theme,
 ) -> Result<(), Box<dyn std::error::Error>> {
-    let color_mode = detect_color_mode();
-    let styles = theme.resolve_styles(Some(color_mode));
-
     // First render with the engine to get the final output
     let engine = MiniJinjaEngine::new();
     let data_value = serde_json::to_value(data)?;
     let minijinja_output = engine.render_template(template, &data_value)?;
 
     // Now validate the style tags
-    let resolved_styles = styles.to_resolved_map();
-    let parser = BBParser::new(resolved_styles, TagTransform::Remove);
+    let parser = theme.tag_parser(TagTransform::Remove, Some(detect_color_mode()));
     parser.validate(&minijinja_output)?;
 
     Ok(())
@@ -327,6 +356,65 @@ pub fn render_with_mode<T: Serialize>(
     output_mode: OutputMode,
     color_mode: ColorMode,
 ) -> Result<String, RenderError> {
+    let (template_output, styles) = render_template_pass(template, data, theme, color_mode)?;
+
+    // Pass 2: BBParser style tag processing
+    let final_output = apply_style_tags(&template_output, &styles, output_mode);
+
+    Ok(final_output)
+}
+
+/// Renders a template like [`render_with_output`], also returning warnings.
+///
+/// Style tags that the theme doesn't define are passed through (as with the
+/// other render functions) and reported as [`RenderWarning::UndefinedStyle`].
+/// Standalone templates have no name, so the warnings' `template` is `None`;
+/// use [`Renderer::render_with_warnings`] for named templates.
+///
+/// # Example
+///
+/// ```rust
+/// use standout_render::{render_with_warnings, OutputMode, RenderWarning, Theme};
+/// use console::Style;
+///
+/// let theme = Theme::new().add("title", Style::new().bold());
+/// let (output, warnings) = render_with_warnings(
+///     "[titel]{{ name }}[/titel]",
+///     &serde_json::json!({ "name": "Report" }),
+///     &theme,
+///     OutputMode::Text,
+/// ).unwrap();
+///
+/// assert_eq!(output, "Report"); // Unknown tag silently dropped in text mode
+/// assert_eq!(warnings.len(), 1);
+/// assert_eq!(warnings[0].to_string(), "used undefined style 'titel'");
+/// ```
+///
+/// [`Renderer::render_with_warnings`]: super::renderer::Renderer::render_with_warnings
+pub fn render_with_warnings<T: Serialize>(
+    template: &str,
+    data: &T,
+    theme: &Theme,
+    mode: OutputMode,
+) -> Result<(String, Vec<RenderWarning>), RenderError> {
+    let color_mode = detect_color_mode();
+    let (template_output, styles) = render_template_pass(template, data, theme, color_mode)?;
+    Ok(apply_style_tags_with_warnings(
+        &template_output,
+        &styles,
+        mode,
+        None,
+    ))
+}
+
+/// Pass 1 of the standalone render functions: validates the theme, resolves
+/// its styles and renders the template.
+fn render_template_pass<T: Serialize>(
+    template: &str,
+    data: &T,
+    theme: &Theme,
+    color_mode: ColorMode,
+) -> Result<(String, Styles), RenderError> {
     // Validate style aliases before rendering
     theme
         .validate()
@@ -335,15 +423,11 @@ pub fn render_with_mode<T: Serialize>(
     // Resolve styles for the specified color mode
     let styles = theme.resolve_styles(Some(color_mode));
 
-    // Pass 1: Template rendering
     let engine = MiniJinjaEngine::new();
     let data_value = serde_json::to_value(data)?;
     let template_output = engine.render_template(template, &data_value)?;
 
-    // Pass 2: BBParser style tag processing
-    let final_output = apply_style_tags(&template_output, &styles, output_mode);
-
-    Ok(final_output)
+    Ok((template_output, styles))
 }
 
 /// Renders a template with additional variables injected into the context.
@@ -479,7 +563,10 @@ pub fn render_auto<T: Serialize>(
     if mode.is_structured() {
         match mode {
             OutputMode::Json => Ok(serde_json::to_string_pretty(data)?),
+            OutputMode::JsonCompact => Ok(serde_json::to_string(data)?),
+            OutputMode::JsonLines => Ok(crate::util::to_json_lines(&serde_json::to_value(data)?)),
             OutputMode::Yaml => Ok(serde_yaml::to_string(data)?),
+            OutputMode::Toml => crate::util::to_toml(data),
             OutputMode::Xml => Ok(quick_xml::se::to_string(data)?),
             OutputMode::Csv => {
                 let value = serde_json::to_value(data)?;
@@ -523,7 +610,10 @@ pub fn render_auto_with_spec<T: Serialize>(
     if mode.is_structured() {
         match mode {
             OutputMode::Json => Ok(serde_json::to_string_pretty(data)?),
+            OutputMode::JsonCompact => Ok(serde_json::to_string(data)?),
+            OutputMode::JsonLines => Ok(crate::util::to_json_lines(&serde_json::to_value(data)?)),
             OutputMode::Yaml => Ok(serde_yaml::to_string(data)?),
+            OutputMode::Toml => crate::util::to_toml(data),
             OutputMode::Xml => Ok(quick_xml::se::to_string(data)?),
             OutputMode::Csv => {
                 let value = serde_json::to_value(data)?;
@@ -755,7 +845,10 @@ pub fn render_auto_with_context<T: Serialize>(
     if mode.is_structured() {
         match mode {
             OutputMode::Json => Ok(serde_json::to_string_pretty(data)?),
+            OutputMode::JsonCompact => Ok(serde_json::to_string(data)?),
+            OutputMode::JsonLines => Ok(crate::util::to_json_lines(&serde_json::to_value(data)?)),
             OutputMode::Yaml => Ok(serde_yaml::to_string(data)?),
+            OutputMode::Toml => crate::util::to_toml(data),
             OutputMode::Xml => Ok(quick_xml::se::to_string(data)?),
             OutputMode::Csv => {
                 let value = serde_json::to_value(data)?;
@@ -836,7 +929,10 @@ pub fn render_auto_with_engine(
     if mode.is_structured() {
         match mode {
             OutputMode::Json => Ok(serde_json::to_string_pretty(data)?),
+            OutputMode::JsonCompact => Ok(serde_json::to_string(data)?),
+            OutputMode::JsonLines => Ok(crate::util::to_json_lines(data)),
             OutputMode::Yaml => Ok(serde_yaml::to_string(data)?),
+            OutputMode::Toml => crate::util::to_toml(data),
             OutputMode::Xml => Ok(quick_xml::se::to_string(data)?),
             OutputMode::Csv => {
                 let (headers, rows) = crate::util::flatten_json_for_csv(data);
@@ -1026,6 +1122,168 @@ mod tests {
         assert_eq!(output, "Header");
     }
 
+    #[test]
+    fn test_render_default_filter_fallbacks() {
+        let theme = Theme::new();
+        let data = serde_json::json!({ "owner": null, "note": "", "name": "Ada" });
+        let render =
+            |template: &str| render_with_output(template, &data, &theme, OutputMode::Text).unwrap();
+
+        assert_eq!(render(r#"{{ missing | default("-") }}"#), "-");
+        assert_eq!(
+            render(r#"{{ owner | default("unassigned") }}"#),
+            "unassigned"
+        );
+        assert_eq!(render(r#"{{ note | default("no notes") }}"#), "no notes");
+        assert_eq!(render(r#"{{ name | default("-") }}"#), "Ada");
+        assert_eq!(render("[{{ owner | default }}]"), "[]");
+    }
+
+    #[test]
+    fn test_render_default_filter_boolean_and_alias() {
+        let theme = Theme::new();
+        let data = serde_json::json!({ "zero": 0, "off": false, "note": "", "name": "Ada" });
+        let render =
+            |template: &str| render_with_output(template, &data, &theme, OutputMode::Text).unwrap();
+
+        // Falsy values are kept unless the boolean argument is true
+        assert_eq!(render(r#"{{ zero | default("none") }}"#), "0");
+        assert_eq!(render(r#"{{ zero | default("none", true) }}"#), "none");
+        assert_eq!(render(r#"{{ off | default("no", true) }}"#), "no");
+        assert_eq!(render(r#"{{ name | default("-", true) }}"#), "Ada");
+
+        // `d` is the same filter
+        assert_eq!(render(r#"{{ note | d("no notes") }}"#), "no notes");
+        assert_eq!(render(r#"{{ missing | d("-") }}"#), "-");
+        assert_eq!(render(r#"{{ zero | d("none", true) }}"#), "none");
+    }
+
+    #[test]
+    fn test_render_coalesce_first_non_blank() {
+        let theme = Theme::new();
+        let data = serde_json::json!({ "owner": null, "note": "", "name": "Ada", "zero": 0 });
+        let render =
+            |template: &str| render_with_output(template, &data, &theme, OutputMode::Text).unwrap();
+
+        assert_eq!(
+            render(r#"{{ coalesce(missing, owner, note, name) }}"#),
+            "Ada"
+        );
+        assert_eq!(render(r#"{{ coalesce(owner, note, "n/a") }}"#), "n/a");
+        assert_eq!(render(r#"{{ owner | coalesce(missing, name) }}"#), "Ada");
+        assert_eq!(render("[{{ coalesce(owner, note) }}]"), "[]");
+        // Only none, undefined and "" are skipped; 0 is a value
+        assert_eq!(render(r#"{{ coalesce(zero, name) }}"#), "0");
+    }
+
+    #[test]
+    fn test_render_wrap_filter_cjk_and_long_words() {
+        use crate::tabular::display_width;
+
+        let theme = Theme::new();
+        let data = serde_json::json!({
+            "cjk": "日本語のテキスト を 折り返す",
+            "long": "see https://example.com/a/very/long/path now",
+        });
+        let render =
+            |template: &str| render_with_output(template, &data, &theme, OutputMode::Text).unwrap();
+
+        let cjk = render("{{ cjk | wrap(6) }}");
+        assert_eq!(cjk, "日本…\n語の…\nテキ…\nスト\nを\n折り…\n返す");
+        let odd = render("{{ cjk | wrap(5) }}");
+        for line in odd.lines() {
+            assert!(display_width(line) <= 5, "{:?} is too wide", line);
+        }
+        // Breaks fall between characters: removing the markers restores the text
+        assert_eq!(odd.replace(['…', '\n'], ""), "日本語のテキストを折り返す");
+
+        let long = render("{{ long | wrap(12) }}");
+        assert_eq!(
+            long,
+            "see\nhttps://exa…\nmple.com/a/…\nvery/long/p…\nath now"
+        );
+        for line in long.lines() {
+            assert!(display_width(line) <= 12, "{:?} is too wide", line);
+        }
+    }
+
+    #[test]
+    fn test_render_number_filter() {
+        let theme = Theme::new();
+        let data = serde_json::json!({
+            "small": 999,
+            "count": 1234567,
+            "big": u64::MAX,
+            "negative": -1234567,
+            "price": 1234.5,
+            "tiny": -0.125,
+            "missing": null,
+        });
+        let render =
+            |template: &str| render_with_output(template, &data, &theme, OutputMode::Text).unwrap();
+
+        // Integers
+        assert_eq!(render("{{ small | number }}"), "999");
+        assert_eq!(render("{{ count | number }}"), "1,234,567");
+        assert_eq!(render("{{ count | number(2) }}"), "1,234,567.00");
+        assert_eq!(render("{{ big | number }}"), "18,446,744,073,709,551,615");
+
+        // Floats
+        assert_eq!(render("{{ price | number }}"), "1,234.5");
+        assert_eq!(render("{{ price | number(2) }}"), "1,234.50");
+        assert_eq!(render("{{ price | number(0) }}"), "1,234");
+
+        // Negatives
+        assert_eq!(render("{{ negative | number }}"), "-1,234,567");
+        assert_eq!(render("{{ tiny | number(2) }}"), "-0.12");
+
+        // Custom separators
+        assert_eq!(
+            render(r#"{{ price | number(2, sep=".", decimal_sep=",") }}"#),
+            "1.234,50"
+        );
+        assert_eq!(render(r#"{{ count | number(sep=" ") }}"#), "1 234 567");
+        assert_eq!(render(r#"{{ count | number(sep="") }}"#), "1234567");
+
+        assert_eq!(render("[{{ missing | number }}]"), "[]");
+        let result =
+            render_with_output(r#"{{ "lots" | number }}"#, &data, &theme, OutputMode::Text);
+        assert!(result.is_err());
+    }
+
+    #[test]
+    fn test_render_duration_filter() {
+        let theme = Theme::new();
+        let data = serde_json::json!({
+            "zero": 0,
+            "sub_second": 999,
+            "ninety_s": 90_000,
+            "hours": 7_530_000,
+            "seconds": 93,
+            "half_second": 0.5,
+            "missing": null,
+        });
+        let render =
+            |template: &str| render_with_output(template, &data, &theme, OutputMode::Text).unwrap();
+
+        assert_eq!(render("{{ zero | duration }}"), "0s");
+        assert_eq!(render("{{ sub_second | duration }}"), "999ms");
+        assert_eq!(render("{{ ninety_s | duration }}"), "1m 30s");
+        assert_eq!(render("{{ hours | duration }}"), "2h 5m");
+        assert_eq!(render("{{ hours | duration(precision=1) }}"), "2h");
+        assert_eq!(render(r#"{{ seconds | duration(unit="s") }}"#), "1m 33s");
+        assert_eq!(render(r#"{{ half_second | duration(unit="s") }}"#), "500ms");
+        assert_eq!(render("[{{ missing | duration }}]"), "[]");
+
+        let result = render_with_output(
+            r#"{{ "soon" | duration }}"#,
+            &data,
+            &theme,
+            OutputMode::Text,
+        );
+        assert!(result.is_err());
+    }
+
     #[test]
     fn test_empty_template() {
         let theme = Theme::new();
@@ -1159,6 +1417,19 @@ mod tests {
         assert!(output.contains("\"count\": 42"));
     }
 
+    #[test]
+    fn test_render_auto_json_lines_mode() {
+        use serde_json::json;
+
+        let theme = Theme::new();
+        let data = json!([{"name": "a"}, {"name": "b"}]);
+
+        let output = render_auto("unused", &data, &theme, OutputMode::JsonLines).unwrap();
+
+        let lines: Vec<&str> = output.lines().collect();
+        assert_eq!(lines, vec![r#"{"name":"a"}"#, r#"{"name":"b"}"#]);
+    }
+
     #[test]
     fn test_render_auto_text_mode_uses_template() {
         use serde_json::json;
@@ -1984,6 +2255,63 @@ mod tests {
         assert_eq!(errors.len(), 2); // open and close tags
     }
 
+    #[test]
+    fn test_render_with_warnings_reports_undefined_style() {
+        let theme = Theme::new().add("title", Style::new().bold());
+
+        let (output, warnings) = render_with_warnings(
+            "[title]{{ a }}[/title] [titel]{{ b }}[/titel] [titel]again[/titel] [nope]x[/nope]",
+            &json!({ "a": "A", "b": "B" }),
+            &theme,
+            OutputMode::Text,
+        )
+        .unwrap();
+
+        assert_eq!(output, "A B again x");
+        assert_eq!(
+            warnings,
+            vec![
+                RenderWarning::UndefinedStyle {
+                    style: "titel".to_string(),
+                    template: None,
+                },
+                RenderWarning::UndefinedStyle {
+                    style: "nope".to_string(),
+                    template: None,
+                },
+            ]
+        );
+    }
+
+    #[test]
+    fn test_render_with_warnings_term_debug() {
+        let theme = Theme::new().add("title", Style::new().bold());
+
+        let (output, warnings) = render_with_warnings(
+            "[title]ok[/title] [titel]typo[/titel]",
+            &json!({}),
+            &theme,
+            OutputMode::TermDebug,
+        )
+        .unwrap();
+
+        assert!(output.starts_with("[title]ok[/title]"));
+        assert_eq!(warnings.len(), 1);
+        assert_eq!(warnings[0].to_string(), "used undefined style 'titel'");
+    }
+
+    #[test]
+    fn test_render_with_warnings_none_for_defined_styles() {
+        let theme = Theme::new().add("title", Style::new().bold());
+
+        let (output, warnings) =
+            render_with_warnings("[title]ok[/title]", &json!({}), &theme, OutputMode::Text)
+                .unwrap();
+
+        assert_eq!(output, "ok");
+        assert!(warnings.is_empty());
+    }
+
     #[test]
     fn test_validate_template_multiple_unknown_tags() {
         let theme = Theme::new().add("known", Style::new().bold());
@@ -112,14 +112,16 @@ pub mod registry;
 mod renderer;
 mod simple;
 
-pub use engine::{register_filters, MiniJinjaEngine, TemplateEngine};
+pub use engine::{FilterFn, MiniJinjaEngine, TemplateEngine, DEFAULT_TEMPLATE_CACHE_CAPACITY};
+pub use filters::register_filters;
 pub use functions::{
     render, render_auto, render_auto_with_context, render_auto_with_engine, render_auto_with_spec,
-    render_with_context, render_with_mode, render_with_output, render_with_vars, validate_template,
+    render_with_context, render_with_mode, render_with_output, render_with_vars,
+    render_with_warnings, validate_template,
 };
 pub use registry::{
-    walk_template_dir, RegistryError, ResolvedTemplate, TemplateFile, TemplateRegistry,
-    TEMPLATE_EXTENSIONS,
+    walk_template_dir, walk_template_dir_with_depth, RegistryError, ResolvedTemplate,
+    SharedTemplateRegistry, TemplateFile, TemplateRegistry, TEMPLATE_EXTENSIONS,
 };
 pub use renderer::Renderer;
 pub use simple::SimpleEngine;
@@ -47,6 +47,8 @@
 //!
 //! - Same-directory, different extensions: Higher priority extension wins (no error)
 //! - Cross-directory collisions: Panic with detailed message listing conflicting files
+//! - Embedded templates: Replaced by directory templates of the same name, whether
+//!   they were loaded from memory or hot-reloaded from disk
 //!
 //! This strict behavior catches configuration mistakes early rather than silently
 //! using an arbitrary winner.
@@ -64,8 +66,10 @@
 //! let content = registry.get_content("config")?;
 //! ```
 
-use std::collections::HashMap;
+use std::borrow::Cow;
+use std::collections::{BTreeMap, HashMap, HashSet};
 use std::path::{Path, PathBuf};
+use std::sync::RwLock;
 
 use crate::file_loader::{
     self, build_embedded_registry, FileRegistry, FileRegistryConfig, LoadError, LoadedEntry,
@@ -236,6 +240,12 @@ pub enum RegistryError {
         /// Error message
         message: String,
     },
+
+    /// Template file is not valid UTF-8, e.g. a binary file in a template directory.
+    Encoding {
+        /// Path of the offending file
+        path: PathBuf,
+    },
 }
 
 impl std::fmt::Display for RegistryError {
@@ -271,6 +281,14 @@ impl std::fmt::Display for RegistryError {
                     message
                 )
             }
+            RegistryError::Encoding { path } => {
+                write!(
+                    f,
+                    "Template \"{}\" is not valid UTF-8; remove it from the template \
+                     directory or rename it so its extension isn't loaded as a template",
+                    path.display()
+                )
+            }
         }
     }
 }
@@ -333,7 +351,9 @@ fn template_config() -> FileRegistryConfig<String> {
 /// # Thread Safety
 ///
 /// The registry is not thread-safe. For concurrent access, wrap in appropriate
-/// synchronization primitives.
+/// synchronization primitives, or freeze it with
+/// [`into_shared`](Self::into_shared) to get a read-only
+/// [`SharedTemplateRegistry`] that can be shared across threads.
 ///
 /// # Example
 ///
@@ -349,24 +369,35 @@ fn template_config() -> FileRegistryConfig<String> {
 /// // Resolve and get content
 /// let content = registry.get_content("header")?;
 /// ```
+#[derive(Clone)]
 pub struct TemplateRegistry {
     /// The underlying file registry for directory-based file loading.
     inner: FileRegistry<String>,
 
     /// Inline templates (stored separately for highest priority).
-    inline: HashMap<String, String>,
+    inline: BTreeMap<String, String>,
 
     /// File-based templates from add_from_files (maps name → path).
     /// These are separate from directory-based loading.
-    files: HashMap<String, PathBuf>,
+    files: BTreeMap<String, PathBuf>,
 
     /// Tracks source info for collision detection: name → (path, source_dir).
-    sources: HashMap<String, (PathBuf, PathBuf)>,
+    sources: BTreeMap<String, (PathBuf, PathBuf)>,
+
+    /// Names registered from an embedded source, which directory templates
+    /// replace instead of colliding with.
+    embedded: HashSet<String>,
 
     /// Framework templates (lowest priority fallback).
     /// These are provided by the standout framework and can be overridden
     /// by user templates with the same name.
-    framework: HashMap<String, String>,
+    framework: BTreeMap<String, String>,
+
+    /// Whether names are lowercased on registration and lookup.
+    case_insensitive: bool,
+
+    /// Whether trailing line breaks are stripped from template content.
+    trim_trailing_newline: bool,
 }
 
 impl Default for TemplateRegistry {
@@ -380,10 +411,74 @@ impl TemplateRegistry {
     pub fn new() -> Self {
         Self {
             inner: FileRegistry::new(template_config()),
-            inline: HashMap::new(),
-            files: HashMap::new(),
-            sources: HashMap::new(),
-            framework: HashMap::new(),
+            inline: BTreeMap::new(),
+            files: BTreeMap::new(),
+            sources: BTreeMap::new(),
+            embedded: HashSet::new(),
+            framework: BTreeMap::new(),
+            case_insensitive: false,
+            trim_trailing_newline: false,
+        }
+    }
+
+    /// Enables or disables case-insensitive template names.
+    ///
+    /// When enabled, names are lowercased both when templates are registered
+    /// and when they are looked up, so `get("Config")` finds `config.jinja`.
+    /// This keeps apps working when templates are referenced with inconsistent
+    /// casing, as happens on case-insensitive filesystems. Templates already
+    /// registered are re-keyed; disabling the mode again keeps the lowercased
+    /// names.
+    ///
+    /// Files whose names differ only in case become the same template, so
+    /// [`add_from_files`](Self::add_from_files) reports them as a
+    /// [`RegistryError::Collision`].
+    ///
+    /// Names are case-sensitive by default.
+    pub fn case_insensitive(&mut self, enabled: bool) -> &mut Self {
+        fn lowercase_keys<V>(map: &mut BTreeMap<String, V>) {
+            *map = std::mem::take(map)
+                .into_iter()
+                .map(|(name, value)| (name.to_lowercase(), value))
+                .collect();
+        }
+
+        self.case_insensitive = enabled;
+        if enabled {
+            lowercase_keys(&mut self.inline);
+            lowercase_keys(&mut self.files);
+            lowercase_keys(&mut self.sources);
+            lowercase_keys(&mut self.framework);
+            self.embedded = std::mem::take(&mut self.embedded)
+                .into_iter()
+                .map(|name| name.to_lowercase())
+                .collect();
+        }
+        self
+    }
+
+    /// Enables or disables stripping trailing line breaks from templates.
+    ///
+    /// Template files usually end with a newline, which shows up as a stray
+    /// blank line when the rendered output is printed. When enabled,
+    /// [`get_content`](Self::get_content) strips every trailing `\n` and
+    /// `\r\n` from inline, file, embedded and framework templates alike.
+    /// Content is returned unchanged by default.
+    ///
+    /// This only touches the template source. MiniJinja's own whitespace
+    /// control (`{%- ... -%}`, `trim_blocks`) still applies on top when the
+    /// template is rendered.
+    pub fn trim_trailing_newline(&mut self, enabled: bool) -> &mut Self {
+        self.trim_trailing_newline = enabled;
+        self
+    }
+
+    /// Normalizes `name` for use as a map key.
+    fn key<'a>(&self, name: &'a str) -> Cow<'a, str> {
+        if self.case_insensitive {
+            Cow::Owned(name.to_lowercase())
+        } else {
+            Cow::Borrowed(name)
         }
     }
 
@@ -403,7 +498,9 @@ impl TemplateRegistry {
     /// registry.add_inline("header", "{{ title | style(\"title\") }}");
     /// ```
     pub fn add_inline(&mut self, name: impl Into<String>, content: impl Into<String>) {
-        self.inline.insert(name.into(), content.into());
+        let name = name.into();
+        let name = self.key(&name).into_owned();
+        self.inline.insert(name, content.into());
     }
 
     /// Adds a template directory to search for files.
@@ -441,7 +538,9 @@ impl TemplateRegistry {
     /// # Collision Detection
     ///
     /// If a template name conflicts with one from a different source directory,
-    /// an error is returned with details about both files.
+    /// an error is returned with details about both files. Embedded templates
+    /// are not a directory: files replace them, in debug and release builds
+    /// alike.
     ///
     /// # Arguments
     ///
@@ -457,10 +556,20 @@ impl TemplateRegistry {
         sorted_files.sort_by_key(|f| f.extension_priority());
 
         for file in sorted_files {
-            // Check for cross-directory collision on the base name
-            if let Some((existing_path, existing_dir)) = self.sources.get(&file.name) {
-                // Only error if from different source directories
-                if existing_dir != &file.source_dir {
+            let name = self.key(&file.name).into_owned();
+            let name_with_ext = self.key(&file.name_with_ext).into_owned();
+
+            if self.embedded.remove(&name) {
+                // Replace the embedded template, however it was loaded
+                self.inline.remove(&name);
+                self.inline.remove(&name_with_ext);
+            } else if let Some((existing_path, existing_dir)) = self.sources.get(&name) {
+                // Check for cross-directory collision on the base name
+                // Only error if from different source directories, or for
+                // names differing only in case
+                let case_variant =
+                    existing_path.with_extension("") != file.absolute_path.with_extension("");
+                if existing_dir != &file.source_dir || case_variant {
                     return Err(RegistryError::Collision {
                         name: file.name.clone(),
                         existing_path: existing_path.clone(),
@@ -475,22 +584,58 @@ impl TemplateRegistry {
 
             // Track source for collision detection
             self.sources.insert(
-                file.name.clone(),
+                name.clone(),
                 (file.absolute_path.clone(), file.source_dir.clone()),
             );
 
             // Register the template under extensionless name
-            self.files
-                .insert(file.name.clone(), file.absolute_path.clone());
+            self.files.insert(name, file.absolute_path.clone());
 
             // Register under name with extension (allows explicit access)
-            self.files
-                .insert(file.name_with_ext.clone(), file.absolute_path);
+            self.files.insert(name_with_ext, file.absolute_path);
         }
 
         Ok(())
     }
 
+    /// Adds templates that intentionally replace earlier ones with the same name.
+    ///
+    /// Works like [`add_from_files`](Self::add_from_files), but a name that is
+    /// already registered from another directory, or as an embedded or inline
+    /// template, is shadowed instead of raising [`RegistryError::Collision`]. Use this
+    /// for user override directories layered over an application's own
+    /// templates.
+    ///
+    /// Templates registered afterwards follow the usual rules: a later
+    /// [`add_from_files`](Self::add_from_files) colliding with an override is
+    /// still an error, and a later [`add_inline`](Self::add_inline) still
+    /// takes precedence.
+    pub fn add_override_files(&mut self, files: Vec<TemplateFile>) {
+        let mut sorted_files = files;
+        sorted_files.sort_by_key(|f| f.extension_priority());
+
+        let mut overridden = HashSet::new();
+        for file in sorted_files {
+            let name = self.key(&file.name).into_owned();
+            let name_with_ext = self.key(&file.name_with_ext).into_owned();
+
+            // Same directory, different extension - higher priority already registered
+            if !overridden.insert(name.clone()) {
+                continue;
+            }
+
+            self.embedded.remove(&name);
+            self.inline.remove(&name);
+            self.inline.remove(&name_with_ext);
+            self.sources.insert(
+                name.clone(),
+                (file.absolute_path.clone(), file.source_dir.clone()),
+            );
+            self.files.insert(name, file.absolute_path.clone());
+            self.files.insert(name_with_ext, file.absolute_path);
+        }
+    }
+
     /// Adds pre-embedded templates (for release builds).
     ///
     /// Embedded templates are treated as inline templates, stored directly
@@ -501,10 +646,19 @@ impl TemplateRegistry {
     /// * `templates` - Map of template name to content
     pub fn add_embedded(&mut self, templates: HashMap<String, String>) {
         for (name, content) in templates {
+            let name = self.key(&name).into_owned();
+            self.embedded.insert(name.clone());
             self.inline.insert(name, content);
         }
     }
 
+    /// Marks every template registered so far as embedded, so directory
+    /// templates replace them. Used for hot-reloaded embedded sources.
+    pub(crate) fn mark_embedded(&mut self) {
+        self.embedded.extend(self.sources.keys().cloned());
+        self.embedded.extend(self.inline.keys().cloned());
+    }
+
     /// Adds framework templates (lowest priority fallback).
     ///
     /// Framework templates are provided by the standout framework and serve as
@@ -525,7 +679,9 @@ impl TemplateRegistry {
     /// registry.add_framework("standout/list-view", include_str!("templates/list-view.jinja"));
     /// ```
     pub fn add_framework(&mut self, name: impl Into<String>, content: impl Into<String>) {
-        self.framework.insert(name.into(), content.into());
+        let name = name.into();
+        let name = self.key(&name).into_owned();
+        self.framework.insert(name, content.into());
     }
 
     /// Adds multiple framework templates from embedded entries.
@@ -544,6 +700,7 @@ impl TemplateRegistry {
             .unwrap(); // Safe: Infallible error type
 
         for (name, content) in framework {
+            let name = self.key(&name).into_owned();
             self.framework.insert(name, content);
         }
     }
@@ -604,7 +761,8 @@ impl TemplateRegistry {
             })
             .unwrap(); // Safe: Infallible error type
 
-        registry.inline = inline;
+        registry.inline = inline.into_iter().collect();
+        registry.mark_embedded();
         registry
     }
 
@@ -628,23 +786,34 @@ impl TemplateRegistry {
     ///
     /// Returns [`RegistryError::NotFound`] if the template doesn't exist.
     pub fn get(&self, name: &str) -> Result<ResolvedTemplate, RegistryError> {
+        let key = self.key(name);
+
         // Check inline first (highest priority)
-        if let Some(content) = self.inline.get(name) {
+        if let Some(content) = self.inline.get(key.as_ref()) {
             return Ok(ResolvedTemplate::Inline(content.clone()));
         }
 
         // Check file-based templates from add_from_files
-        if let Some(path) = self.files.get(name) {
+        if let Some(path) = self.files.get(key.as_ref()) {
             return Ok(ResolvedTemplate::File(path.clone()));
         }
 
-        // Check directory-based file registry
-        if let Some(entry) = self.inner.get_entry(name) {
+        // Check directory-based file registry, which keeps names as found on disk
+        let entry = self.inner.get_entry(name).or_else(|| {
+            if !self.case_insensitive {
+                return None;
+            }
+            self.inner
+                .names()
+                .find(|candidate| candidate.to_lowercase() == key)
+                .and_then(|candidate| self.inner.get_entry(candidate))
+        });
+        if let Some(entry) = entry {
             return Ok(ResolvedTemplate::from(entry));
         }
 
         // Check framework templates (lowest priority)
-        if let Some(content) = self.framework.get(name) {
+        if let Some(content) = self.framework.get(key.as_ref()) {
             return Ok(ResolvedTemplate::Inline(content.clone()));
         }
 
@@ -657,20 +826,123 @@ impl TemplateRegistry {
     ///
     /// For inline templates, returns the stored content directly.
     /// For file templates, reads the file from disk (enabling hot reload).
+    /// Trailing line breaks are stripped when
+    /// [`trim_trailing_newline`](Self::trim_trailing_newline) is enabled.
     ///
     /// # Errors
     ///
-    /// Returns an error if the template is not found or cannot be read from disk.
+    /// Returns an error if the template is not found or cannot be read from disk,
+    /// and [`RegistryError::Encoding`] if the file is not valid UTF-8.
     pub fn get_content(&self, name: &str) -> Result<String, RegistryError> {
         let resolved = self.get(name)?;
-        match resolved {
-            ResolvedTemplate::Inline(content) => Ok(content),
+        let mut content = match resolved {
+            ResolvedTemplate::Inline(content) => content,
             ResolvedTemplate::File(path) => {
-                std::fs::read_to_string(&path).map_err(|e| RegistryError::ReadError {
-                    path,
+                let bytes = std::fs::read(&path).map_err(|e| RegistryError::ReadError {
+                    path: path.clone(),
                     message: e.to_string(),
-                })
+                })?;
+                String::from_utf8(bytes).map_err(|_| RegistryError::Encoding { path })?
             }
+        };
+
+        if self.trim_trailing_newline {
+            content.truncate(content.trim_end_matches(['\n', '\r']).len());
+        }
+        Ok(content)
+    }
+
+    /// Lists the templates that `name` references.
+    ///
+    /// Scans the template content for `{% include %}`, `{% import %}`,
+    /// `{% from ... import %}` and `{% extends %}` directives and returns the
+    /// quoted names they reference, in order of first appearance and without
+    /// duplicates. Both quote styles are recognized, as are lists such as
+    /// `{% include ["a.jinja", "b.jinja"] %}`. Names built from expressions
+    /// (e.g. `{% include layout ~ ".jinja" %}`) cannot be resolved statically
+    /// and are skipped, as are directives inside `{# comments #}`.
+    ///
+    /// Only direct references are listed; call this again on each result to
+    /// walk the full tree.
+    ///
+    /// # Errors
+    ///
+    /// Returns an error if the template is not found or cannot be read from disk.
+    ///
+    /// # Example
+    ///
+    /// ```rust
+    /// use standout_render::TemplateRegistry;
+    ///
+    /// let mut registry = TemplateRegistry::new();
+    /// registry.add_inline(
+    ///     "page",
+    ///     "{% extends 'base' %}{% block body %}{% include \"_row\" %}{% endblock %}",
+    /// );
+    ///
+    /// assert_eq!(registry.dependencies("page").unwrap(), vec!["base", "_row"]);
+    /// ```
+    pub fn dependencies(&self, name: &str) -> Result<Vec<String>, RegistryError> {
+        self.get_content(name)
+            .map(|content| scan_dependencies(&content))
+    }
+
+    /// Checks that every include, import and extends reference resolves.
+    ///
+    /// Runs [`dependencies`](Self::dependencies) on every registered
+    /// template and looks each referenced name up in the registry. Call this
+    /// at startup to fail fast instead of discovering a broken include only
+    /// when a rarely used template renders.
+    ///
+    /// # Errors
+    ///
+    /// Returns every problem found rather than stopping at the first: one
+    /// [`RegistryError::NotFound`] per missing name (reported once, however
+    /// many templates reference it), plus any [`RegistryError::ReadError`]
+    /// or [`RegistryError::Encoding`] for template files that could not be read.
+    ///
+    /// # Example
+    ///
+    /// ```rust
+    /// use standout_render::{RegistryError, TemplateRegistry};
+    ///
+    /// let mut registry = TemplateRegistry::new();
+    /// registry.add_inline("list", "{% for item in items %}{% include '_row' %}{% endfor %}");
+    /// assert_eq!(
+    ///     registry.validate_includes(),
+    ///     Err(vec![RegistryError::NotFound { name: "_row".into() }])
+    /// );
+    ///
+    /// registry.add_inline("_row", "{{ item }}");
+    /// assert_eq!(registry.validate_includes(), Ok(()));
+    /// ```
+    pub fn validate_includes(&self) -> Result<(), Vec<RegistryError>> {
+        let mut errors = Vec::new();
+
+        for name in self.sorted_names() {
+            let dependencies = match self.dependencies(name) {
+                Ok(dependencies) => dependencies,
+                Err(e) => {
+                    // A file is listed under its name with and without extension
+                    if !errors.contains(&e) {
+                        errors.push(e);
+                    }
+                    continue;
+                }
+            };
+            for dependency in dependencies {
+                if let Err(e) = self.get(&dependency) {
+                    if !errors.contains(&e) {
+                        errors.push(e);
+                    }
+                }
+            }
+        }
+
+        if errors.is_empty() {
+            Ok(())
+        } else {
+            Err(errors)
         }
     }
 
@@ -706,6 +978,10 @@ impl TemplateRegistry {
     }
 
     /// Returns an iterator over all registered template names.
+    ///
+    /// Names are grouped by source and may repeat when a name is registered
+    /// in several tiers. Use [`sorted_names`](Self::sorted_names) for a stable
+    /// listing.
     pub fn names(&self) -> impl Iterator<Item = &str> {
         self.inline
             .keys()
@@ -715,6 +991,29 @@ impl TemplateRegistry {
             .chain(self.framework.keys().map(|s| s.as_str()))
     }
 
+    /// Returns all registered template names, deduplicated, in lexicographic order.
+    ///
+    /// Unlike [`names`](Self::names), the result doesn't depend on hashing or
+    /// registration order, so it is suitable for listings and golden tests.
+    ///
+    /// # Example
+    ///
+    /// ```rust
+    /// use standout_render::TemplateRegistry;
+    ///
+    /// let mut registry = TemplateRegistry::new();
+    /// registry.add_inline("zeta", "z");
+    /// registry.add_inline("alpha", "a");
+    ///
+    /// assert_eq!(registry.sorted_names(), vec!["alpha", "zeta"]);
+    /// ```
+    pub fn sorted_names(&self) -> Vec<&str> {
+        let mut names: Vec<&str> = self.names().collect();
+        names.sort_unstable();
+        names.dedup();
+        names
+    }
+
     /// Clears all templates from the registry.
     pub fn clear(&mut self) {
         self.inline.clear();
@@ -724,6 +1023,13 @@ impl TemplateRegistry {
         self.framework.clear();
     }
 
+    /// Clears file-based templates, keeping inline and framework templates.
+    pub(crate) fn clear_files(&mut self) {
+        self.files.clear();
+        self.sources.clear();
+        self.inner.clear();
+    }
+
     /// Returns true if the registry has framework templates.
     pub fn has_framework_templates(&self) -> bool {
         !self.framework.is_empty()
@@ -733,6 +1039,159 @@ impl TemplateRegistry {
     pub fn framework_names(&self) -> impl Iterator<Item = &str> {
         self.framework.keys().map(|s| s.as_str())
     }
+
+    /// Freezes the registry into a read-only snapshot that is safe to share
+    /// across threads.
+    ///
+    /// See [`SharedTemplateRegistry`] for how file templates are cached.
+    pub fn into_shared(self) -> SharedTemplateRegistry {
+        SharedTemplateRegistry {
+            registry: self,
+            cache: RwLock::new(HashMap::new()),
+        }
+    }
+}
+
+/// A read-only, thread-safe snapshot of a [`TemplateRegistry`].
+///
+/// Created with [`TemplateRegistry::into_shared`]. The snapshot is `Send` and
+/// `Sync`, so it can be wrapped in an `Arc` and used from many rendering
+/// threads at once.
+///
+/// # Hot Reload
+///
+/// File templates are read from disk the first time they are requested and
+/// then served from a cache, so edits on disk are not picked up the way they
+/// are with [`TemplateRegistry::get_content`]. Call
+/// [`invalidate`](Self::invalidate) to drop the cache and re-read files on
+/// the next lookup. Templates added to or removed from disk still need a new
+/// snapshot.
+///
+/// # Example
+///
+/// ```rust
+/// use std::sync::Arc;
+/// use standout_render::TemplateRegistry;
+///
+/// let mut registry = TemplateRegistry::new();
+/// registry.add_inline("greeting", "Hello, {{ name }}!");
+/// let shared = Arc::new(registry.into_shared());
+///
+/// let worker = Arc::clone(&shared);
+/// let content = std::thread::spawn(move || worker.get_content("greeting").unwrap())
+///     .join()
+///     .unwrap();
+/// assert_eq!(content, "Hello, {{ name }}!");
+/// ```
+pub struct SharedTemplateRegistry {
+    registry: TemplateRegistry,
+
+    /// Content of templates already resolved, keyed by the requested name.
+    cache: RwLock<HashMap<String, String>>,
+}
+
+impl SharedTemplateRegistry {
+    /// Gets the content of a template, reading files from disk only once.
+    ///
+    /// # Errors
+    ///
+    /// Returns the same errors as [`TemplateRegistry::get_content`]. Failed
+    /// lookups are not cached.
+    pub fn get_content(&self, name: &str) -> Result<String, RegistryError> {
+        let cached = self
+            .cache
+            .read()
+            .unwrap_or_else(|e| e.into_inner())
+            .get(name)
+            .cloned();
+        if let Some(content) = cached {
+            return Ok(content);
+        }
+
+        let content = self.registry.get_content(name)?;
+        self.cache
+            .write()
+            .unwrap_or_else(|e| e.into_inner())
+            .insert(name.to_string(), content.clone());
+        Ok(content)
+    }
+
+    /// Drops cached template content, so file templates are re-read on their
+    /// next lookup.
+    pub fn invalidate(&self) {
+        self.cache
+            .write()
+            .unwrap_or_else(|e| e.into_inner())
+            .clear();
+    }
+
+    /// Returns the registry this snapshot was built from.
+    pub fn registry(&self) -> &TemplateRegistry {
+        &self.registry
+    }
+}
+
+/// Directive keywords whose first argument names another template.
+const DEPENDENCY_KEYWORDS: &[&str] = &["include", "import", "from", "extends"];
+
+/// Collects the template names referenced by include-like directives.
+fn scan_dependencies(content: &str) -> Vec<String> {
+    let mut names: Vec<String> = Vec::new();
+    let mut rest = content;
+
+    while let Some(start) = rest.find("{%").into_iter().chain(rest.find("{#")).min() {
+        let is_comment = rest[start..].starts_with("{#");
+        let body = &rest[start + 2..];
+        let close = if is_comment { "#}" } else { "%}" };
+        let Some(end) = body.find(close) else {
+            break;
+        };
+        if !is_comment {
+            for name in directive_targets(&body[..end]) {
+                if !names.contains(&name) {
+                    names.push(name);
+                }
+            }
+        }
+        rest = &body[end + 2..];
+    }
+
+    names
+}
+
+/// Returns the quoted template names of a directive body, or nothing if the
+/// directive is not include-like.
+fn directive_targets(directive: &str) -> Vec<String> {
+    // Strip whitespace control markers (`{%-`, `{%+`, `-%}`)
+    let directive = directive
+        .trim()
+        .trim_start_matches(['-', '+'])
+        .trim_end_matches(['-', '+'])
+        .trim();
+    let keyword_end = directive
+        .find(|c: char| !c.is_ascii_alphabetic())
+        .unwrap_or(directive.len());
+    if !DEPENDENCY_KEYWORDS.contains(&&directive[..keyword_end]) {
+        return Vec::new();
+    }
+
+    let args = directive[keyword_end..].trim_start();
+    match args.strip_prefix('[') {
+        Some(list) => {
+            let list = list.split(']').next().unwrap_or_default();
+            list.split(',')
+                .filter_map(|item| quoted(item.trim()))
+                .collect()
+        }
+        None => quoted(args).into_iter().collect(),
+    }
+}
+
+/// Returns the string literal at the start of `s`, if it begins with one.
+fn quoted(s: &str) -> Option<String> {
+    let quote = s.chars().next().filter(|c| *c == '"' || *c == '\'')?;
+    let inner = &s[1..];
+    inner.find(quote).map(|end| inner[..end].to_string())
 }
 
 /// Walks a template directory and collects template files.
@@ -762,7 +1221,23 @@ impl TemplateRegistry {
 /// }
 /// ```
 pub fn walk_template_dir(root: impl AsRef<Path>) -> Result<Vec<TemplateFile>, std::io::Error> {
-    let files = file_loader::walk_dir(root.as_ref(), TEMPLATE_EXTENSIONS)
+    walk_template_dir_with_depth(root, file_loader::DEFAULT_MAX_DEPTH)
+}
+
+/// Like [`walk_template_dir`], with an explicit directory nesting limit.
+///
+/// Symlinked directories are walked at most once, so symlink loops terminate
+/// regardless of the limit; `max_depth` guards against pathologically deep trees.
+///
+/// # Errors
+///
+/// Returns an error if the directory cannot be read or is nested deeper than
+/// `max_depth`.
+pub fn walk_template_dir_with_depth(
+    root: impl AsRef<Path>,
+    max_depth: usize,
+) -> Result<Vec<TemplateFile>, std::io::Error> {
+    let files = file_loader::walk_dir_with_depth(root.as_ref(), TEMPLATE_EXTENSIONS, max_depth)
         .map_err(|e| std::io::Error::other(e.to_string()))?;
 
     Ok(files.into_iter().map(TemplateFile::from).collect())
@@ -797,6 +1272,150 @@ mod tests {
     // TemplateRegistry inline tests
     // =========================================================================
 
+    #[test]
+    fn test_dependencies_directives() {
+        let mut registry = TemplateRegistry::new();
+        registry.add_inline(
+            "page",
+            r#"{% extends "layouts/base.jinja" %}
+{% import 'macros' as m %}
+{% from "forms.jinja" import field %}
+{% block body %}
+  {%- include '_row' -%}
+  {% include "_row" %}
+  {% for x in xs %}{{ x }}{% endfor %}
+{% endblock %}"#,
+        );
+
+        assert_eq!(
+            registry.dependencies("page").unwrap(),
+            vec!["layouts/base.jinja", "macros", "forms.jinja", "_row"]
+        );
+    }
+
+    #[test]
+    fn test_dependencies_lists_and_dynamic_names() {
+        let mut registry = TemplateRegistry::new();
+        registry.add_inline(
+            "page",
+            r#"{% include ["custom.jinja", 'fallback.jinja'] ignore missing %}
+{% include layout ~ ".jinja" %}
+{# {% include "commented" %} #}"#,
+        );
+
+        assert_eq!(
+            registry.dependencies("page").unwrap(),
+            vec!["custom.jinja", "fallback.jinja"]
+        );
+    }
+
+    #[test]
+    fn test_dependencies_none_and_missing() {
+        let mut registry = TemplateRegistry::new();
+        registry.add_inline("plain", "{{ title }} {% if x %}y{% endif %}");
+
+        assert!(registry.dependencies("plain").unwrap().is_empty());
+        assert!(matches!(
+            registry.dependencies("nope"),
+            Err(RegistryError::NotFound { .. })
+        ));
+    }
+
+    #[test]
+    fn test_get_content_non_utf8_file() {
+        let temp_dir = tempfile::TempDir::new().unwrap();
+        let path = temp_dir.path().join("logo.jinja");
+        std::fs::write(&path, [0x89, b'P', b'N', b'G', 0xff, 0xfe]).unwrap();
+
+        let mut registry = TemplateRegistry::new();
+        registry
+            .add_from_files(walk_template_dir(temp_dir.path()).unwrap())
+            .unwrap();
+
+        let err = registry.get_content("logo").unwrap_err();
+        assert!(matches!(&err, RegistryError::Encoding { path } if path.ends_with("logo.jinja")));
+        let message = err.to_string();
+        assert!(message.contains("logo.jinja"));
+        assert!(message.contains("not valid UTF-8"));
+        assert_eq!(registry.validate_includes(), Err(vec![err]));
+    }
+
+    #[test]
+    fn test_shared_registry_renders_across_threads() {
+        use std::sync::Arc;
+
+        let temp_dir = tempfile::TempDir::new().unwrap();
+        std::fs::write(temp_dir.path().join("greet.jinja"), "Hello, {{ name }}!").unwrap();
+
+        let mut registry = TemplateRegistry::new();
+        registry
+            .add_from_files(walk_template_dir(temp_dir.path()).unwrap())
+            .unwrap();
+        let shared = Arc::new(registry.into_shared());
+
+        let handles: Vec<_> = (0..8)
+            .map(|i| {
+                let shared = Arc::clone(&shared);
+                std::thread::spawn(move || {
+                    let content = shared.get_content("greet").unwrap();
+                    let data = serde_json::json!({ "name": format!("worker {}", i) });
+                    crate::render_with_output(
+                        &content,
+                        &data,
+                        &crate::Theme::new(),
+                        crate::OutputMode::Text,
+                    )
+                    .unwrap()
+                })
+            })
+            .collect();
+
+        for (i, handle) in handles.into_iter().enumerate() {
+            assert_eq!(handle.join().unwrap(), format!("Hello, worker {}!", i));
+        }
+
+        // Cached content survives edits until the snapshot is invalidated
+        std::fs::write(temp_dir.path().join("greet.jinja"), "Bye").unwrap();
+        assert_eq!(shared.get_content("greet").unwrap(), "Hello, {{ name }}!");
+        shared.invalidate();
+        assert_eq!(shared.get_content("greet").unwrap(), "Bye");
+    }
+
+    #[test]
+    fn test_validate_includes_resolved() {
+        let mut registry = TemplateRegistry::new();
+        registry.add_inline("_row", "{{ item }}");
+        registry.add_inline(
+            "list",
+            "{% for item in items %}{% include '_row' %}{% endfor %}",
+        );
+
+        assert_eq!(registry.validate_includes(), Ok(()));
+    }
+
+    #[test]
+    fn test_validate_includes_collects_missing() {
+        let mut registry = TemplateRegistry::new();
+        registry.add_inline("_row", "{{ item }}");
+        registry.add_inline("list", "{% include '_row' %}{% include '_footer' %}");
+        registry.add_inline("detail", "{% extends 'base' %}");
+        registry.add_inline("summary", "{% include '_footer' %}");
+
+        let errors = registry.validate_includes().unwrap_err();
+        assert_eq!(
+            errors,
+            vec![
+                RegistryError::NotFound {
+                    name: "base".to_string()
+                },
+                RegistryError::NotFound {
+                    name: "_footer".to_string()
+                },
+            ]
+        );
+        assert_eq!(errors[0].to_string(), "Template not found: \"base\"");
+    }
+
     #[test]
     fn test_registry_add_inline() {
         let mut registry = TemplateRegistry::new();
@@ -831,6 +1450,93 @@ mod tests {
     // File-based template tests (using synthetic data)
     // =========================================================================
 
+    #[test]
+    fn test_case_insensitive_lookup() {
+        let files = || {
+            vec![TemplateFile::new(
+                "config",
+                "config.tmpl",
+                "/templates/config.tmpl",
+                "/templates",
+            )]
+        };
+
+        let mut registry = TemplateRegistry::new();
+        registry.add_from_files(files()).unwrap();
+        assert!(registry.get("config").is_ok());
+        assert!(matches!(
+            registry.get("Config"),
+            Err(RegistryError::NotFound { .. })
+        ));
+
+        let mut registry = TemplateRegistry::new();
+        registry.case_insensitive(true);
+        registry.add_from_files(files()).unwrap();
+        registry.add_inline("Header", "{{ title }}");
+        assert_eq!(
+            registry.get("Config").unwrap(),
+            ResolvedTemplate::File(PathBuf::from("/templates/config.tmpl"))
+        );
+        assert!(registry.get("CONFIG.TMPL").is_ok());
+        assert!(registry.get("header").is_ok());
+    }
+
+    #[test]
+    fn test_case_insensitive_rekeys_existing_templates() {
+        let mut registry =
+            TemplateRegistry::from_embedded_entries(&[("Reports/Summary.jinja", "x")]);
+        registry.add_framework("standout/List", "y");
+
+        registry.case_insensitive(true);
+
+        assert!(registry.get("reports/summary").is_ok());
+        assert!(registry.get("REPORTS/SUMMARY.jinja").is_ok());
+        assert!(registry.get("standout/list").is_ok());
+    }
+
+    #[test]
+    fn test_case_insensitive_detects_case_collisions() {
+        let across_dirs = || {
+            vec![
+                TemplateFile::new("Config", "Config.jinja", "/a/Config.jinja", "/a"),
+                TemplateFile::new("config", "config.jinja", "/b/config.jinja", "/b"),
+            ]
+        };
+        let same_dir = || {
+            vec![
+                TemplateFile::new("Config", "Config.jinja", "/a/Config.jinja", "/a"),
+                TemplateFile::new("config", "config.jinja", "/a/config.jinja", "/a"),
+            ]
+        };
+
+        // Distinct names when case-sensitive
+        assert!(TemplateRegistry::new()
+            .add_from_files(across_dirs())
+            .is_ok());
+        assert!(TemplateRegistry::new().add_from_files(same_dir()).is_ok());
+
+        for files in [across_dirs(), same_dir()] {
+            let mut registry = TemplateRegistry::new();
+            registry.case_insensitive(true);
+            let err = registry.add_from_files(files).unwrap_err();
+            assert!(matches!(err, RegistryError::Collision { .. }), "{:?}", err);
+        }
+
+        // Extension variants in one directory are still resolved by priority
+        let mut registry = TemplateRegistry::new();
+        registry.case_insensitive(true);
+        registry
+            .add_from_files(vec![
+                TemplateFile::new("Config", "Config.j2", "/a/Config.j2", "/a"),
+                TemplateFile::new("Config", "Config.jinja", "/a/Config.jinja", "/a"),
+            ])
+            .unwrap();
+        assert_eq!(
+            registry.get("config").unwrap(),
+            ResolvedTemplate::File(PathBuf::from("/a/Config.jinja"))
+        );
+    }
+
     #[test]
     fn test_registry_add_from_files() {
         let mut registry = TemplateRegistry::new();
@@ -920,6 +1626,83 @@ mod tests {
         }
     }
 
+    #[test]
+    fn test_registry_override_files_shadow_earlier_dirs() {
+        let mut registry = TemplateRegistry::new();
+        registry
+            .add_from_files(vec![
+                TemplateFile::new(
+                    "config",
+                    "config.jinja",
+                    "/app/templates/config.jinja",
+                    "/app/templates",
+                ),
+                TemplateFile::new(
+                    "list",
+                    "list.jinja",
+                    "/app/templates/list.jinja",
+                    "/app/templates",
+                ),
+            ])
+            .unwrap();
+
+        registry.add_override_files(vec![
+            TemplateFile::new(
+                "config",
+                "config.j2",
+                "/user/templates/config.j2",
+                "/user/templates",
+            ),
+            TemplateFile::new(
+                "config",
+                "config.txt",
+                "/user/templates/config.txt",
+                "/user/templates",
+            ),
+        ]);
+
+        let path = |name: &str| match registry.get(name).unwrap() {
+            ResolvedTemplate::File(path) => path,
+            other => panic!("Expected file template, got {:?}", other),
+        };
+        assert_eq!(path("config"), PathBuf::from("/user/templates/config.j2"));
+        assert_eq!(path("list"), PathBuf::from("/app/templates/list.jinja"));
+
+        // Overrides take part in collision detection for later directories
+        let result = registry.add_from_files(vec![TemplateFile::new(
+            "config",
+            "config.jinja",
+            "/plugins/templates/config.jinja",
+            "/plugins/templates",
+        )]);
+        assert!(matches!(result, Err(RegistryError::Collision { .. })));
+    }
+
+    #[test]
+    fn test_registry_override_files_shadow_embedded() {
+        let mut registry = TemplateRegistry::from_embedded_entries(&[
+            ("list.jinja", "embedded"),
+            ("row.jinja", "row"),
+        ]);
+
+        registry.add_override_files(vec![TemplateFile::new(
+            "list",
+            "list.jinja",
+            "/user/templates/list.jinja",
+            "/user/templates",
+        )]);
+
+        assert!(matches!(
+            registry.get("list").unwrap(),
+            ResolvedTemplate::File(_)
+        ));
+        assert!(matches!(
+            registry.get("list.jinja").unwrap(),
+            ResolvedTemplate::File(_)
+        ));
+        assert_eq!(registry.get_content("row").unwrap(), "row");
+    }
+
     #[test]
     fn test_registry_inline_shadows_file() {
         let mut registry = TemplateRegistry::new();
@@ -951,6 +1734,63 @@ mod tests {
         assert!(names.contains(&"b"));
     }
 
+    #[test]
+    fn test_registry_sorted_names_stable_across_constructions() {
+        let inline = ["zeta", "alpha", "mid/beta", "mid/alpha", "omega"];
+        let files = [("todos/list", "list"), ("config", "cfg"), ("b", "bb")];
+
+        let build = |reverse: bool| {
+            let mut registry = TemplateRegistry::new();
+            let mut inline = inline.to_vec();
+            let mut files = files.to_vec();
+            if reverse {
+                inline.reverse();
+                files.reverse();
+            }
+            for name in inline {
+                registry.add_inline(name, name);
+            }
+            registry
+                .add_from_files(
+                    files
+                        .iter()
+                        .map(|(name, stem)| {
+                            TemplateFile::new(
+                                *name,
+                                format!("{}.jinja", name),
+                                format!("/templates/{}/{}.jinja", stem, name),
+                                "/templates",
+                            )
+                        })
+                        .collect(),
+                )
+                .unwrap();
+            registry.add_framework_entries(&[("standout/list-view.jinja", "fw")]);
+            registry
+        };
+
+        let first = build(false);
+        let expected = first.sorted_names();
+        let mut sorted = expected.clone();
+        sorted.sort_unstable();
+        assert_eq!(expected, sorted);
+        assert_eq!(expected.first(), Some(&"alpha"));
+
+        for i in 0..10 {
+            assert_eq!(build(i % 2 == 1).sorted_names(), expected);
+        }
+    }
+
+    #[test]
+    fn test_registry_sorted_names_deduplicates_tiers() {
+        let mut registry = TemplateRegistry::new();
+        registry.add_inline("list", "user");
+        registry.add_framework("list", "framework");
+
+        assert_eq!(registry.names().filter(|n| *n == "list").count(), 2);
+        assert_eq!(registry.sorted_names(), vec!["list"]);
+    }
+
     #[test]
     fn test_registry_clear() {
         let mut registry = TemplateRegistry::new();
@@ -30,17 +30,20 @@
 //! - Templates can be embedded at compile time for deployment
 //! - Use [`Renderer::with_embedded`] to load pre-embedded templates
 
-use std::collections::HashMap;
+use std::collections::{BTreeSet, HashMap};
 use std::path::Path;
+use std::sync::Arc;
 
 use serde::Serialize;
 use standout_bbparser::{BBParser, TagTransform, UnknownTagBehavior};
 
 use super::engine::{MiniJinjaEngine, TemplateEngine};
-use super::registry::{walk_template_dir, ResolvedTemplate, TemplateRegistry};
-use crate::error::RenderError;
+use super::functions::apply_style_tags_with_warnings;
+use super::registry::{walk_template_dir, RegistryError, ResolvedTemplate, TemplateRegistry};
+use crate::error::{RenderError, RenderWarning};
 use crate::output::OutputMode;
 use crate::style::Styles;
+use crate::tabular::TabularSpec;
 use crate::theme::Theme;
 use crate::EmbeddedTemplates;
 
@@ -125,10 +128,14 @@ pub struct Renderer {
     registry_initialized: bool,
     /// Registered template directories (for lazy initialization)
     template_dirs: Vec<std::path::PathBuf>,
+    /// Whether the engine's view of the registry (for includes) is out of date
+    engine_registry_stale: bool,
     /// Resolved styles for BBParser post-processing
     styles: Styles,
     /// Output mode for BBParser transform selection
     output_mode: OutputMode,
+    /// Names of table specs registered with the engine
+    table_specs: BTreeSet<String>,
 }
 
 impl Renderer {
@@ -179,8 +186,10 @@ impl Renderer {
             registry: TemplateRegistry::new(),
             registry_initialized: false,
             template_dirs: Vec::new(),
+            engine_registry_stale: true,
             styles,
             output_mode: mode,
+            table_specs: BTreeSet::new(),
         })
     }
 
@@ -201,9 +210,63 @@ impl Renderer {
         self.engine.add_template(name, source)?;
         // Also add to registry for consistency
         self.registry.add_inline(name, source);
+        self.engine_registry_stale = true;
         Ok(())
     }
 
+    /// Registers a custom template filter.
+    ///
+    /// The filter receives the piped value and any arguments as JSON values and
+    /// is available to every template rendered by this renderer, alongside the
+    /// built-in `nl`, `style`, and tabular filters. Registering a name that a
+    /// built-in already uses replaces that built-in, at your own risk.
+    ///
+    /// Returns an error if the renderer's engine doesn't support filters.
+    ///
+    /// # Example
+    ///
+    /// ```rust,ignore
+    /// renderer.add_filter("currency", |value, _args| {
+    ///     let cents = value.as_i64().ok_or("expected an integer")?;
+    ///     Ok(format!("${}.{:02}", cents / 100, cents % 100).into())
+    /// })?;
+    /// ```
+    pub fn add_filter<F>(&mut self, name: &str, filter: F) -> Result<(), RenderError>
+    where
+        F: Fn(&serde_json::Value, &[serde_json::Value]) -> Result<serde_json::Value, String>
+            + Send
+            + Sync
+            + 'static,
+    {
+        self.engine.add_filter(name, Arc::new(filter))
+    }
+
+    /// Registers a named table spec for the `row` template function.
+    ///
+    /// Templates can then format a whole row in one call, with widths resolved
+    /// from the cells and the spec's alignment and decorations applied. The
+    /// spec stays registered for every later render; registering a name again
+    /// replaces it.
+    ///
+    /// Returns an error if the renderer's engine doesn't support table functions.
+    ///
+    /// # Example
+    ///
+    /// ```rust,ignore
+    /// renderer.add_table_spec("tasks", spec)?;
+    /// renderer.add_template("task", r#"{{ row([name, status], "tasks") }}"#)?;
+    /// ```
+    pub fn add_table_spec(&mut self, name: &str, spec: TabularSpec) -> Result<(), RenderError> {
+        self.engine.add_table_spec(name, spec)?;
+        self.table_specs.insert(name.to_string());
+        Ok(())
+    }
+
+    /// Returns the names of registered table specs, sorted.
+    pub fn table_spec_names(&self) -> Vec<&str> {
+        self.table_specs.iter().map(String::as_str).collect()
+    }
+
     /// Adds a directory to search for template files.
     ///
     /// Templates in the directory are resolved by their relative path without
@@ -294,6 +357,7 @@ impl Renderer {
     /// ```
     pub fn with_embedded(&mut self, templates: HashMap<String, String>) -> &mut Self {
         self.registry.add_embedded(templates);
+        self.engine_registry_stale = true;
         self
     }
 
@@ -332,6 +396,7 @@ impl Renderer {
                 self.registry.add_inline(name, &content);
             }
         }
+        self.engine_registry_stale = true;
         self
     }
 
@@ -380,7 +445,8 @@ impl Renderer {
     /// Called lazily on first render or explicitly via `refresh()`.
     fn initialize_registry(&mut self) -> Result<(), RenderError> {
         // Clear existing file-based templates (keep inline)
-        let mut new_registry = TemplateRegistry::new();
+        let mut new_registry = self.registry.clone();
+        new_registry.clear_files();
 
         // Walk each directory and collect templates
         for dir in &self.template_dirs {
@@ -399,9 +465,19 @@ impl Renderer {
 
         self.registry = new_registry;
         self.registry_initialized = true;
+        self.engine_registry_stale = true;
         Ok(())
     }
 
+    /// Points the engine at the current registry so includes resolve through it.
+    fn sync_engine_registry(&mut self) {
+        if self.engine_registry_stale {
+            self.engine
+                .set_template_registry(Arc::new(self.registry.clone()));
+            self.engine_registry_stale = false;
+        }
+    }
+
     /// Ensures the registry is initialized, doing so lazily if needed.
     fn ensure_registry_initialized(&mut self) -> Result<(), RenderError> {
         if !self.registry_initialized && !self.template_dirs.is_empty() {
@@ -432,6 +508,117 @@ impl Renderer {
     /// let output = renderer.render("todos/list", &data)?;
     /// ```
     pub fn render<T: Serialize>(&mut self, name: &str, data: &T) -> Result<String, RenderError> {
+        let template_output = self.render_template_pass(name, data)?;
+
+        // Pass 2: BBParser style tag processing
+        let final_output = self.apply_style_tags(&template_output);
+
+        Ok(final_output)
+    }
+
+    /// Renders a registered template like [`render`](Self::render), also
+    /// returning warnings.
+    ///
+    /// Style tags that the theme doesn't define are passed through as usual
+    /// and reported as [`RenderWarning::UndefinedStyle`] with this template's
+    /// name. Useful in debug builds or with `--output=term-debug`.
+    ///
+    /// # Example
+    ///
+    /// ```rust
+    /// use standout_render::{Renderer, RenderWarning, Theme};
+    ///
+    /// let mut renderer = Renderer::new(Theme::new()).unwrap();
+    /// renderer.add_template("greeting", "[titel]Hi[/titel]").unwrap();
+    ///
+    /// let (_, warnings) = renderer.render_with_warnings("greeting", &()).unwrap();
+    /// assert_eq!(
+    ///     warnings,
+    ///     vec![RenderWarning::UndefinedStyle {
+    ///         style: "titel".into(),
+    ///         template: Some("greeting".into()),
+    ///     }]
+    /// );
+    /// ```
+    pub fn render_with_warnings<T: Serialize>(
+        &mut self,
+        name: &str,
+        data: &T,
+    ) -> Result<(String, Vec<RenderWarning>), RenderError> {
+        let template_output = self.render_template_pass(name, data)?;
+        Ok(apply_style_tags_with_warnings(
+            &template_output,
+            &self.styles,
+            self.output_mode,
+            Some(name),
+        ))
+    }
+
+    /// Renders a registered template like [`render`](Self::render), writing
+    /// the result to `w` instead of returning it.
+    ///
+    /// In [`OutputMode::TermDebug`], where style tags are kept as-is, the
+    /// engine streams its output straight into the writer. Other modes need
+    /// the whole template output to resolve style tags, so it is buffered
+    /// before the styled result is written.
+    ///
+    /// # Errors
+    ///
+    /// Returns an error if the template name is not found, rendering fails,
+    /// or writing to `w` fails.
+    ///
+    /// # Example
+    ///
+    /// ```rust
+    /// use standout_render::{OutputMode, Renderer, Theme};
+    ///
+    /// let mut renderer = Renderer::with_output(Theme::new(), OutputMode::Text).unwrap();
+    /// renderer.add_template("greeting", "Hello, {{ name }}!").unwrap();
+    ///
+    /// let mut out = Vec::new();
+    /// renderer
+    ///     .render_to("greeting", &serde_json::json!({ "name": "world" }), &mut out)
+    ///     .unwrap();
+    /// assert_eq!(out, b"Hello, world!");
+    /// ```
+    pub fn render_to<T: Serialize, W: std::io::Write>(
+        &mut self,
+        name: &str,
+        data: &T,
+        mut w: W,
+    ) -> Result<(), RenderError> {
+        if matches!(self.tag_transform(), TagTransform::Keep) {
+            let data_value = self.load_template(name, data)?;
+            return self.engine.render_named_to(name, &data_value, &mut w);
+        }
+
+        let output = self.render(name, data)?;
+        w.write_all(output.as_bytes())?;
+        Ok(())
+    }
+
+    /// Pass 1 of [`render`](Self::render): renders the named template with
+    /// MiniJinja, before style tags are processed.
+    fn render_template_pass<T: Serialize>(
+        &mut self,
+        name: &str,
+        data: &T,
+    ) -> Result<String, RenderError> {
+        let data_value = self.load_template(name, data)?;
+        self.engine.render_named(name, &data_value)
+    }
+
+    /// Makes sure the engine has an up-to-date copy of the named template,
+    /// and converts `data` for rendering it.
+    fn load_template<T: Serialize>(
+        &mut self,
+        name: &str,
+        data: &T,
+    ) -> Result<serde_json::Value, RenderError> {
+        // Let `{% include %}` / `{% import %}` find partials in the registry
+        self.ensure_registry_initialized()?;
+        self.sync_engine_registry();
+
         // First, check if it's an inline template
         // We check this first to avoid filesystem lookups for known templates.
         // In debug mode, if it's a file-based template, we want to skip this check
@@ -447,35 +634,26 @@ impl Renderer {
 
         // In release mode: always use engine cache if available.
         // In debug mode: only use engine cache if it's an inline template (which doesn't change on disk).
-        let template_output = if !cfg!(debug_assertions) || is_inline {
-            // Try to render with the engine's cached template
-            match self.engine.render_named(name, &data_value) {
-                Ok(output) => output,
-                Err(_) => {
-                    // Template not in cache, load and render
-                    self.ensure_registry_initialized()?;
-                    let content = self.get_template_content(name)?;
-                    self.engine.add_template(name, &content)?;
-                    self.engine.render_named(name, &data_value)?
-                }
-            }
-        } else {
-            // Debug mode with file-based template: always reload
-            self.ensure_registry_initialized()?;
+        let use_cache = !cfg!(debug_assertions) || is_inline;
+        if !use_cache || !self.engine.has_template(name) {
+            // Template not in cache (or debug mode with a file-based template): (re)load it
             let content = self.get_template_content(name)?;
             self.engine.add_template(name, &content)?;
-            self.engine.render_named(name, &data_value)?
-        };
-
-        // Pass 2: BBParser style tag processing
-        let final_output = self.apply_style_tags(&template_output);
+        }
 
-        Ok(final_output)
+        Ok(data_value)
     }
 
     /// Applies BBParser style tag post-processing.
     fn apply_style_tags(&self, output: &str) -> String {
-        let transform = match self.output_mode {
+        let parser = BBParser::new(self.styles.to_resolved_map(), self.tag_transform())
+            .unknown_behavior(UnknownTagBehavior::Passthrough);
+        parser.parse(output)
+    }
+
+    /// How style tags are treated in the current output mode.
+    fn tag_transform(&self) -> TagTransform {
+        match self.output_mode {
             OutputMode::Auto => {
                 if self.output_mode.should_use_color() {
                     TagTransform::Apply
@@ -486,38 +664,23 @@ impl Renderer {
             OutputMode::Term => TagTransform::Apply,
             OutputMode::Text => TagTransform::Remove,
             OutputMode::TermDebug => TagTransform::Keep,
-            OutputMode::Json | OutputMode::Yaml | OutputMode::Xml | OutputMode::Csv => {
-                TagTransform::Remove
-            }
-        };
-
-        let resolved_styles = self.styles.to_resolved_map();
-        let parser = BBParser::new(resolved_styles, transform)
-            .unknown_behavior(UnknownTagBehavior::Passthrough);
-        parser.parse(output)
+            OutputMode::Json
+            | OutputMode::JsonCompact
+            | OutputMode::JsonLines
+            | OutputMode::Yaml
+            | OutputMode::Toml
+            | OutputMode::Xml
+            | OutputMode::Csv => TagTransform::Remove,
+        }
     }
 
     /// Gets template content, re-reading from disk in debug mode.
     fn get_template_content(&self, name: &str) -> Result<String, RenderError> {
-        let resolved = self
-            .registry
-            .get(name)
-            .map_err(|e| RenderError::TemplateNotFound(e.to_string()))?;
-
-        match resolved {
-            ResolvedTemplate::Inline(content) => Ok(content),
-            ResolvedTemplate::File(path) => {
-                // In debug mode, always re-read for hot reloading
-                // In release mode, we still read (could optimize with caching)
-                std::fs::read_to_string(&path).map_err(|e| {
-                    RenderError::IoError(std::io::Error::other(format!(
-                        "Failed to read template {}: {}",
-                        path.display(),
-                        e
-                    )))
-                })
-            }
-        }
+        // File templates are re-read on every call, enabling hot reloading
+        self.registry.get_content(name).map_err(|e| match e {
+            RegistryError::NotFound { .. } => RenderError::TemplateNotFound(e.to_string()),
+            _ => RenderError::IoError(std::io::Error::other(e.to_string())),
+        })
     }
 
     /// Returns the number of registered templates.
@@ -653,6 +816,20 @@ mod tests {
         assert_eq!(output, "Config: test");
     }
 
+    #[test]
+    fn test_renderer_non_utf8_template_file() {
+        let temp_dir = TempDir::new().unwrap();
+        std::fs::write(temp_dir.path().join("binary.jinja"), [0xc3, 0x28]).unwrap();
+
+        let mut renderer = Renderer::new(Theme::new()).unwrap();
+        renderer.add_template_dir(temp_dir.path()).unwrap();
+
+        let err = renderer
+            .render("binary", &serde_json::json!({}))
+            .unwrap_err();
+        assert!(err.to_string().contains("not valid UTF-8"));
+    }
+
     #[test]
     fn test_renderer_nested_template_dir() {
         let temp_dir = TempDir::new().unwrap();
@@ -683,6 +860,33 @@ mod tests {
         assert_eq!(detail_output, "Detail: 42");
     }
 
+    #[test]
+    fn test_renderer_include_file_partial() {
+        let temp_dir = TempDir::new().unwrap();
+        create_template_file(temp_dir.path(), "partials/row.jinja", "- {{ item }}");
+        create_template_file(
+            temp_dir.path(),
+            "list.jinja",
+            r#"{% for item in items %}{% include "partials/row" %};{% endfor %}"#,
+        );
+
+        let mut renderer = Renderer::with_output(Theme::new(), OutputMode::Text).unwrap();
+        renderer.add_template_dir(temp_dir.path()).unwrap();
+        renderer
+            .add_template("inline", r#"[{% include "partials/row.jinja" %}]"#)
+            .unwrap();
+
+        let output = renderer
+            .render("list", &serde_json::json!({ "items": ["a", "b"] }))
+            .unwrap();
+        assert_eq!(output, "- a;- b;");
+
+        let output = renderer
+            .render("inline", &serde_json::json!({ "item": "c" }))
+            .unwrap();
+        assert_eq!(output, "[- c]");
+    }
+
     #[test]
     fn test_renderer_template_with_extension() {
         let temp_dir = TempDir::new().unwrap();
@@ -864,6 +1068,33 @@ mod tests {
         let output3 = renderer.render("with_include", &Empty {}).unwrap();
         assert_eq!(output3, "Before PARTIAL After");
     }
+    #[test]
+    fn test_render_to_matches_render() {
+        let theme = Theme::new().add("ok", Style::new().green().force_styling(true));
+        let template = "{% for item in items %}[ok]{{ item }}[/ok]\n{% endfor %}";
+        let data = serde_json::json!({ "items": ["one", "two", "three"] });
+
+        for mode in [OutputMode::Term, OutputMode::Text, OutputMode::TermDebug] {
+            let mut renderer = Renderer::with_output(theme.clone(), mode).unwrap();
+            renderer.add_template("list", template).unwrap();
+
+            let expected = renderer.render("list", &data).unwrap();
+            let mut out: Vec<u8> = Vec::new();
+            renderer.render_to("list", &data, &mut out).unwrap();
+
+            assert_eq!(String::from_utf8(out).unwrap(), expected, "{:?}", mode);
+        }
+    }
+
+    #[test]
+    fn test_render_to_missing_template() {
+        let mut renderer = Renderer::with_output(Theme::new(), OutputMode::TermDebug).unwrap();
+        let mut out: Vec<u8> = Vec::new();
+
+        assert!(renderer.render_to("missing", &(), &mut out).is_err());
+        assert!(out.is_empty());
+    }
+
     #[test]
     fn test_renderer_with_custom_engine() {
         use std::collections::HashMap;
@@ -944,6 +1175,131 @@ mod tests {
         assert_eq!(output, "Mock Named: content data={\"val\":42}");
     }
 
+    #[test]
+    fn test_renderer_add_filter() {
+        let theme = Theme::new().add("price", console::Style::new().bold().force_styling(true));
+        let mut renderer = Renderer::with_output(theme, OutputMode::Term).unwrap();
+        renderer
+            .add_filter("currency", |value, _args| {
+                let cents = value.as_i64().ok_or("expected an integer")?;
+                Ok(format!("${}.{:02}", cents / 100, cents % 100).into())
+            })
+            .unwrap();
+        renderer
+            .add_template(
+                "total",
+                "[price]{{ total | currency }}[/price]{{ '' | nl }}",
+            )
+            .unwrap();
+
+        let output = renderer
+            .render("total", &serde_json::json!({ "total": 1999 }))
+            .unwrap();
+        assert_eq!(output, "\x1b[1m$19.99\x1b[0m\n");
+    }
+
+    #[test]
+    fn test_renderer_add_filter_unsupported_engine() {
+        use crate::template::SimpleEngine;
+
+        let engine = Box::new(SimpleEngine::new());
+        let mut renderer =
+            Renderer::with_output_and_engine(Theme::new(), OutputMode::Text, engine).unwrap();
+        let result = renderer.add_filter("noop", |value, _| Ok(value.clone()));
+        assert!(matches!(result, Err(RenderError::OperationError(_))));
+    }
+
+    #[test]
+    fn test_renderer_add_table_spec() {
+        use crate::tabular::{Align, Column, Width};
+
+        let mut renderer = Renderer::with_output(Theme::new(), OutputMode::Text).unwrap();
+        let spec = TabularSpec::builder()
+            .column(Column::new(Width::Fixed(6)))
+            .column(Column::new(Width::Fixed(4)).align(Align::Right))
+            .separator("  ")
+            .build();
+        renderer.add_table_spec("tasks", spec).unwrap();
+        renderer
+            .add_template("task", r#"{{ row([name, status], "tasks") }}"#)
+            .unwrap();
+
+        let output = renderer
+            .render(
+                "task",
+                &serde_json::json!({ "name": "lint", "status": "ok" }),
+            )
+            .unwrap();
+        assert_eq!(output, "lint      ok");
+
+        // The spec is shared by later renders
+        let output = renderer
+            .render(
+                "task",
+                &serde_json::json!({ "name": "build", "status": "fail" }),
+            )
+            .unwrap();
+        assert_eq!(output, "build   fail");
+    }
+
+    #[test]
+    fn test_renderer_table_spec_names() {
+        let mut renderer = Renderer::with_output(Theme::new(), OutputMode::Text).unwrap();
+        assert!(renderer.table_spec_names().is_empty());
+
+        for name in ["tasks", "files", "tasks"] {
+            renderer
+                .add_table_spec(name, TabularSpec::builder().build())
+                .unwrap();
+        }
+        assert_eq!(renderer.table_spec_names(), vec!["files", "tasks"]);
+    }
+
+    #[test]
+    fn test_renderer_add_table_spec_unsupported_engine() {
+        use crate::template::SimpleEngine;
+
+        let engine = Box::new(SimpleEngine::new());
+        let mut renderer =
+            Renderer::with_output_and_engine(Theme::new(), OutputMode::Text, engine).unwrap();
+        let result = renderer.add_table_spec("tasks", TabularSpec::builder().build());
+        assert!(matches!(result, Err(RenderError::OperationError(_))));
+        assert!(renderer.table_spec_names().is_empty());
+    }
+
+    #[test]
+    fn test_renderer_render_with_warnings_names_template() {
+        let theme = Theme::new().add("title", Style::new().bold());
+        let mut renderer = Renderer::with_output(theme, OutputMode::Text).unwrap();
+        renderer
+            .add_template("report", "[title]{{ name }}[/title] [titel]x[/titel]")
+            .unwrap();
+
+        let (output, warnings) = renderer
+            .render_with_warnings("report", &serde_json::json!({ "name": "Q3" }))
+            .unwrap();
+
+        assert_eq!(output, "Q3 x");
+        assert_eq!(
+            warnings,
+            vec![RenderWarning::UndefinedStyle {
+                style: "titel".to_string(),
+                template: Some("report".to_string()),
+            }]
+        );
+        assert_eq!(
+            warnings[0].to_string(),
+            "used undefined style 'titel' in template 'report'"
+        );
+        // Plain render is unchanged
+        assert_eq!(
+            renderer
+                .render("report", &serde_json::json!({ "name": "Q3" }))
+                .unwrap(),
+            output
+        );
+    }
+
     #[test]
     fn test_renderer_with_simple_engine() {
         use crate::template::SimpleEngine;
@@ -7,6 +7,26 @@
 //!
 //! Color mode detection is typically handled automatically by the render
 //! functions. Use [`set_theme_detector`] to override detection for testing.
+//! To pin the mode for a single render, pass it explicitly with
+//! [`render_with_mode`](crate::render_with_mode) or
+//! [`Theme::resolve_styles`](crate::Theme::resolve_styles).
+//!
+//! The OS query is cached for about a second, so tight render loops
+//! don't hit the OS on every call.
+//!
+//! # Resolution order
+//!
+//! [`detect_color_mode`] picks the mode from the first source that answers
+//! (see [`ColorModeSource`]):
+//!
+//! 1. A detector installed with [`set_theme_detector`]
+//! 2. The terminal background reported in `COLORFGBG` (see [`parse_colorfgbg`])
+//! 3. The OS preference, which falls back to light when the OS gives no answer
+//!
+//! The terminal comes before the OS because it describes the window the
+//! output actually lands in, and because the OS query cannot tell "light"
+//! apart from "no preference". [`resolve_color_mode`] reports which source
+//! was used.
 //!
 //! ```rust
 //! use standout_render::{Theme, ColorMode, set_theme_detector};
@@ -28,6 +48,7 @@
 use dark_light::{detect as detect_os_theme, Mode as OsThemeMode};
 use once_cell::sync::Lazy;
 use std::sync::Mutex;
+use std::time::{Duration, Instant};
 
 /// The user's preferred color mode.
 #[derive(Debug, Clone, Copy, PartialEq, Eq)]
@@ -38,9 +59,25 @@ pub enum ColorMode {
     Dark,
 }
 
+/// Where [`resolve_color_mode`] found the color mode.
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum ColorModeSource {
+    /// A detector installed with [`set_theme_detector`].
+    Detector,
+    /// The terminal background from the `COLORFGBG` environment variable.
+    Terminal,
+    /// The OS light/dark preference.
+    Os,
+}
+
 type ThemeDetector = fn() -> ColorMode;
 
-static THEME_DETECTOR: Lazy<Mutex<ThemeDetector>> = Lazy::new(|| Mutex::new(os_theme_detector));
+static THEME_DETECTOR: Lazy<Mutex<Option<ThemeDetector>>> = Lazy::new(|| Mutex::new(None));
+
+/// How long an OS color mode query is reused before asking the OS again.
+const OS_DETECTION_TTL: Duration = Duration::from_secs(1);
+
+static OS_MODE_CACHE: Lazy<Mutex<Option<(Instant, ColorMode)>>> = Lazy::new(|| Mutex::new(None));
 
 /// Overrides the detector used to determine whether the user prefers a light or dark theme.
 ///
@@ -60,30 +97,106 @@ static THEME_DETECTOR: Lazy<Mutex<ThemeDetector>> = Lazy::new(|| Mutex::new(os_t
 /// ```
 pub fn set_theme_detector(detector: ThemeDetector) {
     let mut guard = THEME_DETECTOR.lock().unwrap();
-    *guard = detector;
+    *guard = Some(detector);
 }
 
-/// Detects the user's preferred color mode from the OS.
+/// Detects the user's preferred color mode.
 ///
-/// Uses the `dark-light` crate to query the OS for the current theme preference.
-/// The detector can be overridden via [`set_theme_detector`] for testing.
+/// Checks the terminal background in `COLORFGBG` first, then asks the OS
+/// through the `dark-light` crate (see the [module docs](self) for the full
+/// order). The OS answer is cached for about a second. The detector can be
+/// overridden via [`set_theme_detector`] for testing; custom detectors are
+/// called on every detection and never cached.
+pub fn detect_color_mode() -> ColorMode {
+    resolve_color_mode().0
+}
+
+/// Detects the color mode and reports which source decided it.
 ///
-/// # Returns
+/// Useful for diagnosing why a theme shows its light or dark variant.
 ///
-/// - [`ColorMode::Light`] if the OS is in light mode
-/// - [`ColorMode::Dark`] if the OS is in dark mode
-pub fn detect_color_mode() -> ColorMode {
-    let detector = THEME_DETECTOR.lock().unwrap();
-    (*detector)()
+/// # Example
+///
+/// ```rust
+/// use standout_render::{resolve_color_mode, set_theme_detector, ColorMode, ColorModeSource};
+///
+/// set_theme_detector(|| ColorMode::Dark);
+/// assert_eq!(resolve_color_mode(), (ColorMode::Dark, ColorModeSource::Detector));
+/// ```
+pub fn resolve_color_mode() -> (ColorMode, ColorModeSource) {
+    let detector = *THEME_DETECTOR.lock().unwrap();
+    match detector {
+        Some(detector) => (detector(), ColorModeSource::Detector),
+        None => resolve_from(|key| std::env::var(key).ok(), cached_os_mode),
+    }
+}
+
+/// Reads the background color from a `COLORFGBG` value.
+///
+/// Terminals such as rxvt and Konsole set `COLORFGBG` to `"fg;bg"` (some
+/// add a middle field, as in `"fg;default;bg"`), where each field is an
+/// ANSI color index. Backgrounds 0-6 and 8 are dark; 7 and 9-15 are light.
+/// Returns `None` when the last field is missing or not a color index.
+///
+/// # Example
+///
+/// ```rust
+/// use standout_render::{parse_colorfgbg, ColorMode};
+///
+/// assert_eq!(parse_colorfgbg("15;0"), Some(ColorMode::Dark));
+/// assert_eq!(parse_colorfgbg("0;default;15"), Some(ColorMode::Light));
+/// assert_eq!(parse_colorfgbg("default;default"), None);
+/// ```
+pub fn parse_colorfgbg(value: &str) -> Option<ColorMode> {
+    let (_, bg) = value.rsplit_once(';')?;
+    match bg.trim().parse::<u8>().ok()? {
+        0..=6 | 8 => Some(ColorMode::Dark),
+        7 | 9..=15 => Some(ColorMode::Light),
+        _ => None,
+    }
 }
 
-fn os_theme_detector() -> ColorMode {
+/// Resolves the mode without a detector override, reading the environment
+/// through `lookup` and asking `os` only when the terminal gives no answer.
+fn resolve_from<F>(lookup: F, os: fn() -> ColorMode) -> (ColorMode, ColorModeSource)
+where
+    F: Fn(&str) -> Option<String>,
+{
+    match lookup("COLORFGBG").as_deref().and_then(parse_colorfgbg) {
+        Some(mode) => (mode, ColorModeSource::Terminal),
+        None => (os(), ColorModeSource::Os),
+    }
+}
+
+fn cached_os_mode() -> ColorMode {
+    let mut cache = OS_MODE_CACHE.lock().unwrap();
+    cached_mode(&mut cache, Instant::now(), query_os_theme)
+}
+
+fn query_os_theme() -> ColorMode {
     match detect_os_theme() {
         OsThemeMode::Dark => ColorMode::Dark,
         OsThemeMode::Light => ColorMode::Light,
     }
 }
 
+/// Returns the cached mode if it is younger than [`OS_DETECTION_TTL`],
+/// otherwise calls `query` and stores its answer.
+fn cached_mode(
+    cache: &mut Option<(Instant, ColorMode)>,
+    now: Instant,
+    query: impl FnOnce() -> ColorMode,
+) -> ColorMode {
+    match *cache {
+        Some((at, mode)) if now.saturating_duration_since(at) < OS_DETECTION_TTL => mode,
+        _ => {
+            let mode = query();
+            *cache = Some((now, mode));
+            mode
+        }
+    }
+}
+
 #[cfg(test)]
 mod tests {
     use super::*;
@@ -157,4 +270,87 @@ mod tests {
         set_theme_detector(|| ColorMode::Light);
         assert_eq!(detect_color_mode(), ColorMode::Light);
     }
+
+    #[test]
+    #[serial]
+    fn test_resolve_color_mode_reports_detector() {
+        set_theme_detector(|| ColorMode::Dark);
+        assert_eq!(
+            resolve_color_mode(),
+            (ColorMode::Dark, ColorModeSource::Detector)
+        );
+        set_theme_detector(|| ColorMode::Light);
+    }
+
+    #[test]
+    fn test_parse_colorfgbg() {
+        assert_eq!(parse_colorfgbg("15;0"), Some(ColorMode::Dark));
+        assert_eq!(parse_colorfgbg("7;8"), Some(ColorMode::Dark));
+        assert_eq!(parse_colorfgbg("0;15"), Some(ColorMode::Light));
+        assert_eq!(parse_colorfgbg("0;7"), Some(ColorMode::Light));
+        assert_eq!(parse_colorfgbg("15;default;0"), Some(ColorMode::Dark));
+        assert_eq!(parse_colorfgbg("0;default;11"), Some(ColorMode::Light));
+    }
+
+    #[test]
+    fn test_parse_colorfgbg_rejects_unknown_values() {
+        assert_eq!(parse_colorfgbg(""), None);
+        assert_eq!(parse_colorfgbg("15"), None);
+        assert_eq!(parse_colorfgbg("15;default"), None);
+        assert_eq!(parse_colorfgbg("15;16"), None);
+        assert_eq!(parse_colorfgbg("15;"), None);
+    }
+
+    #[test]
+    fn test_resolve_prefers_terminal_over_os() {
+        let lookup = |key: &str| (key == "COLORFGBG").then(|| "15;0".to_string());
+        assert_eq!(
+            resolve_from(lookup, || panic!("should not query the OS")),
+            (ColorMode::Dark, ColorModeSource::Terminal)
+        );
+    }
+
+    #[test]
+    fn test_resolve_falls_back_to_os() {
+        assert_eq!(
+            resolve_from(|_| None, || ColorMode::Light),
+            (ColorMode::Light, ColorModeSource::Os)
+        );
+
+        let unparsable = |_: &str| Some("default;default".to_string());
+        assert_eq!(
+            resolve_from(unparsable, || ColorMode::Dark),
+            (ColorMode::Dark, ColorModeSource::Os)
+        );
+    }
+
+    #[test]
+    fn test_cached_mode_reuses_answer_within_ttl() {
+        let start = Instant::now();
+        let mut cache = None;
+
+        assert_eq!(
+            cached_mode(&mut cache, start, || ColorMode::Dark),
+            ColorMode::Dark
+        );
+        let later = start + OS_DETECTION_TTL / 2;
+        assert_eq!(
+            cached_mode(&mut cache, later, || panic!("should use cache")),
+            ColorMode::Dark
+        );
+    }
+
+    #[test]
+    fn test_cached_mode_requeries_after_ttl() {
+        let start = Instant::now();
+        let mut cache = None;
+
+        cached_mode(&mut cache, start, || ColorMode::Dark);
+        let expired = start + OS_DETECTION_TTL;
+        assert_eq!(
+            cached_mode(&mut cache, expired, || ColorMode::Light),
+            ColorMode::Light
+        );
+        assert_eq!(cache.map(|(_, mode)| mode), Some(ColorMode::Light));
+    }
 }
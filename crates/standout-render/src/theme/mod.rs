@@ -38,7 +38,9 @@
 //!
 //! ## Color Mode Detection
 //!
-//! [`detect_color_mode`] queries the OS for the user's preferred scheme. Override
+//! [`detect_color_mode`] reads the terminal background from `COLORFGBG` and
+//! otherwise queries the OS for the user's preferred scheme;
+//! [`resolve_color_mode`] also reports which of the two decided. Override
 //! it for testing with [`set_theme_detector`]:
 //!
 //! ```rust,ignore
@@ -76,8 +78,13 @@
 //! - [`crate::style`]: Low-level style primitives and aliasing
 
 mod adaptive;
+mod palette;
 #[allow(clippy::module_inception)]
 mod theme;
 
-pub use adaptive::{detect_color_mode, set_theme_detector, ColorMode};
+pub use adaptive::{
+    detect_color_mode, parse_colorfgbg, resolve_color_mode, set_theme_detector, ColorMode,
+    ColorModeSource,
+};
+pub use palette::Palette;
 pub use theme::Theme;
@@ -0,0 +1,282 @@
+//! Color-blind-safe palette remapping.
+//!
+//! A [`Palette`] substitutes colors that are hard to tell apart for a given
+//! color vision deficiency with hues that remain distinguishable. It is applied
+//! to a whole theme with [`Theme::remap_palette`](super::Theme::remap_palette).
+//!
+//! # Best-Effort Substitution
+//!
+//! Remapping works on hue families, not on exact colors. Each palette names the
+//! families that collapse together (red and green for deuteranopia and
+//! protanopia, blue and yellow for tritanopia) and replaces every sufficiently
+//! saturated color in those families with a fixed, safer color. Grays, and hues
+//! outside the problem families, are left untouched. Text attributes (bold, dim,
+//! italic, ...) are always preserved.
+//!
+//! This makes typical semantic colors (red errors, green success) distinguishable,
+//! but it is not a color-accurate simulation. Themes with carefully tuned colors
+//! may prefer to ship a dedicated accessible variant instead.
+
+use console::{Color, Style};
+
+use crate::util::ansi256_to_rgb;
+
+/// A color vision deficiency to remap theme colors for.
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Hash)]
+pub enum Palette {
+    /// Reduced sensitivity to green light (the most common form).
+    ///
+    /// Reds become orange and greens become blue.
+    Deuteranopia,
+    /// Reduced sensitivity to red light.
+    ///
+    /// Reds become yellow-orange (reds otherwise appear dark) and greens become sky blue.
+    Protanopia,
+    /// Reduced sensitivity to blue light.
+    ///
+    /// Blues become teal and yellows become pink.
+    Tritanopia,
+}
+
+/// A range of hues (in degrees) and the 256-color index that replaces it.
+struct Substitution {
+    from: f32,
+    to: f32,
+    replacement: u8,
+}
+
+/// Colors with less saturation than this are treated as grays and never remapped.
+const MIN_SATURATION: f32 = 0.25;
+
+impl Palette {
+    fn substitutions(self) -> &'static [Substitution] {
+        match self {
+            Palette::Deuteranopia => &[
+                // Reds → orange
+                Substitution {
+                    from: 330.0,
+                    to: 30.0,
+                    replacement: 208,
+                },
+                // Greens → blue
+                Substitution {
+                    from: 75.0,
+                    to: 165.0,
+                    replacement: 33,
+                },
+            ],
+            Palette::Protanopia => &[
+                // Reds → yellow-orange
+                Substitution {
+                    from: 330.0,
+                    to: 30.0,
+                    replacement: 214,
+                },
+                // Greens → sky blue
+                Substitution {
+                    from: 75.0,
+                    to: 165.0,
+                    replacement: 39,
+                },
+            ],
+            Palette::Tritanopia => &[
+                // Blues → teal
+                Substitution {
+                    from: 195.0,
+                    to: 255.0,
+                    replacement: 30,
+                },
+                // Yellows → pink
+                Substitution {
+                    from: 45.0,
+                    to: 75.0,
+                    replacement: 211,
+                },
+            ],
+        }
+    }
+
+    /// Returns the safe replacement for `color`, or the color itself if it
+    /// doesn't belong to one of this palette's problem hue families.
+    pub fn remap_color(self, color: Color) -> Color {
+        let (hue, saturation) = hue_and_saturation(color_to_rgb(color));
+        if saturation < MIN_SATURATION {
+            return color;
+        }
+
+        for sub in self.substitutions() {
+            let in_range = if sub.from <= sub.to {
+                hue >= sub.from && hue < sub.to
+            } else {
+                // Range wraps around 0° (reds)
+                hue >= sub.from || hue < sub.to
+            };
+            if in_range {
+                return Color::Color256(sub.replacement);
+            }
+        }
+
+        color
+    }
+
+    /// Returns `style` with its foreground and background colors remapped.
+    ///
+    /// All other properties of the style (attributes, forced styling) are kept.
+    pub fn remap_style(self, style: &Style) -> Style {
+        let (fg, bg) = style_colors(style);
+        let mut remapped = style.clone();
+        if let Some(fg) = fg {
+            let new_fg = self.remap_color(fg);
+            if new_fg != fg {
+                remapped = remapped.fg(new_fg);
+            }
+        }
+        if let Some(bg) = bg {
+            let new_bg = self.remap_color(bg);
+            if new_bg != bg {
+                remapped = remapped.bg(new_bg);
+            }
+        }
+        remapped
+    }
+}
+
+/// Extracts the foreground and background colors of a style.
+///
+/// `console::Style` doesn't expose its colors, so this renders a probe with
+/// styling forced on and reads the colors back from the SGR sequences.
+fn style_colors(style: &Style) -> (Option<Color>, Option<Color>) {
+    let probe = style.clone().force_styling(true).apply_to("x").to_string();
+    let codes = probe.split('x').next().unwrap_or("");
+
+    let mut fg = None;
+    let mut bg = None;
+    for sequence in codes.split("\x1b[").filter(|s| !s.is_empty()) {
+        let params: Vec<&str> = sequence.trim_end_matches('m').split(';').collect();
+        match params.as_slice() {
+            ["38", "5", n] => fg = n.parse().ok().map(Color::Color256),
+            ["48", "5", n] => bg = n.parse().ok().map(Color::Color256),
+            [code] => match code.parse::<u8>() {
+                Ok(n @ 30..=37) => fg = Some(basic_color(n - 30)),
+                Ok(n @ 40..=47) => bg = Some(basic_color(n - 40)),
+                _ => {}
+            },
+            _ => {}
+        }
+    }
+    (fg, bg)
+}
+
+fn basic_color(index: u8) -> Color {
+    match index {
+        0 => Color::Black,
+        1 => Color::Red,
+        2 => Color::Green,
+        3 => Color::Yellow,
+        4 => Color::Blue,
+        5 => Color::Magenta,
+        6 => Color::Cyan,
+        _ => Color::White,
+    }
+}
+
+fn color_to_rgb(color: Color) -> (u8, u8, u8) {
+    match color {
+        Color::Black => ansi256_to_rgb(0),
+        Color::Red => ansi256_to_rgb(1),
+        Color::Green => ansi256_to_rgb(2),
+        Color::Yellow => ansi256_to_rgb(3),
+        Color::Blue => ansi256_to_rgb(4),
+        Color::Magenta => ansi256_to_rgb(5),
+        Color::Cyan => ansi256_to_rgb(6),
+        Color::White => ansi256_to_rgb(7),
+        Color::Color256(n) => ansi256_to_rgb(n),
+    }
+}
+
+/// Returns the HSV hue (degrees) and saturation (0-1) of an RGB color.
+fn hue_and_saturation((r, g, b): (u8, u8, u8)) -> (f32, f32) {
+    let r = r as f32 / 255.0;
+    let g = g as f32 / 255.0;
+    let b = b as f32 / 255.0;
+    let max = r.max(g).max(b);
+    let min = r.min(g).min(b);
+    let delta = max - min;
+
+    if delta == 0.0 {
+        return (0.0, 0.0);
+    }
+
+    let hue = if max == r {
+        60.0 * ((g - b) / delta).rem_euclid(6.0)
+    } else if max == g {
+        60.0 * ((b - r) / delta + 2.0)
+    } else {
+        60.0 * ((r - g) / delta + 4.0)
+    };
+
+    (hue, delta / max)
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_style_colors_roundtrip() {
+        let style = Style::new().red().on_blue().bold();
+        assert_eq!(style_colors(&style), (Some(Color::Red), Some(Color::Blue)));
+
+        let style = Style::new().color256(208);
+        assert_eq!(style_colors(&style), (Some(Color::Color256(208)), None));
+
+        assert_eq!(style_colors(&Style::new().dim()), (None, None));
+    }
+
+    #[test]
+    fn test_deuteranopia_red_and_green_diverge() {
+        let red = Palette::Deuteranopia.remap_color(Color::Red);
+        let green = Palette::Deuteranopia.remap_color(Color::Green);
+        assert_ne!(red, Color::Red);
+        assert_ne!(green, Color::Green);
+        assert_ne!(red, green);
+    }
+
+    #[test]
+    fn test_grays_untouched() {
+        for palette in [
+            Palette::Deuteranopia,
+            Palette::Protanopia,
+            Palette::Tritanopia,
+        ] {
+            assert_eq!(palette.remap_color(Color::White), Color::White);
+            assert_eq!(palette.remap_color(Color::Black), Color::Black);
+            assert_eq!(
+                palette.remap_color(Color::Color256(244)),
+                Color::Color256(244)
+            );
+        }
+    }
+
+    #[test]
+    fn test_tritanopia_remaps_blue_and_yellow() {
+        assert_ne!(Palette::Tritanopia.remap_color(Color::Blue), Color::Blue);
+        assert_ne!(
+            Palette::Tritanopia.remap_color(Color::Yellow),
+            Color::Yellow
+        );
+        assert_eq!(Palette::Tritanopia.remap_color(Color::Red), Color::Red);
+    }
+
+    #[test]
+    fn test_remap_style_preserves_attributes() {
+        let style = Style::new().red().bold().italic();
+        let remapped = Palette::Protanopia.remap_style(&style);
+
+        let expected = Style::new().color256(214).bold().italic();
+        assert_eq!(
+            remapped.force_styling(true).apply_to("x").to_string(),
+            expected.force_styling(true).apply_to("x").to_string()
+        );
+    }
+}
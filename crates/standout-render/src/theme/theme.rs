@@ -63,12 +63,14 @@ use std::collections::HashMap;
 use std::path::{Path, PathBuf};
 
 use console::Style;
+use standout_bbparser::{BBParser, TagTransform};
 
 use super::super::style::{
     parse_stylesheet, StyleValidationError, StyleValue, Styles, StylesheetError, ThemeVariants,
 };
 
 use super::adaptive::ColorMode;
+use super::palette::Palette;
 
 /// A named collection of styles used when rendering templates.
 ///
@@ -447,13 +449,29 @@ impl Theme {
     ///
     /// This is a convenience wrapper around [`resolve_styles`](Self::resolve_styles).
     pub fn get_style(&self, name: &str, mode: Option<ColorMode>) -> Option<Style> {
-        let styles = self.resolve_styles(mode);
-        // Styles::resolve is crate-private, so we have to use to_resolved_map or check internal.
-        // Wait, Styles::resolve is pub(crate). We are in rendering/theme/theme.rs,
-        // Styles is in rendering/style/registry.rs. Same crate.
-        // But Theme is in `rendering::theme`, Styles in `rendering::style`.
-        // They are different modules. `pub(crate)` is visible.
-        styles.resolve(name).cloned()
+        self.resolve_styles(mode).resolve(name)
+    }
+
+    /// Builds a [`BBParser`] whose tags use this theme's styles for the given
+    /// mode.
+    ///
+    /// Tag names go through the same alias chains as the `style()` filter, so
+    /// `[disabled]` works when `disabled` is an alias of `muted`. Names that
+    /// don't resolve (dangling aliases, cycles) are treated as unknown tags.
+    ///
+    /// ```rust
+    /// use standout_render::{TagTransform, Theme};
+    /// use console::Style;
+    ///
+    /// let theme = Theme::new()
+    ///     .add("muted", Style::new().dim())
+    ///     .add("disabled", "muted");
+    ///
+    /// let parser = theme.tag_parser(TagTransform::Remove, None);
+    /// assert!(parser.validate("[disabled]off[/disabled]").is_ok());
+    /// ```
+    pub fn tag_parser(&self, transform: TagTransform, mode: Option<ColorMode>) -> BBParser {
+        BBParser::new(self.resolve_styles(mode).to_resolved_map(), transform)
     }
 
     /// Returns the number of light mode overrides.
@@ -466,6 +484,36 @@ impl Theme {
         self.dark.len()
     }
 
+    /// Remaps the theme's colors for a color vision deficiency.
+    ///
+    /// Every concrete style (base, light and dark variants) has its colors
+    /// passed through [`Palette::remap_style`]; attributes like bold or dim are
+    /// preserved. Aliases are left untouched and resolve to the remapped styles.
+    ///
+    /// This is a best-effort substitution of problematic hue families, not an
+    /// exact color-vision simulation. See [`Palette`] for details.
+    ///
+    /// # Example
+    ///
+    /// ```rust
+    /// use standout_render::{Palette, Theme};
+    /// use console::Style;
+    ///
+    /// let theme = Theme::new()
+    ///     .add("error", Style::new().red().bold())
+    ///     .add("success", Style::new().green())
+    ///     .add("failure", "error")
+    ///     .remap_palette(Palette::Deuteranopia);
+    /// ```
+    pub fn remap_palette(mut self, palette: Palette) -> Self {
+        for styles in [&mut self.base, &mut self.light, &mut self.dark] {
+            for style in styles.values_mut() {
+                *style = palette.remap_style(style);
+            }
+        }
+        self
+    }
+
     /// Merges another theme into this one.
     ///
     /// Styles from `other` take precedence over styles in `self`.
@@ -659,6 +707,38 @@ mod tests {
     // YAML parsing tests
     // =========================================================================
 
+    #[test]
+    fn test_theme_tag_parser_resolves_aliases() {
+        let theme = Theme::new()
+            .add("muted", Style::new().dim().force_styling(true))
+            .add("disabled", "muted")
+            .add("inactive", "disabled");
+
+        let parser = theme.tag_parser(TagTransform::Apply, None);
+        assert_eq!(
+            parser.parse("[inactive]off[/inactive]"),
+            "\x1b[2moff\x1b[0m"
+        );
+        assert!(parser.validate("[disabled]x[/disabled]").is_ok());
+    }
+
+    #[test]
+    fn test_theme_tag_parser_uses_mode_overrides() {
+        let theme = Theme::new()
+            .add_adaptive(
+                "panel",
+                Style::new(),
+                Some(Style::new().red().force_styling(true)),
+                Some(Style::new().green().force_styling(true)),
+            )
+            .add("box", "panel");
+
+        let light = theme.tag_parser(TagTransform::Apply, Some(ColorMode::Light));
+        assert_eq!(light.parse("[box]x[/box]"), "\x1b[31mx\x1b[0m");
+        let dark = theme.tag_parser(TagTransform::Apply, Some(ColorMode::Dark));
+        assert_eq!(dark.parse("[box]x[/box]"), "\x1b[32mx\x1b[0m");
+    }
+
     #[test]
     fn test_theme_from_yaml_simple() {
         let theme = Theme::from_yaml(
@@ -856,6 +936,39 @@ mod tests {
         assert!(result.is_err());
     }
 
+    #[test]
+    fn test_remap_palette_red_becomes_non_green() {
+        let theme = Theme::new()
+            .add("error", Style::new().red().bold())
+            .add("success", Style::new().green())
+            .add("failure", "error")
+            .remap_palette(Palette::Deuteranopia);
+
+        let render = |name: &str| {
+            theme
+                .get_style(name, None)
+                .unwrap()
+                .force_styling(true)
+                .apply_to("x")
+                .to_string()
+        };
+
+        let error = render("error");
+        let success = render("success");
+
+        // Pure red is replaced by orange, keeping bold
+        assert!(!error.contains("\x1b[31m"), "red survived: {:?}", error);
+        assert!(error.contains("\x1b[38;5;208m"), "got: {:?}", error);
+        assert!(error.contains("\x1b[1m"));
+
+        // Green is replaced by a hue that is not green either
+        assert!(!success.contains("\x1b[32m"));
+        assert_ne!(error.replace("\x1b[1m", ""), success);
+
+        // Aliases resolve through the remapped concrete style
+        assert_eq!(render("failure"), error);
+    }
+
     #[test]
     fn test_theme_merge() {
         let base = Theme::new()
@@ -1,6 +1,12 @@
 //! Utility functions for text processing and color conversion.
 
+use serde::Serialize;
 use serde_json::Value;
+
+use crate::error::RenderError;
+use crate::tabular::{
+    display_width, truncate_end, truncate_middle, truncate_start, Align, TruncateAt,
+};
 use std::collections::{BTreeMap, BTreeSet};
 
 /// Converts an RGB triplet to the nearest ANSI 256-color palette index.
@@ -33,6 +39,79 @@ pub fn rgb_to_ansi256((r, g, b): (u8, u8, u8)) -> u8 {
     }
 }
 
+/// The xterm default RGB values for the 16 basic ANSI colors.
+const ANSI16_PALETTE: [(u8, u8, u8); 16] = [
+    (0, 0, 0),
+    (205, 0, 0),
+    (0, 205, 0),
+    (205, 205, 0),
+    (0, 0, 238),
+    (205, 0, 205),
+    (0, 205, 205),
+    (229, 229, 229),
+    (127, 127, 127),
+    (255, 0, 0),
+    (0, 255, 0),
+    (255, 255, 0),
+    (92, 92, 255),
+    (255, 0, 255),
+    (0, 255, 255),
+    (255, 255, 255),
+];
+
+/// Converts an RGB triplet to the nearest of the 16 basic ANSI colors.
+///
+/// Returns an index from 0 to 15, where 0-7 are the normal colors and
+/// 8-15 their bright variants.
+///
+/// # Example
+///
+/// ```rust
+/// use standout_render::rgb_to_ansi16;
+///
+/// // Pure red maps to bright red
+/// assert_eq!(rgb_to_ansi16((255, 0, 0)), 9);
+///
+/// // Dark red maps to normal red
+/// assert_eq!(rgb_to_ansi16((190, 10, 10)), 1);
+/// ```
+pub fn rgb_to_ansi16((r, g, b): (u8, u8, u8)) -> u8 {
+    let distance = |(pr, pg, pb): (u8, u8, u8)| {
+        let dr = r as i32 - pr as i32;
+        let dg = g as i32 - pg as i32;
+        let db = b as i32 - pb as i32;
+        dr * dr + dg * dg + db * db
+    };
+
+    ANSI16_PALETTE
+        .iter()
+        .enumerate()
+        .min_by_key(|(_, rgb)| distance(**rgb))
+        .map(|(index, _)| index as u8)
+        .unwrap_or(0)
+}
+
+/// Returns the approximate RGB value of an ANSI 256-color palette index.
+pub(crate) fn ansi256_to_rgb(index: u8) -> (u8, u8, u8) {
+    const CUBE_LEVELS: [u8; 6] = [0, 95, 135, 175, 215, 255];
+
+    match index {
+        0..=15 => ANSI16_PALETTE[index as usize],
+        16..=231 => {
+            let i = index - 16;
+            (
+                CUBE_LEVELS[(i / 36) as usize],
+                CUBE_LEVELS[((i / 6) % 6) as usize],
+                CUBE_LEVELS[(i % 6) as usize],
+            )
+        }
+        232..=255 => {
+            let level = 8 + (index - 232) * 10;
+            (level, level, level)
+        }
+    }
+}
+
 /// Placeholder helper for true-color output.
 ///
 /// Currently returns the RGB triplet unchanged so it can be handed
@@ -46,6 +125,7 @@ pub fn rgb_to_truecolor(rgb: (u8, u8, u8)) -> (u8, u8, u8) {
 /// Uses Unicode width calculations for proper handling of CJK and other wide characters.
 /// If the string fits within `max_width`, it is returned unchanged. If truncation is
 /// needed, characters are removed from the end and replaced with `…` (ellipsis).
+/// Use [`truncate_to_width_with`] for another marker or position.
 ///
 /// # Arguments
 ///
@@ -61,29 +141,221 @@ pub fn rgb_to_truecolor(rgb: (u8, u8, u8)) -> (u8, u8, u8) {
 /// assert_eq!(truncate_to_width("Hello World", 6), "Hello…");
 /// ```
 pub fn truncate_to_width(s: &str, max_width: usize) -> String {
-    use unicode_width::{UnicodeWidthChar, UnicodeWidthStr};
+    // Zero width has always produced a lone ellipsis here; keep that.
+    if max_width == 0 && !s.is_empty() {
+        return "…".to_string();
+    }
+    truncate_to_width_with(s, max_width, TruncateOptions::default())
+}
+
+/// How [`truncate_to_width_with`] shortens a string.
+#[derive(Clone, Debug, PartialEq, Eq)]
+pub struct TruncateOptions {
+    /// Marker inserted where text was removed. May be several characters wide.
+    pub ellipsis: String,
+    /// Which part of the string is removed.
+    pub position: TruncateAt,
+}
+
+impl Default for TruncateOptions {
+    /// `…` at the end, as used by [`truncate_to_width`].
+    fn default() -> Self {
+        Self {
+            ellipsis: "…".to_string(),
+            position: TruncateAt::End,
+        }
+    }
+}
+
+/// Truncates a string to fit within a maximum display width, with a custom
+/// marker and position.
+///
+/// The display widths of the ellipsis and of the kept text are measured
+/// together, so the result never exceeds `max_width` even with wide (CJK)
+/// characters or multi-character markers. Middle truncation keeps the extra
+/// column, if any, on the right.
+///
+/// # Example
+///
+/// ```rust
+/// use standout_render::tabular::TruncateAt;
+/// use standout_render::{truncate_to_width_with, TruncateOptions};
+///
+/// let options = TruncateOptions {
+///     ellipsis: "...".to_string(),
+///     position: TruncateAt::Middle,
+/// };
+/// assert_eq!(
+///     truncate_to_width_with("/very/long/path/to/file.txt", 16, options),
+///     "/very/...ile.txt"
+/// );
+/// ```
+pub fn truncate_to_width_with(s: &str, max_width: usize, options: TruncateOptions) -> String {
+    match options.position {
+        TruncateAt::End => truncate_end(s, max_width, &options.ellipsis),
+        TruncateAt::Start => truncate_start(s, max_width, &options.ellipsis),
+        TruncateAt::Middle => truncate_middle(s, max_width, &options.ellipsis),
+    }
+}
 
-    // If the string fits, return it unchanged
-    if s.width() <= max_width {
-        return s.to_string();
+/// Pads a string to exactly `width` display columns.
+///
+/// The complement to [`truncate_to_width`]: shorter strings are padded with
+/// `fill` according to `align` (centered text puts the odd column on the
+/// right), and longer strings are truncated with `…`. Widths are measured in
+/// terminal columns, so wide characters, emoji and combining marks line up.
+/// If `fill` is itself wide, any column it can't cover is filled with a space.
+///
+/// The result is always exactly `width` columns: a truncation that lands one
+/// column short because of a wide character is padded too, and a width of `0`
+/// gives an empty string.
+///
+/// # Example
+///
+/// ```rust
+/// use standout_render::pad_to_width;
+/// use standout_render::tabular::Align;
+///
+/// assert_eq!(pad_to_width("42", 5, Align::Right, ' '), "   42");
+/// assert_eq!(pad_to_width("日本", 6, Align::Left, '.'), "日本..");
+/// assert_eq!(pad_to_width("Hello World", 6, Align::Left, ' '), "Hello…");
+/// ```
+pub fn pad_to_width(s: &str, width: usize, align: Align, fill: char) -> String {
+    if width == 0 {
+        return String::new();
+    }
+    let current = display_width(s);
+    if current > width {
+        return pad_to_width(&truncate_to_width(s, width), width, align, fill);
     }
 
-    let mut result = String::new();
-    let mut current_width = 0;
-    // Reserve 1 char for ellipsis
-    let limit = max_width.saturating_sub(1);
+    let gap = width - current;
+    let (left, right) = match align {
+        Align::Left => (0, gap),
+        Align::Right => (gap, 0),
+        Align::Center => (gap / 2, gap - gap / 2),
+    };
+    format!(
+        "{}{}{}",
+        fill_columns(left, fill),
+        s,
+        fill_columns(right, fill)
+    )
+}
 
-    for c in s.chars() {
-        let char_width = c.width().unwrap_or(0);
-        if current_width + char_width > limit {
-            result.push('…');
-            return result;
+/// Builds `columns` display columns of `fill`, topping up with spaces.
+fn fill_columns(columns: usize, fill: char) -> String {
+    let fill_width = display_width(fill.encode_utf8(&mut [0; 4]));
+    if fill_width == 0 {
+        return " ".repeat(columns);
+    }
+    let mut out = fill.to_string().repeat(columns / fill_width);
+    out.push_str(&" ".repeat(columns % fill_width));
+    out
+}
+
+/// Formats a number of milliseconds as a short human duration, e.g. `1m 33s`.
+///
+/// Output starts at the largest non-zero unit (`d`, `h`, `m`, `s`, `ms`) and
+/// covers `precision` units from there; smaller units are cut off, not
+/// rounded, and zero components are skipped. A `precision` of `0` counts as
+/// `1`. Zero is `0s` and negative durations get a leading `-`.
+///
+/// # Example
+///
+/// ```rust
+/// use standout_render::format_duration;
+///
+/// assert_eq!(format_duration(93_000, 2), "1m 33s");
+/// assert_eq!(format_duration(7_530_000, 2), "2h 5m");
+/// assert_eq!(format_duration(7_530_000, 1), "2h");
+/// assert_eq!(format_duration(999, 2), "999ms");
+/// assert_eq!(format_duration(0, 2), "0s");
+/// ```
+pub fn format_duration(ms: i64, precision: usize) -> String {
+    const UNITS: [(&str, u64); 5] = [
+        ("d", 86_400_000),
+        ("h", 3_600_000),
+        ("m", 60_000),
+        ("s", 1_000),
+        ("ms", 1),
+    ];
+
+    if ms == 0 {
+        return "0s".to_string();
+    }
+
+    let mut rest = ms.unsigned_abs();
+    let mut slots = precision.max(1);
+    let mut parts = Vec::new();
+    for (suffix, size) in UNITS {
+        let count = rest / size;
+        rest %= size;
+        if parts.is_empty() && count == 0 {
+            continue;
+        }
+        if count > 0 {
+            parts.push(format!("{}{}", count, suffix));
+        }
+        slots -= 1;
+        if slots == 0 {
+            break;
         }
-        result.push(c);
-        current_width += char_width;
     }
 
-    result
+    let sign = if ms < 0 { "-" } else { "" };
+    format!("{}{}", sign, parts.join(" "))
+}
+
+/// Serializes a JSON Value as newline-delimited JSON (JSON Lines).
+///
+/// - If `value` is an Array, each element is written as compact JSON on its own line.
+/// - Any other value is written as a single compact line.
+///
+/// Lines are separated by `\n` with no trailing newline; an empty array yields
+/// an empty string.
+pub fn to_json_lines(value: &Value) -> String {
+    match value {
+        Value::Array(items) => items
+            .iter()
+            .map(Value::to_string)
+            .collect::<Vec<_>>()
+            .join("\n"),
+        _ => value.to_string(),
+    }
+}
+
+/// Serializes data as a TOML document.
+///
+/// TOML documents must be tables, so data that serializes to an array or a
+/// scalar is rejected with a [`RenderError::SerializationError`] naming the
+/// actual type. Without the `toml` feature this always returns an error.
+pub fn to_toml<T: Serialize + ?Sized>(data: &T) -> Result<String, RenderError> {
+    let kind = match serde_json::to_value(data)? {
+        Value::Object(_) => None,
+        Value::Array(_) => Some("an array"),
+        Value::Null => Some("null"),
+        Value::Bool(_) => Some("a boolean"),
+        Value::Number(_) => Some("a number"),
+        Value::String(_) => Some("a string"),
+    };
+    if let Some(kind) = kind {
+        return Err(RenderError::SerializationError(format!(
+            "TOML output requires a table at the top level, but the data is {}",
+            kind
+        )));
+    }
+
+    #[cfg(feature = "toml")]
+    {
+        toml::to_string(data).map_err(|e| RenderError::SerializationError(e.to_string()))
+    }
+    #[cfg(not(feature = "toml"))]
+    {
+        Err(RenderError::SerializationError(
+            "TOML output requires the `toml` feature".to_string(),
+        ))
+    }
 }
 
 /// Flattens a JSON Value into a list of records for CSV export.
@@ -178,6 +450,60 @@ fn flatten_recursive(value: &Value, prefix: &str, acc: &mut BTreeMap<String, Str
 mod tests {
     use super::*;
 
+    #[cfg(feature = "toml")]
+    #[test]
+    fn test_to_toml_table() {
+        let value = serde_json::json!({"name": "app", "server": {"port": 8080}});
+        let output = to_toml(&value).unwrap();
+        assert!(output.contains("name = \"app\""), "{}", output);
+        assert!(output.contains("[server]"), "{}", output);
+        assert!(output.contains("port = 8080"), "{}", output);
+    }
+
+    #[test]
+    fn test_to_toml_rejects_non_table() {
+        let err = to_toml(&serde_json::json!([1, 2])).unwrap_err();
+        assert!(
+            err.to_string()
+                .contains("requires a table at the top level, but the data is an array"),
+            "{}",
+            err
+        );
+        let err = to_toml(&serde_json::json!("text")).unwrap_err();
+        assert!(err.to_string().contains("the data is a string"), "{}", err);
+    }
+
+    #[test]
+    fn test_to_json_lines_array() {
+        let value = serde_json::json!([{"id": 1}, {"id": 2, "tags": ["a"]}]);
+        assert_eq!(
+            to_json_lines(&value),
+            "{\"id\":1}\n{\"id\":2,\"tags\":[\"a\"]}"
+        );
+    }
+
+    #[test]
+    fn test_to_json_lines_non_array_and_empty() {
+        let value = serde_json::json!({"items": [1, 2]});
+        assert_eq!(to_json_lines(&value), "{\"items\":[1,2]}");
+        assert_eq!(to_json_lines(&serde_json::json!([])), "");
+    }
+
+    #[test]
+    fn test_format_duration_magnitudes() {
+        assert_eq!(format_duration(0, 2), "0s");
+        assert_eq!(format_duration(1, 2), "1ms");
+        assert_eq!(format_duration(999, 2), "999ms");
+        assert_eq!(format_duration(1_500, 2), "1s 500ms");
+        assert_eq!(format_duration(90_000, 2), "1m 30s");
+        assert_eq!(format_duration(3_605_000, 2), "1h");
+        assert_eq!(format_duration(3_605_000, 3), "1h 5s");
+        assert_eq!(format_duration(90_061_001, 5), "1d 1h 1m 1s 1ms");
+        assert_eq!(format_duration(-90_000, 2), "-1m 30s");
+        assert_eq!(format_duration(90_000, 0), "1m");
+        assert_eq!(format_duration(i64::MIN, 1), "-106751991167d");
+    }
+
     #[test]
     fn test_rgb_to_ansi256_grayscale() {
         assert_eq!(rgb_to_ansi256((0, 0, 0)), 16);
@@ -193,6 +519,25 @@ mod tests {
         assert_eq!(rgb_to_ansi256((0, 0, 255)), 21);
     }
 
+    #[test]
+    fn test_rgb_to_ansi16() {
+        assert_eq!(rgb_to_ansi16((0, 0, 0)), 0);
+        assert_eq!(rgb_to_ansi16((255, 255, 255)), 15);
+        assert_eq!(rgb_to_ansi16((255, 0, 0)), 9);
+        assert_eq!(rgb_to_ansi16((0, 200, 0)), 2);
+        assert_eq!(rgb_to_ansi16((128, 128, 128)), 8);
+    }
+
+    #[test]
+    fn test_ansi256_to_rgb() {
+        assert_eq!(ansi256_to_rgb(9), (255, 0, 0));
+        assert_eq!(ansi256_to_rgb(16), (0, 0, 0));
+        assert_eq!(ansi256_to_rgb(196), (255, 0, 0));
+        assert_eq!(ansi256_to_rgb(231), (255, 255, 255));
+        assert_eq!(ansi256_to_rgb(232), (8, 8, 8));
+        assert_eq!(ansi256_to_rgb(255), (238, 238, 238));
+    }
+
     #[test]
     fn test_truncate_to_width_no_truncation() {
         assert_eq!(truncate_to_width("Hello", 10), "Hello");
@@ -205,6 +550,157 @@ mod tests {
         assert_eq!(truncate_to_width("Hello World", 7), "Hello …");
     }
 
+    fn options(ellipsis: &str, position: TruncateAt) -> TruncateOptions {
+        TruncateOptions {
+            ellipsis: ellipsis.to_string(),
+            position,
+        }
+    }
+
+    #[test]
+    fn test_truncate_to_width_with_positions() {
+        let s = "Hello World";
+        assert_eq!(
+            truncate_to_width_with(s, 8, options("…", TruncateAt::End)),
+            "Hello W…"
+        );
+        assert_eq!(
+            truncate_to_width_with(s, 8, options("…", TruncateAt::Start)),
+            "…o World"
+        );
+        assert_eq!(
+            truncate_to_width_with(s, 8, options("…", TruncateAt::Middle)),
+            "Hel…orld"
+        );
+    }
+
+    #[test]
+    fn test_truncate_to_width_with_multi_char_ellipsis() {
+        let s = "abcdefghij";
+        assert_eq!(
+            truncate_to_width_with(s, 7, options("...", TruncateAt::End)),
+            "abcd..."
+        );
+        assert_eq!(
+            truncate_to_width_with(s, 7, options("...", TruncateAt::Start)),
+            "...ghij"
+        );
+        assert_eq!(
+            truncate_to_width_with(s, 7, options("...", TruncateAt::Middle)),
+            "ab...ij"
+        );
+        // Marker wider than the space left: the marker itself is cut
+        assert_eq!(
+            truncate_to_width_with(s, 2, options("...", TruncateAt::End)),
+            ".."
+        );
+    }
+
+    #[test]
+    fn test_truncate_to_width_with_cjk() {
+        use unicode_width::UnicodeWidthStr;
+
+        // Each character is 2 columns wide
+        let s = "日本語のテキスト";
+        for position in [TruncateAt::End, TruncateAt::Start, TruncateAt::Middle] {
+            for max_width in 0..s.width() {
+                let out = truncate_to_width_with(s, max_width, options("…", position));
+                assert!(
+                    out.width() <= max_width,
+                    "{:?} {}: {}",
+                    position,
+                    max_width,
+                    out
+                );
+            }
+        }
+
+        assert_eq!(
+            truncate_to_width_with(s, 7, options("…", TruncateAt::End)),
+            "日本語…"
+        );
+        assert_eq!(
+            truncate_to_width_with(s, 7, options("…", TruncateAt::Start)),
+            "…キスト"
+        );
+        // 5 columns for text: 2 on the left, 3 on the right (one is unusable)
+        assert_eq!(
+            truncate_to_width_with(s, 6, options("…", TruncateAt::Middle)),
+            "日…ト"
+        );
+    }
+
+    #[test]
+    fn test_truncate_to_width_with_cjk_ellipsis() {
+        assert_eq!(
+            truncate_to_width_with("abcdefgh", 6, options("〜〜", TruncateAt::End)),
+            "ab〜〜"
+        );
+    }
+
+    #[test]
+    fn test_pad_to_width_alignment() {
+        assert_eq!(pad_to_width("ab", 6, Align::Left, ' '), "ab    ");
+        assert_eq!(pad_to_width("ab", 6, Align::Right, ' '), "    ab");
+        assert_eq!(pad_to_width("ab", 5, Align::Center, '*'), "*ab**");
+        assert_eq!(pad_to_width("ab", 2, Align::Center, '*'), "ab");
+    }
+
+    #[test]
+    fn test_pad_to_width_truncates() {
+        assert_eq!(pad_to_width("Hello World", 6, Align::Right, ' '), "Hello…");
+    }
+
+    #[test]
+    fn test_pad_to_width_wide_chars_exact_width() {
+        // "日" + "…" is 3 columns; the fourth is padded
+        let out = pad_to_width("日本語", 4, Align::Left, ' ');
+        assert_eq!(out, "日… ");
+        assert_eq!(display_width(&out), 4);
+
+        let out = pad_to_width("日本語", 4, Align::Right, '.');
+        assert_eq!(out, ".日…");
+
+        for width in 1..=7 {
+            let out = pad_to_width("日本語", width, Align::Center, ' ');
+            assert_eq!(display_width(&out), width, "width {width}: {out:?}");
+        }
+    }
+
+    #[test]
+    fn test_pad_to_width_zero() {
+        assert_eq!(pad_to_width("abc", 0, Align::Left, ' '), "");
+        assert_eq!(pad_to_width("日本語", 0, Align::Right, ' '), "");
+        assert_eq!(pad_to_width("", 0, Align::Center, ' '), "");
+    }
+
+    #[test]
+    fn test_pad_to_width_emoji() {
+        // The emoji is 2 columns wide
+        let out = pad_to_width("👍 ok", 8, Align::Right, ' ');
+        assert_eq!(out, "   👍 ok");
+        assert_eq!(display_width(&out), 8);
+
+        let out = pad_to_width("👍👍👍", 5, Align::Left, ' ');
+        assert_eq!(out, "👍👍…");
+        assert_eq!(display_width(&out), 5);
+    }
+
+    #[test]
+    fn test_pad_to_width_combining_characters() {
+        // "e" + combining acute accent renders as one column
+        let s = "cafe\u{301}";
+        let out = pad_to_width(s, 6, Align::Left, '.');
+        assert_eq!(out, format!("{}..", s));
+        assert_eq!(display_width(&out), 6);
+    }
+
+    #[test]
+    fn test_pad_to_width_wide_fill() {
+        // A 2-column fill can't cover an odd gap; the rest is a space
+        assert_eq!(pad_to_width("a", 4, Align::Left, '＊'), "a＊ ");
+    }
+
     #[test]
     fn test_truncate_to_width_empty() {
         assert_eq!(truncate_to_width("", 5), "");
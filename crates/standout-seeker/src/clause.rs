@@ -4,6 +4,8 @@
 //! an operator, and a comparison value.
 
 use regex::Regex;
+use unicode_normalization::char::is_combining_mark;
+use unicode_normalization::UnicodeNormalization;
 
 use crate::op::Op;
 use crate::value::{Number, Timestamp, Value};
@@ -51,10 +53,19 @@ impl Clause {
     /// Returns `true` if the value matches the clause's predicate.
     /// Returns `false` if the value doesn't match or if the types are incompatible.
     pub fn matches(&self, field_value: &Value<'_>) -> bool {
+        if self.op.is_presence_op() {
+            return self.match_presence(field_value);
+        }
+
         match (&self.value, field_value) {
             // String comparisons
             (ClauseValue::String(pattern), Value::String(s)) => self.match_string(s, pattern),
 
+            // Accent-insensitive string comparisons; the pattern is already folded
+            (ClauseValue::Unaccented(pattern), Value::String(s)) => {
+                self.match_string(&strip_diacritics(s), pattern)
+            }
+
             // Regex comparison
             (ClauseValue::Regex(regex), Value::String(s)) => regex.is_match(s),
 
@@ -95,6 +106,18 @@ impl Clause {
         }
     }
 
+    /// Presence check. The clause value is a bool; `false` inverts the test,
+    /// so `isnull=false` behaves like `isnotnull`.
+    fn match_presence(&self, field_value: &Value<'_>) -> bool {
+        let expected = !matches!(self.value, ClauseValue::Bool(false));
+        let is_null = matches!(field_value, Value::None);
+        match self.op {
+            Op::IsNull => is_null == expected,
+            Op::IsNotNull => is_null != expected,
+            _ => false,
+        }
+    }
+
     fn match_string(&self, field: &str, pattern: &str) -> bool {
         match self.op.normalize() {
             Op::Eq => field == pattern,
@@ -102,6 +125,7 @@ impl Clause {
             Op::StartsWith => field.starts_with(pattern),
             Op::EndsWith => field.ends_with(pattern),
             Op::Contains => field.contains(pattern),
+            Op::NotContains => !field.contains(pattern),
             // Regex handled separately
             _ => false,
         }
@@ -130,6 +154,7 @@ impl Clause {
     fn match_enum_set(&self, field: u32, set: &[u32]) -> bool {
         match self.op {
             Op::In => set.contains(&field),
+            Op::NotIn => !set.contains(&field),
             _ => false,
         }
     }
@@ -151,6 +176,12 @@ impl Clause {
 pub enum ClauseValue {
     /// String value.
     String(String),
+    /// String value compared ignoring diacritics, so `cafe` matches `café`.
+    ///
+    /// The pattern is stored with diacritics already stripped; build it with
+    /// [`ClauseValue::unaccented`]. Candidates are stripped the same way before
+    /// the comparison.
+    Unaccented(String),
     /// Numeric value.
     Number(Number),
     /// Timestamp value.
@@ -165,6 +196,29 @@ pub enum ClauseValue {
     Regex(Regex),
 }
 
+impl ClauseValue {
+    /// Creates an accent-insensitive string value from `pattern`.
+    ///
+    /// ```
+    /// use standout_seeker::{Clause, ClauseValue, Op, Value};
+    ///
+    /// let clause = Clause::new("name", Op::Contains, ClauseValue::unaccented("cafe"));
+    /// assert!(clause.matches(&Value::String("café noir")));
+    /// ```
+    pub fn unaccented(pattern: &str) -> Self {
+        ClauseValue::Unaccented(strip_diacritics(pattern))
+    }
+}
+
+/// Strips diacritical marks from `s`.
+///
+/// The string is decomposed (NFD) and combining marks are dropped, so `café`
+/// becomes `cafe` and `naïve` becomes `naive`. Letters that don't decompose,
+/// such as `ø` or `ß`, are kept.
+pub fn strip_diacritics(s: &str) -> String {
+    s.nfd().filter(|c| !is_combining_mark(*c)).collect()
+}
+
 // Conversions from common types to ClauseValue
 
 impl From<String> for ClauseValue {
@@ -326,6 +380,37 @@ mod tests {
         assert!(!clause.matches(&Value::String("helo")));
     }
 
+    #[test]
+    fn string_notcontains() {
+        let clause = Clause::new("name", Op::NotContains, "llo");
+        assert!(!clause.matches(&Value::String("hello")));
+        assert!(clause.matches(&Value::String("helo")));
+        assert!(clause.matches(&Value::String("")));
+    }
+
+    #[test]
+    fn string_unaccented() {
+        let clause = Clause::new("name", Op::Contains, ClauseValue::unaccented("cafe"));
+        assert!(clause.matches(&Value::String("café")));
+        assert!(clause.matches(&Value::String("cafe")));
+
+        let clause = Clause::new("name", Op::Eq, ClauseValue::unaccented("naïve"));
+        assert!(clause.matches(&Value::String("naive")));
+        assert!(clause.matches(&Value::String("naïve")));
+        assert!(!clause.matches(&Value::String("Naive"))); // still case sensitive
+
+        // Decomposed input folds the same way as precomposed
+        let clause = Clause::new("name", Op::StartsWith, ClauseValue::unaccented("cafe"));
+        assert!(clause.matches(&Value::String("cafe\u{301} au lait")));
+    }
+
+    #[test]
+    fn string_default_ops_keep_diacritics() {
+        assert!(!Clause::new("name", Op::Contains, "cafe").matches(&Value::String("café")));
+        assert!(!Clause::new("name", Op::Eq, "naive").matches(&Value::String("naïve")));
+        assert!(!Clause::new("name", Op::Eq, "naïve").matches(&Value::String("naive")));
+    }
+
     #[test]
     fn string_regex() {
         let regex = Regex::new(r"^hello\d+$").unwrap();
@@ -409,6 +494,18 @@ mod tests {
         assert!(!clause.matches(&Value::Enum(4)));
     }
 
+    #[test]
+    fn enum_notin() {
+        let clause = Clause::new("status", Op::NotIn, vec![1u32, 2]);
+        assert!(!clause.matches(&Value::Enum(1)));
+        assert!(!clause.matches(&Value::Enum(2)));
+        assert!(clause.matches(&Value::Enum(0)));
+        assert!(clause.matches(&Value::Enum(3)));
+
+        let empty = Clause::new("status", Op::NotIn, Vec::<u32>::new());
+        assert!(empty.matches(&Value::Enum(1)));
+    }
+
     #[test]
     fn bool_comparisons() {
         let clause_true = Clause::new("archived", Op::Eq, true);
@@ -427,6 +524,31 @@ mod tests {
 
         let clause_ne = Clause::new("name", Op::Ne, "test");
         assert!(!clause_ne.matches(&Value::None));
+
+        let clause_notin = Clause::new("status", Op::NotIn, vec![1u32]);
+        assert!(!clause_notin.matches(&Value::None));
+    }
+
+    #[test]
+    fn presence_ops() {
+        let is_null = Clause::new("due", Op::IsNull, true);
+        assert!(is_null.matches(&Value::None));
+        assert!(!is_null.matches(&Value::Number(Number::I64(0))));
+        assert!(!is_null.matches(&Value::String("")));
+
+        let is_not_null = Clause::new("due", Op::IsNotNull, true);
+        assert!(!is_not_null.matches(&Value::None));
+        assert!(is_not_null.matches(&Value::Bool(false)));
+    }
+
+    #[test]
+    fn presence_ops_inverted_by_false() {
+        let is_null_false = Clause::new("due", Op::IsNull, false);
+        assert!(!is_null_false.matches(&Value::None));
+        assert!(is_null_false.matches(&Value::Timestamp(Timestamp(0))));
+
+        let is_not_null_false = Clause::new("due", Op::IsNotNull, false);
+        assert!(is_not_null_false.matches(&Value::None));
     }
 
     #[test]
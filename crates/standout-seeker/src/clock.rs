@@ -0,0 +1,58 @@
+//! Injectable clock for time-dependent parsing.
+//!
+//! Relative timestamps such as `today` or `now` are resolved against
+//! [`now_ms`], which reads the system clock by default. Tests can pin it with
+//! [`set_clock`] so the results are deterministic.
+//!
+//! The clock belongs to this crate and covers seeker's time handling only.
+//! The other standout crates don't depend on seeker: the render crate's
+//! OS theme cache measures its TTL with a monotonic [`Instant`](std::time::Instant)
+//! and takes the current instant as a parameter in its own tests, and there
+//! is no other wall-clock logic to pin.
+
+use std::sync::RwLock;
+use std::time::{SystemTime, UNIX_EPOCH};
+
+/// A function returning the current time in Unix milliseconds.
+pub type Clock = fn() -> i64;
+
+static CLOCK: RwLock<Option<Clock>> = RwLock::new(None);
+
+/// Overrides the clock used wherever seeker needs the current time.
+///
+/// The clock is global to the process and meant for tests: pin it to a
+/// fixed instant, then call [`reset_clock`] when done. Tests that change it
+/// affect every other test running at the same time.
+///
+/// # Example
+///
+/// ```rust
+/// use standout_seeker::{now_ms, reset_clock, set_clock};
+///
+/// set_clock(|| 1_705_312_200_000);
+/// assert_eq!(now_ms(), 1_705_312_200_000);
+/// reset_clock();
+/// ```
+pub fn set_clock(clock: Clock) {
+    *CLOCK.write().unwrap_or_else(|e| e.into_inner()) = Some(clock);
+}
+
+/// Restores the system clock after [`set_clock`].
+pub fn reset_clock() {
+    *CLOCK.write().unwrap_or_else(|e| e.into_inner()) = None;
+}
+
+/// Returns the current time in Unix milliseconds, from the clock set with
+/// [`set_clock`] or the system clock.
+pub fn now_ms() -> i64 {
+    let clock = *CLOCK.read().unwrap_or_else(|e| e.into_inner());
+    clock.map_or_else(system_clock, |clock| clock())
+}
+
+/// Reads the system clock in Unix milliseconds.
+fn system_clock() -> i64 {
+    match SystemTime::now().duration_since(UNIX_EPOCH) {
+        Ok(elapsed) => elapsed.as_millis() as i64,
+        Err(before) => -(before.duration().as_millis() as i64),
+    }
+}
@@ -0,0 +1,350 @@
+//! Human-readable query descriptions.
+//!
+//! [`Query::describe`] renders a query as prose for "showing results
+//! where…" banners, e.g.
+//! `name contains 'urgent' AND priority ≥ 5, ordered by created-at descending, first 10`.
+
+use std::fmt;
+
+use crate::clause::{Clause, ClauseValue};
+use crate::op::Op;
+use crate::ordering::Dir;
+use crate::query::Query;
+use crate::value::{Number, Timestamp};
+
+impl Query {
+    /// Describes the query in prose.
+    ///
+    /// Clauses are joined with `AND` / `OR`, NOT clauses are prefixed with
+    /// `NOT`, and nested groups and mixed OR alternatives are wrapped in
+    /// parentheses. Ordering, offset and limit follow the filter, separated
+    /// by commas. A query without clauses describes its filter as
+    /// `everything`.
+    ///
+    /// Strings are single-quoted, with `'` and `\` escaped by a backslash
+    /// (`'it\'s'`). Enum values are shown as discriminants, since the query
+    /// does not know variant names. Timestamps are shown as UTC dates, with
+    /// the time of day when it is not midnight and milliseconds when present.
+    ///
+    /// # Example
+    ///
+    /// ```
+    /// use standout_seeker::Query;
+    ///
+    /// let query = Query::new()
+    ///     .and_contains("name", "urgent")
+    ///     .and_gte("priority", 5i64)
+    ///     .order_desc("created-at")
+    ///     .limit(10);
+    ///
+    /// assert_eq!(
+    ///     query.describe(),
+    ///     "name contains 'urgent' AND priority ≥ 5, ordered by created-at descending, first 10"
+    /// );
+    /// ```
+    pub fn describe(&self) -> String {
+        let mut parts = vec![describe_filter(self).unwrap_or_else(|| "everything".to_string())];
+
+        if !self.orderings().is_empty() {
+            let keys: Vec<String> = self
+                .orderings()
+                .iter()
+                .map(|order| {
+                    let dir = match order.dir {
+                        Dir::Asc => "ascending",
+                        Dir::Desc => "descending",
+                    };
+                    format!("{} {}", order.field, dir)
+                })
+                .collect();
+            parts.push(format!("ordered by {}", keys.join(", then ")));
+        }
+        if let Some(offset) = self.get_offset() {
+            parts.push(format!("skipping {}", offset));
+        }
+        if let Some(limit) = self.get_limit() {
+            parts.push(format!("first {}", limit));
+        }
+
+        parts.join(", ")
+    }
+}
+
+impl fmt::Display for Query {
+    /// Writes [`Query::describe`].
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        f.write_str(&self.describe())
+    }
+}
+
+/// Describe the clauses and groups of `query`, or `None` if it has none.
+fn describe_filter(query: &Query) -> Option<String> {
+    let mut terms: Vec<String> = query
+        .and_clauses()
+        .iter()
+        .map(describe_clause)
+        .chain(query.and_groups().iter().filter_map(describe_group))
+        .collect();
+
+    let alternatives: Vec<String> = query
+        .or_clauses()
+        .iter()
+        .map(describe_clause)
+        .chain(query.or_groups().iter().filter_map(describe_group))
+        .collect();
+    let negated: Vec<String> = query
+        .not_clauses()
+        .iter()
+        .map(describe_clause)
+        .chain(query.not_groups().iter().filter_map(describe_group))
+        .map(|term| format!("NOT {}", term))
+        .collect();
+
+    let alone = terms.is_empty() && negated.is_empty();
+    match alternatives.len() {
+        0 => {}
+        1 => terms.extend(alternatives),
+        _ if alone => terms.push(alternatives.join(" OR ")),
+        _ => terms.push(format!("({})", alternatives.join(" OR "))),
+    }
+    terms.extend(negated);
+
+    (!terms.is_empty()).then(|| terms.join(" AND "))
+}
+
+/// Describe a nested query as a single term, parenthesized when compound.
+fn describe_group(query: &Query) -> Option<String> {
+    let filter = describe_filter(query)?;
+    let terms = query.and_clauses().len()
+        + query.or_clauses().len()
+        + query.not_clauses().len()
+        + query.and_groups().len()
+        + query.or_groups().len()
+        + query.not_groups().len();
+    Some(if terms > 1 {
+        format!("({})", filter)
+    } else {
+        filter
+    })
+}
+
+fn describe_clause(clause: &Clause) -> String {
+    let field = &clause.field;
+    let value = describe_value(&clause.value);
+    match (clause.op, &clause.value) {
+        // Presence operators are inverted by a `false` value
+        (Op::IsNull, ClauseValue::Bool(false)) | (Op::IsNotNull, ClauseValue::Bool(true)) => {
+            format!("{} is set", field)
+        }
+        (Op::IsNull, _) | (Op::IsNotNull, _) => format!("{} is empty", field),
+        (Op::Regex, _) => format!("{} matches {}", field, value),
+        (Op::In, _) => format!("{} is one of {}", field, value),
+        (Op::NotIn, _) => format!("{} is none of {}", field, value),
+        (op, _) => format!("{} {} {}", field, op_phrase(op), value),
+    }
+}
+
+/// The phrase placed between field and value for binary operators.
+fn op_phrase(op: Op) -> &'static str {
+    match op {
+        Op::Eq => "=",
+        Op::Ne => "≠",
+        Op::StartsWith => "starts with",
+        Op::EndsWith => "ends with",
+        Op::Contains => "contains",
+        Op::NotContains => "does not contain",
+        Op::Regex => "matches",
+        Op::Gt => ">",
+        Op::Gte => "≥",
+        Op::Lt => "<",
+        Op::Lte => "≤",
+        Op::Before => "before",
+        Op::After => "after",
+        Op::In => "is one of",
+        Op::NotIn => "is none of",
+        Op::Is => "is",
+        Op::IsNull => "is empty",
+        Op::IsNotNull => "is set",
+    }
+}
+
+fn describe_value(value: &ClauseValue) -> String {
+    match value {
+        ClauseValue::String(s) => quote(s),
+        ClauseValue::Unaccented(s) => format!("{} ignoring accents", quote(s)),
+        ClauseValue::Number(Number::I64(n)) => n.to_string(),
+        ClauseValue::Number(Number::U64(n)) => n.to_string(),
+        ClauseValue::Number(Number::F64(n)) => n.to_string(),
+        ClauseValue::Timestamp(ts) => describe_timestamp(*ts),
+        ClauseValue::Enum(d) => d.to_string(),
+        ClauseValue::EnumSet(set) => {
+            let items: Vec<String> = set.iter().map(u32::to_string).collect();
+            format!("({})", items.join(", "))
+        }
+        ClauseValue::Bool(b) => b.to_string(),
+        ClauseValue::Regex(re) => format!("/{}/", re.as_str()),
+    }
+}
+
+/// Single-quote a string, escaping quotes and backslashes.
+fn quote(s: &str) -> String {
+    let mut quoted = String::with_capacity(s.len() + 2);
+    quoted.push('\'');
+    for c in s.chars() {
+        if c == '\'' || c == '\\' {
+            quoted.push('\\');
+        }
+        quoted.push(c);
+    }
+    quoted.push('\'');
+    quoted
+}
+
+/// Format a timestamp as a UTC date, adding the time when it is not midnight
+/// and the milliseconds when they are not zero.
+fn describe_timestamp(ts: Timestamp) -> String {
+    const MS_PER_DAY: i64 = 86_400_000;
+    let days = ts.0.div_euclid(MS_PER_DAY);
+    let ms = ts.0.rem_euclid(MS_PER_DAY);
+    let (year, month, day) = civil_from_days(days);
+
+    if ms == 0 {
+        return format!("{:04}-{:02}-{:02}", year, month, day);
+    }
+    let secs = ms / 1000;
+    let fraction = match ms % 1000 {
+        0 => String::new(),
+        millis => format!(".{:03}", millis),
+    };
+    format!(
+        "{:04}-{:02}-{:02}T{:02}:{:02}:{:02}{}Z",
+        year,
+        month,
+        day,
+        secs / 3600,
+        secs % 3600 / 60,
+        secs % 60,
+        fraction
+    )
+}
+
+/// Convert days since the Unix epoch to a proleptic Gregorian `(year, month, day)`.
+fn civil_from_days(days: i64) -> (i64, u32, u32) {
+    let z = days + 719_468;
+    let era = z.div_euclid(146_097);
+    let doe = z.rem_euclid(146_097);
+    let yoe = (doe - doe / 1460 + doe / 36_524 - doe / 146_096) / 365;
+    let doy = doe - (365 * yoe + yoe / 4 - yoe / 100);
+    let mp = (5 * doy + 2) / 153;
+    let day = (doy - (153 * mp + 2) / 5 + 1) as u32;
+    let month = if mp < 10 { mp + 3 } else { mp - 9 } as u32;
+    let year = yoe + era * 400 + i64::from(month <= 2);
+    (year, month, day)
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn empty_query() {
+        assert_eq!(Query::new().describe(), "everything");
+        assert_eq!(
+            Query::new().order_asc("name").offset(5).describe(),
+            "everything, ordered by name ascending, skipping 5"
+        );
+    }
+
+    #[test]
+    fn multi_clause() {
+        let query = Query::new()
+            .and_contains("name", "urgent")
+            .and_gte("priority", 5i64)
+            .and_in("status", [1u32, 2]);
+        assert_eq!(
+            query.describe(),
+            "name contains 'urgent' AND priority ≥ 5 AND status is one of (1, 2)"
+        );
+    }
+
+    #[test]
+    fn or_alternatives_are_parenthesized_when_mixed() {
+        let only_or = Query::new().or_eq("owner", "ada").or_eq("owner", "bob");
+        assert_eq!(only_or.describe(), "owner = 'ada' OR owner = 'bob'");
+
+        let mixed = only_or.and_lt("priority", 3i64);
+        assert_eq!(
+            mixed.describe(),
+            "priority < 3 AND (owner = 'ada' OR owner = 'bob')"
+        );
+    }
+
+    #[test]
+    fn negated() {
+        let query = Query::new()
+            .and_startswith("name", "fix")
+            .not_eq("archived", true)
+            .not_regex("name", r"^wip\b")
+            .unwrap()
+            .and_notin("status", [3u32]);
+        assert_eq!(
+            query.describe(),
+            "name starts with 'fix' AND status is none of (3) AND NOT archived = true AND NOT name matches /^wip\\b/"
+        );
+    }
+
+    #[test]
+    fn nested_groups() {
+        let query = Query::new()
+            .or_group(Query::new().and_eq("a", 1i64).and_eq("b", 2i64))
+            .or_group(Query::new().and_eq("c", 3i64))
+            .not_group(Query::new().or_eq("d", 4i64).or_eq("e", 5i64));
+        assert_eq!(
+            query.describe(),
+            "((a = 1 AND b = 2) OR c = 3) AND NOT (d = 4 OR e = 5)"
+        );
+    }
+
+    #[test]
+    fn ordered_and_paged() {
+        let query = Query::new()
+            .and_ne("owner", "nobody")
+            .order_desc("created-at")
+            .order_asc("name")
+            .offset(20)
+            .limit(10);
+        assert_eq!(
+            query.to_string(),
+            "owner ≠ 'nobody', ordered by created-at descending, then name ascending, skipping 20, first 10"
+        );
+    }
+
+    #[test]
+    fn presence_and_timestamps() {
+        let query = Query::new()
+            .and_is_null("due")
+            .and("closed", Op::IsNotNull, false)
+            .or_before("created", Timestamp::from_millis(1_705_276_800_000))
+            .or_after("created", Timestamp::from_millis(1_705_314_645_000))
+            .or_after("created", Timestamp::from_millis(1_705_314_645_500));
+        assert_eq!(
+            query.describe(),
+            "due is empty AND closed is empty AND (created before 2024-01-15 OR created after 2024-01-15T10:30:45Z OR created after 2024-01-15T10:30:45.500Z)"
+        );
+        assert_eq!(
+            Query::new().and_eq("name", r"it's a\b").describe(),
+            r"name = 'it\'s a\\b'"
+        );
+        assert_eq!(
+            Query::new().and("due", Op::IsNull, false).describe(),
+            "due is set"
+        );
+    }
+
+    #[test]
+    fn civil_dates() {
+        assert_eq!(civil_from_days(0), (1970, 1, 1));
+        assert_eq!(civil_from_days(-1), (1969, 12, 31));
+        assert_eq!(civil_from_days(19_782), (2024, 2, 29));
+    }
+}
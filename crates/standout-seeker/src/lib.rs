@@ -69,31 +69,57 @@
 //!
 //! | Type | Operators |
 //! |------|-----------|
-//! | String | `Eq`, `Ne`, `StartsWith`, `EndsWith`, `Contains`, `Regex` |
+//! | String | `Eq`, `Ne`, `StartsWith`, `EndsWith`, `Contains`, `NotContains`, `Regex` |
 //! | Number | `Eq`, `Ne`, `Gt`, `Gte`, `Lt`, `Lte` |
 //! | Timestamp | `Eq`, `Ne`, `Before`, `After`, `Gt`, `Gte`, `Lt`, `Lte` |
-//! | Enum | `Eq`, `Ne`, `In` |
+//! | Enum | `Eq`, `Ne`, `In`, `NotIn` |
 //! | Bool | `Eq`, `Ne`, `Is` |
+//!
+//! `IsNull` and `IsNotNull` are valid for every type and test whether the
+//! accessor returned [`Value::None`] (e.g. an `Option` field set to `None`).
+//!
+//! String comparisons are accent-sensitive. To match `cafe` against `café`,
+//! compare with [`ClauseValue::unaccented`], or add `_ascii` to the operator
+//! when parsing (`name-contains_ascii=cafe`). The modifier works with `eq`,
+//! `ne`, `startswith`, `endswith`, `contains` and `notcontains`.
+//!
+//! # Describing Queries
+//!
+//! [`Query::describe`] (also the [`Display`](std::fmt::Display) output of a
+//! query) renders it as prose for "showing results where…" banners:
+//! `name contains 'urgent' AND priority ≥ 5, ordered by created-at descending, first 10`.
+//!
+//! # Parallel Evaluation
+//!
+//! With the `parallel` feature, `Query::par_apply` and `Query::par_count`
+//! evaluate clauses on the rayon thread pool. Results are identical to
+//! [`Query::apply`] and [`Query::count`], including the order of items with
+//! equal sort keys. The item type and accessor must be `Sync`.
 
 mod clause;
+mod clock;
+mod describe;
 mod error;
 mod op;
 mod ordering;
 mod parse;
+mod prepared;
 mod query;
 mod schema;
 mod traits;
 mod value;
 
 // Re-export public API
-pub use clause::{Clause, ClauseValue};
+pub use clause::{strip_diacritics, Clause, ClauseValue};
+pub use clock::{now_ms, reset_clock, set_clock, Clock};
 pub use error::{Result, SeekerError};
 pub use op::Op;
 pub use ordering::{compare_values, Dir, OrderBy};
 pub use parse::{
-    parse_key, parse_operator, parse_ordering, parse_query, parse_value, ClauseGroup, ParseError,
-    ParseResult,
+    parse_key, parse_operator, parse_ordering, parse_query, parse_query_indexed, parse_value,
+    ClauseGroup, ParseError, ParseResult,
 };
+pub use prepared::PreparedQuery;
 pub use query::Query;
 pub use schema::{SeekType, SeekerSchema};
 pub use traits::{Seekable, SeekerEnum, SeekerTimestamp};
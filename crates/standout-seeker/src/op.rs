@@ -8,11 +8,11 @@ use std::cmp::Ordering;
 /// Comparison operator for a query clause.
 ///
 /// Operators are grouped by the types they support:
-/// - Universal: `Eq`, `Ne` - work on all types
-/// - String: `StartsWith`, `EndsWith`, `Contains`, `Regex`
+/// - Universal: `Eq`, `Ne`, `IsNull`, `IsNotNull` - work on all types
+/// - String: `StartsWith`, `EndsWith`, `Contains`, `NotContains`, `Regex`
 /// - Numeric/Timestamp: `Gt`, `Gte`, `Lt`, `Lte`
 /// - Timestamp aliases: `Before` (alias for `Lt`), `After` (alias for `Gt`)
-/// - Enum: `In` - check membership in a set
+/// - Enum: `In`, `NotIn` - check membership in a set
 /// - Bool alias: `Is` (alias for `Eq`)
 #[derive(Debug, Clone, Copy, PartialEq, Eq, Hash)]
 pub enum Op {
@@ -29,6 +29,8 @@ pub enum Op {
     EndsWith,
     /// String contains substring.
     Contains,
+    /// String does not contain substring.
+    NotContains,
     /// String matches regular expression.
     Regex,
 
@@ -51,42 +53,94 @@ pub enum Op {
     // Enum operators
     /// Value is one of the given set.
     In,
+    /// Value is none of the given set.
+    NotIn,
 
     // Bool alias
     /// Alias for `Eq` (reads naturally: `archived.is(true)`).
     Is,
+
+    // Presence operators
+    /// Field has no value ([`Value::None`](crate::Value::None)). Valid for all types.
+    IsNull,
+    /// Field has a value. Valid for all types.
+    IsNotNull,
 }
 
 impl Op {
+    /// Every operator, in declaration order.
+    ///
+    /// Useful for building operator pickers; pair with [`Op::as_str`] for
+    /// labels and [`SeekType::valid_operators`](crate::SeekType::valid_operators)
+    /// to narrow the list for a field.
+    pub fn all() -> &'static [Op] {
+        &[
+            Op::Eq,
+            Op::Ne,
+            Op::StartsWith,
+            Op::EndsWith,
+            Op::Contains,
+            Op::NotContains,
+            Op::Regex,
+            Op::Gt,
+            Op::Gte,
+            Op::Lt,
+            Op::Lte,
+            Op::Before,
+            Op::After,
+            Op::In,
+            Op::NotIn,
+            Op::Is,
+            Op::IsNull,
+            Op::IsNotNull,
+        ]
+    }
+
+    /// Returns `true` if this operator tests whether a field has a value.
+    ///
+    /// Presence operators are valid for all types.
+    pub fn is_presence_op(self) -> bool {
+        matches!(self, Op::IsNull | Op::IsNotNull)
+    }
+
     /// Returns `true` if this operator is valid for string comparisons.
     pub fn is_string_op(self) -> bool {
-        matches!(
-            self,
-            Op::Eq | Op::Ne | Op::StartsWith | Op::EndsWith | Op::Contains | Op::Regex
-        )
+        self.is_presence_op()
+            || matches!(
+                self,
+                Op::Eq
+                    | Op::Ne
+                    | Op::StartsWith
+                    | Op::EndsWith
+                    | Op::Contains
+                    | Op::NotContains
+                    | Op::Regex
+            )
     }
 
     /// Returns `true` if this operator is valid for numeric comparisons.
     pub fn is_number_op(self) -> bool {
-        matches!(self, Op::Eq | Op::Ne | Op::Gt | Op::Gte | Op::Lt | Op::Lte)
+        self.is_presence_op()
+            || matches!(self, Op::Eq | Op::Ne | Op::Gt | Op::Gte | Op::Lt | Op::Lte)
     }
 
     /// Returns `true` if this operator is valid for timestamp comparisons.
     pub fn is_timestamp_op(self) -> bool {
-        matches!(
-            self,
-            Op::Eq | Op::Ne | Op::Gt | Op::Gte | Op::Lt | Op::Lte | Op::Before | Op::After
-        )
+        self.is_presence_op()
+            || matches!(
+                self,
+                Op::Eq | Op::Ne | Op::Gt | Op::Gte | Op::Lt | Op::Lte | Op::Before | Op::After
+            )
     }
 
     /// Returns `true` if this operator is valid for enum comparisons.
     pub fn is_enum_op(self) -> bool {
-        matches!(self, Op::Eq | Op::Ne | Op::In)
+        self.is_presence_op() || matches!(self, Op::Eq | Op::Ne | Op::In | Op::NotIn)
     }
 
     /// Returns `true` if this operator is valid for boolean comparisons.
     pub fn is_bool_op(self) -> bool {
-        matches!(self, Op::Eq | Op::Ne | Op::Is)
+        self.is_presence_op() || matches!(self, Op::Eq | Op::Ne | Op::Is)
     }
 
     /// Normalizes timestamp aliases to their canonical form.
@@ -121,6 +175,10 @@ impl Op {
     }
 
     /// Returns the display name of this operator.
+    ///
+    /// This is the canonical name accepted by the query parser
+    /// (`name-contains`), so it round-trips through
+    /// [`parse_operator`](crate::parse_operator).
     pub fn as_str(self) -> &'static str {
         match self {
             Op::Eq => "eq",
@@ -128,6 +186,7 @@ impl Op {
             Op::StartsWith => "startswith",
             Op::EndsWith => "endswith",
             Op::Contains => "contains",
+            Op::NotContains => "notcontains",
             Op::Regex => "regex",
             Op::Gt => "gt",
             Op::Gte => "gte",
@@ -136,7 +195,10 @@ impl Op {
             Op::Before => "before",
             Op::After => "after",
             Op::In => "in",
+            Op::NotIn => "notin",
             Op::Is => "is",
+            Op::IsNull => "isnull",
+            Op::IsNotNull => "isnotnull",
         }
     }
 }
@@ -151,12 +213,24 @@ impl std::fmt::Display for Op {
 mod tests {
     use super::*;
 
+    #[test]
+    fn all_lists_each_operator_once_and_round_trips() {
+        let ops = Op::all();
+        assert_eq!(ops.len(), 18);
+        for (i, op) in ops.iter().enumerate() {
+            assert!(!ops[..i].contains(op), "{} listed twice", op);
+            assert_eq!(crate::parse_operator(op.as_str()), Some(*op));
+        }
+    }
+
     #[test]
     fn op_type_checks() {
         // String ops
         assert!(Op::Eq.is_string_op());
         assert!(Op::Contains.is_string_op());
         assert!(Op::Regex.is_string_op());
+        assert!(Op::NotContains.is_string_op());
+        assert!(!Op::NotIn.is_string_op());
         assert!(!Op::Gt.is_string_op());
 
         // Number ops
@@ -174,12 +248,26 @@ mod tests {
         // Enum ops
         assert!(Op::Eq.is_enum_op());
         assert!(Op::In.is_enum_op());
+        assert!(Op::NotIn.is_enum_op());
+        assert!(!Op::NotContains.is_enum_op());
+        assert!(!Op::NotIn.is_number_op());
         assert!(!Op::Gt.is_enum_op());
 
         // Bool ops
         assert!(Op::Eq.is_bool_op());
         assert!(Op::Is.is_bool_op());
         assert!(!Op::Gt.is_bool_op());
+
+        // Presence ops are valid everywhere
+        for op in [Op::IsNull, Op::IsNotNull] {
+            assert!(op.is_presence_op());
+            assert!(op.is_string_op());
+            assert!(op.is_number_op());
+            assert!(op.is_timestamp_op());
+            assert!(op.is_enum_op());
+            assert!(op.is_bool_op());
+        }
+        assert!(!Op::Eq.is_presence_op());
     }
 
     #[test]
@@ -13,6 +13,11 @@
 //! - Preceding segments form the field name
 //! - If no operator, use the field type's default
 //!
+//! Dots (`.`) are never split: they separate the segments of a nested field
+//! path, so `author.name-contains` targets the field `author.name` with the
+//! `Contains` operator. Dots and dashes combine freely, as in
+//! `author.created-at-before` (field `author.created-at`, operator `Before`).
+//!
 //! # Examples
 //!
 //! ```
@@ -41,7 +46,7 @@
 //! let query = parse_query::<Task>(pairs).unwrap();
 //! ```
 
-use std::collections::HashSet;
+use std::num::{IntErrorKind, ParseIntError};
 
 use crate::clause::ClauseValue;
 use crate::schema::{SeekType, SeekerSchema};
@@ -54,6 +59,8 @@ pub enum ParseError {
     UnknownField {
         field: String,
         available: Vec<String>,
+        /// Closest available field name, if one is near enough.
+        suggestion: Option<String>,
     },
     /// Operator not valid for field type.
     InvalidOperator {
@@ -76,22 +83,36 @@ pub enum ParseError {
     },
     /// Invalid ordering specification.
     InvalidOrdering { value: String, reason: String },
-    /// Invalid limit/offset value.
-    InvalidLimit { key: String, value: String },
+    /// Invalid limit/offset value: not a number, out of range for `usize`,
+    /// or above [`SeekerSchema::max_limit`].
+    InvalidLimit {
+        key: String,
+        value: String,
+        reason: String,
+    },
     /// Unknown operator name.
-    UnknownOperator { operator: String },
+    UnknownOperator {
+        operator: String,
+        /// Closest known operator name, if one is near enough.
+        suggestion: Option<String>,
+    },
+    /// `GROUP_START` and `GROUP_END` markers do not pair up.
+    UnbalancedGroup { reason: String },
 }
 
 impl std::fmt::Display for ParseError {
     fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
         match self {
-            ParseError::UnknownField { field, available } => {
-                write!(
-                    f,
-                    "unknown field '{}'. Available: {}",
-                    field,
-                    available.join(", ")
-                )
+            ParseError::UnknownField {
+                field,
+                available,
+                suggestion,
+            } => {
+                write!(f, "unknown field '{}'", field)?;
+                if let Some(suggestion) = suggestion {
+                    write!(f, ", did you mean '{}'?", suggestion)?;
+                }
+                write!(f, ". Available: {}", available.join(", "))
             }
             ParseError::InvalidOperator {
                 field,
@@ -130,15 +151,21 @@ impl std::fmt::Display for ParseError {
             ParseError::InvalidOrdering { value, reason } => {
                 write!(f, "invalid ordering '{}': {}", value, reason)
             }
-            ParseError::InvalidLimit { key, value } => {
-                write!(
-                    f,
-                    "invalid {} value '{}': expected positive integer",
-                    key, value
-                )
+            ParseError::InvalidLimit { key, value, reason } => {
+                write!(f, "invalid {} value '{}': {}", key, value, reason)
+            }
+            ParseError::UnknownOperator {
+                operator,
+                suggestion,
+            } => {
+                write!(f, "unknown operator '{}'", operator)?;
+                if let Some(suggestion) = suggestion {
+                    write!(f, ", did you mean '{}'?", suggestion)?;
+                }
+                Ok(())
             }
-            ParseError::UnknownOperator { operator } => {
-                write!(f, "unknown operator '{}'", operator)
+            ParseError::UnbalancedGroup { reason } => {
+                write!(f, "unbalanced group markers: {}", reason)
             }
         }
     }
@@ -173,40 +200,72 @@ pub fn parse_operator(s: &str) -> Option<Op> {
         "startswith" | "prefix" => Some(Op::StartsWith),
         "endswith" | "suffix" => Some(Op::EndsWith),
         "contains" => Some(Op::Contains),
+        "notcontains" | "!contains" => Some(Op::NotContains),
         "regex" | "re" | "match" => Some(Op::Regex),
         "before" => Some(Op::Before),
         "after" => Some(Op::After),
         "in" => Some(Op::In),
+        "notin" => Some(Op::NotIn),
         "is" => Some(Op::Is),
+        "isnull" => Some(Op::IsNull),
+        "isnotnull" => Some(Op::IsNotNull),
         _ => None,
     }
 }
 
-/// All valid operator names (for validation).
-fn operator_names() -> HashSet<&'static str> {
-    [
-        "eq",
-        "ne",
-        "neq",
-        "gt",
-        "gte",
-        "lt",
-        "lte",
-        "startswith",
-        "prefix",
-        "endswith",
-        "suffix",
-        "contains",
-        "regex",
-        "re",
-        "match",
-        "before",
-        "after",
-        "in",
-        "is",
-    ]
-    .into_iter()
-    .collect()
+/// All valid operator names (for validation and suggestions).
+const OPERATOR_NAMES: &[&str] = &[
+    "eq",
+    "ne",
+    "neq",
+    "gt",
+    "gte",
+    "lt",
+    "lte",
+    "startswith",
+    "prefix",
+    "endswith",
+    "suffix",
+    "contains",
+    "notcontains",
+    "!contains",
+    "regex",
+    "re",
+    "match",
+    "before",
+    "after",
+    "in",
+    "notin",
+    "is",
+    "isnull",
+    "isnotnull",
+];
+
+/// Suffix that makes a string operator ignore diacritics (`contains_ascii`).
+const UNACCENTED_SUFFIX: &str = "_ascii";
+
+/// Split the accent-insensitive modifier off a key.
+///
+/// `name-contains_ascii` becomes (`name-contains`, true) when the operator
+/// before the suffix is one of `eq`, `ne`, `startswith`, `endswith`,
+/// `contains` or `notcontains` (or their aliases). Other keys are returned
+/// unchanged.
+fn split_unaccented(key: &str) -> (&str, bool) {
+    let split = key.len().checked_sub(UNACCENTED_SUFFIX.len());
+    let Some(stripped) = split
+        .filter(|&at| key.is_char_boundary(at))
+        .filter(|&at| key[at..].eq_ignore_ascii_case(UNACCENTED_SUFFIX))
+        .map(|at| &key[..at])
+    else {
+        return (key, false);
+    };
+
+    match parse_key(stripped).1 {
+        Some(Op::Eq | Op::Ne | Op::StartsWith | Op::EndsWith | Op::Contains | Op::NotContains) => {
+            (stripped, true)
+        }
+        _ => (key, false),
+    }
 }
 
 /// Parse a key string into field name and operator.
@@ -220,13 +279,17 @@ fn operator_names() -> HashSet<&'static str> {
 ///
 /// - `name-contains` → `("name", Some(Op::Contains))`
 /// - `created-at-before` → `("created-at", Some(Op::Before))`
+/// - `author.name-contains` → `("author.name", Some(Op::Contains))`
 /// - `name` → `("name", None)` (use default operator)
+///
+/// Only `-` separates the operator. Dots belong to the field path, so nested
+/// fields (`author.name`) and hyphenated fields (`created-at`) can be mixed.
 pub fn parse_key(key: &str) -> (String, Option<Op>) {
     let parts: Vec<&str> = key.split('-').collect();
 
     if parts.len() > 1 {
         let last = parts.last().unwrap().to_lowercase();
-        if operator_names().contains(last.as_str()) {
+        if OPERATOR_NAMES.contains(&last.as_str()) {
             let field = parts[..parts.len() - 1].join("-");
             let op = parse_operator(&last);
             return (field, op);
@@ -236,6 +299,62 @@ pub fn parse_key(key: &str) -> (String, Option<Op>) {
     (key.to_string(), None)
 }
 
+/// Build the error for a key whose field isn't in the schema.
+///
+/// If the key is a known field followed by a misspelled operator
+/// (`priority-gtee`), this reports [`ParseError::UnknownOperator`] instead,
+/// suggesting the nearest operator name.
+fn unknown_field_error<S: SeekerSchema>(field: &str) -> ParseError {
+    if let Some((prefix, operator)) = field.rsplit_once('-') {
+        if S::field_type(prefix).is_some() {
+            return ParseError::UnknownOperator {
+                operator: operator.to_string(),
+                suggestion: closest_match(operator, OPERATOR_NAMES.iter().copied()),
+            };
+        }
+    }
+
+    ParseError::UnknownField {
+        field: field.to_string(),
+        available: S::field_names().iter().map(|s| s.to_string()).collect(),
+        suggestion: closest_match(field, S::field_names().iter().copied()),
+    }
+}
+
+/// Find the candidate closest to `input` by edit distance.
+///
+/// Only candidates within a third of the input's length (at least one edit)
+/// are considered; ties go to the earliest candidate.
+fn closest_match<'a>(input: &str, candidates: impl IntoIterator<Item = &'a str>) -> Option<String> {
+    let input = input.to_lowercase();
+    let threshold = (input.chars().count() / 3).max(1);
+
+    candidates
+        .into_iter()
+        .map(|candidate| (edit_distance(&input, &candidate.to_lowercase()), candidate))
+        .filter(|(distance, _)| *distance <= threshold)
+        .min_by_key(|(distance, _)| *distance)
+        .map(|(_, candidate)| candidate.to_string())
+}
+
+/// Levenshtein distance between two strings, counting characters.
+fn edit_distance(a: &str, b: &str) -> usize {
+    let b: Vec<char> = b.chars().collect();
+    let mut prev: Vec<usize> = (0..=b.len()).collect();
+    let mut curr = vec![0; b.len() + 1];
+
+    for (i, ca) in a.chars().enumerate() {
+        curr[0] = i + 1;
+        for (j, cb) in b.iter().enumerate() {
+            let substitution = prev[j] + usize::from(ca != *cb);
+            curr[j + 1] = substitution.min(prev[j + 1] + 1).min(curr[j] + 1);
+        }
+        std::mem::swap(&mut prev, &mut curr);
+    }
+
+    prev[b.len()]
+}
+
 /// Parse a value string into a [`ClauseValue`] based on field type.
 ///
 /// # Arguments
@@ -245,6 +364,14 @@ pub fn parse_key(key: &str) -> (String, Option<Op>) {
 /// * `field_type` - The expected field type
 /// * `op` - The operator (affects parsing for `In` operator)
 ///
+/// # Regex Flags
+///
+/// With [`Op::Regex`], a value written as `/pattern/flags` compiles `pattern`
+/// with the given flags: `i` for case-insensitive, `m` for multiline. Any
+/// other value is compiled as-is, so `name-regex=/Foo/i` matches "foo" while
+/// `name-regex=Foo` does not. Unknown flags fail with
+/// [`ParseError::InvalidRegex`].
+///
 /// # Returns
 ///
 /// A typed `ClauseValue` or an error if parsing fails.
@@ -254,28 +381,71 @@ pub fn parse_value<S: SeekerSchema>(
     field_type: SeekType,
     op: Op,
 ) -> ParseResult<ClauseValue> {
+    // Presence checks take a bool regardless of the field type
+    if op.is_presence_op() {
+        return parse_bool::<S>(value, field);
+    }
+
     match field_type {
-        SeekType::String => {
-            if op == Op::Regex {
-                match regex::Regex::new(value) {
-                    Ok(re) => Ok(ClauseValue::Regex(re)),
-                    Err(e) => Err(ParseError::InvalidRegex {
-                        field: field.to_string(),
-                        pattern: value.to_string(),
-                        error: e.to_string(),
-                    }),
-                }
-            } else {
-                Ok(ClauseValue::String(value.to_string()))
-            }
-        }
+        SeekType::String => string_value(value, field, op),
         SeekType::Number => parse_number(value, field),
         SeekType::Timestamp => parse_timestamp(value, field),
         SeekType::Enum => parse_enum::<S>(value, field, op),
-        SeekType::Bool => parse_bool(value, field),
+        SeekType::Bool => parse_bool::<S>(value, field),
+    }
+}
+
+/// Check that `op` can be applied to a field of type `field_type`.
+///
+/// Shared by the string parser and the typed [`Query`] builders.
+pub(crate) fn check_operator(field: &str, field_type: SeekType, op: Op) -> ParseResult<()> {
+    if field_type.is_valid_operator(op) {
+        Ok(())
+    } else {
+        Err(ParseError::InvalidOperator {
+            field: field.to_string(),
+            operator: op.to_string(),
+            field_type,
+        })
+    }
+}
+
+/// Build a string clause value, compiling the pattern for [`Op::Regex`].
+pub(crate) fn string_value(value: &str, field: &str, op: Op) -> ParseResult<ClauseValue> {
+    if op == Op::Regex {
+        match compile_regex(value) {
+            Ok(re) => Ok(ClauseValue::Regex(re)),
+            Err(error) => Err(ParseError::InvalidRegex {
+                field: field.to_string(),
+                pattern: value.to_string(),
+                error,
+            }),
+        }
+    } else {
+        Ok(ClauseValue::String(value.to_string()))
     }
 }
 
+/// Compile a regex pattern, honoring the `/pattern/flags` form.
+///
+/// Flags are `i` (case-insensitive) and `m` (`^`/`$` match at line breaks).
+/// The delimited form applies only when the value starts with `/` and
+/// everything after the last `/` is a flag, so path-like values such as
+/// `/usr/local/bin` are compiled as-is, like any other pattern.
+fn compile_regex(value: &str) -> Result<regex::Regex, String> {
+    let (pattern, flags) = value
+        .strip_prefix('/')
+        .and_then(|rest| rest.rsplit_once('/'))
+        .filter(|(_, flags)| flags.chars().all(|flag| matches!(flag, 'i' | 'm')))
+        .unwrap_or((value, ""));
+
+    regex::RegexBuilder::new(pattern)
+        .case_insensitive(flags.contains('i'))
+        .multi_line(flags.contains('m'))
+        .build()
+        .map_err(|e| e.to_string())
+}
+
 fn parse_number(value: &str, field: &str) -> ParseResult<ClauseValue> {
     // Try integer first
     if let Ok(n) = value.parse::<i64>() {
@@ -287,8 +457,25 @@ fn parse_number(value: &str, field: &str) -> ParseResult<ClauseValue> {
         return Ok(ClauseValue::Number(Number::U64(n)));
     }
 
+    let too_large = || ParseError::InvalidValue {
+        field: field.to_string(),
+        value: value.to_string(),
+        expected: SeekType::Number,
+        reason: "magnitude is too large to compare exactly".to_string(),
+    };
+
+    // An integer past u64 would only survive as a rounded float
+    let digits = value.strip_prefix(['-', '+']).unwrap_or(value);
+    if !digits.is_empty() && digits.bytes().all(|b| b.is_ascii_digit()) {
+        return Err(too_large());
+    }
+
     // Try float
     if let Ok(n) = value.parse::<f64>() {
+        // Literals like `1e400` overflow to infinity
+        if n.is_infinite() && value.bytes().any(|b| b.is_ascii_digit()) {
+            return Err(too_large());
+        }
         return Ok(ClauseValue::Number(Number::F64(n)));
     }
 
@@ -306,9 +493,14 @@ fn parse_timestamp(value: &str, field: &str) -> ParseResult<ClauseValue> {
         return Ok(ClauseValue::Timestamp(Timestamp(ms)));
     }
 
+    // Relative: now, today, yesterday, tomorrow (resolved against the clock)
+    if let Some(ts) = parse_relative(value) {
+        return Ok(ClauseValue::Timestamp(ts));
+    }
+
     // Try ISO 8601 date formats
-    // Full datetime with timezone: 2024-01-15T10:30:00Z
-    // Full datetime: 2024-01-15T10:30:00
+    // Full datetime with timezone: 2024-01-15T10:30:00Z, 2024-01-15T10:30:00+02:00
+    // Full datetime (taken as UTC): 2024-01-15T10:30:00
     // Date only: 2024-01-15
     // Year only: 2024
 
@@ -317,7 +509,7 @@ fn parse_timestamp(value: &str, field: &str) -> ParseResult<ClauseValue> {
         return Ok(ClauseValue::Timestamp(ts));
     }
 
-    // Datetime: YYYY-MM-DDTHH:MM:SS or YYYY-MM-DDTHH:MM:SSZ
+    // Datetime: YYYY-MM-DDTHH:MM:SS, optionally followed by Z or ±HH:MM
     if let Some(ts) = parse_datetime(value) {
         return Ok(ClauseValue::Timestamp(ts));
     }
@@ -336,10 +528,32 @@ fn parse_timestamp(value: &str, field: &str) -> ParseResult<ClauseValue> {
         field: field.to_string(),
         value: value.to_string(),
         expected: SeekType::Timestamp,
-        reason: "expected Unix timestamp (ms), ISO date (YYYY-MM-DD), or datetime".to_string(),
+        reason: "expected Unix timestamp (ms), ISO date (YYYY-MM-DD), datetime \
+                 (YYYY-MM-DDTHH:MM:SS with optional Z or ±HH:MM offset), \
+                 or now/today/yesterday/tomorrow"
+            .to_string(),
     })
 }
 
+/// Resolves `now`, `today`, `yesterday` and `tomorrow` (any case) against
+/// [`now_ms`](crate::now_ms).
+///
+/// `now` is the current instant; the others are midnight UTC of their day.
+fn parse_relative(value: &str) -> Option<Timestamp> {
+    const DAY_MS: i64 = 24 * 60 * 60 * 1000;
+
+    let now = crate::clock::now_ms();
+    let midnight = now.div_euclid(DAY_MS) * DAY_MS;
+    let ms = match value.to_ascii_lowercase().as_str() {
+        "now" => now,
+        "today" => midnight,
+        "yesterday" => midnight - DAY_MS,
+        "tomorrow" => midnight + DAY_MS,
+        _ => return None,
+    };
+    Some(Timestamp(ms))
+}
+
 fn parse_date_only(value: &str) -> Option<Timestamp> {
     // YYYY-MM-DD format
     let parts: Vec<&str> = value.split('-').collect();
@@ -360,16 +574,21 @@ fn parse_date_only(value: &str) -> Option<Timestamp> {
     Some(Timestamp(ms))
 }
 
+/// Parses `YYYY-MM-DDTHH:MM:SS[.fff]` with an optional `Z` or `±HH:MM` offset.
+///
+/// The result is normalized to UTC by subtracting the offset. A datetime
+/// without an offset is taken as UTC, not local time, so queries mean the
+/// same thing on every machine.
 fn parse_datetime(value: &str) -> Option<Timestamp> {
-    // YYYY-MM-DDTHH:MM:SS or YYYY-MM-DDTHH:MM:SSZ
-    let value = value.trim_end_matches('Z');
     let parts: Vec<&str> = value.split('T').collect();
     if parts.len() != 2 {
         return None;
     }
 
+    let (clock, offset_ms) = split_utc_offset(parts[1])?;
+
     let date_parts: Vec<&str> = parts[0].split('-').collect();
-    let time_parts: Vec<&str> = parts[1].split(':').collect();
+    let time_parts: Vec<&str> = clock.split(':').collect();
 
     if date_parts.len() != 3 || time_parts.len() != 3 {
         return None;
@@ -380,9 +599,16 @@ fn parse_datetime(value: &str) -> Option<Timestamp> {
     let day: u32 = date_parts[2].parse().ok()?;
     let hour: u32 = time_parts[0].parse().ok()?;
     let minute: u32 = time_parts[1].parse().ok()?;
-    // Handle fractional seconds
-    let second_str = time_parts[2].split('.').next()?;
+    // Fractional seconds are kept to millisecond precision
+    let (second_str, fraction) = match time_parts[2].split_once('.') {
+        Some((second, fraction)) => (second, Some(fraction)),
+        None => (time_parts[2], None),
+    };
     let second: u32 = second_str.parse().ok()?;
+    let millis = match fraction {
+        Some(fraction) => parse_millis(fraction)?,
+        None => 0,
+    };
 
     if !(1..=12).contains(&month)
         || !(1..=31).contains(&day)
@@ -395,8 +621,49 @@ fn parse_datetime(value: &str) -> Option<Timestamp> {
 
     let days = days_from_ymd(year, month, day)?;
     let seconds = hour * 3600 + minute * 60 + second;
-    let ms = days as i64 * 24 * 60 * 60 * 1000 + seconds as i64 * 1000;
-    Some(Timestamp(ms))
+    let ms = days * 24 * 60 * 60 * 1000 + seconds as i64 * 1000 + millis;
+    Some(Timestamp(ms - offset_ms))
+}
+
+/// Converts the digits after the decimal point of a seconds value to milliseconds.
+///
+/// Shorter fractions are padded (`5` is 500 ms) and digits beyond the third
+/// are truncated, since `Timestamp` only has millisecond precision.
+fn parse_millis(fraction: &str) -> Option<i64> {
+    if fraction.is_empty() || !fraction.bytes().all(|b| b.is_ascii_digit()) {
+        return None;
+    }
+    let digits = &fraction[..fraction.len().min(3)];
+    let scale = 10_i64.pow(3 - digits.len() as u32);
+    Some(digits.parse::<i64>().ok()? * scale)
+}
+
+/// Splits the time of a datetime from its UTC offset, returned in milliseconds.
+///
+/// Accepts a trailing `Z` or a numeric `+HH:MM` / `-HH:MM` offset; no suffix
+/// means UTC. Returns `None` for a malformed offset.
+fn split_utc_offset(time: &str) -> Option<(&str, i64)> {
+    if let Some(clock) = time.strip_suffix('Z') {
+        return Some((clock, 0));
+    }
+
+    let Some(pos) = time.find(['+', '-']) else {
+        return Some((time, 0));
+    };
+    let (clock, offset) = time.split_at(pos);
+    let sign = if offset.starts_with('-') { -1 } else { 1 };
+
+    let (hours, minutes) = offset[1..].split_once(':')?;
+    if hours.len() != 2 || minutes.len() != 2 {
+        return None;
+    }
+    let hours: i64 = hours.parse().ok()?;
+    let minutes: i64 = minutes.parse().ok()?;
+    if hours >= 24 || minutes >= 60 {
+        return None;
+    }
+
+    Some((clock, sign * (hours * 60 + minutes) * 60 * 1000))
 }
 
 /// Calculate days since Unix epoch for a given year (Jan 1).
@@ -451,7 +718,7 @@ fn is_leap_year(year: i32) -> bool {
 }
 
 fn parse_enum<S: SeekerSchema>(value: &str, field: &str, op: Op) -> ParseResult<ClauseValue> {
-    if op == Op::In {
+    if matches!(op, Op::In | Op::NotIn) {
         // Comma-separated values
         let mut discriminants = Vec::new();
         for part in value.split(',') {
@@ -485,17 +752,40 @@ fn parse_single_enum<S: SeekerSchema>(value: &str, field: &str) -> ParseResult<u
     })
 }
 
-fn parse_bool(value: &str, field: &str) -> ParseResult<ClauseValue> {
+/// Parse a bool, trying the schema's tokens for `field` before the built-in sets.
+fn parse_bool<S: SeekerSchema>(value: &str, field: &str) -> ParseResult<ClauseValue> {
+    let custom = S::bool_tokens(field);
+    if let Some((truthy, falsy)) = custom {
+        if truthy.iter().any(|t| t.eq_ignore_ascii_case(value)) {
+            return Ok(ClauseValue::Bool(true));
+        }
+        if falsy.iter().any(|t| t.eq_ignore_ascii_case(value)) {
+            return Ok(ClauseValue::Bool(false));
+        }
+    }
+
     let lower = value.to_lowercase();
     match lower.as_str() {
         "true" | "1" | "yes" | "on" => Ok(ClauseValue::Bool(true)),
         "false" | "0" | "no" | "off" => Ok(ClauseValue::Bool(false)),
-        _ => Err(ParseError::InvalidValue {
-            field: field.to_string(),
-            value: value.to_string(),
-            expected: SeekType::Bool,
-            reason: "expected true/false, 1/0, yes/no, or on/off".to_string(),
-        }),
+        _ => {
+            let builtin = "true/false, 1/0, yes/no, or on/off";
+            let reason = match custom {
+                Some((truthy, falsy)) => format!(
+                    "expected {}/{}, {}",
+                    truthy.join("|"),
+                    falsy.join("|"),
+                    builtin
+                ),
+                None => format!("expected {}", builtin),
+            };
+            Err(ParseError::InvalidValue {
+                field: field.to_string(),
+                value: value.to_string(),
+                expected: SeekType::Bool,
+                reason,
+            })
+        }
     }
 }
 
@@ -506,33 +796,89 @@ fn parse_bool(value: &str, field: &str) -> ParseResult<ClauseValue> {
 /// - `field` → ascending order
 /// - `field-asc` → ascending order
 /// - `field-desc` → descending order
+/// - `+field` → ascending order
+/// - `-field` → descending order
+///
+/// Direction suffixes are case-insensitive and accept the aliases
+/// `ascending` and `descending`. Compound field names keep their dashes, so
+/// `-created-at` sorts descending on `created-at`. A sign and a suffix may
+/// be combined only if they agree.
+///
+/// `up` and `down` are not suffixes here, since they often end field names
+/// (`follow-up`, `break-down`). [`parse_query`] also accepts them as
+/// suffixes when the rest of the value names a schema field.
 pub fn parse_ordering(value: &str) -> ParseResult<OrderBy> {
-    let parts: Vec<&str> = value.split('-').collect();
+    parse_ordering_with(value, |_| false)
+}
 
-    if parts.is_empty() {
-        return Err(ParseError::InvalidOrdering {
-            value: value.to_string(),
-            reason: "empty ordering specification".to_string(),
-        });
+/// Like [`parse_ordering`], but `known_field` decides when an `up`/`down`
+/// suffix is a direction: only when what precedes it is a known field and
+/// the whole value isn't.
+fn parse_ordering_with(value: &str, known_field: impl Fn(&str) -> bool) -> ParseResult<OrderBy> {
+    let invalid = |reason: &str| ParseError::InvalidOrdering {
+        value: value.to_string(),
+        reason: reason.to_string(),
+    };
+
+    if value.is_empty() {
+        return Err(invalid("empty ordering specification"));
     }
 
-    let last = parts.last().unwrap().to_lowercase();
-    let (field, dir) = if last == "asc" {
-        (parts[..parts.len() - 1].join("-"), Dir::Asc)
-    } else if last == "desc" {
-        (parts[..parts.len() - 1].join("-"), Dir::Desc)
+    // Leading sign: `+field` / `-field`
+    let (sign_dir, rest) = if let Some(rest) = value.strip_prefix('+') {
+        (Some(Dir::Asc), rest)
+    } else if let Some(rest) = value.strip_prefix('-') {
+        (Some(Dir::Desc), rest)
     } else {
-        (value.to_string(), Dir::Asc)
+        (None, value)
+    };
+
+    // Trailing direction keyword: `field-desc`. A bare keyword has no field.
+    let is_suffix = |field: &str, last: &str| {
+        if is_word_direction(last) {
+            known_field(field) && !known_field(rest)
+        } else {
+            parse_direction(last).is_some()
+        }
+    };
+    let (field, suffix_dir) = match rest.rsplit_once('-') {
+        Some((field, last)) if is_suffix(field, last) => (field, parse_direction(last)),
+        _ if parse_direction(rest).is_some() && !is_word_direction(rest) => {
+            ("", parse_direction(rest))
+        }
+        _ => (rest, None),
     };
 
     if field.is_empty() {
-        return Err(ParseError::InvalidOrdering {
-            value: value.to_string(),
-            reason: "missing field name".to_string(),
-        });
+        return Err(invalid("missing field name"));
     }
 
-    Ok(OrderBy { field, dir })
+    let dir = match (sign_dir, suffix_dir) {
+        (Some(sign), Some(suffix)) if sign != suffix => {
+            return Err(invalid("sign prefix and direction suffix disagree"));
+        }
+        (sign, suffix) => sign.or(suffix).unwrap_or(Dir::Asc),
+    };
+
+    Ok(OrderBy {
+        field: field.to_string(),
+        dir,
+    })
+}
+
+/// Returns true for the `up`/`down` direction aliases, which are also common
+/// words at the end of field names.
+fn is_word_direction(s: &str) -> bool {
+    s.eq_ignore_ascii_case("up") || s.eq_ignore_ascii_case("down")
+}
+
+/// Parse a sort direction keyword (case-insensitive).
+fn parse_direction(s: &str) -> Option<Dir> {
+    match s.to_lowercase().as_str() {
+        "asc" | "ascending" | "up" => Some(Dir::Asc),
+        "desc" | "descending" | "down" => Some(Dir::Desc),
+        _ => None,
+    }
 }
 
 /// Parse key-value pairs into a [`Query`].
@@ -548,10 +894,39 @@ pub fn parse_ordering(value: &str) -> ParseResult<OrderBy> {
 /// # Special Keys
 ///
 /// - `AND`, `OR`, `NOT` - Change the current clause group
+/// - `GROUP_START`, `GROUP_END` - Open and close a nested group
 /// - `order` - Add ordering specification
-/// - `limit` - Set result limit
+/// - `limit` - Set result limit; `limit=0` returns no results
 /// - `offset` - Set result offset
 ///
+/// `limit` and `offset` take non-negative integers. Values that don't fit in
+/// `usize`, and limits above [`SeekerSchema::max_limit`], fail with
+/// [`ParseError::InvalidLimit`].
+///
+/// # Nested Groups
+///
+/// `GROUP_START` opens a nested query that joins the current clause group
+/// as a single unit, and `GROUP_END` closes it. Inside a group the clause
+/// group starts over at `AND`, and `AND`/`OR`/`NOT` only apply within it;
+/// after `GROUP_END` the enclosing group is back in effect. Groups nest to
+/// any depth. `order`, `limit` and `offset` always apply to the top-level
+/// query. Markers that do not pair up fail with
+/// [`ParseError::UnbalancedGroup`].
+///
+/// `(name contains "a" AND priority >= 3) OR (name contains "b" AND done)`
+/// reads as:
+///
+/// ```text
+/// OR
+/// GROUP_START  name-contains=a  priority-gte=3  GROUP_END
+/// GROUP_START  name-contains=b  done            GROUP_END
+/// ```
+///
+/// Repeated `order` keys accumulate in input order: the first is the primary
+/// sort and each later one breaks ties left by the previous ones. So
+/// `order=priority-desc` followed by `order=name-asc` sorts by priority
+/// descending, then by name ascending.
+///
 /// # Example
 ///
 /// ```
@@ -581,96 +956,214 @@ pub fn parse_ordering(value: &str) -> ParseResult<OrderBy> {
 pub fn parse_query<S: SeekerSchema>(
     pairs: impl IntoIterator<Item = (String, String)>,
 ) -> ParseResult<Query> {
+    parse_query_indexed::<S>(pairs).map_err(|(err, _)| err)
+}
+
+/// Parse key-value pairs into a [`Query`], reporting which pair failed.
+///
+/// Behaves like [`parse_query`], but on failure also returns the zero-based
+/// index of the offending pair in the input. Every pair counts, including
+/// group markers and special keys, so the index maps directly back to the
+/// original argument list and a front-end can point at the exact token.
+///
+/// # Example
+///
+/// ```
+/// use standout_seeker::{parse_query_indexed, ParseError, SeekerSchema, SeekType};
+///
+/// struct Task;
+/// impl SeekerSchema for Task {
+///     fn field_type(field: &str) -> Option<SeekType> {
+///         match field {
+///             "name" => Some(SeekType::String),
+///             _ => None,
+///         }
+///     }
+///     fn field_names() -> &'static [&'static str] {
+///         &["name"]
+///     }
+/// }
+///
+/// let pairs = vec![
+///     ("name-contains".to_string(), "test".to_string()),
+///     ("OR".to_string(), String::new()),
+///     ("titel".to_string(), "x".to_string()),
+/// ];
+///
+/// let (err, index) = parse_query_indexed::<Task>(pairs).unwrap_err();
+/// assert!(matches!(err, ParseError::UnknownField { .. }));
+/// assert_eq!(index, 2);
+/// ```
+pub fn parse_query_indexed<S: SeekerSchema>(
+    pairs: impl IntoIterator<Item = (String, String)>,
+) -> Result<Query, (ParseError, usize)> {
     let mut query = Query::new();
     let mut current_group = ClauseGroup::And;
+    // Enclosing queries of the open groups: (query, its clause group, GROUP_START index)
+    let mut open: Vec<(Query, ClauseGroup, usize)> = Vec::new();
 
-    for (key, value) in pairs {
-        let key_upper = key.to_uppercase();
-
+    for (index, (key, value)) in pairs.into_iter().enumerate() {
         // Handle group markers
-        match key_upper.as_str() {
-            "AND" => {
+        match key.to_uppercase().as_str() {
+            "AND" => current_group = ClauseGroup::And,
+            "OR" => current_group = ClauseGroup::Or,
+            "NOT" => current_group = ClauseGroup::Not,
+            "GROUP_START" => {
+                open.push((std::mem::take(&mut query), current_group, index));
                 current_group = ClauseGroup::And;
-                continue;
             }
-            "OR" => {
-                current_group = ClauseGroup::Or;
-                continue;
+            "GROUP_END" => {
+                let Some((outer, group, _)) = open.pop() else {
+                    let reason = "GROUP_END without a matching GROUP_START";
+                    return Err((unbalanced_group(reason), index));
+                };
+                let inner = std::mem::replace(&mut query, outer).build();
+                query = match group {
+                    ClauseGroup::And => query.and_group(inner),
+                    ClauseGroup::Or => query.or_group(inner),
+                    ClauseGroup::Not => query.not_group(inner),
+                };
+                current_group = group;
             }
-            "NOT" => {
-                current_group = ClauseGroup::Not;
-                continue;
+            _ => {
+                // Ordering and paging belong to the top-level query
+                let target = match open.first_mut() {
+                    Some((root, _, _)) if is_query_option(&key) => root,
+                    _ => &mut query,
+                };
+                *target = parse_pair::<S>(std::mem::take(target), current_group, &key, value)
+                    .map_err(|err| (err, index))?;
             }
-            _ => {}
         }
+    }
 
-        // Handle special keys
-        let key_lower = key.to_lowercase();
-        match key_lower.as_str() {
-            "order" | "orderby" | "order-by" | "sort" => {
-                let order = parse_ordering(&value)?;
-                query = query.order_by(&order.field, order.dir);
-                continue;
-            }
-            "limit" => {
-                let n: usize = value.parse().map_err(|_| ParseError::InvalidLimit {
-                    key: "limit".to_string(),
-                    value: value.clone(),
-                })?;
-                query = query.limit(n);
-                continue;
-            }
-            "offset" | "skip" => {
-                let n: usize = value.parse().map_err(|_| ParseError::InvalidLimit {
-                    key: "offset".to_string(),
-                    value: value.clone(),
-                })?;
-                query = query.offset(n);
-                continue;
-            }
-            _ => {}
+    if let Some((_, _, start)) = open.last() {
+        let reason = "GROUP_START is never closed by GROUP_END";
+        return Err((unbalanced_group(reason), *start));
+    }
+
+    Ok(query.build())
+}
+
+fn unbalanced_group(reason: &str) -> ParseError {
+    ParseError::UnbalancedGroup {
+        reason: reason.to_string(),
+    }
+}
+
+/// Parse a `limit` or `offset` value, rejecting values above `max`.
+fn parse_count(key: &str, value: &str, max: Option<usize>) -> ParseResult<usize> {
+    let invalid = |reason: String| ParseError::InvalidLimit {
+        key: key.to_string(),
+        value: value.to_string(),
+        reason,
+    };
+
+    let n: usize = value.parse().map_err(|e: ParseIntError| match e.kind() {
+        IntErrorKind::PosOverflow => {
+            invalid(format!("out of range, the maximum is {}", usize::MAX))
         }
+        _ => invalid("expected a non-negative integer".to_string()),
+    })?;
 
-        // Parse field and operator
-        let (field, parsed_op) = parse_key(&key);
+    match max {
+        Some(max) if n > max => Err(invalid(format!("exceeds the maximum of {}", max))),
+        _ => Ok(n),
+    }
+}
 
-        // Look up field type
-        let field_type = S::field_type(&field).ok_or_else(|| ParseError::UnknownField {
-            field: field.clone(),
-            available: S::field_names().iter().map(|s| s.to_string()).collect(),
-        })?;
+/// A key that sets ordering or paging rather than adding a clause.
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+enum QueryOption {
+    Order,
+    Limit,
+    Offset,
+}
 
-        // Determine operator (use default if not specified)
-        let op = parsed_op.unwrap_or_else(|| field_type.default_operator());
+impl QueryOption {
+    /// Recognizes a query option key and its aliases, case-insensitively.
+    fn from_key(key: &str) -> Option<Self> {
+        match key.to_lowercase().as_str() {
+            "order" | "orderby" | "order-by" | "sort" => Some(QueryOption::Order),
+            "limit" => Some(QueryOption::Limit),
+            "offset" | "skip" => Some(QueryOption::Offset),
+            _ => None,
+        }
+    }
+}
 
-        // Validate operator for field type
-        if !field_type.is_valid_operator(op) {
-            return Err(ParseError::InvalidOperator {
-                field: field.clone(),
-                operator: op.to_string(),
-                field_type,
-            });
+/// Whether `key` sets ordering or paging rather than adding a clause.
+fn is_query_option(key: &str) -> bool {
+    QueryOption::from_key(key).is_some()
+}
+
+/// Apply a single non-group key-value pair to `query`.
+fn parse_pair<S: SeekerSchema>(
+    query: Query,
+    group: ClauseGroup,
+    key: &str,
+    value: String,
+) -> ParseResult<Query> {
+    // Handle special keys
+    match QueryOption::from_key(key) {
+        Some(QueryOption::Order) => {
+            let order = parse_ordering_with(&value, |f| S::field_type(f).is_some())?;
+            return Ok(query.order_by(&order.field, order.dir));
+        }
+        Some(QueryOption::Limit) => {
+            let n = parse_count("limit", &value, S::max_limit())?;
+            return Ok(query.limit(n));
+        }
+        Some(QueryOption::Offset) => {
+            let n = parse_count("offset", &value, None)?;
+            return Ok(query.offset(n));
         }
+        None => {}
+    }
 
-        // Handle boolean fields with empty value (bare flag)
-        let value = if value.is_empty() && field_type == SeekType::Bool {
-            "true".to_string()
-        } else {
-            value
-        };
+    // Parse field and operator, noting the accent-insensitive modifier
+    let (key, unaccented) = split_unaccented(key);
+    let (field, parsed_op) = parse_key(key);
+
+    // Look up field type
+    let field_type = match S::field_type(&field) {
+        Some(field_type) => field_type,
+        None => return Err(unknown_field_error::<S>(&field)),
+    };
 
-        // Parse the value
-        let clause_value = parse_value::<S>(&value, &field, field_type, op)?;
+    // Determine operator (use default if not specified)
+    let op = parsed_op.unwrap_or_else(|| field_type.default_operator());
 
-        // Add clause to appropriate group
-        query = match current_group {
-            ClauseGroup::And => query.and(&field, op, clause_value),
-            ClauseGroup::Or => query.or(&field, op, clause_value),
-            ClauseGroup::Not => query.not(&field, op, clause_value),
-        };
+    // Validate operator for field type
+    if unaccented && field_type != SeekType::String {
+        return Err(ParseError::InvalidOperator {
+            field: field.to_string(),
+            operator: format!("{}{}", op, UNACCENTED_SUFFIX),
+            field_type,
+        });
     }
+    check_operator(&field, field_type, op)?;
 
-    Ok(query.build())
+    // Handle boolean fields and presence checks with empty value (bare flag)
+    let value = if value.is_empty() && (field_type == SeekType::Bool || op.is_presence_op()) {
+        "true".to_string()
+    } else {
+        value
+    };
+
+    // Parse the value
+    let clause_value = if unaccented {
+        ClauseValue::unaccented(&value)
+    } else {
+        parse_value::<S>(&value, &field, field_type, op)?
+    };
+
+    // Add clause to appropriate group
+    Ok(match group {
+        ClauseGroup::And => query.and(&field, op, clause_value),
+        ClauseGroup::Or => query.or(&field, op, clause_value),
+        ClauseGroup::Not => query.not(&field, op, clause_value),
+    })
 }
 
 #[cfg(test)]
@@ -730,6 +1223,9 @@ mod tests {
         assert_eq!(parse_operator("endswith"), Some(Op::EndsWith));
         assert_eq!(parse_operator("contains"), Some(Op::Contains));
         assert_eq!(parse_operator("regex"), Some(Op::Regex));
+        assert_eq!(parse_operator("notcontains"), Some(Op::NotContains));
+        assert_eq!(parse_operator("!contains"), Some(Op::NotContains));
+        assert_eq!(parse_operator("notin"), Some(Op::NotIn));
     }
 
     #[test]
@@ -748,6 +1244,17 @@ mod tests {
         assert_eq!(parse_operator("BEFORE"), Some(Op::Before));
     }
 
+    #[test]
+    fn test_parse_operator_presence() {
+        assert_eq!(parse_operator("isnull"), Some(Op::IsNull));
+        assert_eq!(parse_operator("IsNotNull"), Some(Op::IsNotNull));
+        assert_eq!(parse_operator("null"), None);
+
+        let (field, op) = parse_key("due-isnotnull");
+        assert_eq!(field, "due");
+        assert_eq!(op, Some(Op::IsNotNull));
+    }
+
     #[test]
     fn test_parse_operator_unknown() {
         assert_eq!(parse_operator("unknown"), None);
@@ -788,6 +1295,32 @@ mod tests {
         assert_eq!(op, None);
     }
 
+    #[test]
+    fn test_parse_key_dotted_field() {
+        let (field, op) = parse_key("author.name-contains");
+        assert_eq!(field, "author.name");
+        assert_eq!(op, Some(Op::Contains));
+
+        let (field, op) = parse_key("author.name");
+        assert_eq!(field, "author.name");
+        assert_eq!(op, None);
+    }
+
+    #[test]
+    fn test_parse_key_dotted_and_hyphenated_field() {
+        let (field, op) = parse_key("author.joined-at-before");
+        assert_eq!(field, "author.joined-at");
+        assert_eq!(op, Some(Op::Before));
+    }
+
+    #[test]
+    fn test_parse_key_dot_does_not_separate_operator() {
+        // Operators are only recognized after a dash
+        let (field, op) = parse_key("author.contains");
+        assert_eq!(field, "author.contains");
+        assert_eq!(op, None);
+    }
+
     // =========================================================================
     // parse_value tests - Numbers
     // =========================================================================
@@ -820,6 +1353,39 @@ mod tests {
         assert!(matches!(result, Err(ParseError::InvalidValue { .. })));
     }
 
+    #[test]
+    fn test_parse_number_integer_overflow() {
+        for value in ["1234567890123456789012345", "-1234567890123456789012345"] {
+            let err =
+                parse_value::<TestTask>(value, "priority", SeekType::Number, Op::Eq).unwrap_err();
+            let ParseError::InvalidValue { reason, .. } = err else {
+                panic!("expected InvalidValue, got {:?}", err);
+            };
+            assert!(reason.contains("too large"));
+        }
+
+        // The edges of u64 and i64 still parse exactly
+        let val =
+            parse_value::<TestTask>("18446744073709551615", "priority", SeekType::Number, Op::Eq)
+                .unwrap();
+        assert!(matches!(val, ClauseValue::Number(Number::U64(u64::MAX))));
+        let val =
+            parse_value::<TestTask>("-9223372036854775808", "priority", SeekType::Number, Op::Eq)
+                .unwrap();
+        assert!(matches!(val, ClauseValue::Number(Number::I64(i64::MIN))));
+    }
+
+    #[test]
+    fn test_parse_number_float_overflow() {
+        let err =
+            parse_value::<TestTask>("1e400", "priority", SeekType::Number, Op::Eq).unwrap_err();
+        assert!(matches!(err, ParseError::InvalidValue { .. }));
+
+        // Large but finite floats are fine
+        let val = parse_value::<TestTask>("1.5e300", "priority", SeekType::Number, Op::Eq).unwrap();
+        assert!(matches!(val, ClauseValue::Number(Number::F64(_))));
+    }
+
     // =========================================================================
     // parse_value tests - Timestamps
     // =========================================================================
@@ -864,23 +1430,126 @@ mod tests {
         }
     }
 
-    #[test]
-    fn test_parse_timestamp_year_only() {
-        let val =
-            parse_value::<TestTask>("2024", "created-at", SeekType::Timestamp, Op::Eq).unwrap();
-        if let ClauseValue::Timestamp(ts) = val {
-            // 2024 should be > 1970
-            assert!(ts.0 > 0);
-        } else {
-            panic!("Expected Timestamp");
+    fn parse_ts(value: &str) -> ParseResult<i64> {
+        match parse_value::<TestTask>(value, "created-at", SeekType::Timestamp, Op::Eq)? {
+            ClauseValue::Timestamp(ts) => Ok(ts.0),
+            _ => panic!("Expected Timestamp"),
         }
     }
 
     #[test]
-    fn test_parse_timestamp_invalid() {
-        let result =
-            parse_value::<TestTask>("not-a-date", "created-at", SeekType::Timestamp, Op::Eq);
-        assert!(matches!(result, Err(ParseError::InvalidValue { .. })));
+    fn test_parse_timestamp_offset_matches_utc() {
+        let utc = parse_ts("2024-01-15T08:30:00Z").unwrap();
+        assert_eq!(parse_ts("2024-01-15T10:30:00+02:00").unwrap(), utc);
+        assert_eq!(parse_ts("2024-01-15T03:00:00-05:30").unwrap(), utc);
+        assert_eq!(utc, 1_705_307_400_000);
+    }
+
+    #[test]
+    fn test_parse_timestamp_without_offset_is_utc() {
+        assert_eq!(
+            parse_ts("2024-01-15T10:30:00").unwrap(),
+            parse_ts("2024-01-15T10:30:00Z").unwrap()
+        );
+        assert_eq!(
+            parse_ts("2024-01-15T10:30:00+00:00").unwrap(),
+            parse_ts("2024-01-15T10:30:00Z").unwrap()
+        );
+    }
+
+    #[test]
+    fn test_parse_timestamp_fractional_seconds() {
+        let whole = parse_ts("2024-01-15T10:30:00Z").unwrap();
+        assert_eq!(parse_ts("2024-01-15T10:30:00.000Z").unwrap(), whole);
+        assert_eq!(parse_ts("2024-01-15T10:30:00.5Z").unwrap(), whole + 500);
+        assert_eq!(parse_ts("2024-01-15T10:30:00.500Z").unwrap(), whole + 500);
+        assert_eq!(parse_ts("2024-01-15T10:30:00.250Z").unwrap(), whole + 250);
+        assert_eq!(
+            parse_ts("2024-01-15T10:30:00.250+02:00").unwrap(),
+            whole + 250 - 2 * 60 * 60 * 1000
+        );
+    }
+
+    #[test]
+    fn test_parse_timestamp_fraction_beyond_millis_truncates() {
+        let whole = parse_ts("2024-01-15T10:30:00Z").unwrap();
+        assert_eq!(
+            parse_ts("2024-01-15T10:30:00.123987654Z").unwrap(),
+            whole + 123
+        );
+    }
+
+    #[test]
+    fn test_parse_timestamp_malformed_fraction() {
+        for value in ["2024-01-15T10:30:00.Z", "2024-01-15T10:30:00.5xZ"] {
+            assert!(
+                matches!(parse_ts(value), Err(ParseError::InvalidValue { .. })),
+                "{}",
+                value
+            );
+        }
+    }
+
+    #[test]
+    fn test_parse_timestamp_offset_crosses_midnight() {
+        assert_eq!(
+            parse_ts("2024-01-15T01:00:00+03:00").unwrap(),
+            parse_ts("2024-01-14T22:00:00Z").unwrap()
+        );
+    }
+
+    #[test]
+    fn test_parse_timestamp_malformed_offset() {
+        for value in [
+            "2024-01-15T10:30:00+2:00",
+            "2024-01-15T10:30:00+0200",
+            "2024-01-15T10:30:00+24:00",
+            "2024-01-15T10:30:00+02:60",
+            "2024-01-15T10:30:00+ab:cd",
+            "2024-01-15T10:30:00ZZ",
+            "2024-01-15T10:30:00+02:00Z",
+        ] {
+            assert!(
+                matches!(parse_ts(value), Err(ParseError::InvalidValue { .. })),
+                "{}",
+                value
+            );
+        }
+    }
+
+    #[test]
+    fn test_parse_timestamp_relative_uses_clock() {
+        // 2024-01-15T10:30:00Z
+        crate::set_clock(|| 1_705_314_600_000);
+        let now = parse_ts("now");
+        let today = parse_ts("Today");
+        let yesterday = parse_ts("yesterday");
+        let tomorrow = parse_ts("tomorrow");
+        crate::reset_clock();
+
+        assert_eq!(now.unwrap(), 1_705_314_600_000);
+        assert_eq!(today.unwrap(), parse_ts("2024-01-15").unwrap());
+        assert_eq!(yesterday.unwrap(), parse_ts("2024-01-14").unwrap());
+        assert_eq!(tomorrow.unwrap(), parse_ts("2024-01-16").unwrap());
+    }
+
+    #[test]
+    fn test_parse_timestamp_year_only() {
+        let val =
+            parse_value::<TestTask>("2024", "created-at", SeekType::Timestamp, Op::Eq).unwrap();
+        if let ClauseValue::Timestamp(ts) = val {
+            // 2024 should be > 1970
+            assert!(ts.0 > 0);
+        } else {
+            panic!("Expected Timestamp");
+        }
+    }
+
+    #[test]
+    fn test_parse_timestamp_invalid() {
+        let result =
+            parse_value::<TestTask>("not-a-date", "created-at", SeekType::Timestamp, Op::Eq);
+        assert!(matches!(result, Err(ParseError::InvalidValue { .. })));
     }
 
     // =========================================================================
@@ -910,6 +1579,17 @@ mod tests {
         }
     }
 
+    #[test]
+    fn test_parse_enum_notin_operator() {
+        assert_eq!(
+            parse_key("status-notin"),
+            ("status".to_string(), Some(Op::NotIn))
+        );
+        let val =
+            parse_value::<TestTask>("pending,done", "status", SeekType::Enum, Op::NotIn).unwrap();
+        assert!(matches!(val, ClauseValue::EnumSet(set) if set == vec![0, 2]));
+    }
+
     #[test]
     fn test_parse_enum_in_with_spaces() {
         let val =
@@ -954,6 +1634,78 @@ mod tests {
         assert!(matches!(result, Err(ParseError::InvalidValue { .. })));
     }
 
+    struct LocalizedTask;
+
+    impl SeekerSchema for LocalizedTask {
+        fn field_type(field: &str) -> Option<SeekType> {
+            match field {
+                "done" | "archived" => Some(SeekType::Bool),
+                _ => None,
+            }
+        }
+
+        fn field_names() -> &'static [&'static str] {
+            &["done", "archived"]
+        }
+
+        fn bool_tokens(field: &str) -> Option<(&'static [&'static str], &'static [&'static str])> {
+            match field {
+                "done" => Some((&["y", "enabled"], &["n", "disabled"])),
+                _ => None,
+            }
+        }
+    }
+
+    #[test]
+    fn test_parse_bool_schema_tokens() {
+        for (s, expected) in [("y", true), ("Y", true), ("enabled", true), ("n", false)] {
+            let val = parse_value::<LocalizedTask>(s, "done", SeekType::Bool, Op::Eq).unwrap();
+            assert!(
+                matches!(val, ClauseValue::Bool(b) if b == expected),
+                "Failed for: {}",
+                s
+            );
+        }
+        // Built-in tokens still work alongside the custom set
+        let val = parse_value::<LocalizedTask>("off", "done", SeekType::Bool, Op::Eq).unwrap();
+        assert!(matches!(val, ClauseValue::Bool(false)));
+    }
+
+    #[test]
+    fn test_parse_bool_schema_tokens_are_per_field() {
+        let val = parse_value::<LocalizedTask>("yes", "archived", SeekType::Bool, Op::Eq).unwrap();
+        assert!(matches!(val, ClauseValue::Bool(true)));
+
+        let result = parse_value::<LocalizedTask>("y", "archived", SeekType::Bool, Op::Eq);
+        assert!(matches!(result, Err(ParseError::InvalidValue { .. })));
+    }
+
+    #[test]
+    fn test_parse_bool_schema_tokens_in_error() {
+        let err =
+            parse_value::<LocalizedTask>("maybe", "done", SeekType::Bool, Op::Eq).unwrap_err();
+        match err {
+            ParseError::InvalidValue { reason, .. } => {
+                assert_eq!(
+                    reason,
+                    "expected y|enabled/n|disabled, true/false, 1/0, yes/no, or on/off"
+                );
+            }
+            other => panic!("expected InvalidValue, got {other:?}"),
+        }
+    }
+
+    #[test]
+    fn test_parse_query_schema_bool_tokens() {
+        let pairs = vec![
+            ("done".to_string(), "n".to_string()),
+            ("archived-isnull".to_string(), "no".to_string()),
+        ];
+        let query = parse_query::<LocalizedTask>(pairs).unwrap();
+        let clauses = query.and_clauses();
+        assert!(matches!(clauses[0].value, ClauseValue::Bool(false)));
+    }
+
     // =========================================================================
     // parse_value tests - Strings
     // =========================================================================
@@ -974,6 +1726,58 @@ mod tests {
         assert!(matches!(val, ClauseValue::Regex(_)));
     }
 
+    #[test]
+    fn test_parse_string_regex_flags() {
+        let val = parse_value::<TestTask>("/Foo/i", "name", SeekType::String, Op::Regex).unwrap();
+        let ClauseValue::Regex(re) = val else {
+            panic!("expected regex, got {:?}", val);
+        };
+        assert!(re.is_match("foo"));
+        assert!(re.is_match("FOO"));
+
+        let val = parse_value::<TestTask>("/^b$/m", "name", SeekType::String, Op::Regex).unwrap();
+        let ClauseValue::Regex(re) = val else {
+            panic!("expected regex, got {:?}", val);
+        };
+        assert!(re.is_match("a\nb\nc"));
+    }
+
+    #[test]
+    fn test_parse_string_regex_without_flags_is_literal_pattern() {
+        let val = parse_value::<TestTask>("Foo", "name", SeekType::String, Op::Regex).unwrap();
+        let ClauseValue::Regex(re) = val else {
+            panic!("expected regex, got {:?}", val);
+        };
+        assert!(!re.is_match("foo"));
+
+        // A lone leading slash is part of the pattern
+        let val = parse_value::<TestTask>("/tmp", "name", SeekType::String, Op::Regex).unwrap();
+        let ClauseValue::Regex(re) = val else {
+            panic!("expected regex, got {:?}", val);
+        };
+        assert!(re.is_match("/tmp/x"));
+    }
+
+    #[test]
+    fn test_parse_string_regex_path_like_is_literal_pattern() {
+        for path in ["/usr/local/bin", "/api/v1", "/Foo/q"] {
+            let val = parse_value::<TestTask>(path, "name", SeekType::String, Op::Regex).unwrap();
+            let ClauseValue::Regex(re) = val else {
+                panic!("expected regex, got {:?}", val);
+            };
+            assert_eq!(re.as_str(), path);
+            assert!(re.is_match(&format!("{path}/more")));
+        }
+
+        // Only the slash-free tail is treated as flags
+        let val =
+            parse_value::<TestTask>("/api/v1/i", "name", SeekType::String, Op::Regex).unwrap();
+        let ClauseValue::Regex(re) = val else {
+            panic!("expected regex, got {:?}", val);
+        };
+        assert!(re.is_match("API/V1"));
+    }
+
     #[test]
     fn test_parse_string_invalid_regex() {
         let result = parse_value::<TestTask>("(unclosed", "name", SeekType::String, Op::Regex);
@@ -1024,10 +1828,121 @@ mod tests {
         assert!(matches!(result, Err(ParseError::InvalidOrdering { .. })));
     }
 
+    #[test]
+    fn test_parse_ordering_direction_aliases() {
+        for (spec, dir) in [
+            ("name-ascending", Dir::Asc),
+            ("name-descending", Dir::Desc),
+            ("name-DESC", Dir::Desc),
+            ("name-Ascending", Dir::Asc),
+        ] {
+            let order = parse_ordering(spec).unwrap();
+            assert_eq!(order.field, "name", "{}", spec);
+            assert_eq!(order.dir, dir, "{}", spec);
+        }
+    }
+
+    #[test]
+    fn test_parse_ordering_sign_prefix() {
+        let order = parse_ordering("-priority").unwrap();
+        assert_eq!(order.field, "priority");
+        assert_eq!(order.dir, Dir::Desc);
+
+        let order = parse_ordering("+priority").unwrap();
+        assert_eq!(order.field, "priority");
+        assert_eq!(order.dir, Dir::Asc);
+    }
+
+    #[test]
+    fn test_parse_ordering_sign_prefix_compound_field() {
+        let order = parse_ordering("-created-at").unwrap();
+        assert_eq!(order.field, "created-at");
+        assert_eq!(order.dir, Dir::Desc);
+
+        let order = parse_ordering("-created-at-desc").unwrap();
+        assert_eq!(order.field, "created-at");
+        assert_eq!(order.dir, Dir::Desc);
+    }
+
+    #[test]
+    fn test_parse_ordering_sign_without_field() {
+        for spec in ["-asc", "+desc", "-", "+"] {
+            let result = parse_ordering(spec);
+            assert!(
+                matches!(result, Err(ParseError::InvalidOrdering { .. })),
+                "{}: {:?}",
+                spec,
+                result
+            );
+        }
+    }
+
+    #[test]
+    fn test_parse_ordering_up_down_end_field_names() {
+        let order = parse_ordering("follow-up").unwrap();
+        assert_eq!(order, OrderBy::new("follow-up", Dir::Asc));
+
+        let order = parse_ordering("-follow-up").unwrap();
+        assert_eq!(order, OrderBy::new("follow-up", Dir::Desc));
+
+        let order = parse_ordering("break-down").unwrap();
+        assert_eq!(order, OrderBy::new("break-down", Dir::Asc));
+
+        let order = parse_ordering("up").unwrap();
+        assert_eq!(order, OrderBy::new("up", Dir::Asc));
+    }
+
+    #[test]
+    fn test_parse_ordering_conflicting_sign_and_suffix() {
+        let result = parse_ordering("-priority-asc");
+        assert!(matches!(result, Err(ParseError::InvalidOrdering { .. })));
+    }
+
     // =========================================================================
     // parse_query tests
     // =========================================================================
 
+    struct NestedTask;
+
+    impl SeekerSchema for NestedTask {
+        fn field_type(field: &str) -> Option<SeekType> {
+            match field {
+                "title" => Some(SeekType::String),
+                "author.name" => Some(SeekType::String),
+                "author.joined-at" => Some(SeekType::Timestamp),
+                _ => None,
+            }
+        }
+
+        fn field_names() -> &'static [&'static str] {
+            &["title", "author.name", "author.joined-at"]
+        }
+    }
+
+    #[test]
+    fn test_parse_query_dotted_fields() {
+        let pairs = vec![
+            ("author.name-contains".to_string(), "ada".to_string()),
+            ("author.joined-at-before".to_string(), "2024".to_string()),
+        ];
+        let query = parse_query::<NestedTask>(pairs).unwrap();
+
+        let clauses = query.and_clauses();
+        assert_eq!(clauses[0].field, "author.name");
+        assert_eq!(clauses[0].op, Op::Contains);
+        assert_eq!(clauses[1].field, "author.joined-at");
+        assert_eq!(clauses[1].op, Op::Before);
+    }
+
+    #[test]
+    fn test_parse_query_unknown_dotted_field() {
+        let pairs = vec![("author.email".to_string(), "x".to_string())];
+        let err = parse_query::<NestedTask>(pairs).unwrap_err();
+        assert!(
+            matches!(err, ParseError::UnknownField { ref field, .. } if field == "author.email")
+        );
+    }
+
     #[test]
     fn test_parse_query_simple() {
         let pairs = vec![("name-eq".to_string(), "test".to_string())];
@@ -1054,6 +1969,72 @@ mod tests {
         assert!(query.count(&Vec::<()>::new(), |_, _| crate::Value::None) == 0);
     }
 
+    #[test]
+    fn test_parse_query_negated_operators() {
+        let pairs = vec![
+            ("status-notin".to_string(), "done,active".to_string()),
+            ("name-!contains".to_string(), "draft".to_string()),
+        ];
+        let query = parse_query::<TestTask>(pairs).unwrap();
+        let clauses = query.and_clauses();
+        assert_eq!(clauses[0].op, Op::NotIn);
+        assert_eq!(clauses[1].op, Op::NotContains);
+
+        let err = parse_query::<TestTask>(vec![("priority-notin".to_string(), "1".to_string())]);
+        assert!(err.is_err());
+    }
+
+    #[test]
+    fn test_parse_query_unaccented_operators() {
+        fn accessor<'a>(name: &'a &str, _: &str) -> crate::Value<'a> {
+            crate::Value::String(name)
+        }
+
+        let names = ["café", "naïve", "cafe", "naive"];
+        let matching = |key: &str, value: &str| {
+            let query =
+                parse_query::<TestTask>(vec![(key.to_string(), value.to_string())]).unwrap();
+            query
+                .filter(&names, accessor)
+                .into_iter()
+                .copied()
+                .collect::<Vec<_>>()
+        };
+
+        assert_eq!(
+            matching("name-contains_ascii", "cafe"),
+            vec!["café", "cafe"]
+        );
+        assert_eq!(matching("name-eq_ascii", "naive"), vec!["naïve", "naive"]);
+        assert_eq!(
+            matching("name-startswith_ASCII", "naï"),
+            vec!["naïve", "naive"]
+        );
+
+        // Default operators stay diacritic-sensitive
+        assert_eq!(matching("name-contains", "cafe"), vec!["cafe"]);
+        assert_eq!(matching("name-eq", "naive"), vec!["naive"]);
+
+        let query = parse_query::<TestTask>(vec![(
+            "name-notcontains_ascii".to_string(),
+            "cafe".to_string(),
+        )])
+        .unwrap();
+        let clause = &query.and_clauses()[0];
+        assert_eq!(clause.op, Op::NotContains);
+        assert!(matches!(&clause.value, ClauseValue::Unaccented(s) if s == "cafe"));
+
+        let err = parse_query::<TestTask>(vec![("priority-eq_ascii".to_string(), "1".to_string())])
+            .unwrap_err();
+        assert!(
+            matches!(err, ParseError::InvalidOperator { ref operator, .. } if operator == "eq_ascii")
+        );
+        assert!(
+            parse_query::<TestTask>(vec![("name-regex_ascii".to_string(), "x".to_string())])
+                .is_err()
+        );
+    }
+
     #[test]
     fn test_parse_query_group_markers() {
         let pairs = vec![
@@ -1077,6 +2058,60 @@ mod tests {
         assert!(query.count(&Vec::<()>::new(), |_, _| crate::Value::None) == 0);
     }
 
+    #[test]
+    fn test_parse_query_up_down_suffix_needs_known_field() {
+        let orderings = |spec: &str| {
+            parse_query::<TestTask>(vec![("order".to_string(), spec.to_string())])
+                .unwrap()
+                .orderings()
+                .to_vec()
+        };
+
+        assert_eq!(orderings("name-up"), vec![OrderBy::new("name", Dir::Asc)]);
+        assert_eq!(
+            orderings("name-DOWN"),
+            vec![OrderBy::new("name", Dir::Desc)]
+        );
+        assert_eq!(
+            orderings("+priority-up"),
+            vec![OrderBy::new("priority", Dir::Asc)]
+        );
+
+        // `follow` isn't a field, so the suffix stays part of the name
+        assert_eq!(
+            orderings("follow-up"),
+            vec![OrderBy::new("follow-up", Dir::Asc)]
+        );
+        assert_eq!(
+            orderings("-follow-up"),
+            vec![OrderBy::new("follow-up", Dir::Desc)]
+        );
+    }
+
+    #[test]
+    fn test_parse_query_multiple_orderings() {
+        let pairs = vec![
+            ("order".to_string(), "priority-desc".to_string()),
+            ("sort".to_string(), "name-asc".to_string()),
+        ];
+        let query = parse_query::<TestTask>(pairs).unwrap();
+        assert_eq!(
+            query.orderings(),
+            &[
+                OrderBy::new("priority", Dir::Desc),
+                OrderBy::new("name", Dir::Asc),
+            ]
+        );
+
+        let items = [(2, "b"), (1, "z"), (2, "a")];
+        let sorted = query.filter(&items, |item, field| match field {
+            "priority" => crate::Value::Number(Number::I64(item.0)),
+            "name" => crate::Value::String(item.1),
+            _ => crate::Value::None,
+        });
+        assert_eq!(sorted, vec![&(2, "a"), &(2, "b"), &(1, "z")]);
+    }
+
     #[test]
     fn test_parse_query_limit_offset() {
         let pairs = vec![
@@ -1096,6 +2131,43 @@ mod tests {
         assert!(query.count(&Vec::<()>::new(), |_, _| crate::Value::None) == 0);
     }
 
+    #[test]
+    fn test_parse_query_isnull_bare_flag() {
+        let pairs = vec![("created-at-isnull".to_string(), "".to_string())];
+        let query = parse_query::<TestTask>(pairs).unwrap();
+
+        let clause = &query.and_clauses()[0];
+        assert_eq!(clause.field, "created-at");
+        assert_eq!(clause.op, Op::IsNull);
+
+        assert!(query.matches(&(), |_, _| crate::Value::None));
+        assert!(!query.matches(&(), |_, _| crate::Value::Timestamp(Timestamp(0))));
+    }
+
+    #[test]
+    fn test_parse_query_isnotnull_on_every_type() {
+        for field in ["name", "priority", "created-at", "status", "done"] {
+            let pairs = vec![(format!("{}-isnotnull", field), "".to_string())];
+            let query = parse_query::<TestTask>(pairs).unwrap();
+            assert!(!query.matches(&(), |_, _| crate::Value::None), "{}", field);
+        }
+    }
+
+    #[test]
+    fn test_parse_query_isnull_false_inverts() {
+        let pairs = vec![("priority-isnull".to_string(), "false".to_string())];
+        let query = parse_query::<TestTask>(pairs).unwrap();
+        assert!(!query.matches(&(), |_, _| crate::Value::None));
+        assert!(query.matches(&(), |_, _| crate::Value::Number(Number::I64(1))));
+    }
+
+    #[test]
+    fn test_parse_query_isnull_rejects_non_bool_value() {
+        let pairs = vec![("name-isnull".to_string(), "maybe".to_string())];
+        let result = parse_query::<TestTask>(pairs);
+        assert!(matches!(result, Err(ParseError::InvalidValue { .. })));
+    }
+
     #[test]
     fn test_parse_query_unknown_field() {
         let pairs = vec![("unknown-field".to_string(), "test".to_string())];
@@ -1103,6 +2175,108 @@ mod tests {
         assert!(matches!(result, Err(ParseError::UnknownField { .. })));
     }
 
+    #[test]
+    fn test_parse_query_indexed_reports_failing_pair() {
+        let pairs = vec![
+            ("name".to_string(), "a".to_string()),
+            ("NOT".to_string(), "".to_string()),
+            ("limit".to_string(), "10".to_string()),
+            ("priority-gte".to_string(), "high".to_string()),
+            ("bogus".to_string(), "x".to_string()),
+        ];
+        let (err, index) = parse_query_indexed::<TestTask>(pairs).unwrap_err();
+        assert!(matches!(err, ParseError::InvalidValue { .. }));
+        assert_eq!(index, 3);
+    }
+
+    #[test]
+    fn test_parse_query_indexed_special_keys() {
+        let pairs = vec![
+            ("name".to_string(), "a".to_string()),
+            ("offset".to_string(), "-1".to_string()),
+        ];
+        let (err, index) = parse_query_indexed::<TestTask>(pairs).unwrap_err();
+        assert!(matches!(err, ParseError::InvalidLimit { .. }));
+        assert_eq!(index, 1);
+
+        let pairs = vec![("name-gt".to_string(), "test".to_string())];
+        let (err, index) = parse_query_indexed::<TestTask>(pairs.clone()).unwrap_err();
+        assert_eq!(index, 0);
+        assert_eq!(parse_query::<TestTask>(pairs).unwrap_err(), err);
+    }
+
+    #[test]
+    fn test_parse_query_indexed_success_matches_parse_query() {
+        let pairs = vec![
+            ("name-contains".to_string(), "x".to_string()),
+            ("OR".to_string(), "".to_string()),
+            ("done".to_string(), "".to_string()),
+            ("order".to_string(), "priority-desc".to_string()),
+        ];
+        assert_eq!(
+            format!(
+                "{:?}",
+                parse_query_indexed::<TestTask>(pairs.clone()).unwrap()
+            ),
+            format!("{:?}", parse_query::<TestTask>(pairs).unwrap())
+        );
+    }
+
+    #[test]
+    fn test_parse_query_unknown_field_suggestion() {
+        let pairs = vec![("prioirty-gte".to_string(), "5".to_string())];
+        let err = parse_query::<TestTask>(pairs).unwrap_err();
+        match &err {
+            ParseError::UnknownField {
+                field, suggestion, ..
+            } => {
+                assert_eq!(field, "prioirty");
+                assert_eq!(suggestion.as_deref(), Some("priority"));
+            }
+            other => panic!("unexpected error: {:?}", other),
+        }
+        assert!(err.to_string().contains("did you mean 'priority'?"));
+    }
+
+    #[test]
+    fn test_parse_query_unknown_field_no_distant_suggestion() {
+        let pairs = vec![("wibble".to_string(), "5".to_string())];
+        let err = parse_query::<TestTask>(pairs).unwrap_err();
+        assert!(matches!(
+            err,
+            ParseError::UnknownField {
+                suggestion: None,
+                ..
+            }
+        ));
+        assert!(!err.to_string().contains("did you mean"));
+    }
+
+    #[test]
+    fn test_parse_query_unknown_operator_suggestion() {
+        let pairs = vec![("created-at-befor".to_string(), "2024-01-01".to_string())];
+        let err = parse_query::<TestTask>(pairs).unwrap_err();
+        assert_eq!(
+            err,
+            ParseError::UnknownOperator {
+                operator: "befor".to_string(),
+                suggestion: Some("before".to_string()),
+            }
+        );
+        assert_eq!(
+            err.to_string(),
+            "unknown operator 'befor', did you mean 'before'?"
+        );
+    }
+
+    #[test]
+    fn test_edit_distance() {
+        assert_eq!(edit_distance("priority", "priority"), 0);
+        assert_eq!(edit_distance("prioirty", "priority"), 2);
+        assert_eq!(edit_distance("gte", "gtee"), 1);
+        assert_eq!(edit_distance("", "abc"), 3);
+    }
+
     #[test]
     fn test_parse_query_invalid_operator() {
         let pairs = vec![("name-gt".to_string(), "test".to_string())];
@@ -1112,9 +2286,251 @@ mod tests {
 
     #[test]
     fn test_parse_query_invalid_limit() {
-        let pairs = vec![("limit".to_string(), "abc".to_string())];
-        let result = parse_query::<TestTask>(pairs);
-        assert!(matches!(result, Err(ParseError::InvalidLimit { .. })));
+        let reason = |key: &str, value: &str| match parse_query::<TestTask>(vec![(
+            key.to_string(),
+            value.to_string(),
+        )]) {
+            Err(ParseError::InvalidLimit { reason, .. }) => reason,
+            other => panic!("expected InvalidLimit, got {:?}", other.map(|_| ())),
+        };
+
+        assert_eq!(reason("limit", "abc"), "expected a non-negative integer");
+        assert_eq!(reason("offset", "-1"), "expected a non-negative integer");
+
+        let overflow = format!("{}0", usize::MAX);
+        let expected = format!("out of range, the maximum is {}", usize::MAX);
+        assert_eq!(reason("limit", &overflow), expected);
+        assert_eq!(reason("skip", &overflow), expected);
+
+        let err = parse_query::<TestTask>(vec![("limit".to_string(), "x".to_string())]);
+        assert_eq!(
+            err.unwrap_err().to_string(),
+            "invalid limit value 'x': expected a non-negative integer"
+        );
+    }
+
+    #[test]
+    fn test_parse_query_limit_zero_returns_nothing() {
+        let query = parse_query::<TestTask>(vec![("limit".to_string(), "0".to_string())]).unwrap();
+        assert_eq!(query.get_limit(), Some(0));
+        assert!(query
+            .apply(&[1, 2, 3], |_, _| crate::Value::None)
+            .is_empty());
+    }
+
+    #[test]
+    fn test_parse_query_max_limit() {
+        struct Capped;
+
+        impl SeekerSchema for Capped {
+            fn field_type(_field: &str) -> Option<SeekType> {
+                None
+            }
+
+            fn field_names() -> &'static [&'static str] {
+                &[]
+            }
+
+            fn max_limit() -> Option<usize> {
+                Some(100)
+            }
+        }
+
+        let parse =
+            |value: &str| parse_query::<Capped>(vec![("limit".to_string(), value.to_string())]);
+        assert_eq!(parse("100").unwrap().get_limit(), Some(100));
+        assert_eq!(
+            parse("101").unwrap_err().to_string(),
+            "invalid limit value '101': exceeds the maximum of 100"
+        );
+
+        // Offsets aren't capped
+        let query = parse_query::<Capped>(vec![("offset".to_string(), "500".to_string())]).unwrap();
+        assert_eq!(query.get_offset(), Some(500));
+    }
+
+    // =========================================================================
+    // Nested group tests
+    // =========================================================================
+
+    fn pairs(items: &[(&str, &str)]) -> Vec<(String, String)> {
+        items
+            .iter()
+            .map(|(k, v)| (k.to_string(), v.to_string()))
+            .collect()
+    }
+
+    #[test]
+    fn test_parse_query_nested_groups() {
+        use crate::Value;
+
+        // (name contains "a" AND (priority >= 3 OR done)) OR status = active
+        let query = parse_query::<TestTask>(pairs(&[
+            ("OR", ""),
+            ("GROUP_START", ""),
+            ("name-contains", "a"),
+            ("GROUP_START", ""),
+            ("OR", ""),
+            ("priority-gte", "3"),
+            ("done", ""),
+            ("GROUP_END", ""),
+            ("GROUP_END", ""),
+            ("status", "active"),
+        ]))
+        .unwrap();
+
+        assert!(query.and_clauses().is_empty());
+        assert_eq!(query.or_clauses().len(), 1);
+        assert_eq!(query.or_groups().len(), 1);
+        let outer = &query.or_groups()[0];
+        assert_eq!(outer.and_clauses().len(), 1);
+        assert_eq!(outer.and_groups().len(), 1);
+        assert_eq!(outer.and_groups()[0].or_clauses().len(), 2);
+
+        // (name, priority, status, done)
+        type Row = (&'static str, i64, u32, bool);
+        fn accessor<'a>(row: &'a Row, field: &str) -> Value<'a> {
+            match field {
+                "name" => Value::String(row.0),
+                "priority" => Value::Number(Number::I64(row.1)),
+                "status" => Value::Enum(row.2),
+                "done" => Value::Bool(row.3),
+                _ => Value::None,
+            }
+        }
+        let rows: Vec<Row> = vec![
+            ("alpha", 5, 0, false), // name and priority
+            ("alpha", 1, 0, true),  // name and done
+            ("alpha", 1, 0, false), // name only
+            ("other", 5, 0, true),  // no name
+            ("other", 1, 1, false), // active
+        ];
+        let matched: Vec<bool> = rows.iter().map(|r| query.matches(r, accessor)).collect();
+        assert_eq!(matched, vec![true, true, false, false, true]);
+    }
+
+    #[test]
+    fn test_parse_query_group_restores_outer_group() {
+        let query = parse_query::<TestTask>(pairs(&[
+            ("NOT", ""),
+            ("GROUP_START", ""),
+            ("name", "x"),
+            ("GROUP_END", ""),
+            ("priority", "1"),
+        ]))
+        .unwrap();
+
+        assert_eq!(query.not_groups().len(), 1);
+        assert_eq!(query.not_clauses().len(), 1);
+    }
+
+    #[test]
+    fn test_parse_query_options_inside_group_apply_to_top_level() {
+        let query = parse_query::<TestTask>(pairs(&[
+            ("GROUP_START", ""),
+            ("limit", "5"),
+            ("order", "name"),
+            ("GROUP_END", ""),
+        ]))
+        .unwrap();
+
+        assert_eq!(query.get_limit(), Some(5));
+        assert_eq!(query.orderings().len(), 1);
+        assert_eq!(query.and_groups()[0].get_limit(), None);
+    }
+
+    #[test]
+    fn test_parse_query_unmatched_group_end() {
+        let result = parse_query_indexed::<TestTask>(pairs(&[("name", "x"), ("GROUP_END", "")]));
+        let (err, index) = result.unwrap_err();
+        assert!(matches!(err, ParseError::UnbalancedGroup { .. }));
+        assert_eq!(index, 1);
+    }
+
+    #[test]
+    fn test_parse_query_unclosed_group_start() {
+        let result = parse_query_indexed::<TestTask>(pairs(&[
+            ("GROUP_START", ""),
+            ("GROUP_START", ""),
+            ("name", "x"),
+            ("GROUP_END", ""),
+        ]));
+        let (err, index) = result.unwrap_err();
+        assert!(matches!(err, ParseError::UnbalancedGroup { .. }));
+        assert_eq!(index, 0);
+        assert!(err.to_string().contains("never closed"));
+    }
+
+    // =========================================================================
+    // Typed builder parity tests
+    // =========================================================================
+
+    fn parse_one(key: &str, value: &str) -> ParseResult<Query> {
+        parse_query::<TestTask>(vec![(key.to_string(), value.to_string())])
+    }
+
+    #[test]
+    fn test_typed_builder_rejects_operators_like_parse() {
+        let cases = [
+            (
+                parse_one("name-gt", "test"),
+                Query::new().and_string("name", Op::Gt, "test"),
+            ),
+            (
+                parse_one("priority-contains", "5"),
+                Query::new().and_number("priority", Op::Contains, 5),
+            ),
+            (
+                parse_one("created-at-startswith", "2024"),
+                Query::new().and_timestamp("created-at", Op::StartsWith, Timestamp(0)),
+            ),
+            (
+                parse_one("status-gte", "1"),
+                Query::new().and_enum("status", Op::Gte, &[1]),
+            ),
+            (
+                parse_one("done-lt", "true"),
+                Query::new().and_bool("done", Op::Lt, true),
+            ),
+        ];
+
+        for (parsed, typed) in cases {
+            let parsed = parsed.unwrap_err();
+            assert!(matches!(parsed, ParseError::InvalidOperator { .. }));
+            assert_eq!(typed.unwrap_err(), parsed);
+        }
+    }
+
+    #[test]
+    fn test_typed_builder_rejects_regex_like_parse() {
+        let parsed = parse_one("name-regex", "(unclosed").unwrap_err();
+        let typed = Query::new()
+            .or_string("name", Op::Regex, "(unclosed")
+            .unwrap_err();
+        assert!(matches!(parsed, ParseError::InvalidRegex { .. }));
+        assert_eq!(typed, parsed);
+    }
+
+    #[test]
+    fn test_typed_builder_builds_same_clauses_as_parse() {
+        let parsed = parse_query::<TestTask>(vec![
+            ("name-contains".to_string(), "urgent".to_string()),
+            ("priority-gte".to_string(), "5".to_string()),
+            ("status-in".to_string(), "0,2".to_string()),
+            ("done".to_string(), String::new()),
+        ])
+        .unwrap();
+        let typed = Query::new()
+            .and_string("name", Op::Contains, "urgent")
+            .and_then(|q| q.and_number("priority", Op::Gte, 5i64))
+            .and_then(|q| q.and_enum("status", Op::In, &[0, 2]))
+            .and_then(|q| q.and_bool("done", Op::Is, true))
+            .unwrap();
+
+        assert_eq!(
+            format!("{:?}", typed.and_clauses()),
+            format!("{:?}", parsed.and_clauses())
+        );
     }
 
     // =========================================================================
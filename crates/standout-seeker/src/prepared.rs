@@ -0,0 +1,160 @@
+//! Shareable, evaluation-ready queries.
+//!
+//! A [`PreparedQuery`] is a [`Query`] behind an [`Arc`]. Cloning it copies a
+//! pointer, so every clone evaluates against the same compiled regexes
+//! instead of carrying its own copy of the clause tree.
+
+use std::ops::Deref;
+use std::sync::Arc;
+
+use crate::query::Query;
+
+/// An immutable query that is cheap to clone and share across threads.
+///
+/// Created with [`Query::prepare`]. Dereferences to [`Query`], so all
+/// evaluation methods (`apply`, `matches`, `count`, ...) are available.
+///
+/// Regex patterns are compiled once, when the clause is built. Preparing
+/// does not recompile them, and neither does cloning the prepared query:
+/// every clone shares the same clauses. For queries without regex clauses
+/// preparing is a no-op apart from the shared allocation; evaluation is
+/// unchanged.
+///
+/// # Example
+///
+/// ```
+/// use standout_seeker::{Query, Value};
+///
+/// let prepared = Query::new().and_regex("name", r"^task-\d+$")?.prepare();
+/// let names = ["task-1", "note", "task-22"];
+///
+/// let handles: Vec<_> = (0..2)
+///     .map(|_| {
+///         let query = prepared.clone();
+///         std::thread::spawn(move || query.count(&names, |n, _| Value::String(n)))
+///     })
+///     .collect();
+/// for handle in handles {
+///     assert_eq!(handle.join().unwrap(), 2);
+/// }
+/// # Ok::<(), standout_seeker::SeekerError>(())
+/// ```
+#[derive(Debug, Clone)]
+pub struct PreparedQuery(Arc<Query>);
+
+impl PreparedQuery {
+    /// Wraps a query for sharing.
+    pub fn new(query: Query) -> Self {
+        Self(Arc::new(query))
+    }
+
+    /// Returns the underlying query.
+    pub fn query(&self) -> &Query {
+        &self.0
+    }
+
+    /// Returns `true` if both handles share the same compiled query.
+    pub fn ptr_eq(&self, other: &PreparedQuery) -> bool {
+        Arc::ptr_eq(&self.0, &other.0)
+    }
+}
+
+impl Deref for PreparedQuery {
+    type Target = Query;
+
+    fn deref(&self) -> &Query {
+        &self.0
+    }
+}
+
+impl From<Query> for PreparedQuery {
+    fn from(query: Query) -> Self {
+        Self::new(query)
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::clause::ClauseValue;
+    use crate::value::Value;
+
+    fn accessor<'a>(name: &'a &str, _field: &str) -> Value<'a> {
+        Value::String(name)
+    }
+
+    fn names() -> Vec<&'static str> {
+        vec!["alpha-1", "beta", "alpha-22", "gamma-3", "alpha"]
+    }
+
+    #[test]
+    fn prepared_matches_like_query() {
+        let names = names();
+        let query = Query::new()
+            .and_regex("name", r"^alpha-\d+$")
+            .unwrap()
+            .or_regex("name", r"-1$")
+            .unwrap()
+            .or_regex("name", r"-22$")
+            .unwrap()
+            .order_desc("name")
+            .build();
+
+        let prepared = query.clone().prepare();
+        assert_eq!(
+            prepared.apply(&names, accessor),
+            query.apply(&names, accessor)
+        );
+        assert_eq!(
+            prepared.count(&names, accessor),
+            query.count(&names, accessor)
+        );
+    }
+
+    #[test]
+    fn clones_share_compiled_regex() {
+        let prepared = Query::new().and_regex("name", r"^alpha").unwrap().prepare();
+        let clone = prepared.clone();
+
+        assert!(prepared.ptr_eq(&clone));
+        let regex = |q: &PreparedQuery| match &q.and_clauses()[0].value {
+            ClauseValue::Regex(regex) => regex as *const _,
+            other => panic!("expected regex, got {other:?}"),
+        };
+        assert_eq!(regex(&prepared), regex(&clone));
+        assert_eq!(
+            clone.apply(&names(), accessor),
+            prepared.apply(&names(), accessor)
+        );
+    }
+
+    #[test]
+    fn regex_free_query_evaluates_unchanged() {
+        let names = names();
+        let query = Query::new().and_startswith("name", "alpha").limit(2);
+        let prepared = PreparedQuery::from(query.clone());
+
+        assert_eq!(
+            prepared.apply(&names, accessor),
+            query.apply(&names, accessor)
+        );
+        assert_eq!(prepared.query().get_limit(), Some(2));
+    }
+
+    #[test]
+    fn shared_across_threads() {
+        let names = Arc::new(names());
+        let prepared = Query::new().and_regex("name", r"\d").unwrap().prepare();
+
+        let handles: Vec<_> = (0..4)
+            .map(|_| {
+                let query = prepared.clone();
+                let names = Arc::clone(&names);
+                std::thread::spawn(move || query.count(&names, accessor))
+            })
+            .collect();
+        for handle in handles {
+            assert_eq!(handle.join().unwrap(), 3);
+        }
+    }
+}
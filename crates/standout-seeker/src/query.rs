@@ -9,7 +9,10 @@ use crate::clause::{Clause, ClauseValue};
 use crate::error::Result;
 use crate::op::Op;
 use crate::ordering::{compare_by_orderings, Dir, OrderBy};
-use crate::value::{Timestamp, Value};
+use crate::parse::{check_operator, string_value, ClauseGroup, ParseError, ParseResult};
+use crate::prepared::PreparedQuery;
+use crate::schema::SeekType;
+use crate::value::{Number, Timestamp, Value};
 
 /// A query for filtering and ordering collections.
 ///
@@ -25,6 +28,10 @@ use crate::value::{Timestamp, Value};
 ///       ∧ (no NOT clause matches)
 /// ```
 ///
+/// Each group may also hold nested queries (see [`Query::or_group`]), which
+/// take part in their group exactly like a single clause. This expresses
+/// shapes such as `(a AND b) OR (c AND d)`.
+///
 /// # Example
 ///
 /// ```
@@ -40,11 +47,33 @@ use crate::value::{Timestamp, Value};
 ///     .limit(20)
 ///     .build();
 /// ```
+///
+/// # Typed Clauses
+///
+/// The `and_string`, `or_number`, `not_enum`, ... builders take the field type
+/// from the method name and check the operator and value with the same rules
+/// as [`parse_query`](crate::parse_query), returning the same [`ParseError`]s:
+///
+/// ```
+/// use standout_seeker::{Op, ParseError, Query};
+///
+/// let query = Query::new()
+///     .and_number("priority", Op::Gte, 5)?
+///     .and_string("name", Op::Contains, "urgent")?
+///     .build();
+///
+/// let err = Query::new().and_string("name", Op::Gt, "x").unwrap_err();
+/// assert!(matches!(err, ParseError::InvalidOperator { .. }));
+/// # Ok::<(), ParseError>(())
+/// ```
 #[derive(Debug, Clone, Default)]
 pub struct Query {
     and_clauses: Vec<Clause>,
     or_clauses: Vec<Clause>,
     not_clauses: Vec<Clause>,
+    and_groups: Vec<Query>,
+    or_groups: Vec<Query>,
+    not_groups: Vec<Query>,
     orderings: Vec<OrderBy>,
     limit: Option<usize>,
     offset: Option<usize>,
@@ -86,6 +115,33 @@ impl Query {
         self
     }
 
+    // ========================================================================
+    // Nested groups
+    // ========================================================================
+
+    /// Adds a nested query that must match, like an AND clause.
+    ///
+    /// Only the clauses of `query` are used; its ordering, limit and offset
+    /// are ignored.
+    pub fn and_group(mut self, query: Query) -> Self {
+        self.and_groups.push(query);
+        self
+    }
+
+    /// Adds a nested query as one OR alternative.
+    ///
+    /// `Query::new().or_group(a).or_group(b)` matches items matching `a` or `b`.
+    pub fn or_group(mut self, query: Query) -> Self {
+        self.or_groups.push(query);
+        self
+    }
+
+    /// Adds a nested query that must not match, like a NOT clause.
+    pub fn not_group(mut self, query: Query) -> Self {
+        self.not_groups.push(query);
+        self
+    }
+
     // ========================================================================
     // AND shorthand methods
     // ========================================================================
@@ -125,6 +181,11 @@ impl Query {
         self.and(field, Op::Contains, value)
     }
 
+    /// Adds an AND not-contains clause (no substring match).
+    pub fn and_notcontains(self, field: &str, value: &str) -> Self {
+        self.and(field, Op::NotContains, value)
+    }
+
     /// Adds an AND starts-with clause.
     pub fn and_startswith(self, field: &str, value: &str) -> Self {
         self.and(field, Op::StartsWith, value)
@@ -152,6 +213,15 @@ impl Query {
         self.and(field, Op::In, ClauseValue::EnumSet(set))
     }
 
+    /// Adds an AND not-in-set clause for enum values.
+    pub fn and_notin<I>(self, field: &str, values: I) -> Self
+    where
+        I: IntoIterator<Item = u32>,
+    {
+        let set: Vec<u32> = values.into_iter().collect();
+        self.and(field, Op::NotIn, ClauseValue::EnumSet(set))
+    }
+
     /// Adds an AND before clause (timestamp less than).
     pub fn and_before(self, field: &str, ts: Timestamp) -> Self {
         self.and(field, Op::Before, ts)
@@ -162,6 +232,16 @@ impl Query {
         self.and(field, Op::After, ts)
     }
 
+    /// Adds an AND clause matching fields without a value.
+    pub fn and_is_null(self, field: &str) -> Self {
+        self.and(field, Op::IsNull, true)
+    }
+
+    /// Adds an AND clause matching fields with a value.
+    pub fn and_is_not_null(self, field: &str) -> Self {
+        self.and(field, Op::IsNotNull, true)
+    }
+
     // ========================================================================
     // OR shorthand methods
     // ========================================================================
@@ -201,6 +281,11 @@ impl Query {
         self.or(field, Op::Contains, value)
     }
 
+    /// Adds an OR not-contains clause.
+    pub fn or_notcontains(self, field: &str, value: &str) -> Self {
+        self.or(field, Op::NotContains, value)
+    }
+
     /// Adds an OR starts-with clause.
     pub fn or_startswith(self, field: &str, value: &str) -> Self {
         self.or(field, Op::StartsWith, value)
@@ -226,6 +311,15 @@ impl Query {
         self.or(field, Op::In, ClauseValue::EnumSet(set))
     }
 
+    /// Adds an OR not-in-set clause.
+    pub fn or_notin<I>(self, field: &str, values: I) -> Self
+    where
+        I: IntoIterator<Item = u32>,
+    {
+        let set: Vec<u32> = values.into_iter().collect();
+        self.or(field, Op::NotIn, ClauseValue::EnumSet(set))
+    }
+
     /// Adds an OR before clause.
     pub fn or_before(self, field: &str, ts: Timestamp) -> Self {
         self.or(field, Op::Before, ts)
@@ -236,6 +330,16 @@ impl Query {
         self.or(field, Op::After, ts)
     }
 
+    /// Adds an OR clause matching fields without a value.
+    pub fn or_is_null(self, field: &str) -> Self {
+        self.or(field, Op::IsNull, true)
+    }
+
+    /// Adds an OR clause matching fields with a value.
+    pub fn or_is_not_null(self, field: &str) -> Self {
+        self.or(field, Op::IsNotNull, true)
+    }
+
     // ========================================================================
     // NOT shorthand methods
     // ========================================================================
@@ -310,11 +414,166 @@ impl Query {
         self.not(field, Op::After, ts)
     }
 
+    /// Adds a NOT clause matching fields without a value.
+    pub fn not_is_null(self, field: &str) -> Self {
+        self.not(field, Op::IsNull, true)
+    }
+
+    /// Adds a NOT clause matching fields with a value.
+    pub fn not_is_not_null(self, field: &str) -> Self {
+        self.not(field, Op::IsNotNull, true)
+    }
+
+    // ========================================================================
+    // Typed clause builders
+    // ========================================================================
+    //
+    // These validate the operator against the field type, and the value
+    // against the operator, with the same rules and errors as `parse_query`.
+    // Presence operators (`IsNull`, `IsNotNull`) ignore the value.
+
+    /// Adds an AND clause on a string field.
+    pub fn and_string(self, field: &str, op: Op, value: &str) -> ParseResult<Self> {
+        self.typed(ClauseGroup::And, field, SeekType::String, op, || {
+            string_value(value, field, op)
+        })
+    }
+
+    /// Adds an AND clause on a number field.
+    pub fn and_number(self, field: &str, op: Op, value: impl Into<Number>) -> ParseResult<Self> {
+        let value = ClauseValue::Number(value.into());
+        self.typed(ClauseGroup::And, field, SeekType::Number, op, || Ok(value))
+    }
+
+    /// Adds an AND clause on a timestamp field.
+    pub fn and_timestamp(self, field: &str, op: Op, value: Timestamp) -> ParseResult<Self> {
+        let value = ClauseValue::Timestamp(value);
+        self.typed(ClauseGroup::And, field, SeekType::Timestamp, op, || {
+            Ok(value)
+        })
+    }
+
+    /// Adds an AND clause on an enum field.
+    ///
+    /// [`Op::In`] and [`Op::NotIn`] take one or more discriminants; other
+    /// operators take exactly one.
+    pub fn and_enum(self, field: &str, op: Op, values: &[u32]) -> ParseResult<Self> {
+        self.typed(ClauseGroup::And, field, SeekType::Enum, op, || {
+            enum_value(field, op, values)
+        })
+    }
+
+    /// Adds an AND clause on a bool field.
+    pub fn and_bool(self, field: &str, op: Op, value: bool) -> ParseResult<Self> {
+        let value = ClauseValue::Bool(value);
+        self.typed(ClauseGroup::And, field, SeekType::Bool, op, || Ok(value))
+    }
+
+    /// Adds an OR clause on a string field.
+    pub fn or_string(self, field: &str, op: Op, value: &str) -> ParseResult<Self> {
+        self.typed(ClauseGroup::Or, field, SeekType::String, op, || {
+            string_value(value, field, op)
+        })
+    }
+
+    /// Adds an OR clause on a number field.
+    pub fn or_number(self, field: &str, op: Op, value: impl Into<Number>) -> ParseResult<Self> {
+        let value = ClauseValue::Number(value.into());
+        self.typed(ClauseGroup::Or, field, SeekType::Number, op, || Ok(value))
+    }
+
+    /// Adds an OR clause on a timestamp field.
+    pub fn or_timestamp(self, field: &str, op: Op, value: Timestamp) -> ParseResult<Self> {
+        let value = ClauseValue::Timestamp(value);
+        self.typed(ClauseGroup::Or, field, SeekType::Timestamp, op, || {
+            Ok(value)
+        })
+    }
+
+    /// Adds an OR clause on an enum field.
+    ///
+    /// [`Op::In`] and [`Op::NotIn`] take one or more discriminants; other
+    /// operators take exactly one.
+    pub fn or_enum(self, field: &str, op: Op, values: &[u32]) -> ParseResult<Self> {
+        self.typed(ClauseGroup::Or, field, SeekType::Enum, op, || {
+            enum_value(field, op, values)
+        })
+    }
+
+    /// Adds an OR clause on a bool field.
+    pub fn or_bool(self, field: &str, op: Op, value: bool) -> ParseResult<Self> {
+        let value = ClauseValue::Bool(value);
+        self.typed(ClauseGroup::Or, field, SeekType::Bool, op, || Ok(value))
+    }
+
+    /// Adds a NOT clause on a string field.
+    pub fn not_string(self, field: &str, op: Op, value: &str) -> ParseResult<Self> {
+        self.typed(ClauseGroup::Not, field, SeekType::String, op, || {
+            string_value(value, field, op)
+        })
+    }
+
+    /// Adds a NOT clause on a number field.
+    pub fn not_number(self, field: &str, op: Op, value: impl Into<Number>) -> ParseResult<Self> {
+        let value = ClauseValue::Number(value.into());
+        self.typed(ClauseGroup::Not, field, SeekType::Number, op, || Ok(value))
+    }
+
+    /// Adds a NOT clause on a timestamp field.
+    pub fn not_timestamp(self, field: &str, op: Op, value: Timestamp) -> ParseResult<Self> {
+        let value = ClauseValue::Timestamp(value);
+        self.typed(ClauseGroup::Not, field, SeekType::Timestamp, op, || {
+            Ok(value)
+        })
+    }
+
+    /// Adds a NOT clause on an enum field.
+    ///
+    /// [`Op::In`] and [`Op::NotIn`] take one or more discriminants; other
+    /// operators take exactly one.
+    pub fn not_enum(self, field: &str, op: Op, values: &[u32]) -> ParseResult<Self> {
+        self.typed(ClauseGroup::Not, field, SeekType::Enum, op, || {
+            enum_value(field, op, values)
+        })
+    }
+
+    /// Adds a NOT clause on a bool field.
+    pub fn not_bool(self, field: &str, op: Op, value: bool) -> ParseResult<Self> {
+        let value = ClauseValue::Bool(value);
+        self.typed(ClauseGroup::Not, field, SeekType::Bool, op, || Ok(value))
+    }
+
+    /// Validate and add a clause to `group`; `value` is built after the operator checks out.
+    fn typed(
+        self,
+        group: ClauseGroup,
+        field: &str,
+        field_type: SeekType,
+        op: Op,
+        value: impl FnOnce() -> ParseResult<ClauseValue>,
+    ) -> ParseResult<Self> {
+        check_operator(field, field_type, op)?;
+        let value = if op.is_presence_op() && field_type != SeekType::Bool {
+            ClauseValue::Bool(true)
+        } else {
+            value()?
+        };
+
+        Ok(match group {
+            ClauseGroup::And => self.and(field, op, value),
+            ClauseGroup::Or => self.or(field, op, value),
+            ClauseGroup::Not => self.not(field, op, value),
+        })
+    }
+
     // ========================================================================
     // Ordering
     // ========================================================================
 
     /// Adds an ordering clause.
+    ///
+    /// Orderings stack in insertion order: the first added is the primary sort
+    /// key and later ones only break ties.
     pub fn order_by(mut self, field: &str, dir: Dir) -> Self {
         self.orderings.push(OrderBy::new(field, dir));
         self
@@ -335,6 +594,8 @@ impl Query {
     // ========================================================================
 
     /// Sets the maximum number of results to return.
+    ///
+    /// A limit of `0` returns no results; leave the limit unset for no cap.
     pub fn limit(mut self, n: usize) -> Self {
         self.limit = Some(n);
         self
@@ -357,6 +618,16 @@ impl Query {
         self
     }
 
+    /// Freezes the query into a [`PreparedQuery`] that is cheap to clone.
+    ///
+    /// Clones share the compiled regexes, so the same query can be evaluated
+    /// repeatedly, or from several threads, without recompiling patterns.
+    /// For regex-free queries this is a no-op apart from the shared
+    /// allocation.
+    pub fn prepare(self) -> PreparedQuery {
+        PreparedQuery::new(self)
+    }
+
     // ========================================================================
     // Introspection
     // ========================================================================
@@ -376,6 +647,21 @@ impl Query {
         &self.not_clauses
     }
 
+    /// Returns the nested AND queries.
+    pub fn and_groups(&self) -> &[Query] {
+        &self.and_groups
+    }
+
+    /// Returns the nested OR queries.
+    pub fn or_groups(&self) -> &[Query] {
+        &self.or_groups
+    }
+
+    /// Returns the nested NOT queries.
+    pub fn not_groups(&self) -> &[Query] {
+        &self.not_groups
+    }
+
     /// Returns the ordering clauses.
     pub fn orderings(&self) -> &[OrderBy] {
         &self.orderings
@@ -391,9 +677,14 @@ impl Query {
         self.offset
     }
 
-    /// Returns `true` if this query has no clauses (matches everything).
+    /// Returns `true` if this query has no clauses or groups (matches everything).
     pub fn is_empty(&self) -> bool {
-        self.and_clauses.is_empty() && self.or_clauses.is_empty() && self.not_clauses.is_empty()
+        self.and_clauses.is_empty()
+            && self.or_clauses.is_empty()
+            && self.not_clauses.is_empty()
+            && self.and_groups.is_empty()
+            && self.or_groups.is_empty()
+            && self.not_groups.is_empty()
     }
 
     // ========================================================================
@@ -407,73 +698,119 @@ impl Query {
     where
         for<'a> F: Fn(&'a T, &str) -> Value<'a>,
     {
-        // All AND clauses must match
-        let and_pass = self
-            .and_clauses
-            .iter()
-            .all(|clause| clause.matches(&accessor(item, &clause.field)));
+        self.matches_with(item, &accessor)
+    }
+
+    /// [`Query::matches`] with a borrowed accessor, so nested groups reuse it.
+    fn matches_with<T, F>(&self, item: &T, accessor: &F) -> bool
+    where
+        for<'a> F: Fn(&'a T, &str) -> Value<'a>,
+    {
+        let clause_matches = |clause: &Clause| clause.matches(&accessor(item, &clause.field));
+        let group_matches = |group: &Query| group.matches_with(item, accessor);
+
+        // All AND clauses and groups must match
+        let and_pass = self.and_clauses.iter().all(clause_matches)
+            && self.and_groups.iter().all(group_matches);
 
         if !and_pass {
             return false;
         }
 
-        // At least one OR clause must match (or none exist)
-        let or_pass = self.or_clauses.is_empty()
-            || self
-                .or_clauses
-                .iter()
-                .any(|clause| clause.matches(&accessor(item, &clause.field)));
+        // At least one OR clause or group must match (or none exist)
+        let or_pass = (self.or_clauses.is_empty() && self.or_groups.is_empty())
+            || self.or_clauses.iter().any(clause_matches)
+            || self.or_groups.iter().any(group_matches);
 
         if !or_pass {
             return false;
         }
 
-        // No NOT clause may match
-        let not_pass = self
-            .not_clauses
-            .iter()
-            .all(|clause| !clause.matches(&accessor(item, &clause.field)));
+        // No NOT clause or group may match
+        let not_pass = !self.not_clauses.iter().any(clause_matches)
+            && !self.not_groups.iter().any(group_matches);
 
         not_pass
     }
 
-    /// Filters a slice, returning references to matching items.
+    /// Evaluates the query over a slice: matching items, ordered, with
+    /// offset and limit applied.
     ///
-    /// Results are sorted according to the query's ordering clauses,
-    /// then offset and limit are applied.
-    pub fn filter<'a, T, F>(&self, items: &'a [T], accessor: F) -> Vec<&'a T>
+    /// Without ordering clauses, evaluation stops as soon as `offset + limit`
+    /// matches are found, so later items are never visited. With ordering,
+    /// every item has to be checked before sorting.
+    ///
+    /// # Example
+    ///
+    /// ```
+    /// use standout_seeker::{Number, Query, Value};
+    ///
+    /// let numbers = [1i64, 7, 3, 9, 4, 8];
+    /// let query = Query::new().and_gt("n", 3i64).limit(2).build();
+    ///
+    /// let found = query.apply(&numbers, |n, _| Value::Number(Number::I64(*n)));
+    /// assert_eq!(found, vec![&7, &9]);
+    /// ```
+    pub fn apply<'a, T, F>(&self, items: &'a [T], accessor: F) -> Vec<&'a T>
     where
         for<'b> F: Fn(&'b T, &str) -> Value<'b>,
     {
-        // First, filter to matching items
-        let mut results: Vec<&'a T> = items
+        let offset = self.offset.unwrap_or(0);
+        let limit = self.limit.unwrap_or(usize::MAX);
+        let matching = items
             .iter()
-            .filter(|item| self.matches(*item, &accessor))
-            .collect();
+            .filter(|item| self.matches_with(*item, &accessor));
 
-        // Sort if orderings exist
-        if !self.orderings.is_empty() {
-            // We need to sort, but the accessor returns Value<'b> which has
-            // a different lifetime than the items. We'll use a closure that
-            // works within the sort.
-            results.sort_by(|a, b| compare_by_orderings(*a, *b, &self.orderings, &accessor));
+        // Unordered: the first matches are the result, so stop pulling early
+        if self.orderings.is_empty() {
+            return matching.skip(offset).take(limit).collect();
         }
 
-        // Apply offset
+        let mut results: Vec<&'a T> = matching.collect();
+        results.sort_by(|a, b| compare_by_orderings(*a, *b, &self.orderings, &accessor));
+        results.into_iter().skip(offset).take(limit).collect()
+    }
+
+    /// Parallel version of [`Query::apply`], evaluating items on the rayon
+    /// thread pool.
+    ///
+    /// Returns exactly what [`Query::apply`] returns: matches keep their
+    /// slice order, ordering uses a stable sort, and offset and limit are
+    /// applied last. Unlike `apply`, every item is evaluated even when the
+    /// query is unordered, so this pays off on large slices with
+    /// non-trivial clauses.
+    #[cfg(feature = "parallel")]
+    pub fn par_apply<'a, T, F>(&self, items: &'a [T], accessor: F) -> Vec<&'a T>
+    where
+        T: Sync,
+        for<'b> F: Fn(&'b T, &str) -> Value<'b> + Sync,
+    {
+        use rayon::prelude::*;
+
         let offset = self.offset.unwrap_or(0);
-        if offset > 0 {
-            if offset >= results.len() {
-                return Vec::new();
-            }
-            results = results.into_iter().skip(offset).collect();
-        }
+        let limit = self.limit.unwrap_or(usize::MAX);
 
-        // Apply limit
-        if let Some(limit) = self.limit {
-            results.truncate(limit);
+        // Collecting an indexed source keeps the original slice order
+        let mut results: Vec<&'a T> = items
+            .par_iter()
+            .filter(|item| self.matches_with(*item, &accessor))
+            .collect();
+
+        if !self.orderings.is_empty() {
+            results.par_sort_by(|a, b| compare_by_orderings(*a, *b, &self.orderings, &accessor));
         }
+        results.into_iter().skip(offset).take(limit).collect()
+    }
 
-        results
+    /// Filters a slice, returning references to matching items.
+    ///
+    /// Results are sorted according to the query's ordering clauses,
+    /// then offset and limit are applied. Same as [`Query::apply`].
+    pub fn filter<'a, T, F>(&self, items: &'a [T], accessor: F) -> Vec<&'a T>
+    where
+        for<'b> F: Fn(&'b T, &str) -> Value<'b>,
+    {
+        self.apply(items, accessor)
     }
 
     /// Filters and clones matching items.
@@ -507,6 +844,21 @@ impl Query {
             .count()
     }
 
+    /// Parallel version of [`Query::count`].
+    #[cfg(feature = "parallel")]
+    pub fn par_count<T, F>(&self, items: &[T], accessor: F) -> usize
+    where
+        T: Sync,
+        for<'a> F: Fn(&'a T, &str) -> Value<'a> + Sync,
+    {
+        use rayon::prelude::*;
+
+        items
+            .par_iter()
+            .filter(|item| self.matches_with(*item, &accessor))
+            .count()
+    }
+
     /// Returns `true` if any item matches.
     pub fn any<T, F>(&self, items: &[T], accessor: F) -> bool
     where
@@ -540,6 +892,31 @@ impl Query {
     }
 }
 
+/// Build an enum clause value: a set for [`Op::In`] and [`Op::NotIn`], a single
+/// discriminant otherwise.
+fn enum_value(field: &str, op: Op, values: &[u32]) -> ParseResult<ClauseValue> {
+    let invalid = |reason: String| ParseError::InvalidValue {
+        field: field.to_string(),
+        value: values
+            .iter()
+            .map(u32::to_string)
+            .collect::<Vec<_>>()
+            .join(","),
+        expected: SeekType::Enum,
+        reason,
+    };
+
+    match (op, values) {
+        (_, []) => Err(invalid("expected at least one value".to_string())),
+        (Op::In | Op::NotIn, _) => Ok(ClauseValue::EnumSet(values.to_vec())),
+        (_, [value]) => Ok(ClauseValue::Enum(*value)),
+        _ => Err(invalid(format!(
+            "operator '{}' takes a single value, use 'in' for sets",
+            op
+        ))),
+    }
+}
+
 #[cfg(test)]
 mod tests {
     use super::*;
@@ -824,6 +1201,162 @@ mod tests {
         assert_eq!(results.len(), 4);
     }
 
+    #[test]
+    fn negated_set_and_substring() {
+        let tasks = sample_tasks();
+        let in_query = Query::new().and_in("status", [1u32, 2]).build();
+        let notin_query = Query::new().and_notin("status", [1u32, 2]).build();
+        assert_eq!(
+            notin_query.count(&tasks, accessor),
+            tasks.len() - in_query.count(&tasks, accessor)
+        );
+
+        let query = Query::new()
+            .and_notcontains("name", "Task")
+            .or_notin("status", [0u32])
+            .or_notcontains("name", "Urgent")
+            .build();
+        assert!(query
+            .filter(&tasks, accessor)
+            .iter()
+            .all(|task| !task.name.contains("Task")));
+    }
+
+    #[test]
+    fn typed_builders_filter() -> ParseResult<()> {
+        let tasks = sample_tasks();
+        let query = Query::new()
+            .and_number("priority", Op::Gte, 5)?
+            .and_bool("archived", Op::Is, false)?
+            .or_string("name", Op::Regex, "^Urgent")?
+            .not_enum("status", Op::In, &[0, 2])?
+            .build();
+
+        let results = query.filter(&tasks, accessor);
+        assert_eq!(results.len(), 1);
+        assert_eq!(results[0].name, "Urgent Task");
+        Ok(())
+    }
+
+    #[test]
+    fn typed_enum_needs_single_value_outside_in() {
+        let err = Query::new()
+            .and_enum("status", Op::Eq, &[1, 2])
+            .unwrap_err();
+        assert!(matches!(err, ParseError::InvalidValue { .. }));
+
+        let err = Query::new().and_enum("status", Op::In, &[]).unwrap_err();
+        assert!(matches!(err, ParseError::InvalidValue { .. }));
+
+        let query = Query::new().and_enum("status", Op::Eq, &[1]).unwrap();
+        assert!(matches!(query.and_clauses()[0].value, ClauseValue::Enum(1)));
+    }
+
+    #[test]
+    fn typed_presence_ignores_value() {
+        let query = Query::new()
+            .and_number("priority", Op::IsNotNull, 0)
+            .unwrap();
+        assert!(matches!(
+            query.and_clauses()[0].value,
+            ClauseValue::Bool(true)
+        ));
+    }
+
+    #[test]
+    fn or_of_and_groups() {
+        let tasks = sample_tasks();
+        // (priority == 5 AND archived) OR (priority == 1 AND status == 0)
+        let query = Query::new()
+            .or_group(
+                Query::new()
+                    .and_eq("priority", 5i64)
+                    .and_eq("archived", true),
+            )
+            .or_group(
+                Query::new()
+                    .and_eq("priority", 1i64)
+                    .and_eq("status", ClauseValue::Enum(0)),
+            )
+            .build();
+
+        let results = query.filter(&tasks, accessor);
+        let names: Vec<&str> = results.iter().map(|t| t.name.as_str()).collect();
+        assert_eq!(names, vec!["Task A", "Critical Task"]);
+    }
+
+    #[test]
+    fn not_group_excludes_matches() {
+        let tasks = sample_tasks();
+        let query = Query::new()
+            .not_group(
+                Query::new()
+                    .and_eq("priority", 5i64)
+                    .and_eq("archived", true),
+            )
+            .build();
+
+        assert_eq!(query.count(&tasks, accessor), 4);
+        assert!(!query.is_empty());
+    }
+
+    #[test]
+    fn apply_filters_orders_and_pages() {
+        let tasks = sample_tasks();
+        let query = Query::new()
+            .and_gte("priority", 2i64)
+            .order_desc("priority")
+            .order_asc("name")
+            .offset(1)
+            .limit(2)
+            .build();
+
+        // Matches ordered: Critical (5), Urgent (5), Done (3), Task B (2)
+        let names: Vec<&str> = query
+            .apply(&tasks, accessor)
+            .iter()
+            .map(|t| t.name.as_str())
+            .collect();
+        assert_eq!(names, vec!["Urgent Task", "Done Task"]);
+    }
+
+    #[test]
+    fn apply_limit_zero_and_offset_past_end() {
+        let tasks = sample_tasks();
+        assert!(Query::new().limit(0).apply(&tasks, accessor).is_empty());
+        assert!(Query::new()
+            .order_asc("name")
+            .offset(10)
+            .apply(&tasks, accessor)
+            .is_empty());
+    }
+
+    #[test]
+    fn apply_short_circuits_without_ordering() {
+        use std::cell::Cell;
+
+        let tasks = sample_tasks();
+        let visited = Cell::new(0);
+        // Offset 1 and limit 1 need two matches: Task A and Task B
+        let query = Query::new().and_eq("archived", false).offset(1).limit(1);
+        let results = query.apply(&tasks, |t, f| {
+            visited.set(visited.get() + 1);
+            accessor(t, f)
+        });
+        assert_eq!(results.len(), 1);
+        assert_eq!(results[0].name, "Task B");
+        assert_eq!(visited.get(), 2);
+
+        // With ordering every item is checked
+        visited.set(0);
+        let ordered = query.order_asc("name");
+        ordered.apply(&tasks, |t, f| {
+            visited.set(visited.get() + 1);
+            accessor(t, f)
+        });
+        assert!(visited.get() >= tasks.len());
+    }
+
     #[test]
     fn introspection() {
         let query = Query::new()
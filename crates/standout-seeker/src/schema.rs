@@ -54,17 +54,66 @@ impl SeekType {
         }
     }
 
-    /// Returns `true` if the given operator is valid for this field type.
-    pub fn is_valid_operator(self, op: Op) -> bool {
+    /// Returns every operator valid for this field type.
+    ///
+    /// Lets a UI offer only the operators a field accepts, e.g. in a filter
+    /// builder dropdown. Presence checks (`IsNull`, `IsNotNull`) come last.
+    ///
+    /// ```
+    /// use standout_seeker::{Op, SeekType};
+    ///
+    /// let labels: Vec<&str> = SeekType::Bool
+    ///     .valid_operators()
+    ///     .iter()
+    ///     .map(|op| op.as_str())
+    ///     .collect();
+    /// assert_eq!(labels, ["eq", "ne", "is", "isnull", "isnotnull"]);
+    /// ```
+    pub fn valid_operators(self) -> &'static [Op] {
         match self {
-            SeekType::String => op.is_string_op(),
-            SeekType::Number => op.is_number_op(),
-            SeekType::Timestamp => op.is_timestamp_op(),
-            SeekType::Enum => op.is_enum_op(),
-            SeekType::Bool => op.is_bool_op(),
+            SeekType::String => &[
+                Op::Eq,
+                Op::Ne,
+                Op::StartsWith,
+                Op::EndsWith,
+                Op::Contains,
+                Op::NotContains,
+                Op::Regex,
+                Op::IsNull,
+                Op::IsNotNull,
+            ],
+            SeekType::Number => &[
+                Op::Eq,
+                Op::Ne,
+                Op::Gt,
+                Op::Gte,
+                Op::Lt,
+                Op::Lte,
+                Op::IsNull,
+                Op::IsNotNull,
+            ],
+            SeekType::Timestamp => &[
+                Op::Eq,
+                Op::Ne,
+                Op::Gt,
+                Op::Gte,
+                Op::Lt,
+                Op::Lte,
+                Op::Before,
+                Op::After,
+                Op::IsNull,
+                Op::IsNotNull,
+            ],
+            SeekType::Enum => &[Op::Eq, Op::Ne, Op::In, Op::NotIn, Op::IsNull, Op::IsNotNull],
+            SeekType::Bool => &[Op::Eq, Op::Ne, Op::Is, Op::IsNull, Op::IsNotNull],
         }
     }
 
+    /// Returns `true` if the given operator is valid for this field type.
+    pub fn is_valid_operator(self, op: Op) -> bool {
+        self.valid_operators().contains(&op)
+    }
+
     /// Returns a human-readable name for this type.
     pub fn as_str(self) -> &'static str {
         match self {
@@ -156,6 +205,39 @@ pub trait SeekerSchema {
     fn resolve_enum_variant(_field: &str, _variant: &str) -> Option<u32> {
         None
     }
+
+    /// Returns extra tokens accepted as `true` and `false` for a bool field.
+    ///
+    /// Override this to accept localized or domain-specific spellings (e.g.,
+    /// `--done=y` or `--enabled=disabled`). Tokens are matched
+    /// case-insensitively and checked before the built-in sets, which stay
+    /// accepted for every field.
+    ///
+    /// # Returns
+    ///
+    /// A `(true_tokens, false_tokens)` pair, or `None` to use only the
+    /// built-in `true/1/yes/on` and `false/0/no/off`.
+    ///
+    /// # Default Implementation
+    ///
+    /// Returns `None` for every field.
+    fn bool_tokens(_field: &str) -> Option<(&'static [&'static str], &'static [&'static str])> {
+        None
+    }
+
+    /// Returns the largest `limit` a parsed query may ask for.
+    ///
+    /// Override this to reject absurd page sizes from user input (e.g.
+    /// `--limit=1000000000`) with a [`ParseError::InvalidLimit`](crate::ParseError::InvalidLimit)
+    /// instead of silently doing the work. Limits set directly with
+    /// [`Query::limit`](crate::Query::limit) are not checked.
+    ///
+    /// # Default Implementation
+    ///
+    /// Returns `None`: any limit that fits in `usize` is accepted.
+    fn max_limit() -> Option<usize> {
+        None
+    }
 }
 
 #[cfg(test)]
@@ -171,11 +253,42 @@ mod tests {
         assert_eq!(SeekType::Bool.default_operator(), Op::Is);
     }
 
+    #[test]
+    fn seek_type_operator_lists_match_op_checks() {
+        let types = [
+            SeekType::String,
+            SeekType::Number,
+            SeekType::Timestamp,
+            SeekType::Enum,
+            SeekType::Bool,
+        ];
+        for seek_type in types {
+            for &op in Op::all() {
+                let expected = match seek_type {
+                    SeekType::String => op.is_string_op(),
+                    SeekType::Number => op.is_number_op(),
+                    SeekType::Timestamp => op.is_timestamp_op(),
+                    SeekType::Enum => op.is_enum_op(),
+                    SeekType::Bool => op.is_bool_op(),
+                };
+                assert_eq!(
+                    seek_type.valid_operators().contains(&op),
+                    expected,
+                    "{} {}",
+                    seek_type,
+                    op
+                );
+                assert_eq!(seek_type.is_valid_operator(op), expected);
+            }
+        }
+    }
+
     #[test]
     fn seek_type_valid_operators() {
         // String
         assert!(SeekType::String.is_valid_operator(Op::Eq));
         assert!(SeekType::String.is_valid_operator(Op::Contains));
+        assert!(SeekType::String.is_valid_operator(Op::NotContains));
         assert!(SeekType::String.is_valid_operator(Op::Regex));
         assert!(!SeekType::String.is_valid_operator(Op::Gt));
         assert!(!SeekType::String.is_valid_operator(Op::Before));
@@ -197,6 +310,9 @@ mod tests {
         // Enum
         assert!(SeekType::Enum.is_valid_operator(Op::Eq));
         assert!(SeekType::Enum.is_valid_operator(Op::In));
+        assert!(SeekType::Enum.is_valid_operator(Op::NotIn));
+        assert!(!SeekType::String.is_valid_operator(Op::NotIn));
+        assert!(!SeekType::Enum.is_valid_operator(Op::NotContains));
         assert!(!SeekType::Enum.is_valid_operator(Op::Gt));
         assert!(!SeekType::Enum.is_valid_operator(Op::Contains));
 
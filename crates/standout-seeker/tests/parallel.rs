@@ -0,0 +1,179 @@
+//! Parity and speedup tests for the rayon-backed evaluation path.
+//!
+//! Run with `cargo test -p standout-seeker --features parallel`. The timing
+//! benchmark is ignored by default; add `--release -- --ignored` to run it.
+
+#![cfg(feature = "parallel")]
+
+use std::time::{Duration, Instant};
+
+use standout_seeker::{Number, Query, Value};
+
+// ============================================================================
+// Test helpers
+// ============================================================================
+
+#[derive(Debug, PartialEq)]
+struct Row {
+    id: u64,
+    name: String,
+    bucket: i64,
+    score: i64,
+}
+
+fn accessor<'a>(row: &'a Row, field: &str) -> Value<'a> {
+    match field {
+        "id" => Value::Number(Number::U64(row.id)),
+        "name" => Value::String(&row.name),
+        "bucket" => Value::Number(Number::I64(row.bucket)),
+        "score" => Value::Number(Number::I64(row.score)),
+        _ => Value::None,
+    }
+}
+
+/// Deterministic pseudo-random rows, with few distinct buckets so ordering
+/// by bucket produces many ties.
+fn dataset(len: u64) -> Vec<Row> {
+    let mut state: u64 = 0x9e37_79b9_7f4a_7c15;
+    (0..len)
+        .map(|id| {
+            state ^= state << 13;
+            state ^= state >> 7;
+            state ^= state << 17;
+            Row {
+                id,
+                name: format!("row-{:x}-{}", state, id % 97),
+                bucket: (state % 8) as i64,
+                score: (state % 10_000) as i64,
+            }
+        })
+        .collect()
+}
+
+fn queries() -> Vec<Query> {
+    vec![
+        Query::new().build(),
+        Query::new().and_gt("score", 5_000i64).build(),
+        Query::new()
+            .and_regex("name", r"-[0-9a-f]*7[0-9a-f]*-")
+            .unwrap()
+            .not_eq("bucket", 3i64)
+            .build(),
+        // Ties on bucket must keep slice order
+        Query::new()
+            .and_lt("score", 2_000i64)
+            .order_desc("bucket")
+            .build(),
+        Query::new()
+            .or_eq("bucket", 1i64)
+            .or_contains("name", "-42")
+            .order_asc("bucket")
+            .offset(100)
+            .limit(250)
+            .build(),
+        Query::new()
+            .and_gte("score", 0i64)
+            .offset(1_000)
+            .limit(10)
+            .build(),
+        Query::new().and_eq("score", -1i64).build(),
+    ]
+}
+
+fn ids(rows: &[&Row]) -> Vec<u64> {
+    rows.iter().map(|row| row.id).collect()
+}
+
+fn time<R>(f: impl Fn() -> R) -> (R, Duration) {
+    let start = Instant::now();
+    let result = f();
+    (result, start.elapsed())
+}
+
+// ============================================================================
+// Parity with the sequential path
+// ============================================================================
+
+#[test]
+fn par_apply_matches_apply() {
+    let rows = dataset(50_000);
+    for query in queries() {
+        assert_eq!(
+            ids(&query.par_apply(&rows, accessor)),
+            ids(&query.apply(&rows, accessor)),
+            "{query:?}"
+        );
+    }
+}
+
+#[test]
+fn par_count_matches_count() {
+    let rows = dataset(50_000);
+    for query in queries() {
+        assert_eq!(
+            query.par_count(&rows, accessor),
+            query.count(&rows, accessor),
+            "{query:?}"
+        );
+    }
+}
+
+#[test]
+fn par_apply_keeps_slice_order_for_equal_keys() {
+    let rows = dataset(20_000);
+    let query = Query::new().order_asc("bucket").build();
+
+    let results = query.par_apply(&rows, accessor);
+    assert_eq!(results.len(), rows.len());
+    for pair in results.windows(2) {
+        assert!(pair[0].bucket <= pair[1].bucket);
+        if pair[0].bucket == pair[1].bucket {
+            assert!(pair[0].id < pair[1].id);
+        }
+    }
+}
+
+#[test]
+fn par_apply_empty_input() {
+    let rows: Vec<Row> = Vec::new();
+    let query = Query::new().and_gt("score", 1i64).order_asc("id").build();
+    assert!(query.par_apply(&rows, accessor).is_empty());
+    assert_eq!(query.par_count(&rows, accessor), 0);
+}
+
+// ============================================================================
+// Benchmark
+// ============================================================================
+
+#[test]
+#[ignore = "timing-sensitive; run with --release -- --ignored"]
+fn par_apply_is_faster_on_large_datasets() {
+    let threads = std::thread::available_parallelism().map_or(1, |n| n.get());
+    let rows = dataset(1_000_000);
+    let query = Query::new()
+        .and_regex("name", r"^row-[0-9a-f]*(ab|cd)[0-9a-f]*-\d+$")
+        .unwrap()
+        .or_gt("score", 9_000i64)
+        .or_lt("score", 500i64)
+        .order_desc("bucket")
+        .build();
+
+    let (sequential, seq_time) = time(|| ids(&query.apply(&rows, accessor)));
+    let (parallel, par_time) = time(|| ids(&query.par_apply(&rows, accessor)));
+    assert_eq!(parallel, sequential);
+
+    println!(
+        "{} rows, {} matches, {} threads: apply {:?}, par_apply {:?}",
+        rows.len(),
+        sequential.len(),
+        threads,
+        seq_time,
+        par_time
+    );
+    if threads > 1 {
+        assert!(
+            par_time < seq_time,
+            "par_apply ({par_time:?}) was not faster than apply ({seq_time:?})"
+        );
+    }
+}
@@ -3,23 +3,25 @@
 //! This module provides the [`App`] type which is the main entry point
 //! for standout-clap integration.
 
+use crate::context::RenderContext;
 use crate::setup::SetupError;
 use crate::topics::{
-    display_with_pager, render_topic, render_topics_list, TopicRegistry, TopicRenderConfig,
+    display_with_pager, render_topic, render_topic_search_results, render_topics_list,
+    TopicRegistry, TopicRenderConfig,
 };
-use crate::{render_auto, OutputMode, Theme};
+use crate::{OutputMode, Theme};
 use clap::{Arg, ArgAction, ArgMatches, Command};
 use serde::Serialize;
 
-use super::core::AppCore;
+use super::core::{dry_run_requested, highlight_json_output, AppCore};
 use super::dispatch::{
-    extract_command_path, get_deepest_matches, has_subcommand, insert_default_command,
-    DispatchOutput, Dispatchable,
+    has_subcommand, insert_default_command, resolve_invocation, DispatchOutput, Dispatchable,
 };
 use super::help::{render_help, render_help_with_topics, HelpConfig};
 use super::hooks::Hooks;
 use super::mode::{HandlerMode, ThreadSafe};
-use super::result::HelpResult;
+use super::progress::terminal_progress_sink;
+use super::result::{AppError, HelpResult};
 use crate::cli::handler::{CommandContext, HandlerResult, Output as HandlerOutput, RunResult};
 use crate::cli::hooks::{HookError, RenderedOutput};
 use std::collections::HashMap;
@@ -27,9 +29,64 @@ use std::collections::HashMap;
 use super::mode::Local;
 use super::LocalAppBuilder;
 
-/// Gets the current terminal width, or None if not available.
-pub(crate) fn get_terminal_width() -> Option<usize> {
-    terminal_size::terminal_size().map(|(w, _)| w.0 as usize)
+/// Width used for layout when nothing else determines it.
+pub(crate) const DEFAULT_TERMINAL_WIDTH: usize = 80;
+
+/// Resolves the width used for rendering.
+///
+/// An explicit `override_width` (from `AppBuilder::terminal_width`) wins,
+/// then the `COLUMNS` environment variable, then the real terminal size,
+/// then [`DEFAULT_TERMINAL_WIDTH`].
+pub(crate) fn get_terminal_width(override_width: Option<usize>) -> usize {
+    resolve_terminal_width(
+        override_width,
+        std::env::var("COLUMNS").ok().as_deref(),
+        terminal_size::terminal_size().map(|(w, _)| w.0 as usize),
+    )
+}
+
+/// Builds the [`RenderContext`] for rendering handler output.
+///
+/// Every render path goes through here, so the width seen by context
+/// providers and tables is always resolved by [`get_terminal_width`].
+pub(crate) fn render_context<'a>(
+    mode: OutputMode,
+    override_width: Option<usize>,
+    theme: &'a Theme,
+    data: &'a serde_json::Value,
+) -> RenderContext<'a> {
+    RenderContext::new(mode, Some(get_terminal_width(override_width)), theme, data)
+}
+
+/// Turns a clap parse failure into a dispatch result.
+///
+/// `--help` and `--version` are reported by clap as errors but are output to
+/// print; everything else is an [`AppError::Usage`].
+fn parse_error(e: clap::Error) -> Result<RunResult, AppError> {
+    if e.use_stderr() {
+        Err(AppError::Usage(e))
+    } else {
+        Ok(RunResult::Handled(e.to_string()))
+    }
+}
+
+/// Picks a width from the given sources, in precedence order.
+///
+/// `columns` is the raw value of `COLUMNS`; values that aren't a positive
+/// integer are ignored.
+pub(crate) fn resolve_terminal_width(
+    override_width: Option<usize>,
+    columns: Option<&str>,
+    terminal: Option<usize>,
+) -> usize {
+    override_width
+        .or_else(|| {
+            columns
+                .and_then(|c| c.trim().parse::<usize>().ok())
+                .filter(|&w| w > 0)
+        })
+        .or(terminal)
+        .unwrap_or(DEFAULT_TERMINAL_WIDTH)
 }
 
 /// Main entry point for standout-clap integration.
@@ -60,6 +117,8 @@ pub struct App<M: HandlerMode = ThreadSafe> {
     pub(crate) registry: TopicRegistry,
     /// Registered command handlers.
     pub(crate) commands: HashMap<String, M::DispatchFn>,
+    /// Handler for subcommands with no registered handler.
+    pub(crate) fallback: Option<M::DispatchFn>,
 }
 
 impl App<ThreadSafe> {
@@ -89,6 +148,7 @@ impl<M: HandlerMode> App<M> {
             core: AppCore::new(),
             registry: TopicRegistry::new(),
             commands: HashMap::new(),
+            fallback: None,
         }
     }
 
@@ -98,6 +158,7 @@ impl<M: HandlerMode> App<M> {
             core: AppCore::new(),
             registry,
             commands: HashMap::new(),
+            fallback: None,
         }
     }
 
@@ -111,6 +172,16 @@ impl<M: HandlerMode> App<M> {
         &mut self.registry
     }
 
+    /// Returns the registered command paths in dot notation, sorted.
+    ///
+    /// Handy for a `commands` introspection subcommand, or for tests that
+    /// assert the expected set of commands is registered.
+    pub fn command_paths(&self) -> Vec<&str> {
+        let mut paths: Vec<&str> = self.commands.keys().map(String::as_str).collect();
+        paths.sort_unstable();
+        paths
+    }
+
     // =========================================================================
     // Delegated accessors (from AppCore)
     // =========================================================================
@@ -209,59 +280,134 @@ impl<M: HandlerMode> App<M> {
     // =========================================================================
 
     /// Dispatches to a registered handler if one matches the command path.
+    ///
+    /// Handler and hook errors come back as `RunResult::Handled` with the
+    /// error text.
     pub fn dispatch(&self, matches: ArgMatches, output_mode: OutputMode) -> RunResult {
-        let path = extract_command_path(&matches);
+        self.try_dispatch(matches, output_mode)
+            .map(|(result, _)| result)
+            .unwrap_or_else(|e| RunResult::Handled(e.to_string()))
+    }
+
+    /// Like `dispatch`, but returns handler and hook errors, and whether the
+    /// text came from `Output::Raw`.
+    fn try_dispatch(
+        &self,
+        matches: ArgMatches,
+        output_mode: OutputMode,
+    ) -> Result<(RunResult, bool), AppError> {
+        let (path, sub_matches) = resolve_invocation(&matches);
         let path_str = path.join(".");
 
-        if let Some(dispatch) = self.commands.get(&path_str) {
+        // An exact match wins; the fallback only sees invocations that named a subcommand
+        let dispatch = self
+            .commands
+            .get(&path_str)
+            .or_else(|| self.fallback.as_ref().filter(|_| !path.is_empty()));
+
+        if let Some(dispatch) = dispatch {
             let mut ctx = CommandContext::new(path, self.core.app_state.clone());
+            ctx.progress_sink = terminal_progress_sink(self.core.theme());
+            ctx.root_matches = Some(matches.clone());
+            ctx.dry_run = dry_run_requested(&matches);
 
             let hooks = self.core.get_hooks(&path_str);
 
             // Run pre-dispatch hooks (hooks can inject state via ctx.extensions)
             if let Some(hooks) = hooks {
-                if let Err(e) = hooks.run_pre_dispatch(&matches, &mut ctx) {
-                    return RunResult::Handled(format!("Hook error: {}", e));
-                }
+                hooks
+                    .run_pre_dispatch(&matches, &mut ctx)
+                    .map_err(|e| AppError::Hook(e.to_string()))?;
             }
 
-            let sub_matches = get_deepest_matches(&matches);
-
             // Run the handler (output_mode passed separately as CommandContext is render-agnostic)
-            let dispatch_output = match dispatch.dispatch(sub_matches, &ctx, hooks, output_mode) {
-                Ok(output) => output,
-                Err(e) => return RunResult::Handled(e),
-            };
+            let dispatch_output = dispatch
+                .dispatch(sub_matches, &ctx, hooks, output_mode)
+                .map_err(AppError::Handler)?;
 
-            // Convert to RenderedOutput for post-output hooks
+            // Convert to RenderedOutput for output hooks
+            let raw = matches!(dispatch_output, DispatchOutput::Raw(_));
             let output = match dispatch_output {
-                DispatchOutput::Text(s) => RenderedOutput::Text(s),
-                DispatchOutput::Binary(b, f) => RenderedOutput::Binary(b, f),
+                DispatchOutput::Text(s) | DispatchOutput::Raw(s) => RenderedOutput::Text(s),
+                DispatchOutput::Binary {
+                    data,
+                    filename,
+                    to_stdout,
+                    content_type,
+                } => RenderedOutput::Binary {
+                    data,
+                    filename,
+                    to_stdout,
+                    content_type,
+                },
                 DispatchOutput::Silent => RenderedOutput::Silent,
             };
 
-            // Run post-output hooks
+            // Run pre-output, then post-output hooks
             let final_output = if let Some(hooks) = hooks {
-                match hooks.run_post_output(&matches, &ctx, output) {
-                    Ok(o) => o,
-                    Err(e) => return RunResult::Handled(format!("Hook error: {}", e)),
-                }
+                hooks
+                    .run_output(&matches, &ctx, output)
+                    .map_err(|e| AppError::Hook(e.to_string()))?
             } else {
                 output
             };
 
-            match final_output {
+            let result = match final_output {
                 RenderedOutput::Text(s) => RunResult::Handled(s),
-                RenderedOutput::Binary(b, f) => RunResult::Binary(b, f),
+                RenderedOutput::Binary {
+                    data,
+                    filename,
+                    to_stdout,
+                    content_type,
+                } => RunResult::Binary {
+                    data,
+                    filename,
+                    to_stdout,
+                    content_type,
+                },
                 RenderedOutput::Silent => RunResult::Handled(String::new()),
-            }
+            };
+            Ok((result, raw))
         } else {
-            RunResult::NoMatch(matches)
+            Ok((RunResult::NoMatch(matches), false))
         }
     }
 
     /// Parses arguments and dispatches to registered handlers.
     pub fn dispatch_from<I, T>(&self, cmd: Command, args: I) -> RunResult
+    where
+        I: IntoIterator<Item = T>,
+        T: Into<std::ffi::OsString> + Clone,
+    {
+        self.parse_and_dispatch(cmd, args)
+            .0
+            .unwrap_or_else(|e| RunResult::Handled(e.to_string()))
+    }
+
+    /// Like `dispatch_from`, but returns handler and hook errors and also the
+    /// output mode that was used and whether the text came from `Output::Raw`.
+    ///
+    /// Parse errors are reported as output, with `OutputMode::Auto`.
+    fn parse_and_dispatch<I, T>(
+        &self,
+        cmd: Command,
+        args: I,
+    ) -> (Result<RunResult, AppError>, OutputMode, bool)
+    where
+        I: IntoIterator<Item = T>,
+        T: Into<std::ffi::OsString> + Clone,
+    {
+        self.parse_and_dispatch_with(cmd, args, OutputMode::Auto)
+    }
+
+    /// Like `parse_and_dispatch`, but renders with `auto_mode` whenever the
+    /// output mode would otherwise resolve to `OutputMode::Auto`.
+    fn parse_and_dispatch_with<I, T>(
+        &self,
+        cmd: Command,
+        args: I,
+        auto_mode: OutputMode,
+    ) -> (Result<RunResult, AppError>, OutputMode, bool)
     where
         I: IntoIterator<Item = T>,
         T: Into<std::ffi::OsString> + Clone,
@@ -275,7 +421,7 @@ impl<M: HandlerMode> App<M> {
 
         let matches = match augmented_cmd.try_get_matches_from(&args) {
             Ok(m) => m,
-            Err(e) => return RunResult::Handled(e.to_string()),
+            Err(e) => return (parse_error(e), OutputMode::Auto, false),
         };
 
         // Check if we need to insert default command
@@ -286,20 +432,29 @@ impl<M: HandlerMode> App<M> {
             let augmented_cmd = self.core.augment_command(cmd);
             match augmented_cmd.try_get_matches_from(&new_args) {
                 Ok(m) => m,
-                Err(e) => return RunResult::Handled(e.to_string()),
+                Err(e) => return (parse_error(e), OutputMode::Auto, false),
             }
         } else {
             matches
         };
 
         // Extract output mode using core
-        let output_mode = self.core.extract_output_mode(&matches);
+        let output_mode = match self.core.extract_output_mode(&matches) {
+            OutputMode::Auto => auto_mode,
+            mode => mode,
+        };
 
-        self.dispatch(matches, output_mode)
+        match self.try_dispatch(matches, output_mode) {
+            Ok((result, raw)) => (Ok(result), output_mode, raw),
+            Err(e) => (Err(e), output_mode, false),
+        }
     }
 
     /// Runs the CLI: parses arguments, dispatches to handlers, and prints output.
     ///
+    /// Handler, hook and binary output errors are printed too; use
+    /// [`try_run`](Self::try_run) to get them back instead.
+    ///
     /// # Returns
     ///
     /// - `true` if a handler processed and printed output
@@ -309,23 +464,84 @@ impl<M: HandlerMode> App<M> {
         I: IntoIterator<Item = T>,
         T: Into<std::ffi::OsString> + Clone,
     {
-        match self.dispatch_from(cmd, args) {
+        let (result, output_mode, raw) = self.parse_and_dispatch(cmd, args);
+        let result = result.unwrap_or_else(|e| RunResult::Handled(e.to_string()));
+        self.emit(result, output_mode, raw).unwrap_or_else(|e| {
+            eprintln!("{}", e);
+            true
+        })
+    }
+
+    /// Runs the CLI like [`run`](Self::run), but returns errors instead of
+    /// printing them.
+    ///
+    /// Successful output is still printed. A handler returning `Err`, a
+    /// failing hook, or binary output that cannot be written comes back as
+    /// an [`AppError`], so `main` can report it and exit with
+    /// [`AppError::exit_code`]. Invalid arguments come back as
+    /// [`AppError::Usage`]; `--help` and `--version` output is printed.
+    ///
+    /// # Example
+    ///
+    /// ```rust,ignore
+    /// fn main() {
+    ///     let app = LocalApp::builder()
+    ///         .command("sync", sync_handler, "{{ count }} synced")?
+    ///         .build()?;
+    ///
+    ///     if let Err(e) = app.try_run(cmd, std::env::args()) {
+    ///         eprintln!("{}", e);
+    ///         std::process::exit(e.exit_code());
+    ///     }
+    /// }
+    /// ```
+    pub fn try_run<I, T>(&self, cmd: Command, args: I) -> Result<bool, AppError>
+    where
+        I: IntoIterator<Item = T>,
+        T: Into<std::ffi::OsString> + Clone,
+    {
+        let (result, output_mode, raw) = self.parse_and_dispatch(cmd, args);
+        self.emit(result?, output_mode, raw)
+    }
+
+    /// Prints a dispatch result, returning whether a handler ran.
+    ///
+    /// `raw` text (from `Output::Raw`) is printed as given, without JSON
+    /// highlighting.
+    fn emit(
+        &self,
+        result: RunResult,
+        output_mode: OutputMode,
+        raw: bool,
+    ) -> Result<bool, AppError> {
+        match result {
             RunResult::Handled(output) => {
                 if !output.is_empty() {
+                    let output = highlight_json_output(
+                        output,
+                        self.core.theme(),
+                        output_mode.should_highlight_json() && !raw,
+                    );
                     println!("{}", output);
                 }
-                true
+                Ok(true)
             }
-            RunResult::Binary(bytes, filename) => {
-                if let Err(e) = std::fs::write(&filename, &bytes) {
-                    eprintln!("Error writing {}: {}", filename, e);
-                } else {
-                    eprintln!("Wrote {} bytes to {}", bytes.len(), filename);
-                }
-                true
+            RunResult::Binary {
+                data,
+                filename,
+                to_stdout,
+                content_type,
+            } => {
+                self.core.binary_output.try_write(
+                    &data,
+                    &filename,
+                    to_stdout,
+                    content_type.as_deref(),
+                )?;
+                Ok(true)
             }
-            RunResult::Silent => true, // Handler ran successfully, no output
-            RunResult::NoMatch(_) => false,
+            RunResult::Silent => Ok(true), // Handler ran successfully, no output
+            RunResult::NoMatch(_) => Ok(false),
         }
     }
 
@@ -338,6 +554,26 @@ impl<M: HandlerMode> App<M> {
         self.dispatch_from(cmd, args)
     }
 
+    /// Like [`run_to_string`](Self::run_to_string), but renders in
+    /// `default_mode` unless `--output` or a per-command default picks a mode.
+    ///
+    /// With `OutputMode::TermDebug`, styles show up as `[name]text[/name]`
+    /// tags regardless of TTY detection, which keeps snapshot tests stable.
+    pub fn run_to_string_with<I, T>(
+        &self,
+        cmd: Command,
+        args: I,
+        default_mode: OutputMode,
+    ) -> RunResult
+    where
+        I: IntoIterator<Item = T>,
+        T: Into<std::ffi::OsString> + Clone,
+    {
+        self.parse_and_dispatch_with(cmd, args, default_mode)
+            .0
+            .unwrap_or_else(|e| RunResult::Handled(e.to_string()))
+    }
+
     /// Executes a command handler with hooks applied automatically.
     ///
     /// This is for the regular API - when you handle dispatch manually
@@ -347,7 +583,7 @@ impl<M: HandlerMode> App<M> {
     /// 1. Runs pre-dispatch hooks (if any)
     /// 2. Calls your handler closure
     /// 3. Renders the result using the template
-    /// 4. Runs post-output hooks (if any)
+    /// 4. Runs pre-output, then post-output hooks (if any)
     /// 5. Returns the final output
     ///
     /// # Arguments
@@ -401,6 +637,8 @@ impl<M: HandlerMode> App<M> {
             path.split('.').map(String::from).collect(),
             self.core.app_state.clone(),
         );
+        ctx.progress_sink = terminal_progress_sink(self.core.theme());
+        ctx.dry_run = dry_run_requested(matches);
 
         let hooks = self.core.get_hooks(path);
 
@@ -425,8 +663,10 @@ impl<M: HandlerMode> App<M> {
                 }
 
                 // Render the (potentially modified) data
-                let theme = self.core.theme().cloned().unwrap_or_default();
-                match render_auto(template, &json_data, &theme, self.core.output_mode()) {
+                match self
+                    .core
+                    .render_inline(template, &json_data, self.core.output_mode())
+                {
                     Ok(rendered) => RenderedOutput::Text(rendered),
                     Err(e) => return Err(HookError::post_output("Render error").with_source(e)),
                 }
@@ -435,12 +675,23 @@ impl<M: HandlerMode> App<M> {
                 return Err(HookError::post_output("Handler error").with_source(e));
             }
             Ok(HandlerOutput::Silent) => RenderedOutput::Silent,
-            Ok(HandlerOutput::Binary { data, filename }) => RenderedOutput::Binary(data, filename),
+            Ok(HandlerOutput::Raw(text)) => RenderedOutput::Text(text),
+            Ok(HandlerOutput::Binary {
+                data,
+                filename,
+                to_stdout,
+                content_type,
+            }) => RenderedOutput::Binary {
+                data,
+                filename,
+                to_stdout,
+                content_type,
+            },
         };
 
-        // Run post-output hooks
+        // Run pre-output, then post-output hooks
         if let Some(hooks) = hooks {
-            hooks.run_post_output(matches, &ctx, output)
+            hooks.run_output(matches, &ctx, output)
         } else {
             Ok(output)
         }
@@ -474,6 +725,87 @@ impl<M: HandlerMode> App<M> {
         self.core.augment_command(cmd)
     }
 
+    /// Describes the full command tree as JSON, for external tooling.
+    ///
+    /// Walks `cmd` after [`augment_command`](Self::augment_command), so the
+    /// injected `help` subcommand and `--output` flags are included (global
+    /// args appear on every command). Each node has its `name`, dotted `path`,
+    /// `about`, `aliases`, `args`, `subcommands`, and whether it is `hidden`.
+    /// `handler` tells whether a handler is registered for the path, and
+    /// `topic` whether a help topic shares its name. The root also lists all
+    /// registered `topics`.
+    ///
+    /// # Example
+    ///
+    /// ```rust,ignore
+    /// let tree = app.dump_command_tree(&cmd);
+    /// std::fs::write("commands.json", serde_json::to_string_pretty(&tree)?)?;
+    /// ```
+    pub fn dump_command_tree(&self, cmd: &Command) -> serde_json::Value {
+        let mut cmd = self.augment_command(cmd.clone());
+        cmd.build();
+
+        let mut tree = self.command_node(&cmd, &[]);
+        let topics: Vec<_> = self
+            .registry
+            .list_topics()
+            .into_iter()
+            .map(|t| serde_json::json!({ "name": t.name, "title": t.title }))
+            .collect();
+        tree["topics"] = serde_json::Value::from(topics);
+        tree
+    }
+
+    /// Builds the JSON node for one command of [`dump_command_tree`](Self::dump_command_tree).
+    fn command_node(&self, cmd: &Command, path: &[String]) -> serde_json::Value {
+        let path_str = path.join(".");
+
+        let args: Vec<_> = cmd
+            .get_arguments()
+            .map(|arg| {
+                serde_json::json!({
+                    "id": arg.get_id().as_str(),
+                    "short": arg.get_short(),
+                    "long": arg.get_long(),
+                    "help": arg.get_help().map(|h| h.to_string()),
+                    "value_names": arg
+                        .get_value_names()
+                        .map(|names| names.iter().map(|n| n.as_str()).collect::<Vec<_>>())
+                        .unwrap_or_default(),
+                    "possible_values": arg
+                        .get_possible_values()
+                        .iter()
+                        .map(|v| v.get_name().to_string())
+                        .collect::<Vec<_>>(),
+                    "required": arg.is_required_set(),
+                    "global": arg.is_global_set(),
+                    "hidden": arg.is_hide_set(),
+                })
+            })
+            .collect();
+
+        let subcommands: Vec<_> = cmd
+            .get_subcommands()
+            .map(|sub| {
+                let mut sub_path = path.to_vec();
+                sub_path.push(sub.get_name().to_string());
+                self.command_node(sub, &sub_path)
+            })
+            .collect();
+
+        serde_json::json!({
+            "name": cmd.get_name(),
+            "path": path_str,
+            "about": cmd.get_about().map(|a| a.to_string()),
+            "aliases": cmd.get_all_aliases().collect::<Vec<_>>(),
+            "hidden": cmd.is_hide_set(),
+            "handler": !path.is_empty() && self.commands.contains_key(&path_str),
+            "topic": !path.is_empty() && self.registry.get_topic(&path_str).is_some(),
+            "args": args,
+            "subcommands": subcommands,
+        })
+    }
+
     /// Parses CLI arguments and returns matches.
     ///
     /// This is the recommended entry point for parsing only. It:
@@ -584,11 +916,7 @@ impl<M: HandlerMode> App<M> {
 
         // 0. Check for "topics" - list all available topics
         if sub_name == "topics" {
-            let topic_config = TopicRenderConfig {
-                output_mode: config.as_ref().and_then(|c| c.output_mode),
-                theme: config.as_ref().and_then(|c| c.theme.clone()),
-                ..Default::default()
-            };
+            let topic_config = topic_render_config(config.as_ref());
             if let Ok(h) = render_topics_list(
                 &self.registry,
                 &format!("{} help", cmd.get_name()),
@@ -617,11 +945,7 @@ impl<M: HandlerMode> App<M> {
 
         // 2. Check if it is a topic
         if let Some(topic) = self.registry.get_topic(sub_name) {
-            let topic_config = TopicRenderConfig {
-                output_mode: config.as_ref().and_then(|c| c.output_mode),
-                theme: config.as_ref().and_then(|c| c.theme.clone()),
-                ..Default::default()
-            };
+            let topic_config = topic_render_config(config.as_ref());
             if let Ok(h) = render_topic(topic, Some(topic_config)) {
                 return if use_pager {
                     HelpResult::PagedHelp(h)
@@ -631,7 +955,26 @@ impl<M: HandlerMode> App<M> {
             }
         }
 
-        // 3. Not found
+        // 3. Fall back to searching topic titles and contents
+        let query = keywords.join(" ");
+        let hits = self.registry.search(&query);
+        if !hits.is_empty() {
+            let topic_config = topic_render_config(config.as_ref());
+            if let Ok(h) = render_topic_search_results(
+                &hits,
+                &query,
+                &format!("{} help", cmd.get_name()),
+                Some(topic_config),
+            ) {
+                return if use_pager {
+                    HelpResult::PagedHelp(h)
+                } else {
+                    HelpResult::Help(h)
+                };
+            }
+        }
+
+        // 4. Not found
         let err = cmd.error(
             clap::error::ErrorKind::InvalidSubcommand,
             format!("The subcommand or topic '{}' wasn't recognized", sub_name),
@@ -640,6 +983,18 @@ impl<M: HandlerMode> App<M> {
     }
 }
 
+/// Builds the topic rendering config for a help request.
+///
+/// Every topic view (list, single topic, search results) goes through here,
+/// so topics get the same theme and output mode as command help.
+fn topic_render_config(config: Option<&HelpConfig>) -> TopicRenderConfig {
+    TopicRenderConfig {
+        output_mode: config.and_then(|c| c.output_mode),
+        theme: config.and_then(|c| c.theme.clone()),
+        ..Default::default()
+    }
+}
+
 impl Default for App {
     fn default() -> Self {
         Self::new()
@@ -660,17 +1015,182 @@ fn find_subcommand_recursive<'a>(cmd: &'a Command, keywords: &[&str]) -> Option<
 
 fn find_subcommand<'a>(cmd: &'a Command, name: &str) -> Option<&'a Command> {
     cmd.get_subcommands()
-        .find(|s| s.get_name() == name || s.get_aliases().any(|a| a == name))
+        .find(|s| s.get_name() == name || s.get_all_aliases().any(|a| a == name))
 }
 
 #[cfg(test)]
 mod tests {
     use super::*;
 
+    fn search_app() -> App {
+        let mut registry = TopicRegistry::new();
+        registry.add_topic(crate::topics::Topic::new(
+            "Storage",
+            "Notes are kept in plain files",
+            crate::topics::TopicType::Text,
+            None,
+        ));
+        App::with_registry(registry)
+    }
+
+    #[test]
+    fn test_help_unknown_keyword_lists_search_hits() {
+        let cmd = Command::new("notes");
+        let result =
+            search_app().get_matches_from(cmd, ["notes", "--output", "text", "help", "files"]);
+        match result {
+            HelpResult::Help(h) => {
+                assert!(h.contains("Topics matching 'files'"), "{}", h);
+                assert!(h.contains("storage"), "{}", h);
+            }
+            _ => panic!("expected search results"),
+        }
+    }
+
+    #[test]
+    fn test_help_resolves_hidden_alias() {
+        let cmd = Command::new("notes")
+            .subcommand(Command::new("list").about("List all notes").alias("ls"));
+        let result = App::<ThreadSafe>::new().get_matches_from(cmd, ["notes", "help", "ls"]);
+        match result {
+            HelpResult::Help(h) => assert!(h.contains("List all notes"), "{}", h),
+            _ => panic!("expected help for the aliased command"),
+        }
+    }
+
+    #[test]
+    fn test_help_topics_use_app_theme() {
+        use console::Style;
+
+        let mut app = search_app();
+        app.core.theme = Some(
+            Theme::new()
+                .add("header", Style::new().red().force_styling(true))
+                .add("item", Style::new().green().force_styling(true)),
+        );
+        let help = |args: &[&str]| match app.get_matches_from(Command::new("notes"), args) {
+            HelpResult::Help(h) => h,
+            _ => panic!("expected help for {:?}", args),
+        };
+
+        let topic = help(&["notes", "--output", "term", "help", "storage"]);
+        assert!(topic.contains("\x1b[31mSTORAGE\x1b[0m"), "{:?}", topic);
+
+        let list = help(&["notes", "--output", "term", "help", "topics"]);
+        assert!(list.contains("\x1b[32m"), "{:?}", list);
+
+        let hits = help(&["notes", "--output", "term", "help", "plain"]);
+        assert!(hits.contains("\x1b[32m"), "{:?}", hits);
+    }
+
+    #[test]
+    fn test_command_paths_sorted() {
+        let silent = |_m: &ArgMatches, _ctx: &CommandContext| Ok(HandlerOutput::<()>::Silent);
+
+        let app = App::<ThreadSafe>::builder()
+            .command("list", silent, "")
+            .unwrap()
+            .command("config.set", silent, "")
+            .unwrap()
+            .command("config.get", silent, "")
+            .unwrap()
+            .build()
+            .unwrap();
+        assert_eq!(
+            app.command_paths(),
+            vec!["config.get", "config.set", "list"]
+        );
+
+        let local = App::<Local>::builder()
+            .command("sync", silent, "")
+            .unwrap()
+            .command("add", silent, "")
+            .unwrap()
+            .build()
+            .unwrap();
+        assert_eq!(local.command_paths(), vec!["add", "sync"]);
+        assert!(App::<ThreadSafe>::new().command_paths().is_empty());
+    }
+
+    #[test]
+    fn test_help_unknown_keyword_without_hits_is_error() {
+        let cmd = Command::new("notes");
+        let result = search_app().get_matches_from(cmd, ["notes", "help", "zebra"]);
+        assert!(matches!(result, HelpResult::Error(_)));
+    }
+
+    #[test]
+    fn test_resolve_terminal_width_precedence() {
+        assert_eq!(resolve_terminal_width(Some(50), Some("120"), Some(200)), 50);
+        assert_eq!(resolve_terminal_width(None, Some("120"), Some(200)), 120);
+        assert_eq!(resolve_terminal_width(None, None, Some(200)), 200);
+        assert_eq!(
+            resolve_terminal_width(None, None, None),
+            DEFAULT_TERMINAL_WIDTH
+        );
+    }
+
+    #[test]
+    fn test_resolve_terminal_width_ignores_invalid_columns() {
+        assert_eq!(resolve_terminal_width(None, Some("wide"), Some(200)), 200);
+        assert_eq!(resolve_terminal_width(None, Some("0"), None), 80);
+    }
+
     #[test]
     fn test_output_flag_enabled_by_default() {
         let standout = App::<ThreadSafe>::new();
         assert!(standout.core.output_flag.is_some());
         assert_eq!(standout.core.output_flag.as_deref(), Some("output"));
     }
+
+    #[test]
+    fn test_dump_command_tree_marks_nested_handler() {
+        let app = App::<ThreadSafe>::builder()
+            .command("config.get", |_m, _ctx| Ok(HandlerOutput::<()>::Silent), "")
+            .unwrap()
+            .build()
+            .unwrap();
+        let cmd = Command::new("app").subcommand(
+            Command::new("config")
+                .about("Manage configuration")
+                .subcommand(
+                    Command::new("get")
+                        .about("Read a value")
+                        .arg(Arg::new("key").long("key").help("Key to read")),
+                ),
+        );
+
+        let tree = app.dump_command_tree(&cmd);
+
+        let sub = |node: &serde_json::Value, name: &str| {
+            node["subcommands"]
+                .as_array()
+                .unwrap()
+                .iter()
+                .find(|s| s["name"] == name)
+                .cloned()
+                .unwrap_or_else(|| panic!("missing subcommand {name}"))
+        };
+        let has_long = |node: &serde_json::Value, long: &str| {
+            node["args"]
+                .as_array()
+                .unwrap()
+                .iter()
+                .any(|a| a["long"] == long)
+        };
+
+        let config = sub(&tree, "config");
+        assert_eq!(config["about"], "Manage configuration");
+        assert_eq!(config["handler"], false);
+
+        let get = sub(&config, "get");
+        assert_eq!(get["path"], "config.get");
+        assert_eq!(get["handler"], true);
+        assert!(has_long(&get, "key"));
+        assert!(has_long(&get, "output"));
+
+        assert!(has_long(&tree, "output"));
+        assert_eq!(sub(&tree, "help")["handler"], false);
+        assert!(tree["topics"].as_array().unwrap().is_empty());
+    }
 }
@@ -5,17 +5,19 @@
 //! - Struct-based handler registration
 //! - Command groups for nested hierarchies
 //! - Hook registration
+//! - Validation of registered paths against the clap command tree
 
-use clap::ArgMatches;
+use clap::{ArgMatches, Command};
 use serde::Serialize;
 
-use super::{AppBuilder, PendingCommand};
+use super::{AppBuilder, PendingCommand, PendingTemplate};
 use crate::cli::group::{
     ClosureRecipe, CommandConfig, ErasedConfigRecipe, GroupBuilder, GroupEntry, StructRecipe,
 };
 use crate::cli::handler::{CommandContext, FnHandler, Handler, HandlerResult};
 use crate::cli::hooks::Hooks;
 use crate::setup::SetupError;
+use crate::OutputMode;
 
 impl AppBuilder {
     /// Creates a command group for organizing related commands.
@@ -85,6 +87,10 @@ impl AppBuilder {
             self.command_hooks.insert(path.to_string(), hooks);
         }
 
+        if let Some(mode) = config.output_mode {
+            self.command_output_modes.insert(path.to_string(), mode);
+        }
+
         // Create a recipe for deferred closure creation using the handler
         let recipe = ClosureRecipe::new(config.handler);
 
@@ -97,7 +103,7 @@ impl AppBuilder {
             path.to_string(),
             PendingCommand {
                 recipe: Box::new(recipe),
-                template,
+                template: PendingTemplate::Content(template),
             },
         );
 
@@ -126,6 +132,10 @@ impl AppBuilder {
                         self.command_hooks.insert(path.clone(), hooks);
                     }
 
+                    if let Some(mode) = handler.output_mode() {
+                        self.command_output_modes.insert(path.clone(), mode);
+                    }
+
                     // Create a recipe for deferred closure creation
                     let recipe = ErasedConfigRecipe::from_handler(handler);
 
@@ -139,7 +149,7 @@ impl AppBuilder {
                         path,
                         PendingCommand {
                             recipe: Box::new(recipe),
-                            template,
+                            template: PendingTemplate::Content(template),
                         },
                     );
                 }
@@ -181,6 +191,8 @@ impl AppBuilder {
     ///
     /// The handler will be invoked when the command path matches. The path uses
     /// dot notation for nested commands (e.g., "config.get" matches `app config get`).
+    /// Use the canonical subcommand names: invoking a clap alias (`app cfg get`)
+    /// dispatches to the same handler.
     ///
     /// # Arguments
     ///
@@ -211,6 +223,51 @@ impl AppBuilder {
         self.command_handler(path, FnHandler::new(handler), template)
     }
 
+    /// Registers a command handler whose template is looked up by registry name.
+    ///
+    /// Unlike [`command`](Self::command), which takes the template source, this
+    /// names a template in the configured registry (embedded with
+    /// [`templates`](Self::templates) or loaded with
+    /// [`templates_dir`](Self::templates_dir)), so presentation can live in its
+    /// own files. The name is resolved with `TemplateRegistry::get_content` when
+    /// commands are finalized; if it is missing, dispatching the command fails
+    /// with an error naming the template.
+    ///
+    /// # Example
+    ///
+    /// ```rust,ignore
+    /// App::builder()
+    ///     .templates(embed_templates!("src/templates"))
+    ///     .command_with_template_name("report", report_handler, "report/summary")?
+    ///     .build()?
+    ///     .run(cmd, args);
+    /// ```
+    pub fn command_with_template_name<F, T>(
+        self,
+        path: &str,
+        handler: F,
+        template_name: &str,
+    ) -> Result<Self, SetupError>
+    where
+        F: Fn(&ArgMatches, &CommandContext) -> HandlerResult<T> + Send + Sync + 'static,
+        T: Serialize + Send + Sync + 'static,
+    {
+        if self.pending_commands.borrow().contains_key(path) {
+            return Err(SetupError::DuplicateCommand(path.to_string()));
+        }
+
+        let recipe = ClosureRecipe::new(FnHandler::new(handler));
+        self.pending_commands.borrow_mut().insert(
+            path.to_string(),
+            PendingCommand {
+                recipe: Box::new(recipe),
+                template: PendingTemplate::Named(template_name.to_string()),
+            },
+        );
+
+        Ok(self)
+    }
+
     /// Registers a struct handler with a template.
     ///
     /// Use this when your handler needs to carry state (like database connections).
@@ -266,13 +323,50 @@ impl AppBuilder {
             path.to_string(),
             PendingCommand {
                 recipe: Box::new(recipe),
-                template,
+                template: PendingTemplate::Content(template),
             },
         );
 
         Ok(self)
     }
 
+    /// Registers a fallback handler for subcommands without a handler of their own.
+    ///
+    /// When dispatch finds no handler for the invoked command path, the
+    /// fallback runs instead of returning `RunResult::NoMatch`. It receives
+    /// the deepest `ArgMatches` like any handler, and the unmatched path is
+    /// available as `ctx.command_path`. Its output is rendered through
+    /// `template` with the app's theme, so "unknown command" messages and
+    /// plugin-style dispatch look like the rest of the CLI.
+    ///
+    /// An exact match always beats the fallback. Invocations that name no
+    /// subcommand at all (and have no default command) still return
+    /// `RunResult::NoMatch`. Registering a second fallback replaces the first.
+    ///
+    /// # Example
+    ///
+    /// ```rust,ignore
+    /// App::builder()
+    ///     .command("list", list_handler, "{{ items }}")?
+    ///     .on_unmatched(
+    ///         |_m, ctx| Ok(Output::Render(json!({ "command": ctx.command_path.join(" ") }))),
+    ///         "[error]Unknown command:[/error] {{ command }}",
+    ///     )
+    ///     .build()?
+    ///     .run(cmd, args);
+    /// ```
+    pub fn on_unmatched<F, T>(mut self, handler: F, template: &str) -> Self
+    where
+        F: Fn(&ArgMatches, &CommandContext) -> HandlerResult<T> + Send + Sync + 'static,
+        T: Serialize + Send + Sync + 'static,
+    {
+        self.pending_fallback = Some(PendingCommand {
+            recipe: Box::new(ClosureRecipe::new(FnHandler::new(handler))),
+            template: PendingTemplate::Content(template.to_string()),
+        });
+        self
+    }
+
     /// Registers hooks for a specific command path.
     ///
     /// Hooks are executed around the command handler:
@@ -321,6 +415,61 @@ impl AppBuilder {
         self.command_hooks.insert(path.to_string(), hooks);
         self
     }
+
+    /// Sets the output mode for a command when `--output` is not passed.
+    ///
+    /// The app-wide default (usually `auto`) still applies to every other
+    /// command, and an explicit `--output` always wins. Commands registered
+    /// with [`command_with`](Self::command_with) can set this through
+    /// [`CommandConfig::output_mode`] instead.
+    ///
+    /// # Example
+    ///
+    /// ```rust,ignore
+    /// App::builder()
+    ///     .command("export", export_handler, "")?
+    ///     .command_output_mode("export", OutputMode::Json)
+    ///     .build()?
+    /// ```
+    pub fn command_output_mode(mut self, path: &str, mode: OutputMode) -> Self {
+        self.command_output_modes.insert(path.to_string(), mode);
+        self
+    }
+
+    /// Checks that every registered handler path exists in `cmd`.
+    ///
+    /// A handler registered under a path with no matching subcommand (a typo
+    /// such as `"confg.get"`) never runs. This returns one entry per such
+    /// path, with the closest real path as a hint when there is one:
+    /// `"confg.get" (did you mean "config.get"?)`. Call it from a test or at
+    /// startup to catch mistakes early.
+    ///
+    /// # Example
+    ///
+    /// ```rust
+    /// use clap::Command;
+    /// use standout::cli::{App, Output};
+    ///
+    /// let builder = App::<standout::cli::ThreadSafe>::builder()
+    ///     .command("confg.get", |_m, _ctx| Ok(Output::<()>::Silent), "")
+    ///     .unwrap();
+    ///
+    /// let cmd = Command::new("app")
+    ///     .subcommand(Command::new("config").subcommand(Command::new("get")));
+    ///
+    /// let errors = builder.validate_against(&cmd).unwrap_err();
+    /// assert_eq!(errors, vec![r#""confg.get" (did you mean "config.get"?)"#]);
+    /// ```
+    pub fn validate_against(&self, cmd: &Command) -> Result<(), Vec<String>> {
+        let pending = self.pending_commands.borrow();
+        let unreachable =
+            standout_dispatch::find_unreachable_paths(pending.keys().map(String::as_str), cmd);
+        if unreachable.is_empty() {
+            Ok(())
+        } else {
+            Err(unreachable)
+        }
+    }
 }
 
 #[cfg(test)]
@@ -330,6 +479,91 @@ mod tests {
     use crate::OutputMode;
     use clap::Command;
 
+    #[test]
+    fn test_validate_against_accepts_registered_tree() {
+        let builder = AppBuilder::new()
+            .command("list", |_m, _ctx| Ok(HandlerOutput::<()>::Silent), "")
+            .unwrap()
+            .group("config", |g| {
+                g.command("get", |_m, _ctx| Ok(HandlerOutput::<()>::Silent))
+            })
+            .unwrap();
+
+        let cmd = Command::new("app")
+            .subcommand(Command::new("list"))
+            .subcommand(Command::new("config").subcommand(Command::new("get")));
+
+        assert_eq!(builder.validate_against(&cmd), Ok(()));
+    }
+
+    #[test]
+    fn test_validate_against_reports_typo_with_hint() {
+        let builder = AppBuilder::new()
+            .command("list", |_m, _ctx| Ok(HandlerOutput::<()>::Silent), "")
+            .unwrap()
+            .command("confg.get", |_m, _ctx| Ok(HandlerOutput::<()>::Silent), "")
+            .unwrap()
+            .command("deploy", |_m, _ctx| Ok(HandlerOutput::<()>::Silent), "")
+            .unwrap();
+
+        let cmd = Command::new("app")
+            .subcommand(Command::new("list"))
+            .subcommand(Command::new("config").subcommand(Command::new("get")));
+
+        assert_eq!(
+            builder.validate_against(&cmd),
+            Err(vec![
+                r#""confg.get" (did you mean "config.get"?)"#.to_string(),
+                r#""deploy""#.to_string(),
+            ])
+        );
+    }
+
+    #[test]
+    fn test_command_with_template_name_uses_embedded_template() {
+        use serde_json::json;
+
+        static TEMPLATES: &[(&str, &str)] = &[("report/summary.jinja", "Total: {{ total }}")];
+        let builder = AppBuilder::new()
+            .templates(crate::EmbeddedTemplates::new(TEMPLATES, "/nonexistent"))
+            .command_with_template_name(
+                "report",
+                |_m, _ctx| Ok(HandlerOutput::Render(json!({"total": 7}))),
+                "report/summary",
+            )
+            .unwrap();
+
+        let cmd = Command::new("app").subcommand(Command::new("report"));
+        let matches = cmd.try_get_matches_from(["app", "report"]).unwrap();
+        let result = builder.dispatch(matches, OutputMode::Text);
+
+        assert_eq!(result.output(), Some("Total: 7"));
+    }
+
+    #[test]
+    fn test_command_with_template_name_missing_template_errors() {
+        use serde_json::json;
+
+        let builder = AppBuilder::new()
+            .command_with_template_name(
+                "report",
+                |_m, _ctx| Ok(HandlerOutput::Render(json!({}))),
+                "report/missing",
+            )
+            .unwrap();
+
+        let cmd = Command::new("app").subcommand(Command::new("report"));
+        let matches = cmd.try_get_matches_from(["app", "report"]).unwrap();
+        let result = builder.dispatch(matches, OutputMode::Text);
+
+        let output = result.output().unwrap();
+        assert!(
+            output.contains("Template not found: \"report/missing\""),
+            "{}",
+            output
+        );
+    }
+
     #[test]
     fn test_command_registration() {
         use serde_json::json;
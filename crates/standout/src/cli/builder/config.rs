@@ -11,13 +11,13 @@
 use crate::context::ContextProvider;
 use crate::setup::SetupError;
 use crate::topics::Topic;
-use crate::TemplateRegistry;
+use crate::{walk_template_dir, TemplateRegistry};
 use crate::{EmbeddedStyles, EmbeddedTemplates, Theme};
 use minijinja::Value;
 use std::path::PathBuf;
 use std::sync::Arc;
 
-use super::AppBuilder;
+use super::{AppBuilder, BinaryOutputPolicy};
 
 impl AppBuilder {
     /// Adds a static context value available to all templates.
@@ -230,34 +230,63 @@ impl AppBuilder {
 
     /// Adds a template directory to the registry for runtime loading.
     ///
-    /// Templates from directories are loaded immediately and merged with any
-    /// embedded templates. Directory templates take precedence over embedded
-    /// templates with the same name.
+    /// Can be called several times; directories are registered in call order.
+    /// Templates are discovered immediately and read from disk at render
+    /// time.
+    ///
+    /// Templates here replace embedded templates of the same name from
+    /// [`templates`](Self::templates), in debug (hot-reload) and release
+    /// builds alike. Two directories providing the same template name is a
+    /// configuration mistake and fails with a [`RegistryError::Collision`](crate::RegistryError::Collision)
+    /// (as [`SetupError::Template`]). To shadow an earlier directory on
+    /// purpose, use [`templates_override_dir`](Self::templates_override_dir).
     ///
     /// # Example
     ///
     /// ```rust,ignore
     /// App::builder()
     ///     .templates(embed_templates!("src/templates"))
-    ///     .templates_dir("~/.myapp/templates")  // User overrides
+    ///     .templates_dir("plugins/templates")?  // May replace embedded names
     /// ```
     pub fn templates_dir<P: AsRef<std::path::Path>>(mut self, path: P) -> Result<Self, SetupError> {
-        if self.template_registry.is_none() {
-            self.template_registry = Some(Arc::new(TemplateRegistry::new()));
-        }
+        let files = walk_template_dir(path)?;
+        self.template_registry_mut().add_from_files(files)?;
+        Ok(self)
+    }
 
-        let arc = self.template_registry.as_mut().unwrap();
-        match Arc::get_mut(arc) {
-            Some(registry) => {
-                registry.add_template_dir(path)?;
-            }
-            None => {
-                panic!("Cannot modify template registry after commands have been dispatched/finalized.");
-            }
-        }
+    /// Adds a template directory whose templates shadow earlier ones.
+    ///
+    /// Like [`templates_dir`](Self::templates_dir), but a template name
+    /// already provided by an earlier directory or by embedded templates is
+    /// replaced instead of raising a collision error. This is the mechanism
+    /// for intentional overrides, such as user-customized templates.
+    ///
+    /// # Example
+    ///
+    /// ```rust,ignore
+    /// App::builder()
+    ///     .templates(embed_templates!("src/templates"))
+    ///     .templates_override_dir("~/.myapp/templates")?  // User overrides
+    /// ```
+    pub fn templates_override_dir<P: AsRef<std::path::Path>>(
+        mut self,
+        path: P,
+    ) -> Result<Self, SetupError> {
+        let files = walk_template_dir(path)?;
+        self.template_registry_mut().add_override_files(files);
         Ok(self)
     }
 
+    /// Returns the template registry for modification, creating it if needed.
+    fn template_registry_mut(&mut self) -> &mut TemplateRegistry {
+        let arc = self
+            .template_registry
+            .get_or_insert_with(|| Arc::new(TemplateRegistry::new()));
+        Arc::get_mut(arc).expect(
+            "Cannot modify template registry after commands have been dispatched/finalized.",
+        )
+    }
+
     /// Sets the file extension for convention-based template resolution.
     ///
     /// Default is `.j2`.
@@ -297,6 +326,36 @@ impl AppBuilder {
         self
     }
 
+    /// Adds a short alias for the output flag, e.g. `'o'` for `-o json`.
+    ///
+    /// The alias is registered alongside the long flag and is global like it.
+    /// The app is responsible for avoiding collisions: if the host command
+    /// already uses the same short flag, clap's debug assertions panic when
+    /// the command is built. Has no effect when the output flag is disabled.
+    pub fn output_flag_short(mut self, short: char) -> Self {
+        self.output_flag_short = Some(short);
+        self
+    }
+
+    /// Lets the flags the app manages fall back to environment variables.
+    ///
+    /// Each managed global flag reads `<PREFIX>_<FLAG>` when it isn't given
+    /// on the command line, with the flag name upper-cased and dashes turned
+    /// into underscores: `env_prefix("MYAPP")` makes `--output` read
+    /// `MYAPP_OUTPUT`, `--output-file-path` read `MYAPP_OUTPUT_FILE_PATH` and
+    /// `--dry-run` read `MYAPP_DRY_RUN`. A flag on the command line still wins.
+    /// `MYAPP_DRY_RUN` accepts `true`/`false`, `yes`/`no`, `on`/`off`,
+    /// `y`/`n`, `t`/`f` and `1`/`0`, in any case.
+    ///
+    /// Arguments defined by your own commands are left alone. Opt them in with
+    /// clap's `Arg::env` using the same naming, e.g.
+    /// `Arg::new("token").long("token").env("MYAPP_TOKEN")`; handlers then read
+    /// the value from `ArgMatches` as usual.
+    pub fn env_prefix(mut self, prefix: &str) -> Self {
+        self.env_prefix = Some(prefix.to_string());
+        self
+    }
+
     /// Configures the name of the output file path flag.
     ///
     /// When set, an `--<flag>=<PATH>` option is added to all commands.
@@ -342,6 +401,77 @@ impl AppBuilder {
         self
     }
 
+    /// Sets where `run()` writes binary handler output.
+    ///
+    /// With the default [`BinaryOutputPolicy::Auto`], bytes go to stdout when it
+    /// is piped or redirected, and to the handler's suggested filename when stdout
+    /// is a terminal.
+    ///
+    /// # Example
+    ///
+    /// ```rust,ignore
+    /// use standout::cli::{App, BinaryOutputPolicy};
+    ///
+    /// // Never write binary data to stdout, even when piped
+    /// App::builder()
+    ///     .binary_output(BinaryOutputPolicy::AlwaysFile)
+    ///     .command("export", export_handler, "")?
+    ///     .build()?
+    ///     .run(cmd, args);
+    /// ```
+    pub fn binary_output(mut self, policy: BinaryOutputPolicy) -> Self {
+        self.binary_output = policy;
+        self
+    }
+
+    /// Fixes the width passed to templates and context providers.
+    ///
+    /// By default the width comes from the `COLUMNS` environment variable,
+    /// then the terminal size, then 80 columns. An explicit width overrides
+    /// all of these, which keeps table layout stable in tests and CI.
+    ///
+    /// # Example
+    ///
+    /// ```rust,ignore
+    /// App::builder()
+    ///     .terminal_width(100)
+    ///     .command("list", list_handler, "")?
+    ///     .build()?
+    ///     .run(cmd, args);
+    /// ```
+    pub fn terminal_width(mut self, width: usize) -> Self {
+        self.terminal_width = Some(width);
+        self
+    }
+
+    /// Adds a global `--dry-run` flag and passes it to handlers.
+    ///
+    /// When the user gives `--dry-run`, every handler sees `ctx.dry_run ==
+    /// true`. Handlers should skip side effects but still return a preview
+    /// value, which renders through the command's template as usual.
+    ///
+    /// # Example
+    ///
+    /// ```rust,ignore
+    /// fn delete(matches: &ArgMatches, ctx: &CommandContext) -> HandlerResult<Deleted> {
+    ///     let targets = find_targets(matches)?;
+    ///     if !ctx.dry_run {
+    ///         remove_all(&targets)?;
+    ///     }
+    ///     Ok(Output::Render(Deleted { targets, dry_run: ctx.dry_run }))
+    /// }
+    ///
+    /// App::builder()
+    ///     .enable_dry_run()
+    ///     .command("delete", delete, "{{ targets | length }} deleted")?
+    ///     .build()?
+    ///     .run(cmd, args);
+    /// ```
+    pub fn enable_dry_run(mut self) -> Self {
+        self.dry_run = true;
+        self
+    }
+
     /// Controls whether framework-supplied templates are included.
     ///
     /// Framework templates (in the `standout/` namespace) provide defaults for
@@ -465,6 +595,53 @@ mod tests {
         assert!(output.starts_with("Width: "));
     }
 
+    #[test]
+    fn test_terminal_width_override_sizes_fill_column() {
+        use serde_json::json;
+
+        let builder = AppBuilder::new()
+            .terminal_width(30)
+            .context_fn("width", |ctx: &RenderContext| {
+                Value::from(ctx.terminal_width)
+            })
+            .command(
+                "row",
+                |_m, _ctx| Ok(HandlerOutput::Render(json!({}))),
+                r#"{% set t = tabular([{"width": 10}, {"width": "fill"}], separator="  ", width=width) %}[{{ t.row(["id", "name"]) }}]"#,
+            )
+            .unwrap();
+
+        let cmd = Command::new("app").subcommand(Command::new("row"));
+        let matches = cmd.try_get_matches_from(["app", "row"]).unwrap();
+        let result = builder.dispatch(matches, OutputMode::Text);
+
+        // 30 columns: 10 fixed + 2 separator leaves 18 for the fill column
+        let expected = format!("[{:<10}  {:<18}]", "id", "name");
+        assert_eq!(result.output(), Some(expected.as_str()));
+    }
+
+    #[test]
+    fn test_terminal_width_override_reaches_table_default_width() {
+        use serde_json::json;
+
+        let builder = AppBuilder::new()
+            .terminal_width(30)
+            .command(
+                "row",
+                |_m, _ctx| Ok(HandlerOutput::Render(json!({}))),
+                r#"{% set t = tabular([{"width": 10}, {"width": "fill"}], separator="  ") %}[{{ t.row(["id", "name"]) }}]"#,
+            )
+            .unwrap();
+
+        let cmd = Command::new("app").subcommand(Command::new("row"));
+        let matches = cmd.try_get_matches_from(["app", "row"]).unwrap();
+        let result = builder.dispatch(matches, OutputMode::Text);
+
+        // No width= argument: the table sizes itself to the overridden 30 columns
+        let expected = format!("[{:<10}  {:<18}]", "id", "name");
+        assert_eq!(result.output(), Some(expected.as_str()));
+    }
+
     #[test]
     fn test_context_fn_output_mode() {
         use serde_json::json;
@@ -645,6 +822,137 @@ mod tests {
         assert!(!output.contains("should_not_appear"));
     }
 
+    fn template_dirs(files: &[(&str, &str, &str)]) -> tempfile::TempDir {
+        let root = tempfile::tempdir().unwrap();
+        for (dir, name, content) in files {
+            let dir = root.path().join(dir);
+            std::fs::create_dir_all(&dir).unwrap();
+            std::fs::write(dir.join(name), content).unwrap();
+        }
+        root
+    }
+
+    #[test]
+    fn test_templates_dir_multiple_dirs() {
+        let root = template_dirs(&[
+            ("app", "list.jinja", "app list"),
+            ("plugins", "plugin.jinja", "plugin"),
+        ]);
+
+        let app = AppBuilder::new()
+            .templates_dir(root.path().join("app"))
+            .unwrap()
+            .templates_dir(root.path().join("plugins"))
+            .unwrap()
+            .build()
+            .unwrap();
+
+        let registry = app.core.template_registry.as_ref().unwrap();
+        assert_eq!(registry.get_content("list").unwrap(), "app list");
+        assert_eq!(registry.get_content("plugin").unwrap(), "plugin");
+    }
+
+    #[test]
+    fn test_templates_dir_collision_is_error() {
+        let root = template_dirs(&[
+            ("app", "list.jinja", "app list"),
+            ("plugins", "list.jinja", "plugin list"),
+        ]);
+
+        let result = AppBuilder::new()
+            .templates_dir(root.path().join("app"))
+            .unwrap()
+            .templates_dir(root.path().join("plugins"));
+
+        match result {
+            Err(SetupError::Template(message)) => {
+                assert!(message.contains("collision"), "{message}");
+                assert!(message.contains("\"list\""), "{message}");
+            }
+            Err(other) => panic!("expected collision, got {other}"),
+            Ok(_) => panic!("expected collision"),
+        }
+    }
+
+    #[test]
+    fn test_templates_override_dir_shadows_earlier() {
+        let root = template_dirs(&[
+            ("app", "list.jinja", "app list"),
+            ("app", "detail.jinja", "app detail"),
+            ("user", "list.jinja", "user list"),
+        ]);
+
+        let app = AppBuilder::new()
+            .templates_dir(root.path().join("app"))
+            .unwrap()
+            .templates_override_dir(root.path().join("user"))
+            .unwrap()
+            .build()
+            .unwrap();
+
+        let registry = app.core.template_registry.as_ref().unwrap();
+        assert_eq!(registry.get_content("list").unwrap(), "user list");
+        assert_eq!(registry.get_content("list.jinja").unwrap(), "user list");
+        assert_eq!(registry.get_content("detail").unwrap(), "app detail");
+    }
+
+    /// Embedded templates whose source directory is `path`.
+    fn embedded_templates(path: &std::path::Path) -> EmbeddedTemplates {
+        static ENTRIES: &[(&str, &str)] = &[
+            ("list.jinja", "embedded list"),
+            ("detail.jinja", "embedded detail"),
+        ];
+        let path: &'static str = Box::leak(path.to_str().unwrap().to_owned().into_boxed_str());
+        EmbeddedTemplates::new(ENTRIES, path)
+    }
+
+    #[test]
+    fn test_templates_dir_replaces_hot_reloaded_embedded() {
+        let root = template_dirs(&[
+            ("embedded", "list.jinja", "embedded list"),
+            ("embedded", "detail.jinja", "embedded detail"),
+            ("user", "list.jinja", "user list"),
+        ]);
+        let embedded = embedded_templates(&root.path().join("embedded"));
+        assert_eq!(embedded.should_hot_reload(), cfg!(debug_assertions));
+
+        let app = AppBuilder::new()
+            .templates(embedded)
+            .templates_dir(root.path().join("user"))
+            .unwrap()
+            .build()
+            .unwrap();
+
+        let registry = app.core.template_registry.as_ref().unwrap();
+        assert_eq!(registry.get_content("list").unwrap(), "user list");
+        assert_eq!(registry.get_content("detail").unwrap(), "embedded detail");
+    }
+
+    #[test]
+    fn test_templates_dir_replaces_in_memory_embedded() {
+        let root = template_dirs(&[("user", "list.jinja", "user list")]);
+        let embedded = embedded_templates(&root.path().join("missing"));
+        assert!(!embedded.should_hot_reload());
+
+        let app = AppBuilder::new()
+            .templates(embedded)
+            .templates_dir(root.path().join("user"))
+            .unwrap()
+            .build()
+            .unwrap();
+
+        let registry = app.core.template_registry.as_ref().unwrap();
+        assert_eq!(registry.get_content("list").unwrap(), "user list");
+        assert_eq!(registry.get_content("detail").unwrap(), "embedded detail");
+    }
+
+    #[test]
+    fn test_templates_dir_missing_directory() {
+        let root = tempfile::tempdir().unwrap();
+        let result = AppBuilder::new().templates_dir(root.path().join("missing"));
+        assert!(result.is_err());
+    }
+
     #[test]
     fn test_template_dir_convention() {
         use serde_json::json;
@@ -11,14 +11,18 @@ use crate::{write_binary_output, write_output, OutputDestination, OutputMode};
 use clap::{Arg, ArgAction, ArgMatches, Command};
 use std::path::PathBuf;
 
-use super::{AppBuilder, PendingCommand};
+use super::{AppBuilder, PendingCommand, PendingTemplate};
+use crate::cli::core::{
+    dry_run_arg, dry_run_requested, highlight_json_output, resolve_output_mode, with_env_fallback,
+    OUTPUT_MODE_HELP, OUTPUT_MODE_VALUES,
+};
 use crate::cli::dispatch::{
-    extract_command_path, get_deepest_matches, has_subcommand, insert_default_command,
-    DispatchOutput,
+    has_subcommand, insert_default_command, resolve_invocation, DispatchOutput,
 };
 use crate::cli::group::{ErasedConfigRecipe, GroupBuilder, GroupEntry};
 use crate::cli::handler::{CommandContext, RunResult};
 use crate::cli::hooks::RenderedOutput;
+use crate::cli::progress::terminal_progress_sink;
 use crate::SetupError;
 
 impl AppBuilder {
@@ -68,6 +72,10 @@ impl AppBuilder {
                         self.command_hooks.insert(name.clone(), hooks);
                     }
 
+                    if let Some(mode) = handler.output_mode() {
+                        self.command_output_modes.insert(name.clone(), mode);
+                    }
+
                     // Create a recipe for deferred closure creation
                     let recipe = ErasedConfigRecipe::from_handler(handler);
 
@@ -81,7 +89,7 @@ impl AppBuilder {
                         name,
                         PendingCommand {
                             recipe: Box::new(recipe),
-                            template,
+                            template: PendingTemplate::Content(template),
                         },
                     );
                 }
@@ -97,7 +105,9 @@ impl AppBuilder {
     /// Dispatches to a registered handler if one matches the command path.
     ///
     /// Returns `RunResult::Handled(output)` if a handler was found and executed,
-    /// or `RunResult::NoMatch(matches)` if no handler matched.
+    /// or `RunResult::NoMatch(matches)` if no handler matched. A handler
+    /// registered with [`on_unmatched`](Self::on_unmatched) runs for any
+    /// subcommand without a handler of its own.
     ///
     /// If hooks are registered for the command, they are executed:
     /// - Pre-dispatch hooks run before the handler
@@ -106,17 +116,29 @@ impl AppBuilder {
     ///
     /// Hook errors abort execution and return the error as handled output.
     pub fn dispatch(&self, matches: ArgMatches, output_mode: OutputMode) -> RunResult {
+        self.dispatch_with_raw(matches, output_mode).0
+    }
+
+    /// Like `dispatch`, but also returns whether the text came from `Output::Raw`.
+    fn dispatch_with_raw(&self, matches: ArgMatches, output_mode: OutputMode) -> (RunResult, bool) {
         // Ensure commands are finalized (creates dispatch closures with current theme)
         self.ensure_commands_finalized();
 
-        // Build command path from matches
-        let path = extract_command_path(&matches);
+        // Resolve the command path and its matches in a single walk
+        let (path, sub_matches) = resolve_invocation(&matches);
         let path_str = path.join(".");
 
-        // Look up handler
+        // Look up handler, falling back to `on_unmatched` for unregistered subcommands
         let commands = self.get_commands();
-        if let Some(dispatch) = commands.get(&path_str) {
+        let dispatch = commands
+            .get(&path_str)
+            .cloned()
+            .or_else(|| self.get_fallback().filter(|_| !path.is_empty()));
+        if let Some(dispatch) = dispatch {
             let mut ctx = CommandContext::new(path, self.app_state.clone());
+            ctx.progress_sink = terminal_progress_sink(self.theme.as_ref());
+            ctx.root_matches = Some(matches.clone());
+            ctx.dry_run = dry_run_requested(&matches);
 
             // Get hooks for this command (used for pre-dispatch, post-dispatch, and post-output)
             let hooks = self.command_hooks.get(&path_str);
@@ -124,32 +146,40 @@ impl AppBuilder {
             // Run pre-dispatch hooks if registered (hooks can inject state via ctx.extensions)
             if let Some(hooks) = hooks {
                 if let Err(e) = hooks.run_pre_dispatch(&matches, &mut ctx) {
-                    return RunResult::Handled(format!("Hook error: {}", e));
+                    return (RunResult::Handled(format!("Hook error: {}", e)), false);
                 }
             }
 
-            // Get the subcommand matches for the deepest command
-            let sub_matches = get_deepest_matches(&matches);
-
             // Run the handler (post-dispatch hooks are run inside dispatch function)
             // output_mode is passed separately because CommandContext is render-agnostic
             let dispatch_output = match dispatch(sub_matches, &ctx, hooks, output_mode) {
                 Ok(output) => output,
-                Err(e) => return RunResult::Handled(e),
+                Err(e) => return (RunResult::Handled(e), false),
             };
 
-            // Convert to Output enum for post-output hooks
+            // Convert to Output enum for output hooks
+            let raw = matches!(dispatch_output, DispatchOutput::Raw(_));
             let output = match dispatch_output {
-                DispatchOutput::Text(s) => RenderedOutput::Text(s),
-                DispatchOutput::Binary(b, f) => RenderedOutput::Binary(b, f),
+                DispatchOutput::Text(s) | DispatchOutput::Raw(s) => RenderedOutput::Text(s),
+                DispatchOutput::Binary {
+                    data,
+                    filename,
+                    to_stdout,
+                    content_type,
+                } => RenderedOutput::Binary {
+                    data,
+                    filename,
+                    to_stdout,
+                    content_type,
+                },
                 DispatchOutput::Silent => RenderedOutput::Silent,
             };
 
-            // Run post-output hooks if registered
+            // Run pre-output, then post-output hooks if registered
             let mut final_output = if let Some(hooks) = hooks {
-                match hooks.run_post_output(&matches, &ctx, output) {
+                match hooks.run_output(&matches, &ctx, output) {
                     Ok(o) => o,
-                    Err(e) => return RunResult::Handled(format!("Hook error: {}", e)),
+                    Err(e) => return (RunResult::Handled(format!("Hook error: {}", e)), false),
                 }
             } else {
                 output
@@ -167,14 +197,20 @@ impl AppBuilder {
                     match &final_output {
                         RenderedOutput::Text(s) => {
                             if let Err(e) = write_output(s, &dest) {
-                                return RunResult::Handled(format!("Error writing output: {}", e));
+                                return (
+                                    RunResult::Handled(format!("Error writing output: {}", e)),
+                                    false,
+                                );
                             }
                             // Suppress further output
                             final_output = RenderedOutput::Silent;
                         }
-                        RenderedOutput::Binary(b, _) => {
-                            if let Err(e) = write_binary_output(b, &dest) {
-                                return RunResult::Handled(format!("Error writing output: {}", e));
+                        RenderedOutput::Binary { data, .. } => {
+                            if let Err(e) = write_binary_output(data, &dest) {
+                                return (
+                                    RunResult::Handled(format!("Error writing output: {}", e)),
+                                    false,
+                                );
                             }
                             final_output = RenderedOutput::Silent;
                         }
@@ -184,13 +220,24 @@ impl AppBuilder {
             }
 
             // Convert back to RunResult
-            match final_output {
+            let result = match final_output {
                 RenderedOutput::Text(s) => RunResult::Handled(s),
-                RenderedOutput::Binary(b, f) => RunResult::Binary(b, f),
+                RenderedOutput::Binary {
+                    data,
+                    filename,
+                    to_stdout,
+                    content_type,
+                } => RunResult::Binary {
+                    data,
+                    filename,
+                    to_stdout,
+                    content_type,
+                },
                 RenderedOutput::Silent => RunResult::Handled(String::new()),
-            }
+            };
+            (result, raw)
         } else {
-            RunResult::NoMatch(matches)
+            (RunResult::NoMatch(matches), false)
         }
     }
 
@@ -222,6 +269,33 @@ impl AppBuilder {
     /// }
     /// ```
     pub fn dispatch_from<I, T>(&self, cmd: Command, args: I) -> RunResult
+    where
+        I: IntoIterator<Item = T>,
+        T: Into<std::ffi::OsString> + Clone,
+    {
+        self.parse_and_dispatch(cmd, args).0
+    }
+
+    /// Like `dispatch_from`, but also returns the output mode that was used
+    /// and whether the text came from `Output::Raw`.
+    ///
+    /// Parse errors are reported with `OutputMode::Auto`.
+    fn parse_and_dispatch<I, T>(&self, cmd: Command, args: I) -> (RunResult, OutputMode, bool)
+    where
+        I: IntoIterator<Item = T>,
+        T: Into<std::ffi::OsString> + Clone,
+    {
+        self.parse_and_dispatch_with(cmd, args, OutputMode::Auto)
+    }
+
+    /// Like `parse_and_dispatch`, but renders with `auto_mode` whenever the
+    /// output mode would otherwise resolve to `OutputMode::Auto`.
+    fn parse_and_dispatch_with<I, T>(
+        &self,
+        cmd: Command,
+        args: I,
+        auto_mode: OutputMode,
+    ) -> (RunResult, OutputMode, bool)
     where
         I: IntoIterator<Item = T>,
         T: Into<std::ffi::OsString> + Clone,
@@ -240,7 +314,7 @@ impl AppBuilder {
             Ok(m) => m,
             Err(e) => {
                 // Return error as handled output
-                return RunResult::Handled(e.to_string());
+                return (RunResult::Handled(e.to_string()), OutputMode::Auto, false);
             }
         };
 
@@ -255,7 +329,7 @@ impl AppBuilder {
                 let augmented_cmd = self.augment_command_for_dispatch(cmd);
                 match augmented_cmd.try_get_matches_from(&new_args) {
                     Ok(m) => m,
-                    Err(e) => return RunResult::Handled(e.to_string()),
+                    Err(e) => return (RunResult::Handled(e.to_string()), OutputMode::Auto, false),
                 }
             }
         } else {
@@ -263,26 +337,18 @@ impl AppBuilder {
         };
 
         // Extract output mode
-        let output_mode = if self.output_flag.is_some() {
-            match matches
-                .get_one::<String>("_output_mode")
-                .map(|s| s.as_str())
-            {
-                Some("term") => OutputMode::Term,
-                Some("text") => OutputMode::Text,
-                Some("term-debug") => OutputMode::TermDebug,
-                Some("json") => OutputMode::Json,
-                Some("yaml") => OutputMode::Yaml,
-                Some("xml") => OutputMode::Xml,
-                Some("csv") => OutputMode::Csv,
-                _ => OutputMode::Auto,
-            }
-        } else {
-            OutputMode::Auto
+        let output_mode = match resolve_output_mode(
+            self.output_flag.is_some(),
+            &matches,
+            &self.command_output_modes,
+        ) {
+            OutputMode::Auto => auto_mode,
+            mode => mode,
         };
 
         // Dispatch to handler
-        self.dispatch(matches, output_mode)
+        let (result, raw) = self.dispatch_with_raw(matches, output_mode);
+        (result, output_mode, raw)
     }
 
     /// Runs the CLI: parses arguments, dispatches to handlers, and prints output.
@@ -314,21 +380,27 @@ impl AppBuilder {
         I: IntoIterator<Item = T>,
         T: Into<std::ffi::OsString> + Clone,
     {
-        match self.dispatch_from(cmd, args) {
+        let (result, output_mode, raw) = self.parse_and_dispatch(cmd, args);
+        match result {
             RunResult::Handled(output) => {
                 if !output.is_empty() {
+                    let output = highlight_json_output(
+                        output,
+                        self.theme.as_ref(),
+                        output_mode.should_highlight_json() && !raw,
+                    );
                     println!("{}", output);
                 }
                 true
             }
-            RunResult::Binary(bytes, filename) => {
-                // For binary output, write to stdout or the suggested file
-                // By default, we write to the suggested filename
-                if let Err(e) = std::fs::write(&filename, &bytes) {
-                    eprintln!("Error writing {}: {}", filename, e);
-                } else {
-                    eprintln!("Wrote {} bytes to {}", bytes.len(), filename);
-                }
+            RunResult::Binary {
+                data,
+                filename,
+                to_stdout,
+                content_type,
+            } => {
+                self.binary_output
+                    .write(&data, &filename, to_stdout, content_type.as_deref());
                 true
             }
             RunResult::Silent => true, // Handler ran successfully, no output
@@ -340,11 +412,14 @@ impl AppBuilder {
     ///
     /// Similar to `run()`, but returns the output instead of printing it.
     /// Useful for testing or when you need to capture and process the output.
+    /// The output mode is resolved as in `run()`, so it depends on TTY
+    /// detection; use [`run_to_string_with`](Self::run_to_string_with) for
+    /// output that is the same everywhere.
     ///
     /// # Returns
     ///
     /// - `RunResult::Handled(output)` - Handler executed, output is the rendered string
-    /// - `RunResult::Binary(bytes, filename)` - Handler produced binary output
+    /// - `RunResult::Binary { .. }` - Handler produced binary output
     /// - `RunResult::NoMatch(matches)` - No handler matched
     ///
     /// # Example
@@ -359,7 +434,7 @@ impl AppBuilder {
     ///
     /// match result {
     ///     RunResult::Handled(output) => println!("{}", output),
-    ///     RunResult::Binary(bytes, filename) => std::fs::write(filename, bytes)?,
+    ///     RunResult::Binary { data, filename, .. } => std::fs::write(filename, data)?,
     ///     RunResult::NoMatch(matches) => { /* handle manually */ }
     /// }
     /// ```
@@ -371,32 +446,68 @@ impl AppBuilder {
         self.dispatch_from(cmd, args)
     }
 
-    /// Augments a command for dispatch (adds --output flag without help subcommand).
+    /// Like [`run_to_string`](Self::run_to_string), but renders in
+    /// `default_mode` unless `--output` or a per-command default picks a mode.
+    ///
+    /// With `OutputMode::TermDebug`, styles show up as `[name]text[/name]`
+    /// tags regardless of TTY detection, which keeps snapshot tests stable.
+    ///
+    /// # Example
+    ///
+    /// ```rust,ignore
+    /// let result = builder.run_to_string_with(cmd, ["app", "list"], OutputMode::TermDebug);
+    /// assert_eq!(result.output(), Some("[title]alpha[/title]"));
+    /// ```
+    pub fn run_to_string_with<I, T>(
+        &self,
+        cmd: Command,
+        args: I,
+        default_mode: OutputMode,
+    ) -> RunResult
+    where
+        I: IntoIterator<Item = T>,
+        T: Into<std::ffi::OsString> + Clone,
+    {
+        self.parse_and_dispatch_with(cmd, args, default_mode).0
+    }
+
+    /// Augments a command for dispatch (adds --output, --output-file-path and
+    /// --dry-run flags without help subcommand).
     fn augment_command_for_dispatch(&self, mut cmd: Command) -> Command {
+        let prefix = self.env_prefix.as_deref();
         if let Some(ref flag_name) = self.output_flag {
             let flag: &'static str = Box::leak(flag_name.clone().into_boxed_str());
-            cmd = cmd.arg(
+            cmd = cmd.arg(with_env_fallback(
                 Arg::new("_output_mode")
                     .long(flag)
+                    .short(self.output_flag_short)
                     .value_name("MODE")
                     .global(true)
-                    .value_parser(["auto", "term", "text", "term-debug", "json"])
+                    .value_parser(OUTPUT_MODE_VALUES.to_vec())
                     .default_value("auto")
-                    .help("Output mode: auto, term, text, term-debug, or json"),
-            );
+                    .help(OUTPUT_MODE_HELP),
+                prefix,
+                flag,
+            ));
         }
 
         // Add output file flag if enabled
         if let Some(ref flag_name) = self.output_file_flag {
             let flag: &'static str = Box::leak(flag_name.clone().into_boxed_str());
-            cmd = cmd.arg(
+            cmd = cmd.arg(with_env_fallback(
                 Arg::new("_output_file_path")
                     .long(flag)
                     .value_name("PATH")
                     .global(true)
                     .action(ArgAction::Set)
                     .help("Write output to file instead of stdout"),
-            );
+                prefix,
+                flag,
+            ));
+        }
+
+        if self.dry_run {
+            cmd = cmd.arg(dry_run_arg(prefix));
         }
 
         cmd
@@ -573,6 +684,30 @@ mod tests {
         assert_eq!(result.output(), Some("Count: 42"));
     }
 
+    #[test]
+    fn test_dispatch_via_alias_uses_canonical_handler() {
+        use serde_json::json;
+
+        let builder = AppBuilder::new()
+            .command(
+                "config.list",
+                |_m, _ctx| Ok(HandlerOutput::Render(json!({"count": 3}))),
+                "Count: {{ count }}",
+            )
+            .unwrap();
+
+        let cmd = Command::new("app").subcommand(
+            Command::new("config")
+                .alias("cfg")
+                .subcommand(Command::new("list").visible_alias("ls")),
+        );
+
+        let result = builder.dispatch_from(cmd, ["app", "cfg", "ls", "--output", "text"]);
+
+        assert!(result.is_handled());
+        assert_eq!(result.output(), Some("Count: 3"));
+    }
+
     #[test]
     fn test_dispatch_unhandled_fallthrough() {
         use serde_json::json;
@@ -637,6 +772,70 @@ mod tests {
         assert_eq!(result.output(), Some("value"));
     }
 
+    #[test]
+    fn test_dispatch_nested_handler_reads_root_flag() {
+        use clap::{Arg, ArgAction};
+        use serde_json::json;
+
+        let builder = AppBuilder::new()
+            .command(
+                "config.get",
+                |_m, ctx| {
+                    let verbose = ctx.global_flag::<u8>("verbose").unwrap_or(0);
+                    Ok(HandlerOutput::Render(json!({ "verbose": verbose })))
+                },
+                "{{ verbose }}",
+            )
+            .unwrap();
+
+        let cmd = Command::new("app")
+            .arg(Arg::new("verbose").short('v').action(ArgAction::Count))
+            .subcommand(Command::new("config").subcommand(Command::new("get")));
+
+        let matches = cmd
+            .try_get_matches_from(["app", "-vv", "config", "get"])
+            .unwrap();
+        let result = builder.dispatch(matches, OutputMode::Text);
+
+        assert_eq!(result.output(), Some("2"));
+    }
+
+    #[test]
+    fn test_dry_run_flag_reaches_context() {
+        use serde_json::json;
+
+        let builder = AppBuilder::new()
+            .enable_dry_run()
+            .command(
+                "db.drop",
+                |_m, ctx| Ok(HandlerOutput::Render(json!({ "dry_run": ctx.dry_run }))),
+                "{% if dry_run %}preview{% else %}dropped{% endif %}",
+            )
+            .unwrap();
+        let cmd =
+            || Command::new("app").subcommand(Command::new("db").subcommand(Command::new("drop")));
+
+        let result = builder.dispatch_from(cmd(), ["app", "db", "drop", "--dry-run"]);
+        assert_eq!(result.output(), Some("preview"));
+
+        let result = builder.dispatch_from(cmd(), ["app", "--dry-run", "db", "drop"]);
+        assert_eq!(result.output(), Some("preview"));
+
+        let result = builder.dispatch_from(cmd(), ["app", "db", "drop"]);
+        assert_eq!(result.output(), Some("dropped"));
+    }
+
+    #[test]
+    fn test_dry_run_flag_requires_opt_in() {
+        let builder = AppBuilder::new()
+            .command("drop", |_m, _ctx| Ok(HandlerOutput::<()>::Silent), "")
+            .unwrap();
+        let cmd = Command::new("app").subcommand(Command::new("drop"));
+
+        let result = builder.dispatch_from(cmd, ["app", "drop", "--dry-run"]);
+        assert!(result.output().unwrap().contains("--dry-run"));
+    }
+
     #[test]
     fn test_dispatch_silent_result() {
         let builder = AppBuilder::new()
@@ -652,6 +851,69 @@ mod tests {
         assert_eq!(result.output(), Some(""));
     }
 
+    #[test]
+    fn test_dispatch_raw_result_skips_template() {
+        let builder = AppBuilder::new()
+            .command(
+                "proxy",
+                |_m, _ctx| {
+                    Ok(HandlerOutput::<()>::Raw(
+                        "[bold]{{ as-is }}[/bold]\n".into(),
+                    ))
+                },
+                "never {{ rendered }}",
+            )
+            .unwrap();
+
+        let cmd = Command::new("app").subcommand(Command::new("proxy"));
+
+        // No templating or style-tag processing in any mode, JSON included
+        for mode in [OutputMode::Text, OutputMode::Term, OutputMode::Json] {
+            let matches = cmd.clone().try_get_matches_from(["app", "proxy"]).unwrap();
+            let result = builder.dispatch(matches, mode);
+            assert_eq!(result.output(), Some("[bold]{{ as-is }}[/bold]\n"));
+        }
+    }
+
+    #[test]
+    fn test_raw_json_output_is_not_highlighted() {
+        use serde_json::json;
+
+        let raw_json = r#"{"zeta": 1, "alpha": [2, 3]}"#;
+        let builder = AppBuilder::new()
+            .command(
+                "proxy",
+                move |_m, _ctx| Ok(HandlerOutput::<()>::Raw(raw_json.into())),
+                "",
+            )
+            .unwrap()
+            .command(
+                "list",
+                |_m, _ctx| Ok(HandlerOutput::Render(json!({"zeta": 1}))),
+                "",
+            )
+            .unwrap();
+        let cmd = || {
+            Command::new("app")
+                .subcommand(Command::new("proxy"))
+                .subcommand(Command::new("list"))
+        };
+
+        let (result, mode, raw) =
+            builder.parse_and_dispatch(cmd(), ["app", "--output", "json", "proxy"]);
+        assert_eq!(mode, OutputMode::Json);
+        assert!(raw);
+        let text = result.output().unwrap().to_string();
+        assert_eq!(text, raw_json);
+
+        // On a color terminal, run() highlights only when the text isn't raw,
+        // so key order, spacing and colors stay as the handler wrote them
+        assert_eq!(highlight_json_output(text.clone(), None, !raw), raw_json);
+
+        let (_, _, raw) = builder.parse_and_dispatch(cmd(), ["app", "--output", "json", "list"]);
+        assert!(!raw);
+    }
+
     #[test]
     fn test_dispatch_error_result() {
         let builder = AppBuilder::new()
@@ -673,6 +935,50 @@ mod tests {
         assert!(output.contains("something went wrong"));
     }
 
+    fn failing_builder() -> AppBuilder {
+        AppBuilder::new()
+            .command(
+                "fail",
+                |_m, _ctx| Err::<HandlerOutput<()>, _>(anyhow::anyhow!("disk \"full\"")),
+                "",
+            )
+            .unwrap()
+    }
+
+    fn dispatch_failure(mode: OutputMode) -> String {
+        let cmd = Command::new("app").subcommand(Command::new("fail"));
+        let matches = cmd.try_get_matches_from(["app", "fail"]).unwrap();
+        let result = failing_builder().dispatch(matches, mode);
+        result.output().unwrap().to_string()
+    }
+
+    #[test]
+    fn test_dispatch_error_in_json_mode_is_json_object() {
+        let output = dispatch_failure(OutputMode::Json);
+
+        let value: serde_json::Value = serde_json::from_str(&output).unwrap();
+        assert_eq!(value["error"], "disk \"full\"");
+        assert_eq!(value["ok"], false);
+        assert!(output.contains('\n'), "Json mode pretty-prints: {}", output);
+    }
+
+    #[test]
+    fn test_dispatch_error_in_compact_json_modes_is_one_line() {
+        for mode in [OutputMode::JsonCompact, OutputMode::JsonLines] {
+            let output = dispatch_failure(mode);
+            assert!(!output.contains('\n'), "{:?}: {}", mode, output);
+            let value: serde_json::Value = serde_json::from_str(&output).unwrap();
+            assert_eq!(value["error"], "disk \"full\"");
+        }
+    }
+
+    #[test]
+    fn test_dispatch_error_in_other_modes_stays_text() {
+        for mode in [OutputMode::Term, OutputMode::Yaml] {
+            assert_eq!(dispatch_failure(mode), "Error: disk \"full\"");
+        }
+    }
+
     #[test]
     fn test_dispatch_from_basic() {
         use serde_json::json;
@@ -714,6 +1020,180 @@ mod tests {
         assert!(output.contains("\"count\": 5"));
     }
 
+    #[test]
+    fn test_dispatch_from_with_json_compact_flag() {
+        use serde_json::json;
+
+        let builder = AppBuilder::new()
+            .command(
+                "list",
+                |_m, _ctx| {
+                    Ok(HandlerOutput::Render(
+                        json!({"count": 5, "items": ["a", "b"]}),
+                    ))
+                },
+                "Count: {{ count }}",
+            )
+            .unwrap();
+
+        let cmd = Command::new("app").subcommand(Command::new("list"));
+
+        let result = builder.dispatch_from(cmd, ["app", "--output=json-compact", "list"]);
+
+        assert!(result.is_handled());
+        let output = result.output().unwrap();
+        assert!(!output.contains('\n'), "{}", output);
+        let value: serde_json::Value = serde_json::from_str(output).unwrap();
+        assert_eq!(value, json!({"count": 5, "items": ["a", "b"]}));
+    }
+
+    #[test]
+    fn test_dispatch_from_with_yaml_flag() {
+        use serde_json::json;
+
+        let builder = AppBuilder::new()
+            .command(
+                "list",
+                |_m, _ctx| Ok(HandlerOutput::Render(json!({"count": 5}))),
+                "Count: {{ count }}",
+            )
+            .unwrap();
+
+        let cmd = Command::new("app").subcommand(Command::new("list"));
+
+        let result = builder.dispatch_from(cmd, ["app", "--output=yaml", "list"]);
+
+        assert!(result.is_handled());
+        assert_eq!(result.output().unwrap().trim(), "count: 5");
+    }
+
+    #[cfg(feature = "toml")]
+    #[test]
+    fn test_dispatch_from_with_toml_flag() {
+        use serde_json::json;
+
+        let builder = AppBuilder::new()
+            .command(
+                "config",
+                |_m, _ctx| {
+                    Ok(HandlerOutput::Render(
+                        json!({"name": "app", "server": {"port": 8080}}),
+                    ))
+                },
+                "{{ name }}",
+            )
+            .unwrap();
+
+        let cmd = Command::new("app").subcommand(Command::new("config"));
+
+        let result = builder.dispatch_from(cmd, ["app", "--output=toml", "config"]);
+
+        assert!(result.is_handled());
+        let output = result.output().unwrap();
+        assert!(output.contains("name = \"app\""), "{}", output);
+        assert!(output.contains("[server]\nport = 8080"), "{}", output);
+    }
+
+    #[test]
+    fn test_dispatch_toml_mode_rejects_array() {
+        use crate::dispatch;
+        use serde_json::json;
+
+        let builder = AppBuilder::new()
+            .commands(dispatch! {
+                list => |_m, _ctx| Ok(HandlerOutput::Render(json!(["a", "b"])))
+            })
+            .unwrap();
+
+        let cmd = Command::new("app").subcommand(Command::new("list"));
+        let matches = cmd.try_get_matches_from(["app", "list"]).unwrap();
+        let result = builder.dispatch(matches, OutputMode::Toml);
+
+        assert!(result.is_handled());
+        let output = result.output().unwrap();
+        assert!(
+            output.contains("TOML output requires a table at the top level"),
+            "{}",
+            output
+        );
+    }
+
+    #[test]
+    fn test_dispatch_from_with_json_lines_flag() {
+        use serde_json::json;
+
+        let builder = AppBuilder::new()
+            .command(
+                "list",
+                |_m, _ctx| {
+                    Ok(HandlerOutput::Render(json!([
+                        {"id": 1, "name": "a"},
+                        {"id": 2, "name": "b"},
+                        {"id": 3, "name": "c"}
+                    ])))
+                },
+                "{{ name }}",
+            )
+            .unwrap();
+
+        let cmd = Command::new("app").subcommand(Command::new("list"));
+
+        let result = builder.dispatch_from(cmd, ["app", "--output=json-lines", "list"]);
+
+        assert!(result.is_handled());
+        let lines: Vec<serde_json::Value> = result
+            .output()
+            .unwrap()
+            .lines()
+            .map(|line| serde_json::from_str(line).unwrap())
+            .collect();
+        assert_eq!(
+            lines,
+            vec![
+                json!({"id": 1, "name": "a"}),
+                json!({"id": 2, "name": "b"}),
+                json!({"id": 3, "name": "c"}),
+            ]
+        );
+    }
+
+    #[test]
+    fn test_dispatch_json_lines_mode_non_array() {
+        use crate::dispatch;
+        use serde_json::json;
+
+        let builder = AppBuilder::new()
+            .commands(dispatch! {
+                show => |_m, _ctx| Ok(HandlerOutput::Render(json!({"items": ["a", "b"]})))
+            })
+            .unwrap();
+
+        let cmd = Command::new("app").subcommand(Command::new("show"));
+        let matches = cmd.try_get_matches_from(["app", "show"]).unwrap();
+        let result = builder.dispatch(matches, OutputMode::JsonLines);
+
+        assert_eq!(result.output(), Some(r#"{"items":["a","b"]}"#));
+    }
+
+    #[test]
+    fn test_dispatch_json_compact_mode() {
+        use crate::dispatch;
+        use serde_json::json;
+
+        let builder = AppBuilder::new()
+            .commands(dispatch! {
+                list => |_m, _ctx| Ok(HandlerOutput::Render(json!({"items": ["a", "b"]})))
+            })
+            .unwrap();
+
+        let cmd = Command::new("app").subcommand(Command::new("list"));
+        let matches = cmd.try_get_matches_from(["app", "list"]).unwrap();
+        let result = builder.dispatch(matches, OutputMode::JsonCompact);
+
+        assert!(result.is_handled());
+        assert_eq!(result.output(), Some(r#"{"items":["a","b"]}"#));
+    }
+
     #[test]
     fn test_dispatch_from_unhandled() {
         use serde_json::json;
@@ -828,6 +1308,45 @@ mod tests {
         assert_eq!(result.output(), Some("HELLO"));
     }
 
+    #[test]
+    fn test_dispatch_pre_output_hook_suppresses_text() {
+        use serde_json::json;
+        use std::sync::atomic::{AtomicBool, Ordering};
+        use std::sync::Arc;
+
+        let post_output_ran = Arc::new(AtomicBool::new(false));
+        let seen = post_output_ran.clone();
+
+        let builder = AppBuilder::new()
+            .command(
+                "list",
+                |_m, _ctx| Ok(HandlerOutput::Render(json!({"msg": "hello"}))),
+                "{{ msg }}",
+            )
+            .unwrap()
+            .hooks(
+                "list",
+                Hooks::new()
+                    .pre_output(|_, _ctx, output| {
+                        assert_eq!(output.as_text(), Some("hello"));
+                        Ok(None)
+                    })
+                    .post_output(move |_, _ctx, output| {
+                        seen.store(true, Ordering::SeqCst);
+                        Ok(output)
+                    }),
+            );
+
+        let cmd = Command::new("app").subcommand(Command::new("list"));
+
+        let matches = cmd.try_get_matches_from(["app", "list"]).unwrap();
+        let result = builder.dispatch(matches, OutputMode::Text);
+
+        assert!(result.is_handled());
+        assert_eq!(result.output(), Some(""));
+        assert!(!post_output_ran.load(Ordering::SeqCst));
+    }
+
     #[test]
     fn test_dispatch_post_output_hook_chain() {
         use serde_json::json;
@@ -970,10 +1489,7 @@ mod tests {
             .command(
                 "export",
                 |_m, _ctx| -> HandlerResult<()> {
-                    Ok(HandlerOutput::Binary {
-                        data: vec![1, 2, 3],
-                        filename: "out.bin".into(),
-                    })
+                    Ok(HandlerOutput::binary(vec![1, 2, 3], "out.bin"))
                 },
                 "",
             )
@@ -981,9 +1497,20 @@ mod tests {
             .hooks(
                 "export",
                 Hooks::new().post_output(|_, _ctx, output| {
-                    if let RenderedOutput::Binary(mut bytes, filename) = output {
-                        bytes.push(4);
-                        Ok(RenderedOutput::Binary(bytes, filename))
+                    if let RenderedOutput::Binary {
+                        mut data,
+                        filename,
+                        to_stdout,
+                        content_type,
+                    } = output
+                    {
+                        data.push(4);
+                        Ok(RenderedOutput::Binary {
+                            data,
+                            filename,
+                            to_stdout,
+                            content_type,
+                        })
                     } else {
                         Ok(output)
                     }
@@ -1152,6 +1679,8 @@ mod tests {
                 Ok(HandlerOutput::Binary {
                     data: vec![0xDE, 0xAD],
                     filename: "data.bin".into(),
+                    to_stdout: true,
+                    content_type: Some("application/octet-stream".into()),
                 })
             },
             "",
@@ -1402,6 +1931,252 @@ mod tests {
         assert_eq!(err.phase, HookPhase::PostDispatch);
     }
 
+    // ============================================================================
+    // Fallback Handler Tests
+    // ============================================================================
+
+    fn fallback_builder() -> AppBuilder {
+        use serde_json::json;
+
+        AppBuilder::new()
+            .command(
+                "list",
+                |_m, _ctx| Ok(HandlerOutput::Render(json!({"count": 2}))),
+                "Listed {{ count }}",
+            )
+            .unwrap()
+            .on_unmatched(
+                |_m, ctx| {
+                    Ok(HandlerOutput::Render(
+                        json!({"command": ctx.command_path.join(" ")}),
+                    ))
+                },
+                "Unknown command '{{ command }}'. Try 'app --help'.",
+            )
+    }
+
+    fn fallback_cmd() -> Command {
+        Command::new("app")
+            .subcommand(Command::new("list"))
+            .subcommand(Command::new("remote").subcommand(Command::new("add")))
+    }
+
+    #[test]
+    fn test_on_unmatched_renders_unregistered_subcommand() {
+        let result = fallback_builder().dispatch_from(fallback_cmd(), ["app", "remote", "add"]);
+
+        assert_eq!(
+            result.output(),
+            Some("Unknown command 'remote add'. Try 'app --help'.")
+        );
+    }
+
+    #[test]
+    fn test_on_unmatched_exact_match_wins() {
+        let app = fallback_builder().build().unwrap();
+
+        let result = app.dispatch_from(fallback_cmd(), ["app", "list"]);
+        assert_eq!(result.output(), Some("Listed 2"));
+
+        let result = app.dispatch_from(fallback_cmd(), ["app", "remote"]);
+        assert_eq!(
+            result.output(),
+            Some("Unknown command 'remote'. Try 'app --help'.")
+        );
+    }
+
+    #[test]
+    fn test_on_unmatched_ignores_naked_invocation() {
+        let result = fallback_builder().dispatch_from(fallback_cmd(), ["app"]);
+
+        assert!(matches!(result, RunResult::NoMatch(_)));
+    }
+
+    // ============================================================================
+    // Output Flag Short Alias Tests
+    // ============================================================================
+
+    #[test]
+    fn test_output_flag_short_selects_json() {
+        use serde_json::json;
+
+        let builder = AppBuilder::new()
+            .output_flag_short('o')
+            .command(
+                "list",
+                |_m, _ctx| Ok(HandlerOutput::Render(json!({"count": 2}))),
+                "Count: {{ count }}",
+            )
+            .unwrap();
+        let cmd = || Command::new("app").subcommand(Command::new("list"));
+
+        let (result, mode, _) = builder.parse_and_dispatch(cmd(), ["app", "-o", "json", "list"]);
+        assert_eq!(mode, OutputMode::Json);
+        assert!(result.output().unwrap().contains("\"count\": 2"));
+
+        let app = builder.build().unwrap();
+        let matches = app
+            .augment_command(cmd())
+            .try_get_matches_from(["app", "list", "-o", "json"])
+            .unwrap();
+        assert_eq!(app.core.extract_output_mode(&matches), OutputMode::Json);
+    }
+
+    #[test]
+    fn test_command_output_mode_defaults() {
+        use serde_json::json;
+
+        let builder = AppBuilder::new()
+            .command_with(
+                "export",
+                |_m, _ctx| Ok(HandlerOutput::Render(json!({"count": 2}))),
+                |cfg| {
+                    cfg.template("Count: {{ count }}")
+                        .output_mode(OutputMode::Json)
+                },
+            )
+            .unwrap()
+            .command(
+                "list",
+                |_m, _ctx| Ok(HandlerOutput::Render(json!({"count": 3}))),
+                "Count: {{ count }}",
+            )
+            .unwrap()
+            .command_output_mode("list", OutputMode::Text);
+        let cmd = || {
+            Command::new("app")
+                .subcommand(Command::new("export"))
+                .subcommand(Command::new("list"))
+        };
+
+        let (result, mode, _) = builder.parse_and_dispatch(cmd(), ["app", "export"]);
+        assert_eq!(mode, OutputMode::Json);
+        assert!(result.output().unwrap().contains("\"count\": 2"));
+
+        let (result, mode, _) = builder.parse_and_dispatch(cmd(), ["app", "list"]);
+        assert_eq!(mode, OutputMode::Text);
+        assert_eq!(result.output().unwrap().trim(), "Count: 3");
+
+        // An explicit flag still wins over the per-command default
+        let (_, mode, _) = builder.parse_and_dispatch(cmd(), ["app", "--output", "yaml", "export"]);
+        assert_eq!(mode, OutputMode::Yaml);
+
+        let app = builder.build().unwrap();
+        let mode_for = |args: &[&str]| {
+            let matches = app
+                .augment_command(cmd())
+                .try_get_matches_from(args)
+                .unwrap();
+            app.core.extract_output_mode(&matches)
+        };
+        assert_eq!(mode_for(&["app", "export"]), OutputMode::Json);
+        assert_eq!(mode_for(&["app", "list"]), OutputMode::Text);
+        assert_eq!(
+            mode_for(&["app", "export", "--output", "text"]),
+            OutputMode::Text
+        );
+    }
+
+    #[test]
+    fn test_run_to_string_with_renders_styles_deterministically() {
+        use serde_json::json;
+
+        let builder = AppBuilder::new()
+            .command(
+                "list",
+                |_m, _ctx| Ok(HandlerOutput::Render(json!({"name": "alpha"}))),
+                "[title]{{ name }}[/title]",
+            )
+            .unwrap();
+        let cmd = || Command::new("app").subcommand(Command::new("list"));
+
+        let result = builder.run_to_string_with(cmd(), ["app", "list"], OutputMode::TermDebug);
+        assert_eq!(result.output().unwrap(), "[title]alpha[/title]");
+
+        let result = builder.run_to_string_with(
+            cmd(),
+            ["app", "--output", "text", "list"],
+            OutputMode::TermDebug,
+        );
+        assert_eq!(result.output().unwrap(), "alpha");
+
+        // Without a default mode, plain capture renders as before
+        let result = builder.run_to_string(cmd(), ["app", "--output", "text", "list"]);
+        assert_eq!(result.output().unwrap(), "alpha");
+
+        let app = builder.build().unwrap();
+        let result = app.run_to_string_with(cmd(), ["app", "list"], OutputMode::TermDebug);
+        assert_eq!(result.output().unwrap(), "[title]alpha[/title]");
+    }
+
+    /// Removes an environment variable even if an assertion fails.
+    struct EnvVarGuard(&'static str);
+    impl Drop for EnvVarGuard {
+        fn drop(&mut self) {
+            std::env::remove_var(self.0);
+        }
+    }
+
+    #[test]
+    fn test_env_prefix_supplies_output_mode() {
+        use serde_json::json;
+
+        // The prefix is unique to this test, and no other test reads the
+        // environment through it, so parallel tests don't see the var
+        let _guard = EnvVarGuard("STANDOUT_ENV_PREFIX_TEST_OUTPUT");
+        std::env::set_var("STANDOUT_ENV_PREFIX_TEST_OUTPUT", "json");
+
+        let builder = AppBuilder::new()
+            .env_prefix("standout_env_prefix_test")
+            .command(
+                "list",
+                |_m, _ctx| Ok(HandlerOutput::Render(json!({"count": 2}))),
+                "Count: {{ count }}",
+            )
+            .unwrap();
+        let cmd = || Command::new("app").subcommand(Command::new("list"));
+
+        let (result, mode, _) = builder.parse_and_dispatch(cmd(), ["app", "list"]);
+        assert_eq!(mode, OutputMode::Json);
+        assert!(result.output().unwrap().contains("\"count\": 2"));
+
+        // The flag still wins over the environment
+        let (_, mode, _) = builder.parse_and_dispatch(cmd(), ["app", "--output", "text", "list"]);
+        assert_eq!(mode, OutputMode::Text);
+
+        let app = builder.build().unwrap();
+        let matches = app
+            .augment_command(cmd())
+            .try_get_matches_from(["app", "list"])
+            .unwrap();
+        assert_eq!(app.core.extract_output_mode(&matches), OutputMode::Json);
+    }
+
+    #[test]
+    fn test_env_prefix_dry_run_accepts_boolish_values() {
+        use serde_json::json;
+
+        // Unique prefix, as in test_env_prefix_supplies_output_mode
+        let _guard = EnvVarGuard("STANDOUT_ENV_DRY_RUN_TEST_DRY_RUN");
+
+        let builder = AppBuilder::new()
+            .env_prefix("standout_env_dry_run_test")
+            .enable_dry_run()
+            .command(
+                "drop",
+                |_m, ctx| Ok(HandlerOutput::Render(json!({ "dry_run": ctx.dry_run }))),
+                "{% if dry_run %}preview{% else %}dropped{% endif %}",
+            )
+            .unwrap();
+        let cmd = || Command::new("app").subcommand(Command::new("drop"));
+
+        for (value, expected) in [("1", "preview"), ("yes", "preview"), ("0", "dropped")] {
+            std::env::set_var("STANDOUT_ENV_DRY_RUN_TEST_DRY_RUN", value);
+            let result = builder.dispatch_from(cmd(), ["app", "drop"]);
+            assert_eq!(result.output(), Some(expected), "{}", value);
+        }
+    }
+
     // ============================================================================
     // Default Command Tests
     // ============================================================================
@@ -1644,6 +2419,83 @@ mod tests {
         );
     }
 
+    #[test]
+    fn test_configured_theme_styles_command_output() {
+        use crate::Theme;
+        use console::Style;
+        use serde_json::json;
+
+        let theme = Theme::new().add("title", Style::new().bold().force_styling(true));
+
+        let builder = AppBuilder::new()
+            .theme(theme)
+            .command(
+                "show",
+                |_m, _ctx| Ok(HandlerOutput::Render(json!({"name": "widget"}))),
+                "[title]{{ name }}[/title]",
+            )
+            .unwrap();
+
+        let cmd = Command::new("app").subcommand(Command::new("show"));
+        let result = builder.dispatch_from(cmd, ["app", "--output=term", "show"]);
+
+        let output = result.output().unwrap();
+        assert!(
+            output.contains("\x1b[1mwidget\x1b[0m"),
+            "Configured theme style was not applied - output: {:?}",
+            output
+        );
+    }
+
+    #[test]
+    fn test_custom_filter_in_command_template() {
+        use crate::Theme;
+        use console::Style;
+        use serde_json::json;
+
+        let theme = Theme::new().add("price", Style::new().bold().force_styling(true));
+
+        let builder = AppBuilder::new()
+            .theme(theme)
+            .add_filter("currency", |value, _args| {
+                let cents = value.as_i64().ok_or("expected an integer")?;
+                Ok(format!("${}.{:02}", cents / 100, cents % 100).into())
+            })
+            .unwrap()
+            .command(
+                "total",
+                |_m, _ctx| Ok(HandlerOutput::Render(json!({"cents": 1250}))),
+                "[price]{{ cents | currency }}[/price]{{ '' | nl }}",
+            )
+            .unwrap();
+
+        let cmd = Command::new("app").subcommand(Command::new("total"));
+        let result = builder.dispatch_from(cmd, ["app", "--output=term", "total"]);
+
+        assert_eq!(result.output().unwrap(), "\x1b[1m$12.50\x1b[0m\n");
+    }
+
+    #[test]
+    fn test_add_filter_unsupported_engine() {
+        let result = AppBuilder::new()
+            .template_engine(Box::new(standout_render::template::SimpleEngine::new()))
+            .add_filter("noop", |value, _| Ok(value.clone()));
+        assert!(matches!(result, Err(SetupError::Template(_))));
+    }
+
+    #[test]
+    fn test_add_filter_after_dispatch_is_an_error() {
+        let builder = AppBuilder::new()
+            .command("list", |_m, _ctx| Ok(HandlerOutput::Render(())), "")
+            .unwrap();
+
+        let cmd = Command::new("app").subcommand(Command::new("list"));
+        builder.dispatch_from(cmd, ["app", "list"]);
+
+        let result = builder.add_filter("noop", |value, _| Ok(value.clone()));
+        assert!(matches!(result, Err(SetupError::Config(_))));
+    }
+
     // ============================================================================
     // App State Tests
     // ============================================================================
@@ -40,16 +40,25 @@ use std::path::PathBuf;
 use std::sync::Arc;
 
 use super::app::App;
+use super::core::BinaryOutputPolicy;
 use super::dispatch::DispatchFn;
 use super::group::CommandRecipe;
 use super::handler::Extensions;
 use super::hooks::Hooks;
 use super::mode::ThreadSafe;
 
-/// Stores a pending command recipe along with its resolved template.
+/// Stores a pending command recipe along with its template.
 struct PendingCommand {
     recipe: Box<dyn CommandRecipe>,
-    template: String,
+    template: PendingTemplate,
+}
+
+/// Where a pending command's template comes from.
+enum PendingTemplate {
+    /// Template source (or file path), resolved at registration.
+    Content(String),
+    /// Template registry name, looked up when commands are finalized.
+    Named(String),
 }
 
 /// Builder for constructing an App instance.
@@ -93,6 +102,9 @@ struct PendingCommand {
 pub struct AppBuilder {
     pub(crate) registry: TopicRegistry,
     pub(crate) output_flag: Option<String>,
+    pub(crate) output_flag_short: Option<char>,
+    /// Prefix for environment variables backing the managed flags
+    pub(crate) env_prefix: Option<String>,
     pub(crate) output_file_flag: Option<String>,
     pub(crate) theme: Option<Theme>,
     /// Stylesheet registry (built from embedded styles)
@@ -104,7 +116,13 @@ pub struct AppBuilder {
     pending_commands: RefCell<HashMap<String, PendingCommand>>,
     /// Finalized dispatch functions (lazily created from pending_commands)
     finalized_commands: RefCell<Option<HashMap<String, DispatchFn>>>,
+    /// Pending handler for unmatched subcommands (see `on_unmatched`)
+    pending_fallback: Option<PendingCommand>,
+    /// Finalized fallback dispatch function, created alongside the commands
+    finalized_fallback: RefCell<Option<DispatchFn>>,
     pub(crate) command_hooks: HashMap<String, Hooks>,
+    /// Per-command output modes used when `--output` is not passed
+    pub(crate) command_output_modes: HashMap<String, OutputMode>,
     pub(crate) context_registry: ContextRegistry,
     pub(crate) template_dir: Option<PathBuf>,
     pub(crate) template_ext: String,
@@ -124,6 +142,23 @@ pub struct AppBuilder {
     ///
     /// If not provided, a default MiniJinja engine will be created.
     pub(crate) template_engine: Arc<Box<dyn standout_render::template::TemplateEngine>>,
+
+    /// Where `run()` writes binary handler output.
+    pub(crate) binary_output: BinaryOutputPolicy,
+
+    /// Fixed render width, overriding `COLUMNS` and the terminal size.
+    pub(crate) terminal_width: Option<usize>,
+
+    /// Whether the global `--dry-run` flag is added.
+    pub(crate) dry_run: bool,
+}
+
+/// A dispatch function that always fails with `message`.
+///
+/// Stands in for commands whose named template could not be found, so the
+/// error surfaces when the command runs.
+fn missing_template_dispatch(message: String) -> DispatchFn {
+    Arc::new(move |_matches, _ctx, _hooks, _output_mode| Err(message.clone()))
 }
 
 impl Default for AppBuilder {
@@ -141,6 +176,8 @@ impl AppBuilder {
         Self {
             registry: TopicRegistry::new(),
             output_flag: Some("output".to_string()), // Enabled by default
+            output_flag_short: None,
+            env_prefix: None,
             output_file_flag: Some("output-file-path".to_string()),
             theme: None,
             stylesheet_registry: None,
@@ -148,7 +185,10 @@ impl AppBuilder {
             default_theme_name: None,
             pending_commands: RefCell::new(HashMap::new()),
             finalized_commands: RefCell::new(None),
+            pending_fallback: None,
+            finalized_fallback: RefCell::new(None),
             command_hooks: HashMap::new(),
+            command_output_modes: HashMap::new(),
             context_registry: ContextRegistry::new(),
             template_dir: None,
             template_ext: ".j2".to_string(),
@@ -157,6 +197,9 @@ impl AppBuilder {
             include_framework_styles: true,
             app_state: Arc::new(Extensions::new()),
             template_engine: Arc::new(Box::new(standout_render::template::MiniJinjaEngine::new())),
+            binary_output: BinaryOutputPolicy::Auto,
+            terminal_width: None,
+            dry_run: false,
         }
     }
 
@@ -238,6 +281,53 @@ impl AppBuilder {
         self
     }
 
+    /// Registers a custom template filter for all command templates.
+    ///
+    /// The filter receives the piped value and any arguments as JSON values and
+    /// is available alongside the built-in `nl`, `style`, and tabular filters.
+    /// A name that collides with a built-in replaces it, at your own risk.
+    ///
+    /// The filter is registered on the current template engine, so call
+    /// `template_engine()` first when using a custom engine.
+    ///
+    /// # Errors
+    ///
+    /// Returns an error if the template engine doesn't support filters, or
+    /// `SetupError::Config` if commands have already been dispatched and
+    /// share the engine.
+    ///
+    /// # Example
+    ///
+    /// ```rust,ignore
+    /// let app = App::builder()
+    ///     .add_filter("currency", |value, _args| {
+    ///         let cents = value.as_i64().ok_or("expected an integer")?;
+    ///         Ok(format!("${}.{:02}", cents / 100, cents % 100).into())
+    ///     })?
+    ///     .command("total", handler, "Total: {{ cents | currency }}")?
+    ///     .build()?;
+    /// ```
+    pub fn add_filter<F>(mut self, name: &str, filter: F) -> Result<Self, SetupError>
+    where
+        F: Fn(&serde_json::Value, &[serde_json::Value]) -> Result<serde_json::Value, String>
+            + Send
+            + Sync
+            + 'static,
+    {
+        // Once commands have been finalized they share the engine, and a filter
+        // added now would not reach them.
+        Arc::get_mut(&mut self.template_engine)
+            .ok_or_else(|| {
+                SetupError::Config(format!(
+                    "cannot add filter '{}' after commands have been dispatched",
+                    name
+                ))
+            })?
+            .add_filter(name, Arc::new(filter))
+            .map_err(|e| SetupError::Template(e.to_string()))?;
+        Ok(self)
+    }
+
     /// Ensures all pending commands have been finalized into dispatch functions.
     ///
     /// This method is called lazily on first dispatch. It creates the actual
@@ -255,20 +345,45 @@ impl AppBuilder {
         let context_registry = &self.context_registry;
 
         // Build dispatch functions from recipes
-        let mut commands = HashMap::new();
-        for (path, pending) in self.pending_commands.borrow().iter() {
-            let dispatch = pending.recipe.create_dispatch(
-                &pending.template,
+        let finalize = |pending: &PendingCommand| {
+            let template = match &pending.template {
+                PendingTemplate::Content(content) => content.clone(),
+                PendingTemplate::Named(name) => match self.named_template(name) {
+                    Ok(content) => content,
+                    Err(message) => return missing_template_dispatch(message),
+                },
+            };
+            pending.recipe.create_dispatch(
+                &template,
                 context_registry,
                 &theme,
                 self.template_engine.clone(),
-            );
-            commands.insert(path.clone(), dispatch);
-        }
+                self.terminal_width,
+            )
+        };
 
+        let commands = self
+            .pending_commands
+            .borrow()
+            .iter()
+            .map(|(path, pending)| (path.clone(), finalize(pending)))
+            .collect();
+
+        *self.finalized_fallback.borrow_mut() = self.pending_fallback.as_ref().map(finalize);
         *self.finalized_commands.borrow_mut() = Some(commands);
     }
 
+    /// Looks up a template by name in the configured registry.
+    fn named_template(&self, name: &str) -> Result<String, String> {
+        let registry = self.template_registry.as_ref().ok_or_else(|| {
+            format!(
+                "Template not found: \"{}\" (no template registry is configured)",
+                name
+            )
+        })?;
+        registry.get_content(name).map_err(|e| e.to_string())
+    }
+
     /// Returns the finalized commands map, creating it if necessary.
     fn get_commands(&self) -> std::cell::Ref<'_, HashMap<String, DispatchFn>> {
         self.ensure_commands_finalized();
@@ -278,6 +393,12 @@ impl AppBuilder {
         })
     }
 
+    /// Returns the finalized fallback handler, if one was registered.
+    fn get_fallback(&self) -> Option<DispatchFn> {
+        self.ensure_commands_finalized();
+        self.finalized_fallback.borrow().clone()
+    }
+
     /// Test helper: Check if a command path is registered.
     #[cfg(test)]
     pub(crate) fn has_command(&self, path: &str) -> bool {
@@ -333,6 +454,8 @@ impl AppBuilder {
                         let _ = engine_box.add_template(name, &content);
                     }
                 }
+                // Resolve includes of anything not added above through the registry
+                engine_box.set_template_registry(registry.clone());
             } else {
                 // If we can't get mut, it means the engine is already shared (e.g. via ensure_commands_finalized called early?)
                 // In that case, we can't add templates.
@@ -346,11 +469,12 @@ impl AppBuilder {
             .finalized_commands
             .into_inner()
             .expect("Commands should be finalized");
+        let fallback = self.finalized_fallback.into_inner();
 
         // Resolve theme: explicit theme takes precedence, then stylesheet registry
         let theme = if let Some(theme) = self.theme.take() {
             Some(theme)
-        } else if let Some(ref mut registry) = self.stylesheet_registry {
+        } else if let Some(registry) = &self.stylesheet_registry {
             if let Some(name) = &self.default_theme_name {
                 let theme = registry
                     .get(name)
@@ -374,22 +498,29 @@ impl AppBuilder {
         // Build the AppCore with all shared configuration
         let core = AppCore {
             output_flag: self.output_flag,
+            output_flag_short: self.output_flag_short,
+            env_prefix: self.env_prefix,
             output_file_flag: self.output_file_flag,
             output_mode: OutputMode::Auto,
             theme,
             command_hooks: self.command_hooks,
+            command_output_modes: self.command_output_modes,
             default_command: self.default_command,
             template_registry,
             stylesheet_registry: self.stylesheet_registry,
             context_registry: self.context_registry,
             app_state: self.app_state,
             template_engine: self.template_engine,
+            binary_output: self.binary_output,
+            terminal_width: self.terminal_width,
+            dry_run: self.dry_run,
         };
 
         Ok(App {
             core,
             registry: self.registry,
             commands,
+            fallback,
         })
     }
 
@@ -421,6 +552,21 @@ mod tests {
         assert!(standout.core.output_flag.is_none());
     }
 
+    #[test]
+    fn test_binary_output_policy() {
+        let standout = AppBuilder::new().build().unwrap();
+        assert_eq!(standout.core.binary_output, BinaryOutputPolicy::Auto);
+
+        let standout = AppBuilder::new()
+            .binary_output(BinaryOutputPolicy::AlwaysStdout)
+            .build()
+            .unwrap();
+        assert_eq!(
+            standout.core.binary_output,
+            BinaryOutputPolicy::AlwaysStdout
+        );
+    }
+
     #[test]
     fn test_custom_output_flag_name() {
         let standout = AppBuilder::new()
@@ -14,8 +14,11 @@
 //! to access shared resources like database connections and configuration.
 
 use std::collections::HashMap;
+use std::io::{IsTerminal, Write};
 use std::sync::Arc;
 
+use clap::builder::BoolishValueParser;
+use clap::parser::ValueSource;
 use clap::{Arg, ArgAction, ArgMatches, Command};
 use serde::Serialize;
 
@@ -24,8 +27,217 @@ use crate::setup::SetupError;
 use crate::{detect_color_mode, OutputMode, StylesheetRegistry, TemplateRegistry, Theme};
 use standout_dispatch::Extensions;
 
-use super::app::get_terminal_width;
+use super::app::render_context;
 use super::hooks::Hooks;
+use super::result::AppError;
+
+/// Values accepted by the `--output` flag.
+pub(crate) const OUTPUT_MODE_VALUES: &[&str] = &[
+    "auto",
+    "term",
+    "text",
+    "term-debug",
+    "json",
+    "json-compact",
+    "json-lines",
+    "yaml",
+    #[cfg(feature = "toml")]
+    "toml",
+    "xml",
+    "csv",
+];
+
+/// Help text for the `--output` flag.
+#[cfg(not(feature = "toml"))]
+pub(crate) const OUTPUT_MODE_HELP: &str =
+    "Output mode: auto, term, text, term-debug, json, json-compact, json-lines, yaml, xml, or csv";
+#[cfg(feature = "toml")]
+pub(crate) const OUTPUT_MODE_HELP: &str =
+    "Output mode: auto, term, text, term-debug, json, json-compact, json-lines, yaml, toml, xml, or csv";
+
+/// Argument id of the `--dry-run` flag.
+pub(crate) const DRY_RUN_ARG: &str = "_dry_run";
+
+/// Builds the environment variable a managed flag falls back to.
+///
+/// The flag name is upper-cased with dashes turned into underscores and
+/// joined to the prefix: `MYAPP` and `output-file-path` give
+/// `MYAPP_OUTPUT_FILE_PATH`. Returns None when no prefix is configured.
+pub(crate) fn env_var_name(prefix: Option<&str>, flag: &str) -> Option<String> {
+    Some(format!("{}_{}", prefix?, flag.replace('-', "_")).to_uppercase())
+}
+
+/// Makes `arg` read the environment variable for `flag` when it is absent
+/// from the command line. Leaves `arg` unchanged without a prefix.
+pub(crate) fn with_env_fallback(arg: Arg, prefix: Option<&str>, flag: &str) -> Arg {
+    match env_var_name(prefix, flag) {
+        Some(name) => arg.env(name),
+        None => arg,
+    }
+}
+
+/// Builds the managed `--dry-run` flag.
+///
+/// The flag reads `<PREFIX>_DRY_RUN` when a prefix is set. The variable
+/// accepts `true`/`false`, `yes`/`no`, `on`/`off`, `y`/`n`, `t`/`f` and
+/// `1`/`0`, case-insensitively.
+pub(crate) fn dry_run_arg(prefix: Option<&str>) -> Arg {
+    with_env_fallback(
+        Arg::new(DRY_RUN_ARG)
+            .long("dry-run")
+            .global(true)
+            .action(ArgAction::SetTrue)
+            .value_parser(BoolishValueParser::new())
+            .help("Show what would happen without making changes"),
+        prefix,
+        "dry-run",
+    )
+}
+
+/// Returns true if `--dry-run` was given.
+///
+/// False when dry runs aren't enabled, since the flag is then never registered.
+pub(crate) fn dry_run_requested(matches: &ArgMatches) -> bool {
+    matches
+        .try_get_one::<bool>(DRY_RUN_ARG)
+        .ok()
+        .flatten()
+        .copied()
+        .unwrap_or(false)
+}
+
+/// Syntax-highlights JSON output before `run()` prints it.
+///
+/// Returns `output` unchanged unless `highlight` is true and the output parses
+/// as JSON, so error messages and text rewritten by post-output hooks print
+/// as-is. Token colors come from the theme's `json.*` styles (see
+/// [`highlight_json`](crate::highlight_json)).
+pub(crate) fn highlight_json_output(
+    output: String,
+    theme: Option<&Theme>,
+    highlight: bool,
+) -> String {
+    if !highlight {
+        return output;
+    }
+    match serde_json::from_str::<serde_json::Value>(&output) {
+        Ok(value) => {
+            let styles = theme
+                .map(|t| t.resolve_styles(Some(crate::detect_color_mode())))
+                .unwrap_or_default();
+            crate::highlight_json(&value, &styles)
+        }
+        Err(_) => output,
+    }
+}
+
+/// Controls where `run()` writes binary handler output.
+///
+/// Handlers returning `Output::Binary` provide the bytes and a suggested
+/// filename. Writing raw bytes into an interactive terminal is never useful,
+/// but when stdout is piped (`myapp export > out.zip`) the bytes belong there.
+///
+/// A handler that sets `to_stdout` on its output always writes to stdout,
+/// whatever the policy.
+///
+/// # Example
+///
+/// ```rust,ignore
+/// use standout::cli::{App, BinaryOutputPolicy};
+///
+/// App::builder()
+///     .binary_output(BinaryOutputPolicy::AlwaysStdout)
+///     .command("export", export_handler, "")?
+///     .build()?
+///     .run(cmd, std::env::args());
+/// ```
+#[derive(Debug, Clone, Copy, Default, PartialEq, Eq)]
+pub enum BinaryOutputPolicy {
+    /// Write to stdout when it is not a terminal, otherwise to the suggested file.
+    #[default]
+    Auto,
+    /// Always write to the suggested file.
+    AlwaysFile,
+    /// Always write raw bytes to stdout.
+    AlwaysStdout,
+}
+
+impl BinaryOutputPolicy {
+    /// Returns true if binary output should go to stdout.
+    ///
+    /// `stdout_is_terminal` is only consulted for [`BinaryOutputPolicy::Auto`].
+    pub fn use_stdout(&self, stdout_is_terminal: bool) -> bool {
+        match self {
+            BinaryOutputPolicy::Auto => !stdout_is_terminal,
+            BinaryOutputPolicy::AlwaysFile => false,
+            BinaryOutputPolicy::AlwaysStdout => true,
+        }
+    }
+
+    /// Writes binary output according to this policy, or to stdout when the
+    /// handler asked for it with `to_stdout`.
+    ///
+    /// When writing to a file, a note with the byte count (and content type,
+    /// if known) is printed to stderr. Errors are reported on stderr.
+    pub(crate) fn write(
+        &self,
+        bytes: &[u8],
+        filename: &str,
+        to_stdout: bool,
+        content_type: Option<&str>,
+    ) {
+        if let Err(e) = self.try_write(bytes, filename, to_stdout, content_type) {
+            eprintln!("{}", e);
+        }
+    }
+
+    /// Like [`write`](Self::write), but returns write errors.
+    pub(crate) fn try_write(
+        &self,
+        bytes: &[u8],
+        filename: &str,
+        to_stdout: bool,
+        content_type: Option<&str>,
+    ) -> Result<(), AppError> {
+        let stdout = std::io::stdout();
+        let is_terminal = stdout.is_terminal();
+        self.write_to(
+            &mut stdout.lock(),
+            is_terminal,
+            bytes,
+            filename,
+            to_stdout,
+            content_type,
+        )
+    }
+
+    /// Writes to `out` in place of stdout, so the choice can be tested.
+    fn write_to(
+        &self,
+        out: &mut dyn Write,
+        stdout_is_terminal: bool,
+        bytes: &[u8],
+        filename: &str,
+        to_stdout: bool,
+        content_type: Option<&str>,
+    ) -> Result<(), AppError> {
+        if to_stdout || self.use_stdout(stdout_is_terminal) {
+            out.write_all(bytes)
+                .and_then(|_| out.flush())
+                .map_err(|source| AppError::BinaryOutput { path: None, source })
+        } else {
+            std::fs::write(filename, bytes).map_err(|source| AppError::BinaryOutput {
+                path: Some(filename.to_string()),
+                source,
+            })?;
+            match content_type {
+                Some(ct) => eprintln!("Wrote {} bytes ({}) to {}", bytes.len(), ct, filename),
+                None => eprintln!("Wrote {} bytes to {}", bytes.len(), filename),
+            }
+            Ok(())
+        }
+    }
+}
 
 /// Shared core state for App and LocalApp.
 ///
@@ -39,14 +251,23 @@ use super::hooks::Hooks;
 /// - Theme and styles: `theme`, `stylesheet_registry`
 /// - Templates: `template_registry`
 /// - Hooks: `command_hooks`
+/// - Per-command output defaults: `command_output_modes`
 /// - Default command: `default_command`
 /// - Context: `context_registry`
 /// - App state: `app_state` (shared across all dispatches)
+/// - Binary output: `binary_output`
 pub struct AppCore {
     /// Name of the output mode flag (e.g., "output" for `--output`).
     /// Set to None to disable the flag.
     pub(crate) output_flag: Option<String>,
 
+    /// Optional short alias for the output mode flag (e.g., `'o'` for `-o`).
+    pub(crate) output_flag_short: Option<char>,
+
+    /// Prefix for environment variables backing the managed flags
+    /// (e.g., "MYAPP" for `MYAPP_OUTPUT`).
+    pub(crate) env_prefix: Option<String>,
+
     /// Name of the output file flag (e.g., "output-file-path" for `--output-file-path`).
     /// Set to None to disable the flag.
     pub(crate) output_file_flag: Option<String>,
@@ -60,6 +281,9 @@ pub struct AppCore {
     /// Per-command hooks for pre/post processing.
     pub(crate) command_hooks: HashMap<String, Hooks>,
 
+    /// Per-command output modes used when `--output` is not passed.
+    pub(crate) command_output_modes: HashMap<String, OutputMode>,
+
     /// Default command to run when no subcommand is provided.
     pub(crate) default_command: Option<String>,
 
@@ -83,6 +307,56 @@ pub struct AppCore {
     ///
     /// Wraps the engine execution logic (minijinja or custom).
     pub(crate) template_engine: Arc<Box<dyn standout_render::template::TemplateEngine>>,
+
+    /// Where `run()` writes binary handler output.
+    pub(crate) binary_output: BinaryOutputPolicy,
+
+    /// Fixed render width, overriding `COLUMNS` and the terminal size.
+    pub(crate) terminal_width: Option<usize>,
+
+    /// Whether the global `--dry-run` flag is added.
+    pub(crate) dry_run: bool,
+}
+
+/// Resolves the output mode for a parse, honoring per-command defaults.
+///
+/// An explicit `--output` (or its env var) always wins. Otherwise the invoked command's entry
+/// in `command_modes` applies, falling back to the flag's own default.
+pub(crate) fn resolve_output_mode(
+    flag_enabled: bool,
+    matches: &ArgMatches,
+    command_modes: &HashMap<String, OutputMode>,
+) -> OutputMode {
+    if !flag_enabled {
+        return OutputMode::Auto;
+    }
+
+    if matches!(
+        matches.value_source("_output_mode"),
+        None | Some(ValueSource::DefaultValue)
+    ) {
+        let (path, _) = standout_dispatch::resolve_invocation(matches);
+        if let Some(mode) = command_modes.get(&path.join(".")) {
+            return *mode;
+        }
+    }
+
+    match matches
+        .get_one::<String>("_output_mode")
+        .map(|s| s.as_str())
+    {
+        Some("term") => OutputMode::Term,
+        Some("text") => OutputMode::Text,
+        Some("term-debug") => OutputMode::TermDebug,
+        Some("json") => OutputMode::Json,
+        Some("json-compact") => OutputMode::JsonCompact,
+        Some("json-lines") => OutputMode::JsonLines,
+        Some("yaml") => OutputMode::Yaml,
+        Some("toml") => OutputMode::Toml,
+        Some("xml") => OutputMode::Xml,
+        Some("csv") => OutputMode::Csv,
+        _ => OutputMode::Auto,
+    }
 }
 
 impl Default for AppCore {
@@ -105,19 +379,26 @@ impl AppCore {
     /// - No stylesheet registry
     /// - Empty context registry
     /// - Empty app state
+    /// - Auto binary output policy
     pub fn new() -> Self {
         Self {
             output_flag: Some("output".to_string()),
+            output_flag_short: None,
+            env_prefix: None,
             output_file_flag: Some("output-file-path".to_string()),
             output_mode: OutputMode::Auto,
             theme: None,
             command_hooks: HashMap::new(),
+            command_output_modes: HashMap::new(),
             default_command: None,
             template_registry: None,
             stylesheet_registry: None,
             context_registry: ContextRegistry::new(),
             app_state: Arc::new(Extensions::new()),
             template_engine: Arc::new(Box::new(standout_render::template::MiniJinjaEngine::new())),
+            binary_output: BinaryOutputPolicy::Auto,
+            terminal_width: None,
+            dry_run: false,
         }
     }
 
@@ -197,41 +478,44 @@ impl AppCore {
 
     /// Augments a clap Command with Standout's global flags.
     ///
-    /// Adds `--output` and `--output-file-path` flags if configured.
-    /// These flags are global (apply to all subcommands).
+    /// Adds `--output` and `--output-file-path` flags if configured, and
+    /// `--dry-run` if dry runs are enabled.
+    /// These flags are global (apply to all subcommands). With an env prefix,
+    /// each flag also reads `<PREFIX>_<FLAG>` when absent from the command line.
     pub fn augment_command(&self, mut cmd: Command) -> Command {
+        let prefix = self.env_prefix.as_deref();
         if let Some(ref flag_name) = self.output_flag {
             let flag: &'static str = Box::leak(flag_name.clone().into_boxed_str());
-            cmd = cmd.arg(
+            cmd = cmd.arg(with_env_fallback(
                 Arg::new("_output_mode")
                     .long(flag)
+                    .short(self.output_flag_short)
                     .value_name("MODE")
                     .global(true)
-                    .value_parser([
-                        "auto",
-                        "term",
-                        "text",
-                        "term-debug",
-                        "json",
-                        "yaml",
-                        "xml",
-                        "csv",
-                    ])
+                    .value_parser(OUTPUT_MODE_VALUES.to_vec())
                     .default_value("auto")
-                    .help("Output mode: auto, term, text, term-debug, json, yaml, xml, or csv"),
-            );
+                    .help(OUTPUT_MODE_HELP),
+                prefix,
+                flag,
+            ));
         }
 
         if let Some(ref flag_name) = self.output_file_flag {
             let flag: &'static str = Box::leak(flag_name.clone().into_boxed_str());
-            cmd = cmd.arg(
+            cmd = cmd.arg(with_env_fallback(
                 Arg::new("_output_file_path")
                     .long(flag)
                     .value_name("PATH")
                     .global(true)
                     .action(ArgAction::Set)
                     .help("Write output to file instead of stdout"),
-            );
+                prefix,
+                flag,
+            ));
+        }
+
+        if self.dry_run {
+            cmd = cmd.arg(dry_run_arg(prefix));
         }
 
         cmd
@@ -240,25 +524,15 @@ impl AppCore {
     /// Extracts the output mode from parsed ArgMatches.
     ///
     /// Reads the `_output_mode` argument value and converts it to an OutputMode.
+    /// When the user did not pass the flag, the invoked command's default from
+    /// `command_output_modes` is used instead.
     /// Returns Auto if the flag is disabled or the value is unrecognized.
     pub fn extract_output_mode(&self, matches: &ArgMatches) -> OutputMode {
-        if self.output_flag.is_some() {
-            match matches
-                .get_one::<String>("_output_mode")
-                .map(|s| s.as_str())
-            {
-                Some("term") => OutputMode::Term,
-                Some("text") => OutputMode::Text,
-                Some("term-debug") => OutputMode::TermDebug,
-                Some("json") => OutputMode::Json,
-                Some("yaml") => OutputMode::Yaml,
-                Some("xml") => OutputMode::Xml,
-                Some("csv") => OutputMode::Csv,
-                _ => OutputMode::Auto,
-            }
-        } else {
-            OutputMode::Auto
-        }
+        resolve_output_mode(
+            self.output_flag.is_some(),
+            matches,
+            &self.command_output_modes,
+        )
     }
 
     // =========================================================================
@@ -347,7 +621,7 @@ impl AppCore {
         // Build render context for context providers
         let json_data =
             serde_json::to_value(data).map_err(|e| SetupError::Config(e.to_string()))?;
-        let render_ctx = RenderContext::new(mode, get_terminal_width(), &theme, &json_data);
+        let render_ctx = render_context(mode, self.terminal_width, &theme, &json_data);
 
         // Build combined context: context providers + data
         let combined_minijinja_map = self.build_combined_context(data, &render_ctx)?;
@@ -429,9 +703,16 @@ impl AppCore {
             OutputMode::Json => {
                 serde_json::to_string_pretty(data).map_err(|e| SetupError::Config(e.to_string()))
             }
+            OutputMode::JsonCompact => {
+                serde_json::to_string(data).map_err(|e| SetupError::Config(e.to_string()))
+            }
+            OutputMode::JsonLines => serde_json::to_value(data)
+                .map(|value| crate::to_json_lines(&value))
+                .map_err(|e| SetupError::Config(e.to_string())),
             OutputMode::Yaml => {
                 serde_yaml::to_string(data).map_err(|e| SetupError::Config(e.to_string()))
             }
+            OutputMode::Toml => crate::to_toml(data).map_err(|e| SetupError::Config(e.to_string())),
             OutputMode::Xml => {
                 quick_xml::se::to_string(data).map_err(|e| SetupError::Config(e.to_string()))
             }
@@ -464,6 +745,62 @@ impl AppCore {
 mod tests {
     use super::*;
 
+    #[test]
+    fn test_binary_output_policy_auto_follows_terminal() {
+        assert!(BinaryOutputPolicy::Auto.use_stdout(false));
+        assert!(!BinaryOutputPolicy::Auto.use_stdout(true));
+    }
+
+    #[test]
+    fn test_binary_output_policy_overrides() {
+        assert!(!BinaryOutputPolicy::AlwaysFile.use_stdout(false));
+        assert!(!BinaryOutputPolicy::AlwaysFile.use_stdout(true));
+        assert!(BinaryOutputPolicy::AlwaysStdout.use_stdout(false));
+        assert!(BinaryOutputPolicy::AlwaysStdout.use_stdout(true));
+    }
+
+    #[test]
+    fn test_binary_output_to_stdout_overrides_policy() {
+        let dir = tempfile::tempdir().unwrap();
+        let path = dir.path().join("report.pdf");
+        let mut out = Vec::new();
+
+        BinaryOutputPolicy::AlwaysFile
+            .write_to(
+                &mut out,
+                false,
+                b"%PDF",
+                path.to_str().unwrap(),
+                true,
+                Some("application/pdf"),
+            )
+            .unwrap();
+
+        assert_eq!(out, b"%PDF");
+        assert!(!path.exists());
+    }
+
+    #[test]
+    fn test_binary_output_follows_policy_to_file() {
+        let dir = tempfile::tempdir().unwrap();
+        let path = dir.path().join("report.pdf");
+        let mut out = Vec::new();
+
+        BinaryOutputPolicy::Auto
+            .write_to(
+                &mut out,
+                true,
+                b"%PDF",
+                path.to_str().unwrap(),
+                false,
+                Some("application/pdf"),
+            )
+            .unwrap();
+
+        assert!(out.is_empty());
+        assert_eq!(std::fs::read(&path).unwrap(), b"%PDF");
+    }
+
     #[test]
     fn test_app_core_default() {
         let core = AppCore::new();
@@ -504,6 +841,73 @@ mod tests {
         assert_eq!(core.extract_output_mode(&matches), OutputMode::Json);
     }
 
+    #[test]
+    fn test_augment_command_adds_dry_run_when_enabled() {
+        let core = AppCore::new();
+        let cmd = core.augment_command(Command::new("test"));
+        assert!(cmd.get_arguments().all(|a| a.get_id() != DRY_RUN_ARG));
+        let matches = cmd.try_get_matches_from(["test"]).unwrap();
+        assert!(!dry_run_requested(&matches));
+
+        let core = AppCore {
+            dry_run: true,
+            ..AppCore::new()
+        };
+        let cmd = core.augment_command(Command::new("test"));
+        let matches = cmd.try_get_matches_from(["test", "--dry-run"]).unwrap();
+        assert!(dry_run_requested(&matches));
+    }
+
+    #[test]
+    fn test_highlight_json_output_only_in_color_mode() {
+        let output = "{\n  \"name\": \"test\"\n}".to_string();
+
+        let plain = highlight_json_output(output.clone(), None, false);
+        assert_eq!(plain, output);
+
+        let colored = highlight_json_output(output.clone(), None, true);
+        assert!(colored.contains("\x1b["), "{:?}", colored);
+        assert_eq!(console::strip_ansi_codes(&colored), output);
+    }
+
+    #[test]
+    fn test_highlight_json_output_uses_theme_and_skips_non_json() {
+        let theme = Theme::new().add(crate::JSON_STRING_STYLE, console::Style::new().magenta());
+        let colored = highlight_json_output("\"hi\"".to_string(), Some(&theme), true);
+        let expected = console::Style::new()
+            .magenta()
+            .force_styling(true)
+            .apply_to("\"hi\"")
+            .to_string();
+        assert_eq!(colored, expected);
+
+        let error = "Error: not json".to_string();
+        assert_eq!(highlight_json_output(error.clone(), None, true), error);
+    }
+
+    #[test]
+    fn test_extract_output_mode_json_compact() {
+        let core = AppCore::new();
+        let cmd = core.augment_command(Command::new("test"));
+
+        let matches = cmd
+            .try_get_matches_from(["test", "--output", "json-compact"])
+            .unwrap();
+        assert_eq!(core.extract_output_mode(&matches), OutputMode::JsonCompact);
+    }
+
+    #[test]
+    fn test_render_inline_json_compact_mode() {
+        let core = AppCore::new();
+        let data = serde_json::json!({"name": "test", "count": 42});
+
+        let result = core
+            .render_inline("ignored", &data, OutputMode::JsonCompact)
+            .unwrap();
+        assert!(!result.contains('\n'));
+        assert!(result.contains(r#""name":"test""#));
+    }
+
     #[test]
     fn test_extract_output_mode_disabled() {
         let mut core = AppCore::new();
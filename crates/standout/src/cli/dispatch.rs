@@ -12,16 +12,18 @@ use std::cell::RefCell;
 use std::rc::Rc;
 use std::sync::Arc;
 
+use crate::cli::app::render_context;
 use crate::cli::handler::CommandContext;
 use crate::cli::handler::Output as HandlerOutput;
 use crate::cli::hooks::Hooks;
-use crate::context::{ContextRegistry, RenderContext};
+use crate::context::ContextRegistry;
 use crate::Theme;
 use serde::Serialize;
 
 // Re-export pure dispatch utilities from standout-dispatch
 pub use standout_dispatch::{
     extract_command_path, get_deepest_matches, has_subcommand, insert_default_command,
+    resolve_invocation,
 };
 
 /// Trait for dispatching commands.
@@ -47,8 +49,15 @@ pub trait Dispatchable {
 pub enum DispatchOutput {
     /// Text output (rendered template or JSON)
     Text(String),
-    /// Binary output (bytes, filename)
-    Binary(Vec<u8>, String),
+    /// Text from [`HandlerOutput::Raw`], printed without JSON highlighting
+    Raw(String),
+    /// Binary output, with the fields of [`HandlerOutput::Binary`]
+    Binary {
+        data: Vec<u8>,
+        filename: String,
+        to_stdout: bool,
+        content_type: Option<String>,
+    },
     /// No output (silent)
     Silent,
 }
@@ -72,6 +81,7 @@ pub(crate) fn render_handler_output<T: Serialize>(
     context_registry: &ContextRegistry,
     template_engine: &dyn standout_render::template::TemplateEngine,
     output_mode: crate::OutputMode,
+    terminal_width: Option<usize>,
 ) -> Result<DispatchOutput, String> {
     match result {
         Ok(output) => match output {
@@ -85,12 +95,7 @@ pub(crate) fn render_handler_output<T: Serialize>(
                         .map_err(|e| format!("Hook error: {}", e))?;
                 }
 
-                let render_ctx = RenderContext::new(
-                    output_mode,
-                    crate::cli::app::get_terminal_width(),
-                    theme,
-                    &json_data,
-                );
+                let render_ctx = render_context(output_mode, terminal_width, theme, &json_data);
 
                 let output = standout_render::template::render_auto_with_engine(
                     template_engine,
@@ -105,10 +110,39 @@ pub(crate) fn render_handler_output<T: Serialize>(
                 Ok(DispatchOutput::Text(output))
             }
             HandlerOutput::Silent => Ok(DispatchOutput::Silent),
-            HandlerOutput::Binary { data, filename } => Ok(DispatchOutput::Binary(data, filename)),
+            HandlerOutput::Raw(text) => Ok(DispatchOutput::Raw(text)),
+            HandlerOutput::Binary {
+                data,
+                filename,
+                to_stdout,
+                content_type,
+            } => Ok(DispatchOutput::Binary {
+                data,
+                filename,
+                to_stdout,
+                content_type,
+            }),
         },
-        Err(e) => Err(format!("Error: {}", e)),
+        Err(e) => Err(format_handler_error(&e, output_mode)),
+    }
+}
+
+/// Formats a handler error for the given output mode.
+///
+/// JSON modes produce an object like `{"error": "...", "ok": false}` so
+/// scripts can parse failures; pretty-printed for `Json`, on one line for
+/// `JsonCompact` and `JsonLines`. Every other mode gets `Error: <message>`.
+pub(crate) fn format_handler_error(message: &str, output_mode: crate::OutputMode) -> String {
+    if !output_mode.is_json() {
+        return format!("Error: {}", message);
+    }
+    let error = serde_json::json!({ "error": message, "ok": false });
+    if output_mode == crate::OutputMode::Json {
+        serde_json::to_string_pretty(&error)
+    } else {
+        serde_json::to_string(&error)
     }
+    .expect("error objects always serialize")
 }
 
 /// Type-erased dispatch function for thread-safe handlers.
@@ -178,5 +212,5 @@ impl Dispatchable for LocalDispatchFn {
 }
 
 // Note: extract_command_path, get_deepest_matches, has_subcommand, insert_default_command,
-// path_to_string, and string_to_path are now re-exported from standout-dispatch at the top
-// of this file. Tests for these functions are in the standout-dispatch crate.
+// resolve_invocation, path_to_string, and string_to_path are now re-exported from
+// standout-dispatch at the top of this file. Tests for these functions are in the standout-dispatch crate.
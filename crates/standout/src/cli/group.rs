@@ -5,7 +5,7 @@
 
 use crate::context::ContextRegistry;
 
-use crate::Theme;
+use crate::{OutputMode, Theme};
 
 use clap::ArgMatches;
 use serde::Serialize;
@@ -49,6 +49,7 @@ pub(crate) trait CommandRecipe: Send + Sync {
         context_registry: &ContextRegistry,
         theme: &Theme,
         template_engine: Arc<Box<dyn standout_render::template::TemplateEngine>>,
+        terminal_width: Option<usize>,
     ) -> DispatchFn;
 }
 
@@ -112,6 +113,7 @@ where
         context_registry: &ContextRegistry,
         theme: &Theme,
         template_engine: Arc<Box<dyn standout_render::template::TemplateEngine>>,
+        terminal_width: Option<usize>,
     ) -> DispatchFn {
         let handler = self.handler.clone();
         let template = template.to_string();
@@ -134,6 +136,7 @@ where
                     &context_registry,
                     &**template_engine,
                     output_mode,
+                    terminal_width,
                 )
             },
         )
@@ -203,6 +206,7 @@ where
         context_registry: &ContextRegistry,
         theme: &Theme,
         template_engine: Arc<Box<dyn standout_render::template::TemplateEngine>>,
+        terminal_width: Option<usize>,
     ) -> DispatchFn {
         let handler = self.handler.clone();
         let template = template.to_string();
@@ -225,6 +229,7 @@ where
                     &context_registry,
                     &**template_engine,
                     output_mode,
+                    terminal_width,
                 )
             },
         )
@@ -276,6 +281,7 @@ impl CommandRecipe for ErasedConfigRecipe {
         context_registry: &ContextRegistry,
         theme: &Theme,
         template_engine: Arc<Box<dyn standout_render::template::TemplateEngine>>,
+        terminal_width: Option<usize>,
     ) -> DispatchFn {
         let config = self
             .config
@@ -289,18 +295,20 @@ impl CommandRecipe for ErasedConfigRecipe {
             context_registry.clone(),
             theme.clone(),
             template_engine,
+            terminal_width,
         )
     }
 }
 
 /// Configuration for a single command.
 ///
-/// Used internally to collect handler, template, and hooks before
-/// registering with the builder.
+/// Used internally to collect handler, template, hooks, and default output
+/// mode before registering with the builder.
 pub struct CommandConfig<H> {
     pub(crate) handler: H,
     pub(crate) template: Option<String>,
     pub(crate) hooks: Option<Hooks>,
+    pub(crate) output_mode: Option<OutputMode>,
 }
 
 impl<H> CommandConfig<H> {
@@ -310,6 +318,7 @@ impl<H> CommandConfig<H> {
             handler,
             template: None,
             hooks: None,
+            output_mode: None,
         }
     }
 
@@ -328,6 +337,15 @@ impl<H> CommandConfig<H> {
         self
     }
 
+    /// Sets the output mode used when `--output` is not passed.
+    ///
+    /// Overrides the app-wide default for this command only; an explicit
+    /// `--output` on the command line still wins.
+    pub fn output_mode(mut self, mode: OutputMode) -> Self {
+        self.output_mode = Some(mode);
+        self
+    }
+
     /// Adds a pre-dispatch hook for this command.
     ///
     /// Pre-dispatch hooks receive mutable access to [`CommandContext`], allowing
@@ -361,6 +379,26 @@ impl<H> CommandConfig<H> {
         self
     }
 
+    /// Adds a pre-output hook for this command.
+    ///
+    /// Returning `Ok(None)` suppresses the output and skips post-output hooks.
+    pub fn pre_output<F>(mut self, f: F) -> Self
+    where
+        F: Fn(
+                &ArgMatches,
+                &CommandContext,
+                crate::cli::hooks::RenderedOutput,
+            )
+                -> Result<Option<crate::cli::hooks::RenderedOutput>, crate::cli::hooks::HookError>
+            + Send
+            + Sync
+            + 'static,
+    {
+        let hooks = self.hooks.take().unwrap_or_default();
+        self.hooks = Some(hooks.pre_output(f));
+        self
+    }
+
     /// Adds a post-output hook for this command.
     pub fn post_output<F>(mut self, f: F) -> Self
     where
@@ -542,6 +580,7 @@ pub(crate) trait ErasedCommandConfig {
     #[allow(dead_code)]
     fn hooks(&self) -> Option<&Hooks>;
     fn take_hooks(&mut self) -> Option<Hooks>;
+    fn output_mode(&self) -> Option<OutputMode>;
     fn register(
         self: Box<Self>,
         path: &str,
@@ -549,6 +588,7 @@ pub(crate) trait ErasedCommandConfig {
         context_registry: ContextRegistry,
         theme: Theme,
         template_engine: Arc<Box<dyn standout_render::template::TemplateEngine>>,
+        terminal_width: Option<usize>,
     ) -> DispatchFn;
 }
 
@@ -646,6 +686,7 @@ impl GroupBuilder {
                     handler: config.handler,
                     template: config.template,
                     hooks: config.hooks,
+                    output_mode: config.output_mode,
                 }),
             },
         );
@@ -677,6 +718,7 @@ impl GroupBuilder {
                     handler: config.handler,
                     template: config.template,
                     hooks: config.hooks,
+                    output_mode: config.output_mode,
                 }),
             },
         );
@@ -742,6 +784,7 @@ where
     handler: FnHandler<F, T>,
     template: Option<String>,
     hooks: Option<Hooks>,
+    output_mode: Option<OutputMode>,
 }
 
 impl<F, T> ErasedCommandConfig for ClosureCommandConfig<F, T>
@@ -761,6 +804,10 @@ where
         self.hooks.take()
     }
 
+    fn output_mode(&self) -> Option<OutputMode> {
+        self.output_mode
+    }
+
     fn register(
         self: Box<Self>,
         _path: &str,
@@ -768,6 +815,7 @@ where
         context_registry: ContextRegistry,
         theme: Theme,
         template_engine: Arc<Box<dyn standout_render::template::TemplateEngine>>,
+        terminal_width: Option<usize>,
     ) -> DispatchFn {
         let handler = Arc::new(self.handler);
 
@@ -787,6 +835,7 @@ where
                     &context_registry,
                     &**template_engine,
                     output_mode,
+                    terminal_width,
                 )
             },
         )
@@ -802,6 +851,7 @@ where
     handler: H,
     template: Option<String>,
     hooks: Option<Hooks>,
+    output_mode: Option<OutputMode>,
 }
 
 impl<H, T> ErasedCommandConfig for StructCommandConfig<H, T>
@@ -821,6 +871,10 @@ where
         self.hooks.take()
     }
 
+    fn output_mode(&self) -> Option<OutputMode> {
+        self.output_mode
+    }
+
     fn register(
         self: Box<Self>,
         _path: &str,
@@ -828,6 +882,7 @@ where
         context_registry: ContextRegistry,
         theme: Theme,
         template_engine: Arc<Box<dyn standout_render::template::TemplateEngine>>,
+        terminal_width: Option<usize>,
     ) -> DispatchFn {
         let handler = Arc::new(self.handler);
 
@@ -847,6 +902,7 @@ where
                     &context_registry,
                     &**template_engine,
                     output_mode,
+                    terminal_width,
                 )
             },
         )
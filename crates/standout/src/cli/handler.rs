@@ -89,7 +89,7 @@
 // These types are render-agnostic and focus on handler execution.
 pub use standout_dispatch::{
     CommandContext, Extensions, FnHandler, Handler, HandlerResult, LocalFnHandler, LocalHandler,
-    Output, RunResult,
+    Output, ProgressSink, RunResult,
 };
 
 // Tests for these types are in the standout-dispatch crate.
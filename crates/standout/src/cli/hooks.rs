@@ -8,7 +8,9 @@
 //! - Pre-dispatch: Runs before the command handler. Can abort execution.
 //! - Post-dispatch: Runs after the handler but before rendering. Receives the raw
 //!   handler data as `serde_json::Value`. Can inspect, modify, or replace the data.
-//! - Post-output: Runs after output is generated. Can transform output or abort.
+//! - Pre-output: Runs after output is generated, before post-output hooks. Can
+//!   replace the output or suppress it by returning `None`.
+//! - Post-output: Runs after pre-output hooks. Can transform output or abort.
 //!
 //! # Example
 //!
@@ -47,7 +49,8 @@
 // Re-export all hook types from standout-dispatch.
 // These types are render-agnostic and focus on hook execution.
 pub use standout_dispatch::{
-    HookError, HookPhase, Hooks, PostDispatchFn, PostOutputFn, PreDispatchFn, RenderedOutput,
+    HookError, HookPhase, Hooks, PostDispatchFn, PostOutputFn, PreDispatchFn, PreOutputFn,
+    RenderedOutput,
 };
 
 // Tests for these types are in the standout-dispatch crate.
@@ -58,6 +58,7 @@ use super::hooks::Hooks;
 use crate::setup::SetupError;
 
 use super::app::App;
+use super::core::BinaryOutputPolicy;
 use super::mode::Local;
 use crate::topics::TopicRegistry;
 
@@ -69,6 +70,7 @@ trait LocalCommandRecipe {
         context_registry: &ContextRegistry,
         theme: &Theme,
         template_engine: Arc<Box<dyn TemplateEngine>>,
+        terminal_width: Option<usize>,
     ) -> LocalDispatchFn;
 }
 
@@ -106,6 +108,7 @@ where
         context_registry: &ContextRegistry,
         theme: &Theme,
         template_engine: Arc<Box<dyn TemplateEngine>>,
+        terminal_width: Option<usize>,
     ) -> LocalDispatchFn {
         let mut handler = LocalFnHandler::new(self.handler);
         let template = template.to_string();
@@ -128,6 +131,7 @@ where
                     &context_registry,
                     &**template_engine,
                     output_mode,
+                    terminal_width,
                 )
             },
         ))
@@ -168,6 +172,7 @@ where
         context_registry: &ContextRegistry,
         theme: &Theme,
         template_engine: Arc<Box<dyn TemplateEngine>>,
+        terminal_width: Option<usize>,
     ) -> LocalDispatchFn {
         let template = template.to_string();
         let context_registry = context_registry.clone();
@@ -189,6 +194,7 @@ where
                     &context_registry,
                     &**template_engine,
                     output_mode,
+                    terminal_width,
                 )
             },
         ))
@@ -233,6 +239,8 @@ struct PendingLocalCommand {
 pub struct LocalAppBuilder {
     // pub(crate) registry: TopicRegistry, // Unused
     pub(crate) output_flag: Option<String>,
+    pub(crate) output_flag_short: Option<char>,
+    pub(crate) env_prefix: Option<String>,
     pub(crate) output_file_flag: Option<String>,
     pub(crate) theme: Option<Theme>,
     pub(crate) stylesheet_registry: Option<crate::StylesheetRegistry>,
@@ -240,7 +248,10 @@ pub struct LocalAppBuilder {
     pub(crate) default_theme_name: Option<String>,
     pending_commands: RefCell<HashMap<String, PendingLocalCommand>>,
     finalized_commands: RefCell<Option<HashMap<String, LocalDispatchFn>>>,
+    pending_fallback: RefCell<Option<PendingLocalCommand>>,
+    finalized_fallback: RefCell<Option<LocalDispatchFn>>,
     pub(crate) command_hooks: HashMap<String, Hooks>,
+    pub(crate) command_output_modes: HashMap<String, OutputMode>,
     pub(crate) context_registry: ContextRegistry,
     pub(crate) template_dir: Option<std::path::PathBuf>,
     pub(crate) template_ext: String,
@@ -248,6 +259,9 @@ pub struct LocalAppBuilder {
     /// App-level state shared across all dispatches.
     pub(crate) app_state: Arc<Extensions>,
     pub(crate) template_engine: Arc<Box<dyn TemplateEngine>>,
+    pub(crate) binary_output: BinaryOutputPolicy,
+    pub(crate) terminal_width: Option<usize>,
+    pub(crate) dry_run: bool,
 }
 
 impl Default for LocalAppBuilder {
@@ -262,6 +276,8 @@ impl LocalAppBuilder {
         Self {
             // registry: TopicRegistry::new(),
             output_flag: Some("output".to_string()),
+            output_flag_short: None,
+            env_prefix: None,
             output_file_flag: Some("output-file-path".to_string()),
             theme: None,
             stylesheet_registry: None,
@@ -269,13 +285,19 @@ impl LocalAppBuilder {
             default_theme_name: None,
             pending_commands: RefCell::new(HashMap::new()),
             finalized_commands: RefCell::new(None),
+            pending_fallback: RefCell::new(None),
+            finalized_fallback: RefCell::new(None),
             command_hooks: HashMap::new(),
+            command_output_modes: HashMap::new(),
             context_registry: ContextRegistry::new(),
             template_dir: None,
             template_ext: ".j2".to_string(),
             default_command: None,
             app_state: Arc::new(Extensions::new()),
             template_engine: Arc::new(Box::new(standout_render::template::MiniJinjaEngine::new())),
+            binary_output: BinaryOutputPolicy::Auto,
+            terminal_width: None,
+            dry_run: false,
         }
     }
 
@@ -322,6 +344,51 @@ impl LocalAppBuilder {
         self
     }
 
+    /// Registers a custom template filter for all command templates.
+    ///
+    /// The filter receives the piped value and any arguments as JSON values and
+    /// is available alongside the built-in `nl`, `style`, and tabular filters.
+    /// A name that collides with a built-in replaces it, at your own risk.
+    ///
+    /// The filter is registered on the current template engine, so call
+    /// `template_engine()` first when using a custom engine.
+    ///
+    /// # Errors
+    ///
+    /// Returns an error if the template engine doesn't support filters.
+    ///
+    /// # Example
+    ///
+    /// ```rust,ignore
+    /// let app = LocalApp::builder()
+    ///     .add_filter("currency", |value, _args| {
+    ///         let cents = value.as_i64().ok_or("expected an integer")?;
+    ///         Ok(format!("${}.{:02}", cents / 100, cents % 100).into())
+    ///     })?
+    ///     .command("total", handler, "Total: {{ cents | currency }}")?
+    ///     .build()?;
+    /// ```
+    pub fn add_filter<F>(mut self, name: &str, filter: F) -> Result<Self, SetupError>
+    where
+        F: Fn(&serde_json::Value, &[serde_json::Value]) -> Result<serde_json::Value, String>
+            + Send
+            + Sync
+            + 'static,
+    {
+        // Once commands have been finalized they share the engine, and a filter
+        // added now would not reach them.
+        Arc::get_mut(&mut self.template_engine)
+            .ok_or_else(|| {
+                SetupError::Config(format!(
+                    "cannot add filter '{}' after commands have been dispatched",
+                    name
+                ))
+            })?
+            .add_filter(name, Arc::new(filter))
+            .map_err(|e| SetupError::Template(e.to_string()))?;
+        Ok(self)
+    }
+
     /// Registers a command handler (FnMut closure) with a template.
     ///
     /// Unlike [`AppBuilder::command`](super::AppBuilder::command), this accepts
@@ -419,12 +486,35 @@ impl LocalAppBuilder {
         Ok(self)
     }
 
+    /// Registers a fallback handler for subcommands without a handler of their own.
+    ///
+    /// Mirrors [`AppBuilder::on_unmatched`](super::AppBuilder::on_unmatched):
+    /// an exact match always wins, and the unmatched path is available as
+    /// `ctx.command_path`.
+    pub fn on_unmatched<F, T>(self, handler: F, template: &str) -> Self
+    where
+        F: FnMut(&ArgMatches, &CommandContext) -> HandlerResult<T> + 'static,
+        T: Serialize + 'static,
+    {
+        *self.pending_fallback.borrow_mut() = Some(PendingLocalCommand {
+            recipe: Box::new(LocalClosureRecipe::new(handler)),
+            template: template.to_string(),
+        });
+        self
+    }
+
     /// Registers hooks for a specific command path.
     pub fn hooks(mut self, path: &str, hooks: Hooks) -> Self {
         self.command_hooks.insert(path.to_string(), hooks);
         self
     }
 
+    /// Sets the output mode for a command when `--output` is not passed.
+    pub fn command_output_mode(mut self, path: &str, mode: OutputMode) -> Self {
+        self.command_output_modes.insert(path.to_string(), mode);
+        self
+    }
+
     // ============================================================================
     // Configuration (mirrors AppBuilder)
     // ============================================================================
@@ -465,12 +555,57 @@ impl LocalAppBuilder {
         self
     }
 
+    /// Adds a short alias for the output flag, e.g. `'o'` for `-o json`.
+    pub fn output_flag_short(mut self, short: char) -> Self {
+        self.output_flag_short = Some(short);
+        self
+    }
+
+    /// Lets the managed flags fall back to `<PREFIX>_<FLAG>` env vars.
+    pub fn env_prefix(mut self, prefix: &str) -> Self {
+        self.env_prefix = Some(prefix.to_string());
+        self
+    }
+
     /// Sets a default command.
     pub fn default_command(mut self, name: &str) -> Self {
         self.default_command = Some(name.to_string());
         self
     }
 
+    /// Checks that every registered handler path exists in `cmd`.
+    ///
+    /// Returns the unreachable paths, each with the closest real path as a
+    /// hint when there is one. See `AppBuilder::validate_against`.
+    pub fn validate_against(&self, cmd: &clap::Command) -> Result<(), Vec<String>> {
+        let pending = self.pending_commands.borrow();
+        let unreachable =
+            standout_dispatch::find_unreachable_paths(pending.keys().map(String::as_str), cmd);
+        if unreachable.is_empty() {
+            Ok(())
+        } else {
+            Err(unreachable)
+        }
+    }
+
+    /// Sets where `run()` writes binary handler output.
+    pub fn binary_output(mut self, policy: BinaryOutputPolicy) -> Self {
+        self.binary_output = policy;
+        self
+    }
+
+    /// Fixes the width used for rendering, overriding `COLUMNS` and the terminal.
+    pub fn terminal_width(mut self, width: usize) -> Self {
+        self.terminal_width = Some(width);
+        self
+    }
+
+    /// Adds a global `--dry-run` flag and passes it to handlers as `ctx.dry_run`.
+    pub fn enable_dry_run(mut self) -> Self {
+        self.dry_run = true;
+        self
+    }
+
     // ============================================================================
     // Build and Dispatch
     // ============================================================================
@@ -513,10 +648,26 @@ impl LocalAppBuilder {
                 context_registry,
                 theme,
                 template_engine.clone(),
+                self.terminal_width,
             );
             commands.insert(path, dispatch);
         }
 
+        let fallback = self
+            .pending_fallback
+            .borrow_mut()
+            .take()
+            .map(|pending_cmd| {
+                pending_cmd.recipe.create_dispatch(
+                    &pending_cmd.template,
+                    context_registry,
+                    theme,
+                    template_engine,
+                    self.terminal_width,
+                )
+            });
+
+        *self.finalized_fallback.borrow_mut() = fallback;
         *self.finalized_commands.borrow_mut() = Some(commands);
     }
 
@@ -527,7 +678,7 @@ impl LocalAppBuilder {
         // Resolve theme
         let theme = if let Some(theme) = self.theme.take() {
             Some(theme)
-        } else if let Some(ref mut registry) = self.stylesheet_registry {
+        } else if let Some(registry) = &self.stylesheet_registry {
             if let Some(name) = &self.default_theme_name {
                 let theme = registry
                     .get(name)
@@ -552,6 +703,8 @@ impl LocalAppBuilder {
                         let _ = engine_box.add_template(name, &content);
                     }
                 }
+                // Resolve includes of anything not added above through the registry
+                engine_box.set_template_registry(Arc::new(registry.clone()));
             }
         }
 
@@ -567,22 +720,29 @@ impl LocalAppBuilder {
         // Build the AppCore with all shared configuration
         let core = AppCore {
             output_flag: self.output_flag,
+            output_flag_short: self.output_flag_short,
+            env_prefix: self.env_prefix,
             output_file_flag: self.output_file_flag,
             output_mode: OutputMode::Auto,
             theme,
             command_hooks: self.command_hooks,
+            command_output_modes: self.command_output_modes,
             default_command: self.default_command,
             template_registry,
             stylesheet_registry: self.stylesheet_registry,
             context_registry: self.context_registry,
             app_state: self.app_state,
             template_engine: self.template_engine,
+            binary_output: self.binary_output,
+            terminal_width: self.terminal_width,
+            dry_run: self.dry_run,
         };
 
         Ok(App {
             core,
             registry: TopicRegistry::new(),
             commands: self.finalized_commands.take().unwrap_or_default(),
+            fallback: self.finalized_fallback.take(),
         })
     }
 
@@ -55,6 +55,7 @@
 ///         template: "template.j2",           // optional
 ///         pre_dispatch: hook_fn,             // optional
 ///         post_dispatch: hook_fn,            // optional
+///         pre_output: hook_fn,               // optional
 ///         post_output: hook_fn,              // optional
 ///     },
 ///
@@ -213,6 +214,14 @@ macro_rules! dispatch_apply_config {
         $cfg.post_dispatch($hook)
     };
 
+    // Pre-output hook
+    ($cfg:expr; pre_output : $hook:expr , $($rest:tt)*) => {
+        $crate::dispatch_apply_config!($cfg.pre_output($hook); $($rest)*)
+    };
+    ($cfg:expr; pre_output : $hook:expr) => {
+        $cfg.pre_output($hook)
+    };
+
     // Post-output hook
     ($cfg:expr; post_output : $hook:expr , $($rest:tt)*) => {
         $crate::dispatch_apply_config!($cfg.post_output($hook); $($rest)*)
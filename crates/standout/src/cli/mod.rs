@@ -111,7 +111,7 @@
 //! match app.run_to_string(cmd, args) {
 //!     RunResult::Handled(output) => println!("{}", output),
 //!     RunResult::NoMatch(matches) => legacy_dispatch(matches),
-//!     RunResult::Binary(bytes, filename) => std::fs::write(filename, bytes)?,
+//!     RunResult::Binary { data, filename, .. } => std::fs::write(filename, data)?,
 //! }
 //! ```
 //!
@@ -166,12 +166,14 @@ pub mod handler;
 pub mod help;
 pub mod hooks;
 pub mod mode;
+pub mod progress;
 #[macro_use]
 pub mod macros;
 
 // Re-export main types from app and builder modules
 pub use app::App;
 pub use builder::AppBuilder;
+pub use core::BinaryOutputPolicy;
 
 // Re-export local app types
 // Re-export local app types
@@ -181,14 +183,16 @@ pub use local_builder::LocalAppBuilder;
 // Re-export group types for declarative dispatch
 pub use group::{CommandConfig, GroupBuilder};
 
-// Re-export result type
-pub use result::HelpResult;
+// Re-export result types
+pub use result::{AppError, HelpResult};
 
 // Re-export help types
 pub use help::{default_help_theme, render_help, render_help_with_topics, HelpConfig};
 
 // Re-export handler types (thread-safe)
-pub use handler::{CommandContext, FnHandler, Handler, HandlerResult, Output, RunResult};
+pub use handler::{
+    CommandContext, FnHandler, Handler, HandlerResult, Output, ProgressSink, RunResult,
+};
 
 // Re-export local handler types
 pub use handler::{LocalFnHandler, LocalHandler};
@@ -196,6 +200,9 @@ pub use handler::{LocalFnHandler, LocalHandler};
 // Re-export mode types
 pub use mode::{HandlerMode, Local, ThreadSafe};
 
+// Re-export progress types
+pub use progress::TerminalProgress;
+
 // Re-export hook types
 pub use hooks::{HookError, HookPhase, Hooks, RenderedOutput};
 
@@ -208,6 +215,7 @@ pub use crate::setup::SetupError;
 // Re-export dispatch utilities from standout-dispatch
 pub use dispatch::{
     extract_command_path, get_deepest_matches, has_subcommand, insert_default_command,
+    resolve_invocation,
 };
 
 /// Parses a clap command with styled help output.
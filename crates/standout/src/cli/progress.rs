@@ -0,0 +1,147 @@
+//! Terminal progress line for long-running handlers.
+//!
+//! When stderr is a terminal, dispatch installs a [`TerminalProgress`] sink on the
+//! [`CommandContext`](super::CommandContext), so `ctx.progress(current, total, message)`
+//! redraws a single status line in place:
+//!
+//! ```text
+//! importing [##########----------] 5/10
+//! ```
+//!
+//! The bar uses the `standout-progress` style from the app theme, falling back to
+//! cyan. When stderr is not a terminal, no sink is installed and progress reports
+//! are discarded, keeping piped and logged output clean.
+
+use std::io::{IsTerminal, Write};
+use std::sync::Arc;
+
+use console::Style;
+
+use super::handler::ProgressSink;
+use crate::{detect_color_mode, Theme};
+
+/// Style name used for the progress bar.
+pub const PROGRESS_STYLE: &str = "standout-progress";
+
+/// Width of the progress bar in characters.
+const BAR_WIDTH: u64 = 20;
+
+/// A [`ProgressSink`] that draws a styled progress line on stderr.
+#[derive(Debug, Clone)]
+pub struct TerminalProgress {
+    bar_style: Style,
+}
+
+impl TerminalProgress {
+    /// Creates a progress line styled with `theme`, if given.
+    pub fn new(theme: Option<&Theme>) -> Self {
+        let bar_style = theme
+            .and_then(|t| t.get_style(PROGRESS_STYLE, Some(detect_color_mode())))
+            .unwrap_or_else(|| Style::new().cyan());
+        Self {
+            bar_style: bar_style.for_stderr(),
+        }
+    }
+
+    /// Formats a progress line without styling or terminal control codes.
+    fn format_line(current: u64, total: u64, message: &str) -> (String, String) {
+        if total == 0 {
+            return (String::new(), format!("{} {}", message, current));
+        }
+        let current = current.min(total);
+        // Widen so large byte counts can't overflow the multiplication
+        let filled = (current as u128 * BAR_WIDTH as u128 / total as u128) as usize;
+        let bar = format!(
+            "[{}{}]",
+            "#".repeat(filled),
+            "-".repeat(BAR_WIDTH as usize - filled)
+        );
+        (bar, format!("{}/{}", current, total))
+    }
+
+    /// Renders a progress line, with styling applied to the bar.
+    pub fn render(&self, current: u64, total: u64, message: &str) -> String {
+        let (bar, count) = Self::format_line(current, total, message);
+        if bar.is_empty() {
+            count
+        } else {
+            format!("{} {} {}", message, self.bar_style.apply_to(bar), count)
+        }
+    }
+}
+
+impl ProgressSink for TerminalProgress {
+    fn progress(&self, current: u64, total: u64, message: &str) {
+        let line = self.render(current, total, message);
+        let done = total > 0 && current >= total;
+        let mut stderr = std::io::stderr().lock();
+        // Clear the line and redraw in place; finish with a newline once complete
+        let _ = write!(stderr, "\r\x1b[2K{}", line);
+        if done {
+            let _ = writeln!(stderr);
+        }
+        let _ = stderr.flush();
+    }
+}
+
+/// Returns a terminal progress sink if stderr is a terminal.
+pub(crate) fn terminal_progress_sink(theme: Option<&Theme>) -> Option<Arc<dyn ProgressSink>> {
+    if std::io::stderr().is_terminal() {
+        Some(Arc::new(TerminalProgress::new(theme)))
+    } else {
+        None
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_render_bar_fraction() {
+        let progress = TerminalProgress::new(None);
+        let line = console::strip_ansi_codes(&progress.render(5, 10, "importing")).to_string();
+        assert_eq!(line, "importing [##########----------] 5/10");
+    }
+
+    #[test]
+    fn test_render_clamps_overflow() {
+        let progress = TerminalProgress::new(None);
+        let line = console::strip_ansi_codes(&progress.render(12, 10, "sync")).to_string();
+        assert_eq!(line, "sync [####################] 10/10");
+    }
+
+    #[test]
+    fn test_render_huge_counts() {
+        let progress = TerminalProgress::new(None);
+        let line =
+            console::strip_ansi_codes(&progress.render(u64::MAX / 2, u64::MAX, "copy")).to_string();
+        assert!(
+            line.starts_with("copy [#########-----------] "),
+            "line: {:?}",
+            line
+        );
+
+        let line =
+            console::strip_ansi_codes(&progress.render(u64::MAX, u64::MAX, "copy")).to_string();
+        assert!(
+            line.starts_with("copy [####################] "),
+            "line: {:?}",
+            line
+        );
+    }
+
+    #[test]
+    fn test_render_unknown_total() {
+        let progress = TerminalProgress::new(None);
+        assert_eq!(progress.render(42, 0, "scanning"), "scanning 42");
+    }
+
+    #[test]
+    fn test_render_uses_theme_style() {
+        let theme = Theme::new().add(PROGRESS_STYLE, Style::new().red().force_styling(true));
+        let progress = TerminalProgress::new(Some(&theme));
+        let line = progress.render(1, 2, "x");
+        assert!(line.contains("\x1b[31m"), "line: {:?}", line);
+    }
+}
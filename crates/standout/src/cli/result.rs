@@ -1,4 +1,4 @@
-//! Help interception result type.
+//! Help interception result type and the error type for running an app.
 
 /// Result of the help interception.
 ///
@@ -15,3 +15,67 @@ pub enum HelpResult {
     /// Error: Subcommand or topic not found.
     Error(clap::Error),
 }
+
+/// Error from [`App::try_run`](super::App::try_run).
+///
+/// [`App::run`](super::App::run) prints these instead of returning them.
+#[derive(Debug)]
+pub enum AppError {
+    /// A handler returned an error.
+    ///
+    /// Holds the message as `run` would print it, formatted for the output
+    /// mode (`Error: ...`, or a JSON object in JSON modes).
+    Handler(String),
+    /// A pre-dispatch or output hook failed.
+    Hook(String),
+    /// Binary output could not be written. `path` is `None` for stdout.
+    BinaryOutput {
+        path: Option<String>,
+        source: std::io::Error,
+    },
+    /// The arguments could not be parsed (unknown flag, missing value, ...).
+    ///
+    /// `--help` and `--version` are not errors; their output is printed.
+    Usage(clap::Error),
+}
+
+impl AppError {
+    /// Process exit code for this error.
+    ///
+    /// `1` for handler and hook errors, `74` (`EX_IOERR`) when binary output
+    /// could not be written, and clap's code (`2`) for usage errors.
+    pub fn exit_code(&self) -> i32 {
+        match self {
+            AppError::Handler(_) | AppError::Hook(_) => 1,
+            AppError::BinaryOutput { .. } => 74,
+            AppError::Usage(e) => e.exit_code(),
+        }
+    }
+}
+
+impl std::fmt::Display for AppError {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        match self {
+            AppError::Handler(message) => write!(f, "{}", message),
+            AppError::Hook(message) => write!(f, "Hook error: {}", message),
+            AppError::BinaryOutput { path: None, source } => {
+                write!(f, "Error writing to stdout: {}", source)
+            }
+            AppError::BinaryOutput {
+                path: Some(path),
+                source,
+            } => write!(f, "Error writing {}: {}", path, source),
+            AppError::Usage(e) => write!(f, "{}", e),
+        }
+    }
+}
+
+impl std::error::Error for AppError {
+    fn source(&self) -> Option<&(dyn std::error::Error + 'static)> {
+        match self {
+            AppError::BinaryOutput { source, .. } => Some(source),
+            AppError::Usage(e) => Some(e),
+            _ => None,
+        }
+    }
+}
@@ -236,21 +236,31 @@ pub use standout_render::style;
 pub use standout_render::tabular;
 
 // Error type (from standout-render)
-pub use standout_render::RenderError;
+pub use standout_render::{RenderError, RenderWarning};
 
 // Style module exports (from standout-render)
 pub use standout_render::{
-    parse_css, parse_stylesheet, ColorDef, StyleAttributes, StyleDefinition, StyleValidationError,
-    StyleValue, Styles, StylesheetError, StylesheetRegistry, ThemeVariants,
-    DEFAULT_MISSING_STYLE_INDICATOR, STYLESHEET_EXTENSIONS,
+    detect_color_depth, parse_css, parse_stylesheet, set_color_depth, ColorDef, ColorDepth,
+    MissingStylePolicy, StyleAttributes, StyleDefinition, StyleValidationError, StyleValue, Styles,
+    StylesheetError, StylesheetRegistry, ThemeVariants, DEFAULT_MISSING_STYLE_INDICATOR,
+    STYLESHEET_EXTENSIONS,
 };
 
 // Theme module exports (from standout-render)
-pub use standout_render::{detect_color_mode, set_theme_detector, ColorMode, Theme};
+pub use standout_render::{
+    detect_color_mode, parse_colorfgbg, resolve_color_mode, set_theme_detector, ColorMode,
+    ColorModeSource, Palette, Theme,
+};
 
 // Output module exports (from standout-render)
 pub use standout_render::{write_binary_output, write_output, OutputDestination, OutputMode};
 
+// JSON highlighting exports (from standout-render)
+pub use standout_render::{
+    highlight_json, JSON_BOOL_STYLE, JSON_KEY_STYLE, JSON_NULL_STYLE, JSON_NUMBER_STYLE,
+    JSON_STRING_STYLE,
+};
+
 // Render module exports (from standout-render)
 pub use standout_render::{
     render,
@@ -261,14 +271,18 @@ pub use standout_render::{
     render_with_mode,
     render_with_output,
     render_with_vars,
+    render_with_warnings,
     validate_template,
     // Template registry
     walk_template_dir,
+    walk_template_dir_with_depth,
     // Template engine abstraction
+    FilterFn,
     MiniJinjaEngine,
     RegistryError,
     Renderer,
     ResolvedTemplate,
+    SharedTemplateRegistry,
     TemplateEngine,
     TemplateFile,
     TemplateRegistry,
@@ -276,17 +290,23 @@ pub use standout_render::{
 };
 
 // Re-export BBParser types for template validation
-pub use standout_bbparser::{UnknownTagError, UnknownTagErrors, UnknownTagKind};
+pub use standout_bbparser::{
+    BBParser, TagSeverity, TagTransform, UnknownTagBehavior, UnknownTagError, UnknownTagErrors,
+    UnknownTagKind,
+};
 
 // Utility exports (from standout-render)
 pub use standout_render::{
-    flatten_json_for_csv, rgb_to_ansi256, rgb_to_truecolor, truncate_to_width,
+    flatten_json_for_csv, format_duration, pad_to_width, rgb_to_ansi16, rgb_to_ansi256,
+    rgb_to_truecolor, to_json_lines, to_toml, truncate_to_width, truncate_to_width_with,
+    TruncateOptions,
 };
 
 // File loader exports (from standout-render)
 pub use standout_render::{
-    build_embedded_registry, extension_priority, strip_extension, walk_dir, FileRegistry,
-    FileRegistryConfig, LoadError, LoadedEntry, LoadedFile,
+    build_embedded_registry, build_named_embedded_registry, extension_priority, strip_extension,
+    walk_dir, walk_dir_with_depth, FileRegistry, FileRegistryConfig, LoadError, LoadedEntry,
+    LoadedFile, DEFAULT_MAX_DEPTH,
 };
 
 // Embedded source types (from standout-render, for macros)
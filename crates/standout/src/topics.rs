@@ -47,13 +47,14 @@
 //! - [`Topic`]: A single help topic with title, content, and name
 //! - [`TopicRegistry`]: Collection of topics with lookup by name
 //! - [`TopicType`]: Text or Markdown (affects rendering)
-//! - [`render_topic`] / [`render_topics_list`]: Rendering functions
+//! - [`render_topic`] / [`render_topics_list`] / [`render_topic_search_results`]:
+//!   Rendering functions
 //! - [`display_with_pager`]: Show long content through less/more
 
 use deunicode::deunicode;
-use std::collections::HashMap;
+use std::collections::{BTreeMap, HashMap};
 use std::fs;
-use std::io::Write;
+use std::io::{IsTerminal, Write};
 use std::path::Path;
 use std::process::{Command as ProcessCommand, Stdio};
 
@@ -79,6 +80,10 @@ pub struct Topic {
     pub content: String,
     pub topic_type: TopicType,
     pub name: String,
+    /// Alternate names that resolve to this topic in [`TopicRegistry::get_topic`].
+    pub aliases: Vec<String>,
+    /// Category used to group topics in [`render_topics_list`].
+    pub category: Option<String>,
 }
 
 impl Topic {
@@ -98,9 +103,27 @@ impl Topic {
             content: content.into(),
             topic_type,
             name,
+            aliases: Vec::new(),
+            category: None,
         }
     }
 
+    /// Sets alternate names for the topic.
+    pub fn with_aliases<I, S>(mut self, aliases: I) -> Self
+    where
+        I: IntoIterator<Item = S>,
+        S: Into<String>,
+    {
+        self.aliases = aliases.into_iter().map(Into::into).collect();
+        self
+    }
+
+    /// Sets the category the topic is listed under.
+    pub fn with_category(mut self, category: impl Into<String>) -> Self {
+        self.category = Some(category.into());
+        self
+    }
+
     fn generate_slug(title: &str) -> String {
         let transliterated = deunicode(title);
         let mut slug: String = transliterated
@@ -120,17 +143,21 @@ impl Topic {
 #[derive(Default, Clone)]
 pub struct TopicRegistry {
     topics: HashMap<String, Topic>,
+    /// Maps each alias to the name of the topic declaring it.
+    aliases: HashMap<String, String>,
 }
 
 impl TopicRegistry {
     pub fn new() -> Self {
         Self {
             topics: HashMap::new(),
+            aliases: HashMap::new(),
         }
     }
 
     /// Adds a topic to the registry.
-    /// Panics if a topic with the same name already exists.
+    /// Panics if the topic's name or any of its aliases is already taken by
+    /// another topic's name or alias.
     pub fn add_topic(&mut self, topic: Topic) {
         if self.topics.contains_key(&topic.name) {
             panic!(
@@ -138,11 +165,42 @@ impl TopicRegistry {
                 topic.name
             );
         }
+        if let Some(owner) = self.aliases.get(&topic.name) {
+            panic!(
+                "Topic collision: The name '{}' is already an alias of topic '{}'.",
+                topic.name, owner
+            );
+        }
+        for alias in &topic.aliases {
+            if self.topics.contains_key(alias) {
+                panic!(
+                    "Topic collision: Alias '{}' of topic '{}' is already a topic name.",
+                    alias, topic.name
+                );
+            }
+            if let Some(owner) = self.aliases.get(alias) {
+                panic!(
+                    "Topic collision: Alias '{}' of topic '{}' is already an alias of topic '{}'.",
+                    alias, topic.name, owner
+                );
+            }
+        }
+
+        for alias in &topic.aliases {
+            if *alias != topic.name {
+                self.aliases.insert(alias.clone(), topic.name.clone());
+            }
+        }
         self.topics.insert(topic.name.clone(), topic);
     }
 
+    /// Looks up a topic by name or alias.
     pub fn get_topic(&self, name: &str) -> Option<&Topic> {
-        self.topics.get(name)
+        self.topics.get(name).or_else(|| {
+            self.aliases
+                .get(name)
+                .and_then(|target| self.topics.get(target))
+        })
     }
 
     pub fn list_topics(&self) -> Vec<&Topic> {
@@ -151,6 +209,34 @@ impl TopicRegistry {
         topics
     }
 
+    /// Searches topic titles and contents for `query`, ignoring case.
+    ///
+    /// Topics whose title contains the query come first, followed by topics
+    /// that only match in their content. Within each group, topics are sorted
+    /// by name. An empty or whitespace-only query matches nothing.
+    pub fn search(&self, query: &str) -> Vec<&Topic> {
+        let needle = query.trim().to_lowercase();
+        if needle.is_empty() {
+            return Vec::new();
+        }
+
+        let mut hits: Vec<(u8, &Topic)> = self
+            .topics
+            .values()
+            .filter_map(|t| {
+                if t.title.to_lowercase().contains(&needle) {
+                    Some((0, t))
+                } else if t.content.to_lowercase().contains(&needle) {
+                    Some((1, t))
+                } else {
+                    None
+                }
+            })
+            .collect();
+        hits.sort_by(|a, b| a.0.cmp(&b.0).then_with(|| a.1.name.cmp(&b.1.name)));
+        hits.into_iter().map(|(_, t)| t).collect()
+    }
+
     /// Adds topics from files in the specified directory.
     /// Only .txt and .md files are processed.
     /// Empty files or files with only one line are ignored.
@@ -201,6 +287,12 @@ impl TopicRegistry {
             };
 
             let content = fs::read_to_string(&path)?;
+            let (front_matter, content) = split_front_matter(&content).ok_or_else(|| {
+                std::io::Error::new(
+                    std::io::ErrorKind::InvalidData,
+                    format!("Unterminated front matter in {}", path.display()),
+                )
+            })?;
             let lines: Vec<&str> = content.lines().collect();
 
             // Skip empty or single-line files
@@ -234,7 +326,10 @@ impl TopicRegistry {
                     .and_then(|s| s.to_str())
                     .map(|s| s.to_string());
 
-                let topic = Topic::new(title, body, topic_type, name);
+                let mut topic = Topic::new(title, body, topic_type, name);
+                if let Some(front_matter) = front_matter {
+                    front_matter.apply(&mut topic);
+                }
                 self.add_topic(topic);
             }
         }
@@ -242,6 +337,74 @@ impl TopicRegistry {
     }
 }
 
+/// Metadata from a topic file's front-matter block.
+#[derive(Debug, Default, PartialEq)]
+struct FrontMatter {
+    aliases: Vec<String>,
+    category: Option<String>,
+}
+
+impl FrontMatter {
+    fn apply(self, topic: &mut Topic) {
+        topic.aliases = self.aliases;
+        topic.category = self.category;
+    }
+}
+
+/// Splits an optional front-matter block off the start of a topic file.
+///
+/// The block is delimited by `---` lines and holds `key: value` pairs:
+///
+/// ```text
+/// ---
+/// aliases: login, credentials
+/// category: Security
+/// ---
+/// Authentication
+/// ...
+/// ```
+///
+/// `aliases` is a comma-separated list. Unknown keys are ignored. Returns
+/// `None` if the block is opened but never closed.
+fn split_front_matter(content: &str) -> Option<(Option<FrontMatter>, &str)> {
+    let trimmed = content.trim_start();
+    let Some(after_open) = trimmed
+        .strip_prefix("---\r\n")
+        .or_else(|| trimmed.strip_prefix("---\n"))
+    else {
+        return Some((None, content));
+    };
+
+    let mut front_matter = FrontMatter::default();
+    let mut offset = 0;
+    for line in after_open.split_inclusive('\n') {
+        offset += line.len();
+        let line = line.trim();
+        if line == "---" {
+            return Some((Some(front_matter), &after_open[offset..]));
+        }
+        let Some((key, value)) = line.split_once(':') else {
+            continue;
+        };
+        let value = value.trim();
+        match key.trim() {
+            "aliases" => {
+                front_matter.aliases = value
+                    .split(',')
+                    .map(str::trim)
+                    .filter(|a| !a.is_empty())
+                    .map(String::from)
+                    .collect();
+            }
+            "category" if !value.is_empty() => {
+                front_matter.category = Some(value.to_string());
+            }
+            _ => {}
+        }
+    }
+    None
+}
+
 // ============================================================================
 // TOPIC RENDERING
 // ============================================================================
@@ -253,13 +416,16 @@ pub struct TopicRenderConfig {
     pub topic_template: Option<String>,
     /// Custom template string for topic list. If None, uses built-in template.
     pub list_template: Option<String>,
-    /// Custom theme. If None, uses the default topic theme.
+    /// Custom theme, layered over the default topic theme. If None, uses the default topic theme.
     pub theme: Option<Theme>,
     /// Output mode. If None, uses Auto (auto-detects).
     pub output_mode: Option<OutputMode>,
 }
 
 /// Returns the default theme for topic rendering.
+///
+/// `header`, `code`, `emphasis` and `strong` are also used for Markdown
+/// topics (headings, inline code, `*emphasis*` and `**strong**`).
 pub fn default_topic_theme() -> Theme {
     Theme::new()
         .add("header", Style::new().bold())
@@ -267,6 +433,9 @@ pub fn default_topic_theme() -> Theme {
         .add("desc", Style::new())
         .add("usage", Style::new())
         .add("about", Style::new())
+        .add("code", Style::new().cyan())
+        .add("emphasis", Style::new().italic())
+        .add("strong", Style::new().bold())
 }
 
 #[derive(Serialize)]
@@ -277,11 +446,21 @@ struct TopicData {
 
 #[derive(Serialize)]
 struct TopicsListData {
+    about: String,
     usage: String,
+    /// All topics, ungrouped (kept for custom list templates).
     topics: Vec<TopicListItem>,
+    /// Topics grouped by category; uncategorized topics come first.
+    groups: Vec<TopicGroup>,
 }
 
 #[derive(Serialize)]
+struct TopicGroup {
+    title: String,
+    topics: Vec<TopicListItem>,
+}
+
+#[derive(Serialize, Clone)]
 struct TopicListItem {
     name: String,
     title: String,
@@ -290,6 +469,11 @@ struct TopicListItem {
 
 /// Renders a single topic using standout templating.
 ///
+/// Markdown topics are converted to style tags first: headings use the
+/// `header` style, list bullets become `•`, and inline code, emphasis and
+/// strong text use the `code`, `emphasis` and `strong` styles. Text topics
+/// are rendered verbatim.
+///
 /// # Example
 ///
 /// ```rust
@@ -315,17 +499,33 @@ pub fn render_topic(
         .as_deref()
         .unwrap_or(include_str!("topic_template.txt"));
 
-    let theme = config.theme.unwrap_or_else(default_topic_theme);
+    let theme = topic_theme(config.theme);
     let mode = config.output_mode.unwrap_or(OutputMode::Auto);
 
+    let content = match topic.topic_type {
+        TopicType::Markdown => markdown_to_markup(&topic.content),
+        _ => topic.content.clone(),
+    };
+
     let data = TopicData {
         title: topic.title.clone(),
-        content: topic.content.clone(),
+        content,
     };
 
     render_with_output(template, &data, &theme, mode)
 }
 
+/// Resolves the theme used for topic rendering.
+///
+/// Custom themes are layered over [`default_topic_theme`], so the styles the
+/// topic templates and Markdown rendering rely on always resolve.
+fn topic_theme(theme: Option<Theme>) -> Theme {
+    match theme {
+        Some(theme) => default_topic_theme().merge(theme),
+        None => default_topic_theme(),
+    }
+}
+
 /// Renders a list of all available topics.
 ///
 /// # Arguments
@@ -350,6 +550,51 @@ pub fn render_topics_list(
     registry: &TopicRegistry,
     usage_prefix: &str,
     config: Option<TopicRenderConfig>,
+) -> Result<String, RenderError> {
+    render_topic_items(
+        &registry.list_topics(),
+        "Available Topics",
+        usage_prefix,
+        config,
+    )
+}
+
+/// Renders the topics matching a search query.
+///
+/// Uses the same template as [`render_topics_list`], with a heading that
+/// names the query. Pass the results of [`TopicRegistry::search`].
+///
+/// # Example
+///
+/// ```rust
+/// use standout::topics::{TopicRegistry, Topic, TopicType, render_topic_search_results};
+///
+/// let mut registry = TopicRegistry::new();
+/// registry.add_topic(Topic::new("Storage", "Where data is stored", TopicType::Text, None));
+///
+/// let hits = registry.search("data");
+/// let output = render_topic_search_results(&hits, "data", "myapp help", None).unwrap();
+/// println!("{}", output);
+/// ```
+pub fn render_topic_search_results(
+    topics: &[&Topic],
+    query: &str,
+    usage_prefix: &str,
+    config: Option<TopicRenderConfig>,
+) -> Result<String, RenderError> {
+    render_topic_items(
+        topics,
+        &format!("Topics matching '{}'", query),
+        usage_prefix,
+        config,
+    )
+}
+
+fn render_topic_items(
+    topics: &[&Topic],
+    about: &str,
+    usage_prefix: &str,
+    config: Option<TopicRenderConfig>,
 ) -> Result<String, RenderError> {
     let config = config.unwrap_or_default();
     let template = config
@@ -357,44 +602,183 @@ pub fn render_topics_list(
         .as_deref()
         .unwrap_or(include_str!("topics_list_template.txt"));
 
-    let theme = config.theme.unwrap_or_else(default_topic_theme);
+    let theme = topic_theme(config.theme);
     let mode = config.output_mode.unwrap_or(OutputMode::Auto);
 
-    let topics = registry.list_topics();
+    let mut uncategorized = Vec::new();
+    let mut categorized: BTreeMap<&str, Vec<TopicListItem>> = BTreeMap::new();
+    let mut topic_items = Vec::with_capacity(topics.len());
+    for t in topics {
+        // +1 accounts for the colon added in the template
+        let pad = NAME_COLUMN_WIDTH.saturating_sub(t.name.len() + 1);
+        let item = TopicListItem {
+            name: t.name.clone(),
+            title: t.title.clone(),
+            padding: " ".repeat(pad),
+        };
+        match t.category.as_deref() {
+            Some(category) => categorized.entry(category).or_default().push(item.clone()),
+            None => uncategorized.push(item.clone()),
+        }
+        topic_items.push(item);
+    }
 
-    let topic_items: Vec<TopicListItem> = topics
-        .iter()
-        .map(|t| {
-            // +1 accounts for the colon added in the template
-            let pad = NAME_COLUMN_WIDTH.saturating_sub(t.name.len() + 1);
-            TopicListItem {
-                name: t.name.clone(),
-                title: t.title.clone(),
-                padding: " ".repeat(pad),
-            }
-        })
-        .collect();
+    let mut groups = Vec::with_capacity(categorized.len() + 1);
+    if !uncategorized.is_empty() {
+        groups.push(TopicGroup {
+            title: "Topics".to_string(),
+            topics: uncategorized,
+        });
+    }
+    groups.extend(
+        categorized
+            .into_iter()
+            .map(|(category, topics)| TopicGroup {
+                title: category.to_string(),
+                topics,
+            }),
+    );
 
     let data = TopicsListData {
+        about: about.to_string(),
         usage: format!("{} <topic>", usage_prefix),
         topics: topic_items,
+        groups,
     };
 
     render_with_output(template, &data, &theme, mode)
 }
 
+// ============================================================================
+// MARKDOWN RENDERING
+// ============================================================================
+
+/// Converts a small Markdown subset into standout style-tag markup.
+///
+/// Supports ATX headings, `-`/`*`/`+` bullets, fenced code blocks, inline
+/// code, `*emphasis*` and `**strong**` (or `__strong__`). Anything else is
+/// passed through unchanged.
+fn markdown_to_markup(content: &str) -> String {
+    let mut lines = Vec::new();
+    let mut in_fence = false;
+
+    for line in content.lines() {
+        let trimmed = line.trim_start();
+        if trimmed.starts_with("```") {
+            in_fence = !in_fence;
+            continue;
+        }
+        if in_fence {
+            lines.push(if line.is_empty() {
+                String::new()
+            } else {
+                format!("    [code]{}[/code]", line)
+            });
+            continue;
+        }
+
+        if let Some(heading) = markdown_heading(trimmed) {
+            lines.push(format!("[header]{}[/header]", markdown_inline(heading)));
+            continue;
+        }
+
+        let indent = &line[..line.len() - trimmed.len()];
+        if let Some(item) = ["- ", "* ", "+ "]
+            .iter()
+            .find_map(|marker| trimmed.strip_prefix(marker))
+        {
+            lines.push(format!("{}• {}", indent, markdown_inline(item)));
+            continue;
+        }
+
+        lines.push(markdown_inline(line));
+    }
+
+    lines.join("\n")
+}
+
+/// Returns the heading text if `line` is an ATX heading (`# Title`).
+fn markdown_heading(line: &str) -> Option<&str> {
+    let level = line.chars().take_while(|c| *c == '#').count();
+    if level == 0 || level > 6 {
+        return None;
+    }
+    let rest = &line[level..];
+    if rest.is_empty() {
+        return Some("");
+    }
+    rest.strip_prefix(' ')
+        .map(|text| text.trim().trim_end_matches('#').trim_end())
+}
+
+/// Converts inline code, emphasis and strong markers to style tags.
+///
+/// Unmatched markers are left as-is.
+fn markdown_inline(text: &str) -> String {
+    let mut out = String::with_capacity(text.len());
+    let mut rest = text;
+
+    while let Some(c) = rest.chars().next() {
+        let span = match c {
+            '`' => inline_span(rest, "`", "code"),
+            '*' if rest.starts_with("**") => inline_span(rest, "**", "strong"),
+            '_' if rest.starts_with("__") => inline_span(rest, "__", "strong"),
+            '*' => inline_span(rest, "*", "emphasis"),
+            _ => None,
+        };
+        match span {
+            Some((markup, consumed)) => {
+                out.push_str(&markup);
+                rest = &rest[consumed..];
+            }
+            None => {
+                out.push(c);
+                rest = &rest[c.len_utf8()..];
+            }
+        }
+    }
+
+    out
+}
+
+/// Matches a `delim`-wrapped span at the start of `text`.
+///
+/// Returns the markup and the number of bytes consumed. The span must be
+/// non-empty and must not start with whitespace.
+fn inline_span(text: &str, delim: &str, style: &str) -> Option<(String, usize)> {
+    let body = &text[delim.len()..];
+    if body.starts_with(char::is_whitespace) {
+        return None;
+    }
+    let end = body.find(delim).filter(|end| *end > 0)?;
+    let inner = &body[..end];
+    let inner = if style == "code" {
+        inner.to_string()
+    } else {
+        markdown_inline(inner)
+    };
+    Some((
+        format!("[{}]{}[/{}]", style, inner, style),
+        delim.len() * 2 + end,
+    ))
+}
+
 // ============================================================================
 // PAGER SUPPORT
 // ============================================================================
 
 /// Displays content through a pager.
 ///
-/// Tries pagers in this order:
-/// 1. `$PAGER` environment variable
-/// 2. `less`
-/// 3. `more`
+/// Tries the pagers returned by [`resolve_pager_candidates`] in order:
+/// 1. `$MANPAGER` environment variable
+/// 2. `$PAGER` environment variable
+/// 3. `less -R` (keeps ANSI colors)
+/// 4. `more`
 ///
-/// If all pagers fail, falls back to printing directly to stdout.
+/// When stdout is not a terminal, no pager is started and the content is
+/// printed directly. If a pager can't be started (e.g. it isn't installed)
+/// the next candidate is tried, and if all fail the content is printed
+/// directly to stdout.
 ///
 /// # Example
 ///
@@ -405,11 +789,11 @@ pub fn render_topics_list(
 /// display_with_pager(long_content).unwrap();
 /// ```
 pub fn display_with_pager(content: &str) -> std::io::Result<()> {
-    let pagers = get_pager_candidates();
-
-    for pager in pagers {
-        if try_pager(&pager, content).is_ok() {
-            return Ok(());
+    if std::io::stdout().is_terminal() {
+        for pager in get_pager_candidates() {
+            if try_pager(&pager, content).is_ok() {
+                return Ok(());
+            }
         }
     }
 
@@ -418,25 +802,61 @@ pub fn display_with_pager(content: &str) -> std::io::Result<()> {
     std::io::stdout().flush()
 }
 
-/// Returns the list of pager candidates to try.
-fn get_pager_candidates() -> Vec<String> {
-    let mut pagers = Vec::new();
-
-    if let Ok(pager) = std::env::var("PAGER") {
-        if !pager.is_empty() {
-            pagers.push(pager);
+/// Resolves the pager commands to try, in order, from the given environment.
+///
+/// `MANPAGER` and `PAGER` come first when set to a non-empty value, followed
+/// by `less -R` and `more`. Duplicates are dropped. Commands may include
+/// arguments, separated by whitespace.
+///
+/// # Example
+///
+/// ```rust
+/// use std::collections::HashMap;
+/// use standout::topics::resolve_pager_candidates;
+///
+/// let env = HashMap::from([("PAGER".to_string(), "most".to_string())]);
+/// assert_eq!(resolve_pager_candidates(&env), vec!["most", "less -R", "more"]);
+/// ```
+pub fn resolve_pager_candidates(env: &HashMap<String, String>) -> Vec<String> {
+    let mut pagers: Vec<String> = Vec::new();
+    let configured = ["MANPAGER", "PAGER"]
+        .iter()
+        .filter_map(|var| env.get(*var))
+        .map(|pager| pager.trim())
+        .filter(|pager| !pager.is_empty());
+    for pager in configured.chain(["less -R", "more"]) {
+        if !pagers.iter().any(|p| p == pager) {
+            pagers.push(pager.to_string());
         }
     }
-
-    pagers.push("less".to_string());
-    pagers.push("more".to_string());
-
     pagers
 }
 
+/// Returns the list of pager candidates to try, based on the process environment.
+fn get_pager_candidates() -> Vec<String> {
+    let env: HashMap<String, String> = ["MANPAGER", "PAGER"]
+        .iter()
+        .filter_map(|var| {
+            std::env::var(var)
+                .ok()
+                .map(|value| (var.to_string(), value))
+        })
+        .collect();
+    resolve_pager_candidates(&env)
+}
+
 /// Attempts to run content through a specific pager.
+///
+/// `pager` is split on whitespace into a program and its arguments.
 fn try_pager(pager: &str, content: &str) -> std::io::Result<()> {
-    let mut child = ProcessCommand::new(pager).stdin(Stdio::piped()).spawn()?;
+    let mut parts = pager.split_whitespace();
+    let program = parts
+        .next()
+        .ok_or_else(|| std::io::Error::other("empty pager command"))?;
+    let mut child = ProcessCommand::new(program)
+        .args(parts)
+        .stdin(Stdio::piped())
+        .spawn()?;
 
     if let Some(mut stdin) = child.stdin.take() {
         stdin.write_all(content.as_bytes())?;
@@ -495,6 +915,101 @@ mod tests {
         registry.add_topic(t2);
     }
 
+    #[test]
+    fn test_get_topic_resolves_aliases() {
+        let mut registry = TopicRegistry::new();
+        registry.add_topic(
+            Topic::new("Authentication", "Log in first", TopicType::Text, None)
+                .with_aliases(["auth", "login"]),
+        );
+
+        assert_eq!(registry.get_topic("auth").unwrap().name, "authentication");
+        assert_eq!(registry.get_topic("login").unwrap().name, "authentication");
+        assert!(registry.get_topic("credentials").is_none());
+    }
+
+    #[test]
+    #[should_panic(expected = "Alias 'auth' of topic 'tokens' is already an alias")]
+    fn test_alias_collision_panic() {
+        let mut registry = TopicRegistry::new();
+        registry.add_topic(
+            Topic::new("Authentication", "A", TopicType::Text, None).with_aliases(["auth"]),
+        );
+        registry.add_topic(Topic::new("Tokens", "B", TopicType::Text, None).with_aliases(["auth"]));
+    }
+
+    #[test]
+    #[should_panic(expected = "is already a topic name")]
+    fn test_alias_shadowing_name_panics() {
+        let mut registry = TopicRegistry::new();
+        registry.add_topic(Topic::new("Storage", "A", TopicType::Text, None));
+        registry
+            .add_topic(Topic::new("Files", "B", TopicType::Text, None).with_aliases(["storage"]));
+    }
+
+    #[test]
+    #[should_panic(expected = "is already an alias of topic 'authentication'")]
+    fn test_name_shadowing_alias_panics() {
+        let mut registry = TopicRegistry::new();
+        registry.add_topic(
+            Topic::new("Authentication", "A", TopicType::Text, None).with_aliases(["login"]),
+        );
+        registry.add_topic(Topic::new("Login", "B", TopicType::Text, None));
+    }
+
+    #[test]
+    fn test_split_front_matter() {
+        let (front_matter, rest) = split_front_matter(
+            "---\naliases: login, auth\ncategory: Security\nother: x\n---\nTitle\nBody",
+        )
+        .unwrap();
+        assert_eq!(
+            front_matter,
+            Some(FrontMatter {
+                aliases: vec!["login".to_string(), "auth".to_string()],
+                category: Some("Security".to_string()),
+            })
+        );
+        assert_eq!(rest, "Title\nBody");
+
+        let (front_matter, rest) = split_front_matter("Title\n---\nBody").unwrap();
+        assert!(front_matter.is_none());
+        assert_eq!(rest, "Title\n---\nBody");
+
+        assert!(split_front_matter("---\naliases: a\nTitle").is_none());
+    }
+
+    #[test]
+    fn test_load_from_dir_front_matter() {
+        let dir = tempdir().unwrap();
+        let mut f = File::create(dir.path().join("auth.md")).unwrap();
+        writeln!(
+            f,
+            "---\naliases: login, credentials\ncategory: Security\n---\nAuthentication\nUse a token."
+        )
+        .unwrap();
+
+        let mut registry = TopicRegistry::new();
+        registry.add_from_directory(dir.path()).unwrap();
+
+        let topic = registry.get_topic("credentials").unwrap();
+        assert_eq!(topic.name, "auth");
+        assert_eq!(topic.title, "Authentication");
+        assert_eq!(topic.content, "Use a token.");
+        assert_eq!(topic.category.as_deref(), Some("Security"));
+    }
+
+    #[test]
+    fn test_load_from_dir_unterminated_front_matter() {
+        let dir = tempdir().unwrap();
+        let mut f = File::create(dir.path().join("auth.md")).unwrap();
+        writeln!(f, "---\naliases: login\nAuthentication\nUse a token.").unwrap();
+
+        let mut registry = TopicRegistry::new();
+        let err = registry.add_from_directory(dir.path()).unwrap_err();
+        assert_eq!(err.kind(), std::io::ErrorKind::InvalidData);
+    }
+
     #[test]
     fn test_load_from_dir() {
         let dir = tempdir().unwrap();
@@ -595,6 +1110,75 @@ mod tests {
         assert!(output.contains("This is the content."));
     }
 
+    #[test]
+    fn test_render_markdown_topic_heading() {
+        let topic = Topic::new(
+            "Guide",
+            "# Getting Started\nRun `app init` to *begin*.",
+            TopicType::Markdown,
+            None,
+        );
+
+        let config = TopicRenderConfig {
+            output_mode: Some(crate::OutputMode::TermDebug),
+            ..Default::default()
+        };
+
+        let output = render_topic(&topic, Some(config)).unwrap();
+        assert!(
+            output.contains("[header]Getting Started[/header]"),
+            "{}",
+            output
+        );
+        assert!(!output.contains("# Getting Started"), "{}", output);
+        assert!(output.contains("[code]app init[/code]"), "{}", output);
+        assert!(output.contains("[emphasis]begin[/emphasis]"), "{}", output);
+    }
+
+    #[test]
+    fn test_render_text_topic_stays_verbatim() {
+        let topic = Topic::new(
+            "Notes",
+            "# not a heading\n- not a bullet",
+            TopicType::Text,
+            None,
+        );
+
+        let config = TopicRenderConfig {
+            output_mode: Some(crate::OutputMode::Text),
+            ..Default::default()
+        };
+
+        let output = render_topic(&topic, Some(config)).unwrap();
+        assert!(output.contains("# not a heading"));
+        assert!(output.contains("- not a bullet"));
+    }
+
+    #[test]
+    fn test_markdown_to_markup() {
+        let markup = markdown_to_markup(
+            "## Usage ##\n- first **bold**\n  * nested\n```\nlet x = 1;\n```\nsnake_case stays",
+        );
+        let lines: Vec<&str> = markup.lines().collect();
+        assert_eq!(
+            lines,
+            vec![
+                "[header]Usage[/header]",
+                "• first [strong]bold[/strong]",
+                "  • nested",
+                "    [code]let x = 1;[/code]",
+                "snake_case stays",
+            ]
+        );
+    }
+
+    #[test]
+    fn test_markdown_inline_unmatched_markers() {
+        assert_eq!(markdown_inline("2 * 3 = 6"), "2 * 3 = 6");
+        assert_eq!(markdown_inline("a `b"), "a `b");
+        assert_eq!(markdown_inline("**"), "**");
+    }
+
     #[test]
     fn test_render_topics_list_basic() {
         let mut registry = TopicRegistry::new();
@@ -623,12 +1207,131 @@ mod tests {
         assert!(output.contains("myapp help <topic>"));
     }
 
+    #[test]
+    fn test_search_ranks_title_hits_first() {
+        let mut registry = TopicRegistry::new();
+        registry.add_topic(Topic::new(
+            "Storage",
+            "Where config files live",
+            TopicType::Text,
+            None,
+        ));
+        registry.add_topic(Topic::new(
+            "Config Reference",
+            "All options",
+            TopicType::Text,
+            None,
+        ));
+        registry.add_topic(Topic::new("Syntax", "Note syntax", TopicType::Text, None));
+
+        let names: Vec<&str> = registry
+            .search("CONFIG")
+            .iter()
+            .map(|t| t.name.as_str())
+            .collect();
+        assert_eq!(names, vec!["config-reference", "storage"]);
+    }
+
+    #[test]
+    fn test_search_no_hits_or_empty_query() {
+        let mut registry = TopicRegistry::new();
+        registry.add_topic(Topic::new("Storage", "Data", TopicType::Text, None));
+
+        assert!(registry.search("missing").is_empty());
+        assert!(registry.search("  ").is_empty());
+    }
+
+    #[test]
+    fn test_render_topic_search_results() {
+        let mut registry = TopicRegistry::new();
+        registry.add_topic(Topic::new(
+            "Storage",
+            "Where data lives",
+            TopicType::Text,
+            None,
+        ));
+        registry.add_topic(Topic::new(
+            "Syntax",
+            "Format reference",
+            TopicType::Text,
+            None,
+        ));
+
+        let config = TopicRenderConfig {
+            output_mode: Some(crate::OutputMode::Text),
+            ..Default::default()
+        };
+
+        let hits = registry.search("data");
+        let output =
+            render_topic_search_results(&hits, "data", "myapp help", Some(config)).unwrap();
+        assert!(output.contains("Topics matching 'data'"));
+        assert!(output.contains("storage"));
+        assert!(!output.contains("syntax"));
+    }
+
+    #[test]
+    fn test_render_topics_list_groups_by_category() {
+        let mut registry = TopicRegistry::new();
+        registry.add_topic(Topic::new("Storage", "A", TopicType::Text, None));
+        registry
+            .add_topic(Topic::new("Tokens", "B", TopicType::Text, None).with_category("Security"));
+        registry.add_topic(
+            Topic::new("Authentication", "C", TopicType::Text, None).with_category("Security"),
+        );
+
+        let config = TopicRenderConfig {
+            output_mode: Some(crate::OutputMode::Text),
+            ..Default::default()
+        };
+
+        let output = render_topics_list(&registry, "myapp help", Some(config)).unwrap();
+        let topics_at = output.find("TOPICS").unwrap();
+        let security_at = output.find("SECURITY").unwrap();
+        let storage_at = output.find("storage").unwrap();
+        let auth_at = output.find("authentication").unwrap();
+        let tokens_at = output.find("tokens").unwrap();
+        assert!(topics_at < storage_at && storage_at < security_at);
+        assert!(security_at < auth_at && auth_at < tokens_at);
+    }
+
     #[test]
     fn test_get_pager_candidates_includes_defaults() {
         // Don't modify env vars (not thread-safe in tests)
         // Just verify the function always includes less and more
         let candidates = get_pager_candidates();
-        assert!(candidates.contains(&"less".to_string()));
+        assert!(candidates.contains(&"less -R".to_string()));
         assert!(candidates.contains(&"more".to_string()));
     }
+
+    fn env(vars: &[(&str, &str)]) -> HashMap<String, String> {
+        vars.iter()
+            .map(|(k, v)| (k.to_string(), v.to_string()))
+            .collect()
+    }
+
+    #[test]
+    fn test_resolve_pager_candidates_defaults() {
+        assert_eq!(resolve_pager_candidates(&env(&[])), vec!["less -R", "more"]);
+    }
+
+    #[test]
+    fn test_resolve_pager_candidates_manpager_before_pager() {
+        let candidates =
+            resolve_pager_candidates(&env(&[("PAGER", "most"), ("MANPAGER", "bat -p")]));
+        assert_eq!(candidates, vec!["bat -p", "most", "less -R", "more"]);
+    }
+
+    #[test]
+    fn test_resolve_pager_candidates_skips_empty_and_duplicates() {
+        let candidates =
+            resolve_pager_candidates(&env(&[("MANPAGER", "  "), ("PAGER", "less -R")]));
+        assert_eq!(candidates, vec!["less -R", "more"]);
+    }
+
+    #[test]
+    fn test_try_pager_missing_executable_errors() {
+        assert!(try_pager("standout-no-such-pager --flag", "content").is_err());
+        assert!(try_pager("   ", "content").is_err());
+    }
 }
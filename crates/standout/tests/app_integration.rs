@@ -1,6 +1,8 @@
 use clap::Command;
 use serde_json::json;
-use standout::cli::{App, HandlerResult, LocalApp, Output};
+use standout::cli::{
+    App, AppError, BinaryOutputPolicy, HandlerResult, HookError, Hooks, LocalApp, Output,
+};
 use std::cell::RefCell;
 use std::rc::Rc;
 
@@ -102,3 +104,134 @@ fn test_local_struct_handler() {
         panic!("Expected RunResult::Handled, got {:?}", result2);
     }
 }
+
+fn failing_app() -> LocalApp {
+    LocalApp::builder()
+        .command(
+            "ok",
+            |_m, _ctx| Ok(Output::Render(json!({"msg": "fine"}))),
+            "{{ msg }}",
+        )
+        .unwrap()
+        .command(
+            "fail",
+            |_m, _ctx| -> HandlerResult<serde_json::Value> { Err(anyhow::anyhow!("boom")) },
+            "",
+        )
+        .unwrap()
+        .command("hooked", |_m, _ctx| Ok(Output::Render(json!({}))), "")
+        .unwrap()
+        .hooks(
+            "hooked",
+            Hooks::new().pre_dispatch(|_m, _ctx| Err(HookError::pre_dispatch("denied"))),
+        )
+        .command(
+            "export",
+            |_m, _ctx| -> HandlerResult<serde_json::Value> {
+                Ok(Output::binary(
+                    b"bytes".to_vec(),
+                    "/nonexistent-dir/standout/export.bin",
+                ))
+            },
+            "",
+        )
+        .unwrap()
+        .binary_output(BinaryOutputPolicy::AlwaysFile)
+        .build()
+        .unwrap()
+}
+
+fn failing_cmd() -> Command {
+    Command::new("test")
+        .subcommand(Command::new("ok"))
+        .subcommand(Command::new("fail"))
+        .subcommand(Command::new("hooked"))
+        .subcommand(Command::new("export"))
+        .subcommand(Command::new("other"))
+}
+
+#[test]
+fn test_local_app_try_run_success_and_no_match() {
+    let app = failing_app();
+    assert!(app.try_run(failing_cmd(), ["test", "ok"]).unwrap());
+    assert!(!app.try_run(failing_cmd(), ["test", "other"]).unwrap());
+}
+
+#[test]
+fn test_local_app_try_run_returns_handler_error() {
+    let err = failing_app()
+        .try_run(failing_cmd(), ["test", "fail"])
+        .unwrap_err();
+
+    assert!(matches!(err, AppError::Handler(_)));
+    assert_eq!(err.to_string(), "Error: boom");
+    assert_eq!(err.exit_code(), 1);
+}
+
+#[test]
+fn test_local_app_try_run_returns_json_handler_error() {
+    let err = failing_app()
+        .try_run(failing_cmd(), ["test", "--output", "json-compact", "fail"])
+        .unwrap_err();
+
+    let value: serde_json::Value = serde_json::from_str(&err.to_string()).unwrap();
+    assert_eq!(value["error"], "boom");
+}
+
+#[test]
+fn test_local_app_try_run_returns_hook_error() {
+    let err = failing_app()
+        .try_run(failing_cmd(), ["test", "hooked"])
+        .unwrap_err();
+
+    assert!(matches!(err, AppError::Hook(_)));
+    assert!(err.to_string().contains("denied"), "{}", err);
+}
+
+#[test]
+fn test_local_app_try_run_returns_binary_write_error() {
+    let err = failing_app()
+        .try_run(failing_cmd(), ["test", "export"])
+        .unwrap_err();
+
+    match &err {
+        AppError::BinaryOutput { path, .. } => {
+            assert_eq!(
+                path.as_deref(),
+                Some("/nonexistent-dir/standout/export.bin")
+            );
+        }
+        other => panic!("expected BinaryOutput, got {:?}", other),
+    }
+    assert_eq!(err.exit_code(), 74);
+    assert!(std::error::Error::source(&err).is_some());
+}
+
+#[test]
+fn test_local_app_try_run_returns_usage_error() {
+    let err = failing_app()
+        .try_run(failing_cmd(), ["test", "--no-such-flag", "ok"])
+        .unwrap_err();
+
+    match &err {
+        AppError::Usage(e) => assert_eq!(e.kind(), clap::error::ErrorKind::UnknownArgument),
+        other => panic!("expected Usage, got {:?}", other),
+    }
+    assert_eq!(err.exit_code(), 2);
+    assert!(err.to_string().contains("--no-such-flag"), "{}", err);
+}
+
+#[test]
+fn test_local_app_try_run_help_is_not_an_error() {
+    assert!(failing_app()
+        .try_run(failing_cmd(), ["test", "--help"])
+        .unwrap());
+}
+
+#[test]
+fn test_local_app_run_stays_infallible() {
+    let app = failing_app();
+    assert!(app.run(failing_cmd(), ["test", "fail"]));
+    assert!(app.run(failing_cmd(), ["test", "export"]));
+    assert!(app.run(failing_cmd(), ["test", "--no-such-flag", "ok"]));
+}
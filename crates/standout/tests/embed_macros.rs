@@ -68,14 +68,112 @@ fn test_embed_templates_names() {
     assert!(names.contains(&"nested/report.jinja"));
 }
 
+// =============================================================================
+// Template subset (include/exclude) tests
+// =============================================================================
+
+fn embedded_names(source: &standout::EmbeddedTemplates) -> Vec<&'static str> {
+    source.entries().iter().map(|(name, _)| *name).collect()
+}
+
+#[test]
+fn test_embed_templates_include() {
+    let source = embed_templates!("tests/fixtures/filtered_templates", include = ["report/**"]);
+
+    assert!(source.is_filtered());
+    assert_eq!(
+        embedded_names(&source),
+        vec![
+            "report/_header.jinja",
+            "report/detail.txt",
+            "report/summary.jinja"
+        ]
+    );
+
+    let templates: TemplateRegistry = source.into();
+    assert!(templates.get("report/summary").is_ok());
+    assert!(templates.get("list").is_err());
+}
+
+#[test]
+fn test_embed_templates_exclude() {
+    let source = embed_templates!("tests/fixtures/filtered_templates", exclude = ["**/_*"]);
+
+    assert_eq!(
+        embedded_names(&source),
+        vec!["list.jinja", "report/detail.txt", "report/summary.jinja"]
+    );
+
+    let templates: TemplateRegistry = source.into();
+    assert!(templates.get("list").is_ok());
+    assert!(templates.get("_base").is_err());
+    assert!(templates.get("report/_header").is_err());
+}
+
+#[test]
+fn test_embed_templates_exclude_wins_over_include() {
+    let source = embed_templates!(
+        "tests/fixtures/filtered_templates",
+        include = ["report/**", "_base"],
+        exclude = ["**/_*"],
+    );
+
+    assert_eq!(
+        embedded_names(&source),
+        vec!["report/detail.txt", "report/summary.jinja"]
+    );
+
+    // Hot-reload (debug builds) stays within the embedded subset
+    let templates: TemplateRegistry = source.into();
+    let mut names: Vec<&str> = templates.names().collect();
+    names.sort_unstable();
+    assert_eq!(
+        names,
+        vec![
+            "report/detail",
+            "report/detail.txt",
+            "report/summary",
+            "report/summary.jinja"
+        ]
+    );
+}
+
+#[test]
+fn test_embed_templates_unfiltered_is_not_marked() {
+    let source = embed_templates!("tests/fixtures/filtered_templates");
+    assert!(!source.is_filtered());
+    assert_eq!(source.entries().len(), 5);
+}
+
 // =============================================================================
 // Stylesheet embedding tests
 // =============================================================================
 
+#[test]
+fn test_embed_templates_trim_trailing_newline() {
+    let raw: TemplateRegistry = embed_templates!("tests/fixtures/trimmed_templates").into();
+    let source = embed_templates!(
+        "tests/fixtures/trimmed_templates",
+        trim_trailing_newline = true
+    );
+    assert!(source.trims_trailing_newline());
+    let trimmed: TemplateRegistry = source.into();
+
+    // Raw content is kept by default
+    assert_eq!(
+        raw.get_content("banner").unwrap(),
+        "Welcome, {{ name }}!\n\n"
+    );
+    assert_eq!(
+        trimmed.get_content("banner").unwrap(),
+        "Welcome, {{ name }}!"
+    );
+}
+
 #[test]
 fn test_embed_styles_simple() {
     // embed_styles! returns EmbeddedStyles, convert to StylesheetRegistry
-    let mut styles: StylesheetRegistry = embed_styles!("tests/fixtures/styles").into();
+    let styles: StylesheetRegistry = embed_styles!("tests/fixtures/styles").into();
 
     // Should be able to get the default stylesheet by base name
     let theme = styles.get("default").expect("default style should exist");
@@ -86,7 +184,7 @@ fn test_embed_styles_simple() {
 
 #[test]
 fn test_embed_styles_with_extension() {
-    let mut styles: StylesheetRegistry = embed_styles!("tests/fixtures/styles").into();
+    let styles: StylesheetRegistry = embed_styles!("tests/fixtures/styles").into();
 
     // Should also be able to access by full name with extension
     let theme = styles
@@ -98,7 +196,7 @@ fn test_embed_styles_with_extension() {
 
 #[test]
 fn test_embed_styles_nested() {
-    let mut styles: StylesheetRegistry = embed_styles!("tests/fixtures/styles").into();
+    let styles: StylesheetRegistry = embed_styles!("tests/fixtures/styles").into();
 
     // Should be able to get nested stylesheets
     let theme = styles
@@ -141,7 +239,7 @@ fn test_embed_templates_extension_priority() {
 fn test_embed_styles_extension_priority() {
     // Similar test for stylesheets
     // .yaml has higher priority than .yml
-    let mut styles: StylesheetRegistry = embed_styles!("tests/fixtures/styles").into();
+    let styles: StylesheetRegistry = embed_styles!("tests/fixtures/styles").into();
     assert!(styles.get("default").is_ok());
 }
 
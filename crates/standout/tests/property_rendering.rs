@@ -7,7 +7,7 @@ use standout::cli::{App, Local, Output, ThreadSafe};
 use standout::{OutputMode, Theme};
 
 // Strategy for generating arbitrary OutputMode values
-// Per design guidelines: all 8 output modes must be covered
+// Per design guidelines: all 11 output modes must be covered
 fn output_mode_strategy() -> impl Strategy<Value = OutputMode> {
     prop_oneof![
         Just(OutputMode::Auto),
@@ -15,7 +15,10 @@ fn output_mode_strategy() -> impl Strategy<Value = OutputMode> {
         Just(OutputMode::Text),
         Just(OutputMode::TermDebug),
         Just(OutputMode::Json),
+        Just(OutputMode::JsonCompact),
+        Just(OutputMode::JsonLines),
         Just(OutputMode::Yaml),
+        Just(OutputMode::Toml),
         Just(OutputMode::Xml),
         Just(OutputMode::Csv),
     ]
@@ -82,6 +85,29 @@ fn validate_structured_output(output: &str, mode: OutputMode) {
                 output
             );
         }
+        OutputMode::JsonCompact => {
+            assert!(
+                !output.contains('\n'),
+                "Compact JSON should be a single line: {}",
+                output
+            );
+            let parsed: Result<Value, _> = serde_json::from_str(output);
+            assert!(
+                parsed.is_ok(),
+                "JSON output should be parseable: {}",
+                output
+            );
+        }
+        OutputMode::JsonLines => {
+            for line in output.lines() {
+                let parsed: Result<Value, _> = serde_json::from_str(line);
+                assert!(
+                    parsed.is_ok(),
+                    "Each JSON Lines line should be parseable: {}",
+                    line
+                );
+            }
+        }
         OutputMode::Yaml => {
             let parsed: Result<Value, _> = serde_yaml::from_str(output);
             assert!(
@@ -90,7 +116,8 @@ fn validate_structured_output(output: &str, mode: OutputMode) {
                 output
             );
         }
-        // XML and CSV are harder to validate generically, but we verify no panic
+        // TOML (which rejects non-table data), XML and CSV are harder to
+        // validate generically, but we verify no panic
         _ => {}
     }
 }
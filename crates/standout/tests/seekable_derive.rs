@@ -324,6 +324,7 @@ fn test_rename_constants() {
 // =============================================================================
 
 #[derive(DeriveSeekable, Clone, Debug)]
+#[seek(query)]
 struct QueryableTask {
     #[seek(String)]
     name: String,
@@ -744,3 +745,305 @@ fn test_parse_query_invalid_operator_error() {
     let result = parse_query::<BasicTask>(pairs);
     assert!(result.is_err());
 }
+
+// =============================================================================
+// Nested (flattened) field tests
+// =============================================================================
+
+#[derive(DeriveSeekable)]
+struct Author {
+    #[seek(String)]
+    name: String,
+
+    #[seek(Timestamp, rename = "joined-at")]
+    joined_at: i64,
+}
+
+#[derive(DeriveSeekable)]
+struct Post {
+    #[seek(String)]
+    title: String,
+
+    #[seek(flatten)]
+    author: Author,
+
+    #[seek(flatten, rename = "editor")]
+    reviewed_by: Author,
+}
+
+fn sample_posts() -> Vec<Post> {
+    vec![
+        Post {
+            title: "Intro".to_string(),
+            author: Author {
+                name: "Ada".to_string(),
+                joined_at: 1000,
+            },
+            reviewed_by: Author {
+                name: "Grace".to_string(),
+                joined_at: 500,
+            },
+        },
+        Post {
+            title: "Follow-up".to_string(),
+            author: Author {
+                name: "Linus".to_string(),
+                joined_at: 3000,
+            },
+            reviewed_by: Author {
+                name: "Ada".to_string(),
+                joined_at: 1000,
+            },
+        },
+    ]
+}
+
+#[test]
+fn test_flatten_field_values() {
+    let post = &sample_posts()[0];
+    assert_eq!(post.seeker_field_value("author.name"), Value::String("Ada"));
+    assert_eq!(
+        post.seeker_field_value("editor.joined-at"),
+        Value::Timestamp(Timestamp(500))
+    );
+    assert_eq!(post.seeker_field_value("author"), Value::None);
+    assert_eq!(post.seeker_field_value("author.missing"), Value::None);
+}
+
+#[test]
+fn test_flatten_schema() {
+    assert_eq!(Post::field_type("author.name"), Some(SeekType::String));
+    assert_eq!(
+        Post::field_type("editor.joined-at"),
+        Some(SeekType::Timestamp)
+    );
+    assert_eq!(Post::field_type("reviewed_by.name"), None);
+    assert_eq!(
+        Post::field_names(),
+        &[
+            "title",
+            "author.name",
+            "author.joined-at",
+            "editor.name",
+            "editor.joined-at"
+        ]
+    );
+}
+
+#[test]
+fn test_flatten_parse_query() {
+    let pairs = vec![
+        ("author.name-startswith".to_string(), "L".to_string()),
+        ("OR".to_string(), String::new()),
+        ("editor.joined-at-before".to_string(), "800".to_string()),
+    ];
+    let query = parse_query::<Post>(pairs).unwrap();
+
+    let posts = sample_posts();
+    let results = query.filter(&posts, Post::accessor);
+    assert_eq!(results.len(), 0);
+
+    let pairs = vec![("editor.name".to_string(), "Ada".to_string())];
+    let query = parse_query::<Post>(pairs).unwrap();
+    let results = query.filter(&posts, Post::accessor);
+    assert_eq!(results.len(), 1);
+    assert_eq!(results[0].title, "Follow-up");
+}
+
+// =============================================================================
+// Typed query builder tests
+// =============================================================================
+
+#[test]
+fn test_typed_query_builder_and_clauses() {
+    let tasks = sample_tasks();
+
+    let query = QueryableTaskQuery::new()
+        .priority_gte(4)
+        .done_is(false)
+        .build();
+
+    let results = query.filter(&tasks, QueryableTask::accessor);
+    assert_eq!(results.len(), 1);
+    assert_eq!(results[0].name, "Review PR");
+}
+
+#[test]
+fn test_typed_query_builder_string_and_enum() {
+    let tasks = sample_tasks();
+
+    let query = QueryableTaskQuery::new()
+        .or_name_contains("docs")
+        .or_status_in([Status::Completed])
+        .not_status_eq(Status::Pending)
+        .order_desc(QueryableTask::PRIORITY)
+        .build();
+
+    let results = query.filter(&tasks, QueryableTask::accessor);
+    let names: Vec<&str> = results.iter().map(|t| t.name.as_str()).collect();
+    assert_eq!(names, vec!["Fix bug", "Write docs"]);
+}
+
+#[test]
+fn test_typed_query_builder_negated_operators() {
+    let tasks = sample_tasks();
+
+    let query = QueryableTaskQuery::new()
+        .status_notin([Status::Completed])
+        .name_notcontains("docs")
+        .build();
+    let results = query.filter(&tasks, QueryableTask::accessor);
+    assert!(results
+        .iter()
+        .all(|t| t.status != Status::Completed && !t.name.contains("docs")));
+
+    let stringly = parse_query::<QueryableTask>(vec![
+        ("status-notin".to_string(), "2".to_string()),
+        ("name-!contains".to_string(), "docs".to_string()),
+    ])
+    .unwrap();
+    assert_eq!(
+        stringly.count(&tasks, QueryableTask::accessor),
+        results.len()
+    );
+}
+
+#[test]
+fn test_typed_query_builder_regex() {
+    let tasks = sample_tasks();
+
+    let query = QueryableTaskQuery::new().name_regex("^F").unwrap().build();
+    let results = query.filter(&tasks, QueryableTask::accessor);
+    assert_eq!(results.len(), 1);
+
+    assert!(QueryableTaskQuery::new().name_regex("[").is_err());
+}
+
+#[test]
+fn test_typed_query_builder_matches_stringly_query() {
+    let typed: Query = QueryableTaskQuery::new()
+        .priority_lt(5)
+        .limit(1)
+        .offset(1)
+        .into();
+    let stringly = Query::new()
+        .and_lt(QueryableTask::PRIORITY, 5i32)
+        .limit(1)
+        .offset(1)
+        .build();
+
+    let tasks = sample_tasks();
+    let a: Vec<&str> = typed
+        .filter(&tasks, QueryableTask::accessor)
+        .iter()
+        .map(|t| t.name.as_str())
+        .collect();
+    let b: Vec<&str> = stringly
+        .filter(&tasks, QueryableTask::accessor)
+        .iter()
+        .map(|t| t.name.as_str())
+        .collect();
+    assert_eq!(a, b);
+}
+
+// =============================================================================
+// Optional field tests
+// =============================================================================
+
+#[derive(DeriveSeekable, Clone, Debug)]
+#[seek(query)]
+struct Ticket {
+    #[seek(String)]
+    title: String,
+
+    #[seek(String)]
+    assignee: Option<String>,
+
+    #[seek(Number)]
+    estimate: Option<u32>,
+
+    #[seek(Timestamp, rename = "due-at")]
+    due_at: Option<i64>,
+
+    #[seek(Enum)]
+    status: Option<Status>,
+}
+
+fn sample_tickets() -> Vec<Ticket> {
+    vec![
+        Ticket {
+            title: "Triage".to_string(),
+            assignee: None,
+            estimate: None,
+            due_at: None,
+            status: None,
+        },
+        Ticket {
+            title: "Ship".to_string(),
+            assignee: Some("ana".to_string()),
+            estimate: Some(3),
+            due_at: Some(5000),
+            status: Some(Status::Active),
+        },
+    ]
+}
+
+#[test]
+fn test_optional_field_values() {
+    let tickets = sample_tickets();
+
+    assert_eq!(tickets[0].seeker_field_value("assignee"), Value::None);
+    assert_eq!(tickets[0].seeker_field_value("estimate"), Value::None);
+    assert_eq!(tickets[0].seeker_field_value("due-at"), Value::None);
+    assert_eq!(tickets[0].seeker_field_value("status"), Value::None);
+
+    assert_eq!(
+        tickets[1].seeker_field_value("assignee"),
+        Value::String("ana")
+    );
+    assert_eq!(
+        tickets[1].seeker_field_value("due-at"),
+        Value::Timestamp(Timestamp(5000))
+    );
+    assert_eq!(tickets[1].seeker_field_value("status"), Value::Enum(1));
+    assert_eq!(Ticket::field_type("estimate"), Some(SeekType::Number));
+}
+
+#[test]
+fn test_optional_field_null_queries() {
+    let tickets = sample_tickets();
+
+    let unassigned = Query::new().and_is_null(Ticket::ASSIGNEE).build();
+    let results = unassigned.filter(&tickets, Ticket::accessor);
+    assert_eq!(results.len(), 1);
+    assert_eq!(results[0].title, "Triage");
+
+    let pairs = vec![("due-at-isnotnull".to_string(), String::new())];
+    let scheduled = parse_query::<Ticket>(pairs).unwrap();
+    let results = scheduled.filter(&tickets, Ticket::accessor);
+    assert_eq!(results.len(), 1);
+    assert_eq!(results[0].title, "Ship");
+
+    // Comparisons never match a missing value
+    let small = Query::new().and_lt(Ticket::ESTIMATE, 10u32).build();
+    assert_eq!(small.filter(&tickets, Ticket::accessor).len(), 1);
+}
+
+#[test]
+fn test_typed_query_builder_optional_fields() {
+    let tickets = sample_tickets();
+
+    let query = TicketQuery::new()
+        .status_eq(Status::Active)
+        .estimate_isnotnull()
+        .not_assignee_isnull()
+        .build();
+    let results = query.filter(&tickets, Ticket::accessor);
+    assert_eq!(results.len(), 1);
+    assert_eq!(results[0].title, "Ship");
+
+    let query = TicketQuery::new().or_estimate_isnull().build();
+    let results = query.filter(&tickets, Ticket::accessor);
+    assert_eq!(results.len(), 1);
+    assert_eq!(results[0].title, "Triage");
+}